@@ -2,7 +2,11 @@ import { GeneratedEntrypoint, GeneratorContext } from "@project-gauntlet/api/hel
 import { walk, WalkOptions } from "@std/fs/walk";
 import { debounce } from "@std/async/debounce";
 import { current_os, wayland } from "gauntlet:bridge/internal-all";
-import { linux_app_from_path, linux_application_dirs, linux_open_application, } from "gauntlet:bridge/internal-linux";
+import {
+    linux_app_from_path,
+    linux_application_dirs,
+    linux_open_application_action,
+} from "gauntlet:bridge/internal-linux";
 import {
     macos_app_from_arbitrary_path,
     macos_app_from_path,
@@ -16,35 +20,63 @@ import {
     macos_settings_pre_13,
     macos_system_applications
 } from "gauntlet:bridge/internal-macos";
-import { applicationAccessories, applicationActions } from "./window/shared";
+import {
+    applicationAccessories,
+    applicationActions,
+    desktopActions,
+    desktopFilePaths,
+    linuxLaunchOptions,
+    openLinuxApplication,
+    setLinuxLaunchOptions
+} from "./window/shared";
 import { applicationEventLoopX11, focusX11Window } from "./window/x11";
 import { applicationEventLoopWayland, focusWaylandWindow } from "./window/wayland";
 import { windows_app_from_path, windows_application_dirs, windows_open_application } from "gauntlet:bridge/internal-windows";
 
-type EntrypointPreferences = { experimentalWindowTracking: boolean, bundleNameLang: "default" | "localized" };
+type EntrypointPreferences = {
+    experimentalWindowTracking: boolean,
+    bundleNameLang: "default" | "localized",
+    linuxTerminal: string,
+    linuxSystemdScope: boolean,
+    linuxIconTheme: string
+};
 
 export default async function Applications(context: GeneratorContext<object, EntrypointPreferences>): Promise<void | (() => void)> {
-    const { add, remove, get, getAll, entrypointPreferences: { experimentalWindowTracking, bundleNameLang } } = context;
+    const { add, remove, get, getAll, entrypointPreferences: { experimentalWindowTracking, bundleNameLang, linuxTerminal, linuxSystemdScope, linuxIconTheme } } = context;
 
     switch (current_os()) {
         case "linux": {
+            setLinuxLaunchOptions({
+                terminal: linuxTerminal || undefined,
+                systemd_scope: linuxSystemdScope
+            })
+
             const cleanup = await genericGenerator<LinuxDesktopApplicationData>(
                 linux_application_dirs(),
-                path => linux_app_from_path(path),
+                path => linux_app_from_path(path, linuxIconTheme || undefined),
                 (id, data) => {
+                    desktopFilePaths()[id] = data.desktop_file_path
+
+                    desktopActions()[id] = data.actions.map(action => ({
+                        label: action.name,
+                        run: () => {
+                            linux_open_application_action(id, data.desktop_file_path, action.id, linuxLaunchOptions())
+                                .catch(err => console.error(`Unable to run action ${action.id} of application ${id}`, err))
+                        },
+                    }))
+
                     if (wayland()) {
                         return {
                             name: data.name,
                             actions: applicationActions(
                                 id,
                                 experimentalWindowTracking,
-                                () => {
-                                    linux_open_application(id)
-                                },
+                                openLinuxApplication(id),
                                 focusWaylandWindow,
                             ),
                             accessories: applicationAccessories(id, experimentalWindowTracking),
                             icon: data.icon, // TODO lazy icons
+                            keywords: data.keywords,
                             "__linux__": {
                                 startupWmClass: data.startup_wm_class,
                                 desktopFilePath: data.desktop_file_path
@@ -56,13 +88,12 @@ export default async function Applications(context: GeneratorContext<object, Ent
                             actions: applicationActions(
                                 id,
                                 experimentalWindowTracking,
-                                () => {
-                                    linux_open_application(id)
-                                },
+                                openLinuxApplication(id),
                                 focusX11Window,
                             ),
                             accessories: applicationAccessories(id, experimentalWindowTracking),
                             icon: data.icon, // TODO lazy icons
+                            keywords: data.keywords,
                             "__linux__": {
                                 startupWmClass: data.startup_wm_class,
                                 desktopFilePath: data.desktop_file_path
@@ -71,7 +102,11 @@ export default async function Applications(context: GeneratorContext<object, Ent
                     }
                 },
                 add,
-                remove,
+                id => {
+                    delete desktopFilePaths()[id]
+                    delete desktopActions()[id]
+                    remove(id)
+                },
             );
 
             if (experimentalWindowTracking) {
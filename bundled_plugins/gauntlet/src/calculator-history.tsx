@@ -0,0 +1,115 @@
+import { Action, ActionPanel, List } from "@project-gauntlet/api/components";
+import React, { ReactElement, useState } from "react";
+import { Clipboard, PasteAction, showHud } from "@project-gauntlet/api/helpers";
+import { clear_numbat_history, numbat_history, run_numbat, save_numbat } from "gauntlet:bridge/internal-all";
+
+const PREVIEW_ID = "preview";
+
+export default function CalculatorHistory(): ReactElement {
+    const [input, setInput] = useState<string>("");
+    const [history, setHistory] = useState<NumbatHistoryEntry[]>(() => numbat_history());
+
+    let preview: { left: string, right: string } | undefined;
+    try {
+        preview = input.trim().length > 0 ? run_numbat(input) : undefined;
+    } catch (e) {
+        preview = undefined;
+    }
+
+    const save = () => {
+        const result = save_numbat(input);
+        setHistory(numbat_history());
+        setInput("");
+        return result
+    }
+
+    return (
+        <List
+            actions={
+                <ActionPanel>
+                    <Action
+                        label="Copy result"
+                        onAction={async id => {
+                            if (id === PREVIEW_ID) {
+                                const { right } = save();
+                                await Clipboard.writeText(right)
+                                showHud("Result copied")
+                            } else if (id) {
+                                const entry = history[Number(id)];
+                                if (entry) {
+                                    await Clipboard.writeText(entry.right)
+                                    showHud("Result copied")
+                                }
+                            }
+                        }}
+                    />
+                    <PasteAction
+                        label="Paste result"
+                        text={id => {
+                            if (id === PREVIEW_ID) {
+                                return save().right
+                            }
+
+                            return id ? history[Number(id)]?.right : undefined
+                        }}
+                    />
+                    <Action
+                        label="Save to history"
+                        onAction={id => {
+                            if (id === PREVIEW_ID) {
+                                save()
+                            }
+                        }}
+                    />
+                    <Action
+                        label="Edit expression"
+                        onAction={id => {
+                            if (id && id !== PREVIEW_ID) {
+                                const entry = history[Number(id)];
+                                if (entry) {
+                                    setInput(entry.input)
+                                }
+                            }
+                        }}
+                    />
+                    <Action
+                        label="Clear history"
+                        onAction={() => {
+                            clear_numbat_history()
+                            setHistory([])
+                        }}
+                    />
+                </ActionPanel>
+            }
+        >
+            <List.SearchBar
+                value={input}
+                placeholder="Expression, e.g. 5 km/h to m/s or let x = 2 ans"
+                onChange={setInput}
+            />
+            {
+                preview && (
+                    <List.Item id={PREVIEW_ID} title={preview.right} subtitle={preview.left}/>
+                )
+            }
+            {
+                history.length == 0 && !preview
+                    ? (
+                        <List.EmptyView
+                            title="No calculations yet"
+                            description="Results are saved when copied or saved from calculator"
+                        />
+                    )
+                    : (
+                        <List.Section title="History">
+                            {
+                                history.map((entry, index) => (
+                                    <List.Item key={index} id={String(index)} title={entry.right} subtitle={entry.left}/>
+                                ))
+                            }
+                        </List.Section>
+                    )
+            }
+        </List>
+    )
+}
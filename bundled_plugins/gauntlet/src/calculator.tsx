@@ -1,7 +1,7 @@
 import { Action, ActionPanel, Content, Icons, Inline } from "@project-gauntlet/api/components";
 import { ReactNode } from "react";
-import { Clipboard, showHud } from "@project-gauntlet/api/helpers";
-import { run_numbat } from "gauntlet:bridge/internal-all";
+import { Clipboard, PasteAction, showHud } from "@project-gauntlet/api/helpers";
+import { run_numbat, save_numbat } from "gauntlet:bridge/internal-all";
 
 export default function Calculator(props: { text: string }): ReactNode | undefined {
     const text = props.text;
@@ -33,10 +33,25 @@ export default function Calculator(props: { text: string }): ReactNode | undefin
                     <Action
                         label={"Copy result"}
                         onAction={async () => {
+                            save_numbat(text)
                             await Clipboard.writeText(right)
                             showHud("Result copied")
                         }}
                     />
+                    <PasteAction
+                        label={"Paste result"}
+                        text={() => {
+                            save_numbat(text)
+                            return right
+                        }}
+                    />
+                    <Action
+                        label={"Save to history"}
+                        onAction={() => {
+                            save_numbat(text)
+                            showHud("Result saved")
+                        }}
+                    />
                 </ActionPanel>
             }
         >
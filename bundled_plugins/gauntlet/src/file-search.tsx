@@ -0,0 +1,112 @@
+import { Action, ActionPanel, Icons, List } from "@project-gauntlet/api/components";
+import React, { ReactElement, useEffect, useState } from "react";
+import { Clipboard, showHud } from "@project-gauntlet/api/helpers";
+import { useEntrypointPreferences } from "@project-gauntlet/api/hooks";
+import {
+    file_search,
+    file_search_configure,
+    file_search_open,
+    file_search_reveal,
+    file_search_status
+} from "gauntlet:bridge/internal-all";
+
+const RESULT_LIMIT = 50;
+
+type FileSearchPreferences = {
+    roots: string,
+    ignore: string,
+}
+
+function splitList(value: string): string[] {
+    return value.split(";")
+        .map(item => item.trim())
+        .filter(item => item.length > 0)
+}
+
+export default function FileSearch(props: { searchText?: string }): ReactElement {
+    const { roots, ignore } = useEntrypointPreferences<FileSearchPreferences>();
+
+    const [query, setQuery] = useState<string>(props.searchText ?? "");
+    const [status, setStatus] = useState<FileSearchStatus>(() => file_search_status());
+
+    useEffect(() => {
+        file_search_configure(splitList(roots), splitList(ignore));
+        setStatus(file_search_status())
+    }, [roots, ignore]);
+
+    useEffect(() => {
+        if (!status.indexing) {
+            return
+        }
+
+        // results and progress are refreshed until initial indexing is finished
+        const interval = setInterval(() => setStatus(file_search_status()), 1000);
+
+        return () => clearInterval(interval)
+    }, [status.indexing]);
+
+    const results = file_search(query, RESULT_LIMIT);
+
+    return (
+        <List
+            isLoading={status.indexing}
+            actions={
+                <ActionPanel>
+                    <Action
+                        label="Open"
+                        onAction={id => {
+                            if (id) {
+                                file_search_open(id)
+                            }
+                        }}
+                    />
+                    <Action
+                        label="Reveal in folder"
+                        onAction={id => {
+                            if (id) {
+                                file_search_reveal(id)
+                            }
+                        }}
+                    />
+                    <Action
+                        label="Copy path"
+                        onAction={async id => {
+                            if (id) {
+                                await Clipboard.writeText(id)
+                                showHud("Path copied")
+                            }
+                        }}
+                    />
+                </ActionPanel>
+            }
+        >
+            <List.SearchBar
+                value={query}
+                placeholder="Search files by name or path"
+                onChange={setQuery}
+            />
+            {
+                results.length == 0
+                    ? (
+                        <List.EmptyView
+                            title={query.trim().length == 0 ? "Type to search files" : "No files found"}
+                            description={
+                                status.indexing
+                                    ? `Indexing, ${status.indexedFiles} files indexed so far`
+                                    : `${status.indexedFiles} files indexed`
+                            }
+                        />
+                    )
+                    : results.map(item => (
+                        <List.Item
+                            key={item.path}
+                            id={item.path}
+                            title={item.name}
+                            subtitle={item.path}
+                            icon={item.directory ? Icons.Folder : Icons.Document}
+                        />
+                    ))
+            }
+        </List>
+    )
+}
@@ -7,9 +7,11 @@ import { linux_open_application } from "gauntlet:bridge/internal-linux";
 import { useState } from "react";
 import { Action, ActionPanel, List } from "@project-gauntlet/api/components";
 
-export function ListOfWindows({ windows, focusWindow, focusSecond }: {
+export function ListOfWindows({ windows, focusWindow, windowAction, workspaces, focusSecond }: {
     windows: Record<string, OpenWindowData>,
     focusWindow: (windowId: string) => void,
+    windowAction?: (windowId: string, action: LinuxWindowAction) => void,
+    workspaces?: LinuxWorkspace[],
     focusSecond: boolean
 }) {
     const knownWindows = readWindowOrder();
@@ -34,6 +36,74 @@ export function ListOfWindows({ windows, focusWindow, focusSecond }: {
                             }
                         }}
                     />
+                    {
+                        windowAction && (
+                            <ActionPanel.Section>
+                                <Action
+                                    label="Close window"
+                                    onAction={id => {
+                                        if (id) {
+                                            windowAction(id, { type: "Close" })
+                                        }
+                                    }}
+                                />
+                                <Action
+                                    label="Close all windows of application"
+                                    onAction={id => {
+                                        const window = id ? windows[id] : undefined;
+                                        if (window) {
+                                            Object.values(windows)
+                                                .filter(other => other.appId == window.appId)
+                                                .forEach(other => windowAction(other.id, { type: "Close" }))
+                                        }
+                                    }}
+                                />
+                                <Action
+                                    label="Minimize window"
+                                    onAction={id => {
+                                        if (id) {
+                                            windowAction(id, { type: "Minimize" })
+                                        }
+                                    }}
+                                />
+                                <Action
+                                    label="Toggle maximize"
+                                    onAction={id => {
+                                        if (id) {
+                                            windowAction(id, { type: "ToggleMaximize" })
+                                        }
+                                    }}
+                                />
+                                <Action
+                                    label="Toggle fullscreen"
+                                    onAction={id => {
+                                        if (id) {
+                                            windowAction(id, { type: "ToggleFullscreen" })
+                                        }
+                                    }}
+                                />
+                            </ActionPanel.Section>
+                        )
+                    }
+                    {
+                        windowAction && workspaces && workspaces.length > 0 && (
+                            <ActionPanel.Section title="Move to workspace">
+                                {
+                                    workspaces.map(workspace => (
+                                        <Action
+                                            key={workspace.id}
+                                            label={`Move to ${workspace.name}`}
+                                            onAction={id => {
+                                                if (id) {
+                                                    windowAction(id, { type: "MoveToWorkspace", workspace: workspace.id })
+                                                }
+                                            }}
+                                        />
+                                    ))
+                                }
+                            </ActionPanel.Section>
+                        )
+                    }
                 </ActionPanel>
             }
             onItemFocusChange={setId}
@@ -60,11 +130,37 @@ export function openWindows(): Record<string, OpenWindowData> {
     return (globalThis as any).__openWindows
 }
 
+export function desktopActions(): Record<string, GeneratedEntrypointAction[]> {
+    if ((globalThis as any).__desktopActions == undefined) {
+        (globalThis as any).__desktopActions = {}
+    }
+    return (globalThis as any).__desktopActions
+}
+
+export function desktopFilePaths(): Record<string, string> {
+    if ((globalThis as any).__desktopFilePaths == undefined) {
+        (globalThis as any).__desktopFilePaths = {}
+    }
+    return (globalThis as any).__desktopFilePaths
+}
+
 export function applicationActions(
     id: string,
     experimentalWindowTracking: boolean,
     openApplication: () => void,
     focusWindow: (windowId: string) => void,
+): GeneratedEntrypointAction[] {
+    return [
+        ...windowActions(id, experimentalWindowTracking, openApplication, focusWindow),
+        ...(desktopActions()[id] || [])
+    ]
+}
+
+function windowActions(
+    id: string,
+    experimentalWindowTracking: boolean,
+    openApplication: () => void,
+    focusWindow: (windowId: string) => void,
 ): GeneratedEntrypointAction[] {
     if (!experimentalWindowTracking) {
         return [
@@ -211,9 +307,27 @@ export function deleteOpenWindow(
     }
 }
 
+export function linuxLaunchOptions(): LinuxLaunchOptions {
+    if ((globalThis as any).__linuxLaunchOptions == undefined) {
+        (globalThis as any).__linuxLaunchOptions = { terminal: undefined, systemd_scope: false }
+    }
+    return (globalThis as any).__linuxLaunchOptions
+}
+
+export function setLinuxLaunchOptions(options: LinuxLaunchOptions) {
+    (globalThis as any).__linuxLaunchOptions = options
+}
+
 export function openLinuxApplication(appId: string) {
     return () => {
-        linux_open_application(appId)
+        const desktopFilePath = desktopFilePaths()[appId];
+        if (desktopFilePath == undefined) {
+            console.error(`Unable to open application ${appId}, desktop file is not known`)
+            return
+        }
+
+        linux_open_application(appId, desktopFilePath, linuxLaunchOptions())
+            .catch(err => console.error(`Unable to open application ${appId}`, err))
     }
 }
 
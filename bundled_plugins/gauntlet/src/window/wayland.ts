@@ -1,12 +1,30 @@
 import { addOpenWindow, deleteOpenWindow, openLinuxApplication } from "./shared";
 import { GeneratedEntrypoint } from "@project-gauntlet/api/helpers";
-import { linux_wayland_focus_window, application_wayland_pending_event } from "gauntlet:bridge/internal-linux";
+import {
+    linux_wayland_focus_window,
+    linux_wayland_window_action,
+    linux_wayland_workspaces,
+    application_wayland_pending_event
+} from "gauntlet:bridge/internal-linux";
 
 
 export function focusWaylandWindow(windowId: string) {
     linux_wayland_focus_window(windowId)
 }
 
+export function waylandWindowAction(windowId: string, action: LinuxWindowAction) {
+    linux_wayland_window_action(windowId, action)
+}
+
+export function waylandWorkspaces(): LinuxWorkspace[] {
+    try {
+        return linux_wayland_workspaces()
+    } catch (e) {
+        console.error("Unable to fetch wayland workspaces", e)
+        return []
+    }
+}
+
 export function applicationEventLoopWayland(
     focusWindow: (windowId: string) => void,
     add: (id: string, data: GeneratedEntrypoint) => void,
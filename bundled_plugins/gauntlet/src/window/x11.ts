@@ -1,6 +1,11 @@
 import { GeneratedEntrypoint } from "@project-gauntlet/api/helpers";
 import { addOpenWindow, deleteOpenWindow, openLinuxApplication } from "./shared";
-import { application_x11_pending_event, linux_x11_focus_window } from "gauntlet:bridge/internal-linux";
+import {
+    application_x11_pending_event,
+    linux_x11_focus_window,
+    linux_x11_window_action,
+    linux_x11_workspaces
+} from "gauntlet:bridge/internal-linux";
 
 export type X11WindowData = {
     // x11 window id
@@ -35,6 +40,19 @@ export function focusX11Window(windowId: string) {
     linux_x11_focus_window(windowId)
 }
 
+export function x11WindowAction(windowId: string, action: LinuxWindowAction) {
+    linux_x11_window_action(windowId, action)
+}
+
+export function x11Workspaces(): LinuxWorkspace[] {
+    try {
+        return linux_x11_workspaces()
+    } catch (e) {
+        console.error("Unable to fetch x11 workspaces", e)
+        return []
+    }
+}
+
 export function applicationEventLoopX11(
     focusWindow: (windowId: string) => void,
     add: (id: string, data: GeneratedEntrypoint) => void,
@@ -2,17 +2,20 @@ import React, { ReactElement } from "react";
 import { List } from "@project-gauntlet/api/components";
 import { ListOfWindows, openWindows } from "./window/shared";
 import { current_os, wayland } from "gauntlet:bridge/internal-all";
-import { focusWaylandWindow } from "./window/wayland";
-import { focusX11Window } from "./window/x11";
+import { focusWaylandWindow, waylandWindowAction, waylandWorkspaces } from "./window/wayland";
+import { focusX11Window, x11WindowAction, x11Workspaces } from "./window/x11";
 
 export default function Windows(): ReactElement {
     switch (current_os()) {
         case "linux": {
             if (wayland()) {
+                // workspaces are only available on cosmic, wlr foreign toplevel protocol can't move windows between workspaces
                 return (
                     <ListOfWindows
                         windows={openWindows()}
                         focusWindow={(windowId) => focusWaylandWindow(windowId)}
+                        windowAction={(windowId, action) => waylandWindowAction(windowId, action)}
+                        workspaces={waylandWorkspaces()}
                         focusSecond={true}
                     />
                 )
@@ -21,6 +24,8 @@ export default function Windows(): ReactElement {
                     <ListOfWindows
                         windows={openWindows()}
                         focusWindow={(windowId) => focusX11Window(windowId)}
+                        windowAction={(windowId, action) => x11WindowAction(windowId, action)}
+                        workspaces={x11Workspaces()}
                         focusSecond={true}
                     />
                 )
@@ -0,0 +1,44 @@
+import { ReactElement, useState } from "react";
+import { Action, ActionPanel, List } from "@project-gauntlet/api/components";
+
+const species = [
+    { id: "Adarian", homeworld: "Adari", diet: "Herbivorous" },
+    { id: "Caphex", homeworld: "Caphex Prime", diet: "Omnivorous" },
+    { id: "Ezaraa", homeworld: "Ezaraa", diet: "Carnivorous" },
+    { id: "Houk", homeworld: "Lijuter", diet: "Omnivorous" },
+]
+
+export default function Main(): ReactElement {
+    const [showingDetail, setShowingDetail] = useState(true);
+
+    return (
+        <List
+            isShowingDetail={showingDetail}
+            actions={
+                <ActionPanel>
+                    <Action
+                        label={"Toggle Detail"}
+                        onAction={() => setShowingDetail(value => !value)}
+                    />
+                </ActionPanel>
+            }
+        >
+            {species.map(item => (
+                <List.Item key={item.id} id={item.id} title={item.id}>
+                    <List.Item.Detail>
+                        <List.Item.Detail.Metadata>
+                            <List.Item.Detail.Metadata.Value label={"Homeworld"}>{item.homeworld}</List.Item.Detail.Metadata.Value>
+                            <List.Item.Detail.Metadata.Value label={"Diet"}>{item.diet}</List.Item.Detail.Metadata.Value>
+                        </List.Item.Detail.Metadata>
+                        <List.Item.Detail.Content>
+                            <List.Item.Detail.Content.Paragraph>
+                                {`${item.id} are native to the planet ${item.homeworld}`}
+                            </List.Item.Detail.Content.Paragraph>
+                        </List.Item.Detail.Content>
+                    </List.Item.Detail>
+                </List.Item>
+            ))}
+            <List.Item id="Inleshat" title="Inleshat (no detail)"/>
+        </List>
+    )
+}
@@ -0,0 +1,15 @@
+import { runTask } from "@project-gauntlet/api/helpers";
+
+export default async function Command() {
+    await runTask("Test Task", async ({ signal, update }) => {
+        const steps = 20;
+
+        for (let step = 0; step < steps; step++) {
+            signal.throwIfAborted()
+
+            update({ progress: step / steps, status: `Step ${step + 1} of ${steps}` })
+
+            await new Promise(resolve => setTimeout(resolve, 500))
+        }
+    })
+}
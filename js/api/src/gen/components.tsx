@@ -78,6 +78,7 @@ declare global {
             ["gauntlet:detail"]: {
                 children?: ElementComponent<typeof ActionPanel | typeof Metadata | typeof Content>;
                 isLoading?: boolean;
+                onDrop?: (path: string) => void;
             };
             ["gauntlet:text_field"]: {
                 label?: string;
@@ -109,6 +110,7 @@ declare global {
             ["gauntlet:form"]: {
                 children?: ElementComponent<typeof ActionPanel | typeof TextField | typeof PasswordField | typeof Checkbox | typeof Select | typeof Separator>;
                 isLoading?: boolean;
+                onDrop?: (path: string) => void;
             };
             ["gauntlet:inline_separator"]: {
                 icon?: Icons;
@@ -135,8 +137,24 @@ declare global {
                 placeholder?: string;
                 onChange?: (value: string) => void;
             };
+            ["gauntlet:search_bar_dropdown_item"]: {
+                value: string;
+                title: string;
+            };
+            ["gauntlet:search_bar_dropdown_section"]: {
+                children?: ElementComponent<typeof SearchBarDropdownItem>;
+                title?: string;
+            };
+            ["gauntlet:search_bar_dropdown"]: {
+                children?: ElementComponent<typeof SearchBarDropdownItem | typeof SearchBarDropdownSection>;
+                value?: string;
+                defaultValue?: string;
+                placeholder?: string;
+                storageKey?: string;
+                onChange?: (value: string) => void;
+            };
             ["gauntlet:list_item"]: {
-                children?: ElementComponent<typeof TextAccessory | typeof IconAccessory>;
+                children?: ElementComponent<typeof TextAccessory | typeof IconAccessory | typeof Detail>;
                 id: string;
                 title: string;
                 subtitle?: string;
@@ -148,10 +166,13 @@ declare global {
                 subtitle?: string;
             };
             ["gauntlet:list"]: {
-                children?: ElementComponent<typeof ActionPanel | typeof ListItem | typeof ListSection | typeof SearchBar | typeof EmptyView | typeof Detail>;
+                children?: ElementComponent<typeof ActionPanel | typeof ListItem | typeof ListSection | typeof SearchBar | typeof SearchBarDropdown | typeof EmptyView | typeof Detail>;
                 isLoading?: boolean;
                 onItemFocusChange?: (itemId: string | null) => void;
                 focusedItemId?: string | null;
+                onLoadMore?: () => void;
+                isShowingDetail?: boolean;
+                onDrop?: (path: string) => void;
             };
             ["gauntlet:grid_item"]: {
                 children?: ElementComponent<typeof IconAccessory | typeof Content>;
@@ -166,11 +187,13 @@ declare global {
                 columns?: number;
             };
             ["gauntlet:grid"]: {
-                children?: ElementComponent<typeof ActionPanel | typeof GridItem | typeof GridSection | typeof SearchBar | typeof EmptyView>;
+                children?: ElementComponent<typeof ActionPanel | typeof GridItem | typeof GridSection | typeof SearchBar | typeof SearchBarDropdown | typeof EmptyView>;
                 isLoading?: boolean;
                 columns?: number;
                 onItemFocusChange?: (itemId: string | null) => void;
                 focusedItemId?: string | null;
+                onLoadMore?: () => void;
+                onDrop?: (path: string) => void;
             };
         }
     }
@@ -548,12 +571,13 @@ export interface DetailProps {
     children?: ElementComponent<typeof Metadata | typeof Content>;
     isLoading?: boolean;
     actions?: ElementComponent<typeof ActionPanel>;
+    onDrop?: (path: string) => void;
 }
 export const Detail: FC<DetailProps> & {
     Metadata: typeof Metadata;
     Content: typeof Content;
 } = (props: DetailProps): ReactNode => {
-    return <gauntlet:detail isLoading={props.isLoading}>{props.actions as any}{props.children}</gauntlet:detail>;
+    return <gauntlet:detail isLoading={props.isLoading} onDrop={props.onDrop}>{props.actions as any}{props.children}</gauntlet:detail>;
 };
 Detail.Metadata = Metadata;
 Detail.Content = Content;
@@ -608,6 +632,7 @@ export interface FormProps {
     children?: ElementComponent<typeof TextField | typeof PasswordField | typeof Checkbox | typeof Select | typeof Separator>;
     isLoading?: boolean;
     actions?: ElementComponent<typeof ActionPanel>;
+    onDrop?: (path: string) => void;
 }
 export const Form: FC<FormProps> & {
     TextField: typeof TextField;
@@ -616,7 +641,7 @@ export const Form: FC<FormProps> & {
     Select: typeof Select;
     Separator: typeof Separator;
 } = (props: FormProps): ReactNode => {
-    return <gauntlet:form isLoading={props.isLoading}>{props.actions as any}{props.children}</gauntlet:form>;
+    return <gauntlet:form isLoading={props.isLoading} onDrop={props.onDrop}>{props.actions as any}{props.children}</gauntlet:form>;
 };
 Form.TextField = TextField;
 Form.PasswordField = PasswordField;
@@ -676,16 +701,53 @@ export interface SearchBarProps {
 export const SearchBar: FC<SearchBarProps> = (props: SearchBarProps): ReactNode => {
     return <gauntlet:search_bar value={props.value} placeholder={props.placeholder} onChange={props.onChange}></gauntlet:search_bar>;
 };
+export interface SearchBarDropdownItemProps {
+    value: string;
+    title: string;
+}
+export const SearchBarDropdownItem: FC<SearchBarDropdownItemProps> = (props: SearchBarDropdownItemProps): ReactNode => {
+    return <gauntlet:search_bar_dropdown_item value={props.value} title={props.title}></gauntlet:search_bar_dropdown_item>;
+};
+export interface SearchBarDropdownSectionProps {
+    children?: ElementComponent<typeof SearchBarDropdownItem>;
+    title?: string;
+}
+export const SearchBarDropdownSection: FC<SearchBarDropdownSectionProps> & {
+    Item: typeof SearchBarDropdownItem;
+} = (props: SearchBarDropdownSectionProps): ReactNode => {
+    return <gauntlet:search_bar_dropdown_section title={props.title}>{props.children}</gauntlet:search_bar_dropdown_section>;
+};
+SearchBarDropdownSection.Item = SearchBarDropdownItem;
+export interface SearchBarDropdownProps {
+    children?: ElementComponent<typeof SearchBarDropdownItem | typeof SearchBarDropdownSection>;
+    value?: string;
+    defaultValue?: string;
+    placeholder?: string;
+    storageKey?: string;
+    onChange?: (value: string) => void;
+}
+export const SearchBarDropdown: FC<SearchBarDropdownProps> & {
+    Item: typeof SearchBarDropdownItem;
+    Section: typeof SearchBarDropdownSection;
+} = (props: SearchBarDropdownProps): ReactNode => {
+    return <gauntlet:search_bar_dropdown value={props.value} defaultValue={props.defaultValue} placeholder={props.placeholder} storageKey={props.storageKey} onChange={props.onChange}>{props.children}</gauntlet:search_bar_dropdown>;
+};
+SearchBarDropdown.Item = SearchBarDropdownItem;
+SearchBarDropdown.Section = SearchBarDropdownSection;
 export interface ListItemProps {
+    children?: ElementComponent<typeof Detail>;
     id: string;
     title: string;
     subtitle?: string;
     icon?: ImageLike;
     accessories?: (ElementComponent<typeof TextAccessory> | ElementComponent<typeof IconAccessory>)[];
 }
-export const ListItem: FC<ListItemProps> = (props: ListItemProps): ReactNode => {
-    return <gauntlet:list_item id={props.id} title={props.title} subtitle={props.subtitle} icon={props.icon}>{props.accessories as any}</gauntlet:list_item>;
+export const ListItem: FC<ListItemProps> & {
+    Detail: typeof Detail;
+} = (props: ListItemProps): ReactNode => {
+    return <gauntlet:list_item id={props.id} title={props.title} subtitle={props.subtitle} icon={props.icon}>{props.accessories as any}{props.children}</gauntlet:list_item>;
 };
+ListItem.Detail = Detail;
 export interface ListSectionProps {
     children?: ElementComponent<typeof ListItem>;
     title: string;
@@ -698,24 +760,29 @@ export const ListSection: FC<ListSectionProps> & {
 };
 ListSection.Item = ListItem;
 export interface ListProps {
-    children?: ElementComponent<typeof ListItem | typeof ListSection | typeof SearchBar | typeof EmptyView | typeof Detail>;
+    children?: ElementComponent<typeof ListItem | typeof ListSection | typeof SearchBar | typeof SearchBarDropdown | typeof EmptyView | typeof Detail>;
     actions?: ElementComponent<typeof ActionPanel>;
     isLoading?: boolean;
     onItemFocusChange?: (itemId: string | null) => void;
     focusedItemId?: string | null;
+    onLoadMore?: () => void;
+    isShowingDetail?: boolean;
+    onDrop?: (path: string) => void;
 }
 export const List: FC<ListProps> & {
     Item: typeof ListItem;
     Section: typeof ListSection;
     SearchBar: typeof SearchBar;
+    Dropdown: typeof SearchBarDropdown;
     EmptyView: typeof EmptyView;
     Detail: typeof Detail;
 } = (props: ListProps): ReactNode => {
-    return <gauntlet:list isLoading={props.isLoading} onItemFocusChange={props.onItemFocusChange} focusedItemId={props.focusedItemId}>{props.actions as any}{props.children}</gauntlet:list>;
+    return <gauntlet:list isLoading={props.isLoading} onItemFocusChange={props.onItemFocusChange} focusedItemId={props.focusedItemId} onLoadMore={props.onLoadMore} isShowingDetail={props.isShowingDetail} onDrop={props.onDrop}>{props.actions as any}{props.children}</gauntlet:list>;
 };
 List.Item = ListItem;
 List.Section = ListSection;
 List.SearchBar = SearchBar;
+List.Dropdown = SearchBarDropdown;
 List.EmptyView = EmptyView;
 List.Detail = Detail;
 export interface GridItemProps {
@@ -744,22 +811,26 @@ export const GridSection: FC<GridSectionProps> & {
 };
 GridSection.Item = GridItem;
 export interface GridProps {
-    children?: ElementComponent<typeof GridItem | typeof GridSection | typeof SearchBar | typeof EmptyView>;
+    children?: ElementComponent<typeof GridItem | typeof GridSection | typeof SearchBar | typeof SearchBarDropdown | typeof EmptyView>;
     isLoading?: boolean;
     actions?: ElementComponent<typeof ActionPanel>;
     columns?: number;
     onItemFocusChange?: (itemId: string | null) => void;
     focusedItemId?: string | null;
+    onLoadMore?: () => void;
+    onDrop?: (path: string) => void;
 }
 export const Grid: FC<GridProps> & {
     Item: typeof GridItem;
     Section: typeof GridSection;
     SearchBar: typeof SearchBar;
+    Dropdown: typeof SearchBarDropdown;
     EmptyView: typeof EmptyView;
 } = (props: GridProps): ReactNode => {
-    return <gauntlet:grid isLoading={props.isLoading} columns={props.columns} onItemFocusChange={props.onItemFocusChange} focusedItemId={props.focusedItemId}>{props.actions as any}{props.children}</gauntlet:grid>;
+    return <gauntlet:grid isLoading={props.isLoading} columns={props.columns} onItemFocusChange={props.onItemFocusChange} focusedItemId={props.focusedItemId} onLoadMore={props.onLoadMore} onDrop={props.onDrop}>{props.actions as any}{props.children}</gauntlet:grid>;
 };
 Grid.Item = GridItem;
 Grid.Section = GridSection;
 Grid.SearchBar = SearchBar;
+Grid.Dropdown = SearchBarDropdown;
 Grid.EmptyView = EmptyView;
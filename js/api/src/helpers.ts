@@ -1,7 +1,8 @@
 // @ts-ignore TODO how to add declaration for this?
-import { getAssetData, getAssetDataSync, getPluginPreferences, getEntrypointPreferences, showHudWindow } from "ext:gauntlet/renderer.js";
+import { getAssetData, getAssetDataSync, getPluginPreferences, getEntrypointPreferences, showHudWindow, runTask as runRendererTask } from "ext:gauntlet/renderer.js";
 import {
     clipboard_clear,
+    clipboard_paste_text,
     clipboard_read,
     clipboard_read_text,
     clipboard_write,
@@ -9,9 +10,14 @@ import {
     environment_gauntlet_version,
     environment_is_development,
     environment_plugin_cache_dir,
-    environment_plugin_data_dir
+    environment_plugin_data_dir,
+    launch_context_focused_window,
+    launch_context_selected_text,
+    plugin_command_call
 } from "ext:core/ops";
-import type { FC } from "react";
+import { createElement } from "react";
+import type { FC, ReactNode } from "react";
+import { Action } from "./gen/components";
 
 export function assetDataSync(path: string): ArrayBuffer {
     return getAssetDataSync(path)
@@ -25,11 +31,21 @@ export function showHud(display: string): void {
     return showHudWindow(display)
 }
 
+export interface TaskContext {
+    signal: AbortSignal
+    update: (update: { progress?: number, status?: string }) => void
+}
+
+export function runTask<T>(title: string, task: (context: TaskContext) => Promise<T>): Promise<T> {
+    return runRendererTask(title, task)
+}
+
 export interface GeneratedEntrypoint {
     name: string
     actions: GeneratedEntrypointAction[]
     icon?: ArrayBuffer
     accessories?: GeneratedEntrypointAccessory[]
+    keywords?: string[]
 }
 
 export type GeneratedEntrypointAction = GeneratedEntrypointActionRun | GeneratedEntrypointActionView
@@ -71,6 +87,7 @@ export type GeneratorContext<P = object, E = object> = {
 export type CommandContext<P = object, E = object> = {
     pluginPreferences: P,
     entrypointPreferences: E,
+    arguments: { [id: string]: string },
 };
 
 export const Clipboard: Clipboard = {
@@ -113,6 +130,9 @@ export const Clipboard: Clipboard = {
     },
     clear: async function (): Promise<void> {
         await clipboard_clear()
+    },
+    paste: async function (data: string): Promise<void> {
+        await clipboard_paste_text(data)
     }
 }
 
@@ -122,6 +142,64 @@ export interface Clipboard {
     write(data: { ["text/plain"]?: string, ["image/png"]?: ArrayBuffer }): Promise<void>;
     writeText(data: string): Promise<void>;
     clear(): Promise<void>;
+    paste(data: string): Promise<void>;
+}
+
+export interface PasteActionProps {
+    id?: string
+    label?: string
+    // text can also be resolved when action is run, e.g. from id of focused list item,
+    // nothing is pasted if function returns undefined
+    text: string | ((id: string | null) => string | undefined)
+}
+
+// action panel action which pastes given text into previously focused window using Clipboard.paste
+export function PasteAction(props: PasteActionProps): ReactNode {
+    return createElement(Action, {
+        id: props.id,
+        label: props.label ?? "Paste",
+        onAction: async (id: string | null) => {
+            const text = typeof props.text === "string" ? props.text : props.text(id);
+
+            if (text !== undefined) {
+                await Clipboard.paste(text)
+            }
+        }
+    })
+}
+
+export const LaunchContext: LaunchContext = {
+    selectedText: async function (): Promise<string | undefined> {
+        return (await launch_context_selected_text()) ?? undefined
+    },
+    focusedWindow: async function (): Promise<FocusedWindow | undefined> {
+        const window = await launch_context_focused_window();
+
+        if (!window) {
+            return undefined
+        }
+
+        return {
+            appId: window.appId ?? undefined,
+            title: window.title ?? undefined,
+        }
+    },
+}
+
+export interface LaunchContext {
+    selectedText(): Promise<string | undefined>;
+    focusedWindow(): Promise<FocusedWindow | undefined>;
+}
+
+export interface FocusedWindow {
+    appId?: string;
+    title?: string;
+}
+
+export async function callPluginCommand<T = unknown>(pluginId: string, commandId: string, args: Record<string, string | number | boolean | undefined> = {}): Promise<T> {
+    const result = await plugin_command_call(pluginId, commandId, JSON.stringify(args));
+
+    return JSON.parse(result)
 }
 
 export const Environment: Environment = {
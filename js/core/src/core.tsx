@@ -1,6 +1,7 @@
 import type { FC } from "react";
 import { runEntrypointGenerators, runGeneratedEntrypoint, runGeneratedEntrypointAction } from "./entrypoint-generator";
 import { reloadSearchIndex } from "./search-index";
+import { cancelTask, replaceView } from "ext:gauntlet/renderer.js";
 import {
     closeView,
     handleEvent,
@@ -13,9 +14,10 @@ import {
     get_entrypoint_preferences,
     get_plugin_preferences,
     op_entrypoint_names,
-    op_inline_view_entrypoint_id,
+    op_inline_view_entrypoint_ids,
     op_log_trace,
     op_plugin_get_pending_event,
+    plugin_command_result,
     plugin_preferences_required,
     show_plugin_error_view,
     show_preferences_required_view
@@ -55,6 +57,40 @@ async function checkRequiredPreferencesAndAsk(entrypointId: string): Promise<boo
     return required;
 }
 
+// called by other plugin, result is sent back to the caller through the server
+async function runExportedCommand(event: CallExportedCommand) {
+    let result: string | undefined;
+    let error: string | undefined;
+
+    try {
+        const pluginPreferencesRequired = await plugin_preferences_required();
+        const entrypointPreferencesRequired = await entrypoint_preferences_required(event.commandId);
+
+        if (pluginPreferencesRequired || entrypointPreferencesRequired) {
+            throw new Error("Plugin requires preferences to be set before command can be called")
+        }
+
+        const pluginPreferences = get_plugin_preferences();
+        const entrypointPreferences = get_entrypoint_preferences(event.commandId);
+
+        const command = (await import(`gauntlet:entrypoint?${event.commandId}`)).default;
+        const value = await command({ pluginPreferences, entrypointPreferences, arguments: JSON.parse(event.arguments) })
+
+        result = JSON.stringify(value ?? null)
+    } catch (e) {
+        console.error("Error occurred when running an exported command", event.commandId, e)
+
+        error = e instanceof Error ? e.message : String(e)
+    }
+
+    // this function is not awaited by plugin loop, so failure to send the result is only logged
+    try {
+        await plugin_command_result(event.callId, result, error)
+    } catch (e) {
+        console.error("Error occurred when sending result of an exported command", event.commandId, e)
+    }
+}
+
 export async function runPluginLoop() {
     await runEntrypointGenerators();
 
@@ -88,8 +124,8 @@ export async function runPluginLoop() {
                         break;
                     }
 
-                    const view: FC = (await import(`gauntlet:entrypoint?${entrypointId}`)).default;
-                    renderView(entrypointId, getEntrypointName(entrypointId), view)
+                    const view: FC<{ arguments: { [id: string]: string }, searchText?: string }> = (await import(`gauntlet:entrypoint?${entrypointId}`)).default;
+                    renderView(entrypointId, getEntrypointName(entrypointId), view, pluginEvent.arguments, pluginEvent.searchText)
                 } catch (e) {
                     console.error("Error occurred when rendering view", entrypointId, e)
                     show_plugin_error_view(entrypointId, "View")
@@ -119,13 +155,14 @@ export async function runPluginLoop() {
                     type CommandContext<P = object, E = object> = {
                         pluginPreferences: P,
                         entrypointPreferences: E,
+                        arguments: { [id: string]: string },
                     };
 
                     const pluginPreferences = get_plugin_preferences();
                     const entrypointPreferences = get_entrypoint_preferences(pluginEvent.entrypointId);
 
                     const command: (context: CommandContext) => Promise<void> | void = (await import(`gauntlet:entrypoint?${pluginEvent.entrypointId}`)).default;
-                    command({ pluginPreferences, entrypointPreferences })
+                    command({ pluginPreferences, entrypointPreferences, arguments: pluginEvent.arguments })
                 } catch (e) {
                     console.error("Error occurred when running a command", pluginEvent.entrypointId, e)
                 }
@@ -140,20 +177,22 @@ export async function runPluginLoop() {
                 break;
             }
             case "OpenInlineView": {
-                const entrypointId = op_inline_view_entrypoint_id();
+                // every inline view of the plugin is rendered, unless search is scoped to one of them
+                const entrypointIds = op_inline_view_entrypoint_ids()
+                    .filter(entrypointId => pluginEvent.entrypointId == null || pluginEvent.entrypointId === entrypointId);
 
-                if (entrypointId) {
+                for (const entrypointId of entrypointIds) {
                     if (await checkRequiredPreferences(entrypointId)) {
-                        break;
+                        continue;
                     }
 
                     try {
                         type InlineView = { text: string };
                         const handler: FC<InlineView> = (await import(`gauntlet:entrypoint?${entrypointId}`)).default;
 
-                        renderInlineView(entrypointId, getEntrypointName(entrypointId), handler, pluginEvent.text)
+                        renderInlineView(entrypointId, getEntrypointName(entrypointId), handler, pluginEvent.text, pluginEvent.generation)
                     } catch (e) {
-                        console.error("Error occurred when rendering inline view", e)
+                        console.error("Error occurred when rendering inline view", entrypointId, e)
                     }
                 }
                 break;
@@ -163,6 +202,19 @@ export async function runPluginLoop() {
                 reloadSearchIndex(false)
                 break;
             }
+            case "CancelTask": {
+                cancelTask(pluginEvent.taskId)
+                break;
+            }
+            case "CallExportedCommand": {
+                // noinspection ES6MissingAwait
+                runExportedCommand(pluginEvent)
+                break;
+            }
+            case "ReplaceView": {
+                replaceView()
+                break;
+            }
         }
     }
 }
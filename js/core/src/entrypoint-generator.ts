@@ -16,6 +16,7 @@ interface GeneratedEntrypoint { // TODO is it possible to import api here
     actions: GeneratedEntrypointAction[]
     icon?: ArrayBuffer
     accessories?: GeneratedEntrypointAccessory[]
+    keywords?: string[]
 }
 
 type GeneratedEntrypointAction = GeneratedEntrypointActionRun | GeneratedEntrypointActionView
@@ -211,7 +212,8 @@ export function generatedEntrypointSearchIndex(): GeneratedSearchItem[] {
                 action_type: action.type,
                 label: action.label
             })),
-        entrypoint_accessories: value.command.accessories || []
+        entrypoint_accessories: value.command.accessories || [],
+        entrypoint_keywords: value.command.keywords || []
     }))
 }
 
@@ -256,7 +258,7 @@ function runAction(entrypointId: string, action: GeneratedEntrypointDerivedActio
                 .command
                 .name
 
-            renderView(entrypointId, entrypointName, action.view)
+            renderView(entrypointId, entrypointName, action.view, {})
             break;
         }
     }
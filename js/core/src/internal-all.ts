@@ -1,6 +1,14 @@
 export {
     run_numbat,
+    save_numbat,
+    numbat_history,
+    clear_numbat_history,
     open_settings,
     current_os,
     wayland,
+    file_search_configure,
+    file_search,
+    file_search_status,
+    file_search_open,
+    file_search_reveal,
 } from "ext:core/ops";
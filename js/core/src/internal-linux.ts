@@ -2,8 +2,13 @@ export {
     linux_app_from_path,
     linux_application_dirs,
     linux_open_application,
+    linux_open_application_action,
     linux_x11_focus_window,
     linux_wayland_focus_window,
+    linux_x11_window_action,
+    linux_x11_workspaces,
+    linux_wayland_window_action,
+    linux_wayland_workspaces,
     application_x11_pending_event,
     application_wayland_pending_event,
 } from "ext:core/ops";
@@ -3,43 +3,44 @@ import {
     op_log_trace,
     hide_window
 } from "ext:core/ops";
-import { clearRenderer, rerender, render, popView } from "ext:gauntlet/renderer.js";
+import {
+    clearRenderer,
+    rerender,
+    render,
+    popView,
+    rootUiWidget,
+    rootUiWidgets,
+    setInlineViewGeneration
+} from "ext:gauntlet/renderer.js";
 import type { FC } from "react";
 
-let latestRootUiWidget: UiWidget | undefined = undefined
-let latestRootUiRenderLocation: RenderLocation | undefined = undefined
-
-export function renderView(entrypointId: string, entrypointName: string, View: FC) {
-    latestRootUiRenderLocation = "View";
-    latestRootUiWidget = render(entrypointId, entrypointName, "View", <View/>);
+export function renderView(entrypointId: string, entrypointName: string, View: FC<{ arguments: { [id: string]: string }, searchText?: string }>, args: { [id: string]: string }, searchText?: string) {
+    render(entrypointId, entrypointName, "View", <View arguments={args} searchText={searchText}/>);
 }
 
 export function popMainView() {
     popView()
 }
 
-export function renderInlineView(entrypointId: string, entrypointName: string, Handler: FC<{ text: string }>, text: string) {
-    switch (latestRootUiRenderLocation) {
-        case "InlineView": {
-            rerender(<Handler text={text}/>);
-            break
-        }
-        default: {
-            latestRootUiRenderLocation = "InlineView";
-            latestRootUiWidget = render(entrypointId, entrypointName, "InlineView", <Handler text={text}/>);
-            break
-        }
+export function renderInlineView(entrypointId: string, entrypointName: string, Handler: FC<{ text: string }>, text: string, generation: number) {
+    // entrypoint is either a view or an inline view, so existing root of this entrypoint is always an inline view
+    if (rootUiWidget(entrypointId)) {
+        setInlineViewGeneration(entrypointId, generation)
+        rerender(entrypointId, <Handler text={text}/>);
+    } else {
+        render(entrypointId, entrypointName, "InlineView", <Handler text={text}/>, generation);
     }
 }
 
 export function closeView() {
-    latestRootUiRenderLocation = undefined;
     clearRenderer()
 }
 
 export async function handlePluginViewKeyboardEvent(entrypointId: string, key: string, modifierShift: boolean, modifierControl: boolean, modifierAlt: boolean, modifierMeta: boolean) {
-    if (latestRootUiWidget) {
-        const actionHandlers = findAllActionHandlers(latestRootUiWidget);
+    const rootWidget = rootUiWidget(entrypointId);
+
+    if (rootWidget) {
+        const actionHandlers = findAllActionHandlers(rootWidget);
 
         const id = await fetch_action_id_for_shortcut(entrypointId, key, modifierShift, modifierControl, modifierAlt, modifierMeta);
 
@@ -76,55 +77,56 @@ function findAllActionHandlers(widget: UiWidget): { id: string, onAction: () =>
 
 export function handleEvent(event: ViewEvent) {
     op_log_trace("plugin_event_handler", `Handling view event: ${Deno.inspect(event)}`);
-    op_log_trace("plugin_event_handler", `Root widget: ${Deno.inspect(latestRootUiWidget)}`);
-    if (latestRootUiWidget) {
-        const widgetWithId = findWidgetWithId(latestRootUiWidget, event.widgetId);
-        op_log_trace("plugin_event_handler", `Found widget with id ${event.widgetId}: ${Deno.inspect(widgetWithId)}`)
+    // widget ids are unique across all views and inline views of the plugin
+    const widgetWithId = rootUiWidgets()
+        .map(rootWidget => findWidgetWithId(rootWidget, event.widgetId))
+        .find(widget => widget !== undefined);
 
-        if (widgetWithId) {
-            const property = widgetWithId.widgetProperties[event.eventName];
-
-            op_log_trace("plugin_event_handler", `Found event handler with name ${event.eventName}: ${Deno.inspect(property)}`)
-
-            if (property) {
-                if (typeof property === "function") {
-
-                    const eventArgs = event.eventArguments
-                        .map(arg => {
-                            switch (arg.type) {
-                                case "Undefined": {
-                                    return undefined
-                                }
-                                case "Null": {
-                                    return null
-                                }
-                                case "String": {
-                                    return arg.value
-                                }
-                                case "Number": {
-                                    return arg.value
-                                }
-                                case "Bool": {
-                                    return arg.value
-                                }
-                            }
-                        });
+    op_log_trace("plugin_event_handler", `Found widget with id ${event.widgetId}: ${Deno.inspect(widgetWithId)}`)
+
+    if (widgetWithId) {
+        const property = widgetWithId.widgetProperties[event.eventName];
 
-                    op_log_trace("plugin_event_handler", `Calling handler with arguments ${Deno.inspect(eventArgs)}`);
+        op_log_trace("plugin_event_handler", `Found event handler with name ${event.eventName}: ${Deno.inspect(property)}`)
 
-                    (async () => {
-                        const result = await property(...eventArgs);
+        if (property) {
+            if (typeof property === "function") {
 
-                        // special case for action results
-                        if (event.eventName == "onAction") {
-                            if (result?.close === true) {
-                                hide_window()
+                const eventArgs = event.eventArguments
+                    .map(arg => {
+                        switch (arg.type) {
+                            case "Undefined": {
+                                return undefined
                             }
+                            case "Null": {
+                                return null
+                            }
+                            case "String": {
+                                return arg.value
+                            }
+                            case "Number": {
+                                return arg.value
+                            }
+                            case "Bool": {
+                                return arg.value
+                            }
+                        }
+                    });
+
+                op_log_trace("plugin_event_handler", `Calling handler with arguments ${Deno.inspect(eventArgs)}`);
+
+                (async () => {
+                    const result = await property(...eventArgs);
+
+                    // special case for action results
+                    if (event.eventName == "onAction") {
+                        if (result?.close === true) {
+                            hide_window()
                         }
-                    })();
-                } else {
-                    throw new Error(`Event handler has type ${typeof property}, but should be function`)
-                }
+                    }
+                })();
+            } else {
+                throw new Error(`Event handler has type ${typeof property}, but should be function`)
             }
         }
     }
@@ -0,0 +1,136 @@
+import assert from "node:assert";
+import { createPatch, OrderedMemberTypes } from "./patch.ts";
+
+const orderedMemberTypes: OrderedMemberTypes = {
+    "gauntlet:list": new Set(["gauntlet:list_item", "gauntlet:list_section"]),
+    "gauntlet:list_section": new Set(["gauntlet:list_item"]),
+}
+
+function widget(widgetId: number, widgetType: string, widgetProperties: Props = {}, widgetChildren: UiWidget[] = []): UiWidget {
+    return { widgetId, widgetType, widgetProperties, widgetChildren }
+}
+
+function item(widgetId: number, title: string): UiWidget {
+    return widget(widgetId, "gauntlet:list_item", { title, onClick: () => {} })
+}
+
+const searchBar = widget(1, "gauntlet:search_bar", { value: "" });
+const first = item(2, "first");
+const second = item(3, "second");
+const third = item(4, "third");
+
+function list(children: UiWidget[], props: Props = {}): UiWidget {
+    return widget(100, "gauntlet:list", props, [searchBar, ...children])
+}
+
+Deno.test("unchanged view produces empty patch", () => {
+    const view = list([first, second]);
+
+    assert.deepStrictEqual(createPatch([view], [view], orderedMemberTypes), []);
+    assert.deepStrictEqual(createPatch([view], [list([first, second])], orderedMemberTypes), []);
+});
+
+Deno.test("different root replaces whole view", () => {
+    const grid = widget(101, "gauntlet:grid");
+
+    assert.strictEqual(createPatch([list([first])], [grid], orderedMemberTypes), undefined);
+    assert.strictEqual(createPatch([], [list([first])], orderedMemberTypes), undefined);
+});
+
+Deno.test("reordered members reuse existing widgets", () => {
+    const patch = createPatch([list([first, second, third])], [list([third, first, second])], orderedMemberTypes);
+
+    assert.deepStrictEqual(patch, [
+        {
+            type: "updateChildren",
+            widgetId: 100,
+            children: [
+                { type: "existing", widgetId: 4 },
+                { type: "existing", widgetId: 2 },
+                { type: "existing", widgetId: 3 },
+            ]
+        }
+    ]);
+});
+
+Deno.test("inserted member is sent whole without functions", () => {
+    const patch = createPatch([list([first, third])], [list([first, second, third])], orderedMemberTypes);
+
+    assert.deepStrictEqual(patch, [
+        {
+            type: "updateChildren",
+            widgetId: 100,
+            children: [
+                { type: "existing", widgetId: 2 },
+                {
+                    type: "new",
+                    widget: { widgetId: 3, widgetType: "gauntlet:list_item", widgetProperties: { title: "second" }, widgetChildren: [] }
+                },
+                { type: "existing", widgetId: 4 },
+            ]
+        }
+    ]);
+});
+
+Deno.test("removed member is left out of children", () => {
+    const patch = createPatch([list([first, second, third])], [list([first, third])], orderedMemberTypes);
+
+    assert.deepStrictEqual(patch, [
+        {
+            type: "updateChildren",
+            widgetId: 100,
+            children: [
+                { type: "existing", widgetId: 2 },
+                { type: "existing", widgetId: 4 },
+            ]
+        }
+    ]);
+});
+
+Deno.test("changed props are sent without ordered members", () => {
+    const patch = createPatch([list([first, second])], [list([first, second], { isLoading: true })], orderedMemberTypes);
+
+    assert.deepStrictEqual(patch, [
+        {
+            type: "updateProps",
+            widget: {
+                widgetId: 100,
+                widgetType: "gauntlet:list",
+                widgetProperties: { isLoading: true },
+                widgetChildren: [
+                    { widgetId: 1, widgetType: "gauntlet:search_bar", widgetProperties: { value: "" }, widgetChildren: [] }
+                ]
+            }
+        }
+    ]);
+});
+
+Deno.test("changed function props are not sent", () => {
+    const oldView = list([first], { onLoadMore: () => {} });
+    const newView = list([first], { onLoadMore: () => {} });
+
+    assert.deepStrictEqual(createPatch([oldView], [newView], orderedMemberTypes), []);
+});
+
+Deno.test("changed member inside section is replaced", () => {
+    const oldSection = widget(200, "gauntlet:list_section", { title: "Section" }, [first, second]);
+    const newSection = widget(200, "gauntlet:list_section", { title: "Section" }, [first, item(3, "changed")]);
+
+    const patch = createPatch([list([oldSection])], [list([newSection])], orderedMemberTypes);
+
+    assert.deepStrictEqual(patch, [
+        {
+            type: "replace",
+            widget: { widgetId: 3, widgetType: "gauntlet:list_item", widgetProperties: { title: "changed" }, widgetChildren: [] }
+        }
+    ]);
+});
+
+Deno.test("changes on multiple levels produce one op per level", () => {
+    const oldSection = widget(200, "gauntlet:list_section", { title: "Section" }, [first, second]);
+    const newSection = widget(200, "gauntlet:list_section", { title: "Renamed" }, [second, item(2, "changed")]);
+
+    const patch = createPatch([list([oldSection])], [list([newSection], { isLoading: true })], orderedMemberTypes);
+
+    assert.deepStrictEqual(patch?.map(op => op.type), ["updateProps", "updateProps", "updateChildren", "replace"]);
+});
@@ -0,0 +1,148 @@
+// widget tree diffing, kept separate from renderer so it can be tested without deno runtime ops
+
+export type PatchOp = { type: "replace", widget: any }
+    | { type: "updateProps", widget: any }
+    | { type: "updateChildren", widgetId: number, children: PatchChild[] }
+
+export type PatchChild = { type: "existing", widgetId: number } | { type: "new", widget: any }
+
+// for each component which has ordered members, widget types of these members
+export type OrderedMemberTypes = Record<string, Set<string>>
+
+export function orderedMemberTypesOf(componentModel: Record<string, Component>): OrderedMemberTypes {
+    return Object.fromEntries(
+        Object.values(componentModel)
+            .flatMap((component): [string, Set<string>][] => {
+                if (component.type !== "standard") {
+                    return []
+                }
+
+                switch (component.children.type) {
+                    case "members":
+                    case "string_or_members": {
+                        const types = Object.values(component.children.ordered_members)
+                            .map(member => `gauntlet:${member.componentInternalName}`);
+
+                        return types.length === 0 ? [] : [[`gauntlet:${component.internalName}`, new Set(types)]]
+                    }
+                    default: {
+                        return []
+                    }
+                }
+            })
+    );
+}
+
+export function convertComponents(widget: UiWidget): any {
+    const widgetProperties = Object.fromEntries(
+        Object.entries(widget.widgetProperties)
+            .filter(([_, value]) => typeof value !== "function")
+    );
+
+    const widgetChildren = widget.widgetChildren
+        .map(child => convertComponents(child))
+
+    return {
+        widgetId: widget.widgetId,
+        widgetType: widget.widgetType,
+        widgetProperties: widgetProperties,
+        widgetChildren: widgetChildren,
+    }
+}
+
+// returns undefined if the view has to be replaced as a whole
+export function createPatch(oldChildren: UiWidget[], newChildren: UiWidget[], orderedMemberTypes: OrderedMemberTypes): PatchOp[] | undefined {
+    if (oldChildren.length !== 1 || newChildren.length !== 1) {
+        return undefined
+    }
+
+    const [oldWidget] = oldChildren;
+    const [newWidget] = newChildren;
+
+    if (oldWidget.widgetId !== newWidget.widgetId || oldWidget.widgetType !== newWidget.widgetType) {
+        return undefined
+    }
+
+    const patch: PatchOp[] = []
+
+    diffWidget(oldWidget, newWidget, orderedMemberTypes, patch)
+
+    return patch
+}
+
+function diffWidget(oldWidget: UiWidget, newWidget: UiWidget, orderedMemberTypes: OrderedMemberTypes, patch: PatchOp[]) {
+    // instances are never mutated, so unchanged subtrees are shared between renders
+    if (oldWidget === newWidget) {
+        return
+    }
+
+    const orderedTypes = orderedMemberTypes[newWidget.widgetType];
+
+    if (orderedTypes === undefined) {
+        patch.push({ type: "replace", widget: convertComponents(newWidget) })
+        return
+    }
+
+    const isOrdered = (child: UiWidget) => orderedTypes.has(child.widgetType);
+
+    const oldOther = oldWidget.widgetChildren.filter(child => !isOrdered(child));
+    const newOther = newWidget.widgetChildren.filter(child => !isOrdered(child));
+
+    const propsChanged = shallowDiff(sentProperties(oldWidget), sentProperties(newWidget)) !== null
+        || oldOther.length !== newOther.length
+        || oldOther.some((child, index) => child !== newOther[index]);
+
+    if (propsChanged) {
+        // ordered members are not sent, client keeps the ones it already has
+        patch.push({
+            type: "updateProps",
+            widget: convertComponents({ ...newWidget, widgetChildren: newOther })
+        })
+    }
+
+    const oldOrdered = oldWidget.widgetChildren.filter(child => isOrdered(child));
+    const newOrdered = newWidget.widgetChildren.filter(child => isOrdered(child));
+
+    const oldOrderedById = new Map(oldOrdered.map(child => [child.widgetId, child]));
+
+    const sameOrder = oldOrdered.length === newOrdered.length
+        && oldOrdered.every((child, index) => child.widgetId === newOrdered[index].widgetId);
+
+    if (!sameOrder) {
+        patch.push({
+            type: "updateChildren",
+            widgetId: newWidget.widgetId,
+            children: newOrdered.map(child => {
+                if (oldOrderedById.has(child.widgetId)) {
+                    return { type: "existing", widgetId: child.widgetId }
+                } else {
+                    return { type: "new", widget: convertComponents(child) }
+                }
+            })
+        })
+    }
+
+    for (const newChild of newOrdered) {
+        const oldChild = oldOrderedById.get(newChild.widgetId);
+
+        if (oldChild !== undefined) {
+            diffWidget(oldChild, newChild, orderedMemberTypes, patch)
+        }
+    }
+}
+
+function sentProperties(widget: UiWidget): Record<string, any> {
+    return Object.fromEntries(
+        Object.entries(widget.widgetProperties)
+            .filter(([_, value]) => typeof value !== "function")
+    );
+}
+
+export function shallowDiff(oldObj: Record<string, any>, newObj: Record<string, any>): string[] | null {
+    const uniqueProps = new Set([...Object.keys(oldObj), ...Object.keys(newObj)]);
+    const diff = Array.from(uniqueProps)
+        .filter(propName => propName != "children")
+        .filter(propName => oldObj[propName] !== newObj[propName]);
+
+    return diff.length === 0 ? null : diff;
+}
@@ -1,5 +1,5 @@
 import ReactReconciler, { HostConfig, OpaqueHandle } from "react-reconciler";
-import { createContext, ReactNode, useContext } from 'react';
+import { createContext, createElement, ReactNode, useContext } from 'react';
 import { DefaultEventPriority } from 'react-reconciler/constants';
 import {
     asset_data,
@@ -8,9 +8,14 @@ import {
     get_plugin_preferences,
     op_component_model,
     op_log_trace,
+    op_react_patch_view,
     op_react_replace_view,
-    show_hud
+    show_hud,
+    task_finish,
+    task_start,
+    task_update
 } from "ext:core/ops";
+import { convertComponents, createPatch, orderedMemberTypesOf, shallowDiff } from "./patch";
 
 // Usage of MessageChannel seems to block Deno runtime from exiting
 // causing plugin to be in stuck state where it is disabled but still have running runtime
@@ -20,7 +25,7 @@ import {
 globalThis.MessageChannel = undefined as any;
 
 class HostContext {
-    constructor(public nextId: number, public componentModel: Record<string, Component>) {
+    constructor(public gauntletContext: GauntletContextValue, public componentModel: Record<string, Component>) {
     }
 
     [Symbol.for("Deno.customInspect")]() {
@@ -32,7 +37,9 @@ type Instance = UiWidget & {
     hostContext: HostContext
 }
 
-type RootUiWidget = UiWidget
+type RootUiWidget = UiWidget & {
+    gauntletContext: GauntletContextValue
+}
 type PublicInstance = Instance;
 type TextInstance = Instance
 type TimeoutHandle = number;
@@ -49,6 +56,7 @@ class GauntletContextValue {
     private _entrypointId: string | undefined;
     private _entrypointName: string | undefined;
     private _clear: (() => void) | undefined;
+    private _inlineViewGeneration: number | undefined;
 
     reset(entrypointId: string, entrypointName: string, renderLocation: RenderLocation, view: ReactNode, rerender: (node: ReactNode) => void, clear: () => void) {
         this._entrypointId = entrypointId
@@ -64,6 +72,16 @@ class GauntletContextValue {
         return this._renderLocation!!
     }
 
+    // search query generation for which inline view is currently rendered,
+    // sent back to client so it can tell which inline views are outdated
+    inlineViewGeneration = (): number | undefined => {
+        return this._renderLocation === "InlineView" ? this._inlineViewGeneration : undefined
+    }
+
+    setInlineViewGeneration = (generation: number) => {
+        this._inlineViewGeneration = generation
+    }
+
     isBottommostView = () => {
         return this._navStack.length === 1
     }
@@ -109,8 +127,7 @@ class GauntletContextValue {
     }
 }
 
-const gauntletContextValue = new GauntletContextValue()
-const gauntletContext = createContext(gauntletContextValue);
+const gauntletContext = createContext(new GauntletContextValue());
 
 export function useGauntletContext() {
     return useContext(gauntletContext);
@@ -125,25 +142,110 @@ export function getAssetDataSync(path: string): ArrayBuffer {
 }
 
 export function getPluginPreferences(): Record<string, any> {
-    return gauntletContextValue.pluginPreferences()
+    return get_plugin_preferences()
 }
 
 export function getEntrypointPreferences(): Record<string, any> {
-    return gauntletContextValue.entrypointPreferences()
+    return latestGauntletContextValue!!.entrypointPreferences()
 }
 
 export function showHudWindow(display: string): void {
     show_hud(display)
 }
 
+export interface TaskContext {
+    signal: AbortSignal
+    update: (update: { progress?: number, status?: string }) => void
+}
+
+const runningTasks = new Map<string, AbortController>();
+
+const TASK_UPDATE_INTERVAL_MS = 100;
+
+export async function runTask<T>(title: string, task: (context: TaskContext) => Promise<T>): Promise<T> {
+    const taskId = crypto.randomUUID();
+    const abortController = new AbortController();
+
+    runningTasks.set(taskId, abortController)
+
+    await task_start(taskId, title)
+
+    // updates are sent at most once per TASK_UPDATE_INTERVAL_MS,
+    // otherwise tasks reporting progress in a tight loop would flood the launcher with re-renders
+    let pendingUpdate: { progress?: number, status?: string } | undefined = undefined;
+    let pendingUpdateTimeout: number | undefined = undefined;
+    let lastUpdateTime = 0;
+
+    const flushUpdate = () => {
+        if (pendingUpdateTimeout != undefined) {
+            clearTimeout(pendingUpdateTimeout)
+            pendingUpdateTimeout = undefined
+        }
+
+        if (pendingUpdate != undefined) {
+            const { progress, status } = pendingUpdate
+            pendingUpdate = undefined
+            lastUpdateTime = Date.now()
+
+            // noinspection JSIgnoredPromiseFromCall
+            task_update(taskId, progress, status)
+        }
+    }
+
+    const context: TaskContext = {
+        signal: abortController.signal,
+        update: ({ progress, status }) => {
+            pendingUpdate = {
+                progress: progress ?? pendingUpdate?.progress,
+                status: status ?? pendingUpdate?.status,
+            }
+
+            if (pendingUpdateTimeout != undefined) {
+                return
+            }
+
+            const wait = lastUpdateTime + TASK_UPDATE_INTERVAL_MS - Date.now();
+            if (wait <= 0) {
+                flushUpdate()
+            } else {
+                pendingUpdateTimeout = setTimeout(flushUpdate, wait)
+            }
+        }
+    }
+
+    try {
+        const result = await task(context);
+
+        flushUpdate()
+        await task_finish(taskId, undefined)
+
+        return result
+    } catch (e) {
+        flushUpdate()
+        await task_finish(taskId, e instanceof Error ? e.message : String(e))
+
+        throw e
+    } finally {
+        runningTasks.delete(taskId)
+    }
+}
+
+export function cancelTask(taskId: string) {
+    runningTasks.get(taskId)?.abort()
+}
+
 function createWidget(id: number | undefined, hostContext: HostContext, type: ComponentType, properties: Props, children: UiWidget[]): Instance {
     const props = Object.fromEntries(
         Object.entries(properties)
             .filter(([key, _]) => key !== "children")
     );
 
+    if (type === "gauntlet:search_bar_dropdown" && typeof props.storageKey === "string") {
+        storeSearchBarDropdownValue(hostContext.gauntletContext, props, props.storageKey, id == undefined)
+    }
+
     const instance: Instance = {
-        widgetId: id != undefined ? id : hostContext.nextId,
+        widgetId: id != undefined ? id : nextWidgetId,
         widgetType: type,
         widgetProperties: props,
         widgetChildren: children,
@@ -151,14 +253,41 @@ function createWidget(id: number | undefined, hostContext: HostContext, type: Co
     };
 
     if (id == undefined) {
-        hostContext.nextId += 1
+        nextWidgetId += 1
     }
 
     return instance
 }
 
+// selected value is kept in plugin's local storage per entrypoint and storage key of the dropdown
+// and restored as default value next time the view is opened
+function storeSearchBarDropdownValue(gauntletContextValue: GauntletContextValue, props: Record<string, any>, storageKey: string, mount: boolean) {
+    const key = `gauntlet:search_bar_dropdown:${gauntletContextValue.entrypointId()}:${storageKey}`;
+
+    const onChange = props.onChange;
+
+    const storedValue = localStorage.getItem(key);
+    if (storedValue != null) {
+        props.defaultValue = storedValue
+
+        // plugin state is not supposed to be changed while react is rendering
+        if (mount && typeof onChange === "function") {
+            setTimeout(() => onChange(storedValue), 0)
+        }
+    }
+
+    props.onChange = (value: string) => {
+        localStorage.setItem(key, value)
+        if (typeof onChange === "function") {
+            onChange(value)
+        }
+    }
+}
+
 const componentModel = op_component_model();
 
+const orderedMemberTypes = orderedMemberTypesOf(componentModel);
+
 export const createHostConfig = (): HostConfig<
     ComponentType,
     PropsWithChildren,
@@ -237,9 +366,9 @@ export const createHostConfig = (): HostConfig<
     shouldSetTextContent: (_type: ComponentType, _props: PropsWithChildren): boolean => {
         return false;
     },
-    getRootHostContext: (_rootContainer: RootUiWidget): HostContext | null => {
+    getRootHostContext: (rootContainer: RootUiWidget): HostContext | null => {
 
-        return new HostContext(1, componentModel);
+        return new HostContext(rootContainer.gauntletContext, componentModel);
     },
     getChildHostContext: (parentHostContext: HostContext, _type: ComponentType, _rootContainer: RootUiWidget): HostContext => {
         return parentHostContext;
@@ -303,21 +432,22 @@ export const createHostConfig = (): HostConfig<
     ): Instance {
         op_log_trace("renderer_js_persistence", `cloneInstance is called, instance: ${Deno.inspect(instance)}, updatePayload: ${Deno.inspect(updatePayload)}, type: ${type}, oldProps: ${Deno.inspect(oldProps)}, newProps: ${Deno.inspect(newProps)}, keepChildren: ${keepChildren}, recyclableInstance: ${Deno.inspect(recyclableInstance)}`)
 
-        const recyclableId = recyclableInstance != null ? recyclableInstance.widgetId : undefined;
+        // clone keeps the id of the original instance, so that the previous render can be patched
+        const widgetId = instance.widgetId;
 
         let clonedInstance: Instance;
 
         if (keepChildren) {
             if (updatePayload !== null) {
-                clonedInstance = createWidget(recyclableId, instance.hostContext, type, newProps, instance.widgetChildren)
+                clonedInstance = createWidget(widgetId, instance.hostContext, type, newProps, instance.widgetChildren)
             } else {
-                clonedInstance = createWidget(recyclableId, instance.hostContext, type, oldProps, instance.widgetChildren)
+                clonedInstance = createWidget(widgetId, instance.hostContext, type, oldProps, instance.widgetChildren)
             }
         } else {
             if (updatePayload !== null) {
-                clonedInstance = createWidget(recyclableId, instance.hostContext, type, newProps, [])
+                clonedInstance = createWidget(widgetId, instance.hostContext, type, newProps, [])
             } else {
-                clonedInstance = createWidget(recyclableId, instance.hostContext, type, oldProps, [])
+                clonedInstance = createWidget(widgetId, instance.hostContext, type, oldProps, [])
             }
         }
 
@@ -345,19 +475,39 @@ export const createHostConfig = (): HostConfig<
     replaceContainerChildren(container: RootUiWidget, newChildren: ChildSet): void {
         // op_log_info("renderer_js_persistence", `replaceContainerChildren is called, container: ${Deno.inspect(container)}, newChildren: ${Deno.inspect(newChildren, { depth: Number.MAX_VALUE })}`)
 
+        const gauntletContextValue = container.gauntletContext
+
+        const oldChildren = container.widgetChildren
+
         container.widgetChildren = newChildren
 
-        const containerComponent = { content: newChildren.map(value => convertComponents(value)) }
+        // root was closed or replaced by newer render of the same entrypoint, client doesn't show it anymore
+        if (roots.get(gauntletContextValue.entrypointId()) !== container) {
+            return
+        }
+
+        // inline views can be dropped by the client at any time, so they are always sent whole
+        const patch = gauntletContextValue.renderLocation() === "View"
+            ? createPatch(oldChildren, newChildren, orderedMemberTypes)
+            : undefined
 
-        // op_log_info("renderer_js_persistence", `Converted container: ${Deno.inspect(containerComponent, { depth: Number.MAX_VALUE })}`)
+        if (patch !== undefined) {
+            // op_log_info("renderer_js_persistence", `Created patch: ${Deno.inspect(patch, { depth: Number.MAX_VALUE })}`)
+
+            if (patch.length === 0) {
+                return
+            }
 
-        op_react_replace_view(
-            gauntletContextValue.renderLocation(),
-            gauntletContextValue.isBottommostView(),
-            gauntletContextValue.entrypointId(),
-            gauntletContextValue.entrypointName(),
-            containerComponent
-        )
+            op_react_patch_view(
+                gauntletContextValue.renderLocation(),
+                gauntletContextValue.isBottommostView(),
+                gauntletContextValue.entrypointId(),
+                gauntletContextValue.entrypointName(),
+                patch
+            )
+        } else {
+            sendReplaceView(gauntletContextValue, newChildren)
+        }
     },
 
     cloneHiddenInstance(
@@ -380,33 +530,21 @@ export const createHostConfig = (): HostConfig<
 });
 
 
-function convertComponents(widget: UiWidget): any {
-    const widgetProperties = Object.fromEntries(
-        Object.entries(widget.widgetProperties)
-            .filter(([_, value]) => typeof value !== "function")
-    );
-
-    const widgetChildren = widget.widgetChildren
-        .map(child => convertComponents(child))
+function sendReplaceView(gauntletContextValue: GauntletContextValue, children: ChildSet) {
+    const containerComponent = { content: children.map(value => convertComponents(value)) }
 
-    return {
-        widgetId: widget.widgetId,
-        widgetType: widget.widgetType,
-        widgetProperties: widgetProperties,
-        widgetChildren: widgetChildren,
-    }
-}
+    // op_log_info("renderer_js_persistence", `Converted container: ${Deno.inspect(containerComponent, { depth: Number.MAX_VALUE })}`)
 
-function shallowDiff(oldObj: Record<string, any>, newObj: Record<string, any>): string[] | null {
-    const uniqueProps = new Set([...Object.keys(oldObj), ...Object.keys(newObj)]);
-    const diff = Array.from(uniqueProps)
-        .filter(propName => propName != "children")
-        .filter(propName => oldObj[propName] !== newObj[propName]);
-
-    return diff.length === 0 ? null : diff;
+    op_react_replace_view(
+        gauntletContextValue.renderLocation(),
+        gauntletContextValue.isBottommostView(),
+        gauntletContextValue.entrypointId(),
+        gauntletContextValue.entrypointName(),
+        gauntletContextValue.inlineViewGeneration(),
+        containerComponent
+    )
 }
 
-
 const createTracedHostConfig = (hostConfig: any) => new Proxy(hostConfig, {
     get(target, propKey, _receiver) {
         const f = (target as any)[propKey];
@@ -435,31 +573,90 @@ const createTracedHostConfig = (hostConfig: any) => new Proxy(hostConfig, {
     }
 });
 
+// widget ids are unique across all roots of the plugin,
+// so that events can be dispatched without knowing which view the widget belongs to
+let nextWidgetId = 1
+
+// every entrypoint is rendered into its own root, there can be one view
+// and any number of inline views of the same plugin shown at the same time
+const roots = new Map<string, RootUiWidget>()
+
+let latestGauntletContextValue: GauntletContextValue | undefined = undefined
+
+function findViewRoot(): RootUiWidget | undefined {
+    return Array.from(roots.values())
+        .find(container => container.gauntletContext.renderLocation() === "View")
+}
+
+export function rootUiWidget(entrypointId: string): UiWidget | undefined {
+    return roots.get(entrypointId)
+}
+
+export function rootUiWidgets(): UiWidget[] {
+    return Array.from(roots.values())
+}
+
 export function clearRenderer() {
-    gauntletContextValue.clear()
+    const containers = Array.from(roots.values());
+
+    roots.clear()
+
+    for (const container of containers) {
+        container.gauntletContext.clear()
+    }
 }
 
-export function rerender(view: ReactNode) {
-    gauntletContextValue.rerender(view)
+export function rerender(entrypointId: string, view: ReactNode) {
+    roots.get(entrypointId)?.gauntletContext.rerender(view)
 }
 
 export function popView() {
-    gauntletContextValue.popView()
+    findViewRoot()?.gauntletContext.popView()
+}
+
+export function setInlineViewGeneration(entrypointId: string, generation: number) {
+    roots.get(entrypointId)?.gauntletContext.setInlineViewGeneration(generation)
+}
+
+// client failed to apply a patch, so its view is out of sync, send current tree whole
+export function replaceView() {
+    const container = findViewRoot();
+
+    if (container !== undefined) {
+        sendReplaceView(container.gauntletContext, container.widgetChildren)
+    }
 }
 
-export function render(entrypointId: string, entrypointName: string, renderLocation: RenderLocation, view: ReactNode): UiWidget {
+export function render(entrypointId: string, entrypointName: string, renderLocation: RenderLocation, view: ReactNode, inlineViewGeneration?: number): UiWidget {
+    // only one view can be opened at a time
+    const replacedRoot = renderLocation === "View" ? findViewRoot() : roots.get(entrypointId);
+    if (replacedRoot !== undefined) {
+        roots.delete(replacedRoot.gauntletContext.entrypointId())
+        replacedRoot.gauntletContext.clear()
+    }
+
     const hostConfig = createHostConfig();
 
     // const reconciler = ReactReconciler(createTracedHostConfig(hostConfig));
     const reconciler = ReactReconciler(hostConfig);
 
+    const gauntletContextValue = new GauntletContextValue();
+
+    if (inlineViewGeneration !== undefined) {
+        gauntletContextValue.setInlineViewGeneration(inlineViewGeneration)
+    }
+
     const container: RootUiWidget = {
         widgetId: 0,
         widgetType: "gauntlet:root",
         widgetProperties: {},
         widgetChildren: [],
+        gauntletContext: gauntletContextValue,
     };
 
+    roots.set(entrypointId, container)
+    latestGauntletContextValue = gauntletContextValue
+
     gauntletContextValue.reset(
         entrypointId,
         entrypointName,
@@ -467,7 +664,7 @@ export function render(entrypointId: string, entrypointName: string, renderLocat
         view,
         (node: ReactNode) => {
             reconciler.updateContainer(
-                node,
+                createElement(gauntletContext.Provider, { value: gauntletContextValue }, node),
                 root,
                 null,
                 null
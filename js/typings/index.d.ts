@@ -19,6 +19,30 @@ type LinuxDesktopApplicationData = {
     icon: ArrayBuffer | undefined,
     desktop_file_path: string,
     startup_wm_class: string | undefined,
+    keywords: string[],
+    actions: LinuxDesktopApplicationAction[],
+}
+
+type LinuxDesktopApplicationAction = {
+    id: string
+    name: string
+}
+
+type LinuxLaunchOptions = {
+    terminal: string | undefined
+    systemd_scope: boolean
+}
+
+type LinuxWindowAction = {
+    type: "Close" | "Minimize" | "ToggleMaximize" | "ToggleFullscreen"
+} | {
+    type: "MoveToWorkspace"
+    workspace: number
+}
+
+type LinuxWorkspace = {
+    id: number
+    name: string
 }
 
 type MacOSDesktopApplicationData = {
@@ -45,7 +69,24 @@ type MacOSDesktopSettings13AndPostData = {
     icon: ArrayBuffer | undefined,
 }
 
-type PluginEvent = ViewEvent | NotReactsKeyboardEvent | RunCommand | RunGeneratedEntrypoint | OpenView | CloseView | PopView | OpenInlineView | RefreshSearchIndex
+type NumbatHistoryEntry = {
+    input: string
+    left: string
+    right: string
+}
+
+type FileSearchItem = {
+    path: string
+    name: string
+    directory: boolean
+}
+
+type FileSearchStatus = {
+    indexing: boolean
+    indexedFiles: number
+}
+
+type PluginEvent = ViewEvent | NotReactsKeyboardEvent | RunCommand | RunGeneratedEntrypoint | OpenView | CloseView | PopView | OpenInlineView | RefreshSearchIndex | CancelTask | CallExportedCommand | ReplaceView
 type RenderLocation = "InlineView" | "View"
 
 type ViewEvent = {
@@ -72,6 +113,8 @@ type NotReactsKeyboardEvent = {
 type OpenView = {
     type: "OpenView"
     entrypointId: string
+    arguments: { [id: string]: string }
+    searchText?: string
 }
 
 type CloseView = {
@@ -86,6 +129,7 @@ type PopView = {
 type RunCommand = {
     type: "RunCommand"
     entrypointId: string
+    arguments: { [id: string]: string }
 }
 
 type RunGeneratedEntrypoint = {
@@ -97,12 +141,30 @@ type RunGeneratedEntrypoint = {
 type OpenInlineView = {
     type: "OpenInlineView"
     text: string
+    generation: number
+    entrypointId: string | null
 }
 
 type RefreshSearchIndex = {
     type: "RefreshSearchIndex"
 }
 
+type CancelTask = {
+    type: "CancelTask"
+    taskId: string
+}
+
+type CallExportedCommand = {
+    type: "CallExportedCommand"
+    callId: string
+    commandId: string
+    arguments: string
+}
+
+type ReplaceView = {
+    type: "ReplaceView"
+}
+
 type PropertyValue = PropertyValueString | PropertyValueNumber | PropertyValueBool | PropertyValueUndefined | PropertyValueNull
 type PropertyValueString = { type: "String", value: string }
 type PropertyValueNumber = { type: "Number", value: number }
@@ -140,6 +202,7 @@ type GeneratedSearchItem = {
     entrypoint_icon: ArrayBuffer | undefined,
     entrypoint_actions: GeneratedSearchItemAction[],
     entrypoint_accessories: GeneratedEntrypointAccessory[],
+    entrypoint_keywords: string[],
 }
 
 type GeneratedSearchItemAction = {
@@ -155,16 +218,29 @@ declare module "gauntlet:core" {
 declare module "gauntlet:bridge/internal-all" {
     function open_settings(): void
     function run_numbat(input: string): { left: string, right: string }
+    function save_numbat(input: string): { left: string, right: string }
+    function numbat_history(): NumbatHistoryEntry[]
+    function clear_numbat_history(): void
     function current_os(): string
     function wayland(): boolean
+    function file_search_configure(roots: string[], ignore: string[]): void
+    function file_search(query: string, limit: number): FileSearchItem[]
+    function file_search_status(): FileSearchStatus
+    function file_search_open(path: string): void
+    function file_search_reveal(path: string): void
 }
 
 declare module "gauntlet:bridge/internal-linux" {
-    function linux_open_application(desktop_id: string): void
+    function linux_open_application(desktop_id: string, desktop_file_path: string, options: LinuxLaunchOptions): Promise<void>
+    function linux_open_application_action(desktop_id: string, desktop_file_path: string, action_id: string, options: LinuxLaunchOptions): Promise<void>
     function linux_x11_focus_window(window_id: string): void
     function linux_wayland_focus_window(window_id: string): void
+    function linux_x11_window_action(window_id: string, action: LinuxWindowAction): void
+    function linux_x11_workspaces(): LinuxWorkspace[]
+    function linux_wayland_window_action(window_id: string, action: LinuxWindowAction): void
+    function linux_wayland_workspaces(): LinuxWorkspace[]
     function linux_application_dirs(): string[]
-    function linux_app_from_path(path: string): Promise<undefined | DesktopPathAction<LinuxDesktopApplicationData>>
+    function linux_app_from_path(path: string, iconTheme: string | undefined): Promise<undefined | DesktopPathAction<LinuxDesktopApplicationData>>
     function application_x11_pending_event(): Promise<X11ApplicationEvent>
     function application_wayland_pending_event(): Promise<WaylandApplicationEvent>
 }
@@ -193,26 +269,44 @@ declare module "gauntlet:bridge/internal-windows" {
 declare module "ext:gauntlet/renderer.js" {
     import { ReactNode } from "react";
 
-    export const render: (entrypointId: string, entrypointName: string, renderLocation: RenderLocation, component: ReactNode) => UiWidget;
+    export const render: (entrypointId: string, entrypointName: string, renderLocation: RenderLocation, component: ReactNode, inlineViewGeneration?: number) => UiWidget;
     export const popView: () => void;
-    export const rerender: (component: ReactNode) => void;
+    export const rerender: (entrypointId: string, component: ReactNode) => void;
     export const clearRenderer: () => void;
+    export const setInlineViewGeneration: (entrypointId: string, generation: number) => void;
+    export const replaceView: () => void;
+    export const rootUiWidget: (entrypointId: string) => UiWidget | undefined;
+    export const rootUiWidgets: () => UiWidget[];
 }
 
 declare module "ext:core/ops" {
     function open_settings(): void
     function run_numbat(input: string): { left: string, right: string }
+    function save_numbat(input: string): { left: string, right: string }
+    function numbat_history(): NumbatHistoryEntry[]
+    function clear_numbat_history(): void
+
+    function file_search_configure(roots: string[], ignore: string[]): void
+    function file_search(query: string, limit: number): FileSearchItem[]
+    function file_search_status(): FileSearchStatus
+    function file_search_open(path: string): void
+    function file_search_reveal(path: string): void
 
     function current_os(): string
     function wayland(): boolean
     function application_x11_pending_event(): Promise<X11ApplicationEvent>
     function application_wayland_pending_event(): Promise<WaylandApplicationEvent>
 
-    function linux_open_application(desktop_id: string): void
+    function linux_open_application(desktop_id: string, desktop_file_path: string, options: LinuxLaunchOptions): Promise<void>
+    function linux_open_application_action(desktop_id: string, desktop_file_path: string, action_id: string, options: LinuxLaunchOptions): Promise<void>
     function linux_x11_focus_window(window_id: string): void
     function linux_wayland_focus_window(window_id: string): void
+    function linux_x11_window_action(window_id: string, action: LinuxWindowAction): void
+    function linux_x11_workspaces(): LinuxWorkspace[]
+    function linux_wayland_window_action(window_id: string, action: LinuxWindowAction): void
+    function linux_wayland_workspaces(): LinuxWorkspace[]
     function linux_application_dirs(): string[]
-    function linux_app_from_path(path: string): Promise<undefined | DesktopPathAction<LinuxDesktopApplicationData>>
+    function linux_app_from_path(path: string, iconTheme: string | undefined): Promise<undefined | DesktopPathAction<LinuxDesktopApplicationData>>
 
     function macos_major_version(): number
     function macos_settings_pre_13(): MacOSDesktopSettingsPre13Data[]
@@ -241,7 +335,7 @@ declare module "ext:core/ops" {
     function asset_data(path: string): Promise<ArrayBuffer>;
     function asset_data_blocking(path: string): ArrayBuffer;
 
-    function op_inline_view_entrypoint_id(): string | null;
+    function op_inline_view_entrypoint_ids(): string[];
     function op_entrypoint_names(): Record<string, string | undefined>;
     function op_plugin_get_pending_event(): Promise<PluginEvent>;
     function hide_window(): void;
@@ -258,8 +352,12 @@ declare module "ext:core/ops" {
 
     function show_hud(display: string): void;
     function update_loading_bar(entrypoint_id: string, show: boolean): void;
+    function task_start(task_id: string, title: string): Promise<void>;
+    function task_update(task_id: string, progress: number | undefined, status: string | undefined): Promise<void>;
+    function task_finish(task_id: string, error: string | undefined): Promise<void>;
 
-    function op_react_replace_view(render_location: RenderLocation, top_level_view: boolean, entrypoint_id: string, entrypoint_name: string, container: any): void;
+    function op_react_replace_view(render_location: RenderLocation, top_level_view: boolean, entrypoint_id: string, entrypoint_name: string, inline_view_generation: number | undefined, container: any): void;
+    function op_react_patch_view(render_location: RenderLocation, top_level_view: boolean, entrypoint_id: string, entrypoint_name: string, patch: any): void;
     function show_plugin_error_view(entrypoint_id: string, render_location: RenderLocation): void;
 
     function fetch_action_id_for_shortcut(entrypointId: string, key: string, modifierShift: boolean, modifierControl: boolean, modifierAlt: boolean, modifierMeta: boolean): Promise<string | undefined>;
@@ -269,6 +367,13 @@ declare module "ext:core/ops" {
     function clipboard_write(data: { text_data?: string, png_data?: ArrayBuffer }): Promise<void>;
     function clipboard_write_text(data: string): Promise<void>;
     function clipboard_clear(): Promise<void>;
+    function clipboard_paste_text(data: string): Promise<void>;
+
+    function launch_context_selected_text(): Promise<string | undefined>;
+    function launch_context_focused_window(): Promise<{ appId?: string, title?: string } | undefined>;
+
+    function plugin_command_call(plugin_id: string, command_id: string, arguments: string): Promise<string>;
+    function plugin_command_result(call_id: string, result: string | undefined, error: string | undefined): Promise<void>;
 
     function environment_gauntlet_version(): number;
     function environment_is_development(): boolean;
@@ -6,24 +6,38 @@ use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
 use clap::Parser;
+use gauntlet_common::cli::add_quicklink;
 use gauntlet_common::cli::is_server_running;
+use gauntlet_common::cli::list_quicklinks;
+use gauntlet_common::cli::open_deep_link;
 use gauntlet_common::cli::open_settings_window;
 use gauntlet_common::cli::open_window;
+use gauntlet_common::cli::remove_quicklink;
 use gauntlet_common::cli::run_action;
 use gauntlet_common::dirs::Dirs;
+use gauntlet_common::model::QuicklinkKind;
 use gauntlet_server::PLUGIN_CONNECT_ENV;
 use gauntlet_server::PLUGIN_UUID_ENV;
 use tracing_subscriber::EnvFilter;
 use vergen_pretty::vergen_pretty_env;
 
+const DEEP_LINK_PREFIX: &str = "gauntlet://";
+
 /// Gauntlet CLI
 ///
 /// If no subcommand is provided server will be started or if one is already running window will be opened
 #[derive(Debug, clap::Parser)]
+#[command(args_conflicts_with_subcommands = true)]
 struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
 
+    /// Deep link to handle, in `gauntlet://run/<plugin-id>/<entrypoint-id>/<action-id>?<argument-id>=<value>`
+    /// or `gauntlet://open/<plugin-id>/<entrypoint-id>?query=<search-text>` format.
+    /// Plugin ID has to be percent-encoded
+    #[arg(value_parser = parse_deep_link)]
+    url: Option<String>,
+
     /// Start server without opening Gauntlet window, only used if no subcommand is provided
     #[arg(long)]
     minimized: bool,
@@ -52,6 +66,43 @@ enum Commands {
         /// `:primary` (action run with Enter shortcut) or
         /// `:secondary` (action run with Shift+Enter shortcut)
         action_id: String,
+
+        /// Argument value in `<id>=<value>` format, can be specified multiple times.
+        /// Argument IDs can be found in plugin manifest at `entrypoint.arguments.*.id`
+        #[arg(long = "argument", short = 'a', value_parser = parse_argument)]
+        arguments: Vec<(String, String)>,
+    },
+    /// Manage quicklinks
+    Quicklink {
+        #[command(subcommand)]
+        command: QuicklinkCommands,
+    },
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum QuicklinkCommands {
+    /// List quicklinks, one per line: id, name, kind and template separated by tabs
+    List,
+    /// Add quicklink and print its id
+    Add {
+        /// Name shown in search results
+        name: String,
+
+        /// URL or shell command, can contain `{query}`, `{clipboard}` and `{selection}` placeholders
+        template: String,
+
+        /// Run template as shell command instead of opening it as URL
+        #[arg(long)]
+        command: bool,
+
+        /// Path to icon image
+        #[arg(long)]
+        icon: Option<String>,
+    },
+    /// Remove quicklink
+    Remove {
+        /// Quicklink ID, can be found using `list` subcommand
+        id: String,
     },
 }
 
@@ -71,6 +122,22 @@ pub fn init() {
         return;
     }
 
+    if let Some(url) = cli.url {
+        if is_server_running() {
+            open_deep_link(url);
+        } else {
+            // deep link may be opened by the system while Gauntlet is not running,
+            // in that case server is started in this process and link is delivered once it is ready
+            register_panic_hook(None);
+
+            std::thread::spawn(move || open_deep_link(url));
+
+            start_server(true);
+        }
+
+        return;
+    }
+
     match cli.command {
         None => {
             if cfg!(feature = "release") {
@@ -97,14 +164,7 @@ pub fn init() {
             if is_server_running() {
                 open_window()
             } else {
-                tracing::info!("Gauntlet Build Information:");
-                for (name, value) in vergen_pretty_env!() {
-                    if let Some(value) = value {
-                        tracing::info!("{}: {}", name, value);
-                    }
-                }
-
-                gauntlet_client::run_app(cli.minimized)
+                start_server(cli.minimized)
             }
         }
         Some(command) => {
@@ -115,14 +175,66 @@ pub fn init() {
                     plugin_id,
                     entrypoint_id,
                     action_id,
+                    arguments,
                 } => {
-                    run_action(plugin_id, entrypoint_id, action_id);
+                    run_action(plugin_id, entrypoint_id, action_id, arguments.into_iter().collect());
+                }
+                Commands::Quicklink { command } => {
+                    match command {
+                        QuicklinkCommands::List => list_quicklinks(),
+                        QuicklinkCommands::Add {
+                            name,
+                            template,
+                            command,
+                            icon,
+                        } => {
+                            let kind = if command {
+                                QuicklinkKind::Command
+                            } else {
+                                QuicklinkKind::Url
+                            };
+
+                            add_quicklink(name, template, kind, icon)
+                        }
+                        QuicklinkCommands::Remove { id } => remove_quicklink(id),
+                    }
                 }
             };
         }
     }
 }
 
+fn start_server(minimized: bool) {
+    tracing::info!("Gauntlet Build Information:");
+    for (name, value) in vergen_pretty_env!() {
+        if let Some(value) = value {
+            tracing::info!("{}: {}", name, value);
+        }
+    }
+
+    gauntlet_client::run_app(minimized)
+}
+
+fn parse_argument(value: &str) -> Result<(String, String), String> {
+    match value.split_once('=') {
+        Some((id, value)) if !id.is_empty() => Ok((id.to_string(), value.to_string())),
+        _ => Err(format!("expected argument in `<id>=<value>` format, got: {}", value)),
+    }
+}
+
+// only the scheme is checked here, rest of the link is validated by the server
+fn parse_deep_link(value: &str) -> Result<String, String> {
+    match value.get(..DEEP_LINK_PREFIX.len()) {
+        Some(prefix) if prefix.eq_ignore_ascii_case(DEEP_LINK_PREFIX) => Ok(value.to_string()),
+        _ => {
+            Err(format!(
+                "expected deep link with `{}` scheme, got: {}",
+                DEEP_LINK_PREFIX, value
+            ))
+        }
+    }
+}
+
 #[cfg(target_os = "macos")]
 fn setup_auto_launch_macos() -> anyhow::Result<()> {
     use anyhow::Context;
@@ -7,6 +7,7 @@ use gauntlet_common::model::PluginId;
 use gauntlet_common::model::RootWidget;
 use gauntlet_common::model::UiRenderLocation;
 use gauntlet_common::model::UiWidgetId;
+use gauntlet_common::model::UiWidgetPatchOp;
 use iced::Task;
 use iced::widget::container;
 
@@ -17,8 +18,14 @@ use crate::ui::widget::action_panel::ActionPanel;
 use crate::ui::widget::events::ComponentWidgetEvent;
 
 pub struct ClientContext {
-    views: Vec<(PluginId, PluginViewContainer)>, // Vec to have stable ordering
-    inline_view_shortcuts: HashMap<PluginId, HashMap<String, PhysicalShortcut>>,
+    views: Vec<(PluginId, EntrypointId, PluginViewContainer)>, // Vec to have stable ordering
+    inline_view_shortcuts: HashMap<(PluginId, EntrypointId), HashMap<String, PhysicalShortcut>>,
+    // incremented every time search prompt changes, used to drop inline views which didn't re-render in time
+    inline_view_generation: u64,
+    inline_view_generations: HashMap<(PluginId, EntrypointId), u64>,
+    // inline views which were used more often are shown first, based on entrypoint frecency stored by server
+    inline_view_usage: HashMap<(PluginId, EntrypointId), f64>,
+    focused_inline_view: Option<(PluginId, EntrypointId)>,
 }
 
 impl ClientContext {
@@ -26,20 +33,89 @@ impl ClientContext {
         Self {
             views: vec![],
             inline_view_shortcuts: HashMap::new(),
+            inline_view_generation: 0,
+            inline_view_generations: HashMap::new(),
+            inline_view_usage: HashMap::new(),
+            focused_inline_view: None,
         }
     }
 
-    pub fn get_first_inline_view_container(&self) -> Option<&PluginViewContainer> {
+    pub fn get_focused_inline_view_container(&self) -> Option<&PluginViewContainer> {
+        let containers = self.get_inline_view_containers();
+
+        let focused = self
+            .focused_inline_view
+            .as_ref()
+            .and_then(|(focused_plugin_id, focused_entrypoint_id)| {
+                containers.iter().find(|(plugin_id, entrypoint_id, _)| {
+                    plugin_id == focused_plugin_id && entrypoint_id == focused_entrypoint_id
+                })
+            });
+
+        focused.or(containers.first()).map(|(_, _, container)| container)
+    }
+
+    pub fn get_focused_inline_view_position(&self) -> Option<(usize, usize)> {
+        let containers = self.get_inline_view_containers();
+
+        let focused = self.get_focused_inline_view_container()?;
+
+        let index = containers.iter().position(|(plugin_id, entrypoint_id, _)| {
+            plugin_id == &focused.plugin_id() && entrypoint_id == &focused.entrypoint_id()
+        })?;
+
+        Some((index, containers.len()))
+    }
+
+    pub fn focus_next_inline_view(&mut self) {
+        self.focus_inline_view_by_offset(1)
+    }
+
+    pub fn focus_previous_inline_view(&mut self) {
+        self.focus_inline_view_by_offset(-1)
+    }
+
+    fn focus_inline_view_by_offset(&mut self, offset: isize) {
+        let Some((index, len)) = self.get_focused_inline_view_position() else {
+            return;
+        };
+
+        let index = (index as isize + offset).rem_euclid(len as isize) as usize;
+
+        self.focused_inline_view = self
+            .get_inline_view_containers()
+            .get(index)
+            .map(|(plugin_id, entrypoint_id, _)| (plugin_id.clone(), entrypoint_id.clone()));
+    }
+
+    pub fn set_inline_view_usage(&mut self, usage: HashMap<(PluginId, EntrypointId), f64>) {
+        self.inline_view_usage = usage;
+    }
+
+    pub fn next_inline_view_generation(&mut self) -> u64 {
+        self.inline_view_generation += 1;
+        self.inline_view_generation
+    }
+
+    pub fn get_stale_inline_views(&self, generation: u64) -> Vec<(PluginId, EntrypointId)> {
         self.get_inline_view_containers()
-            .iter()
-            .next()
-            .map(|(_, container)| container)
+            .into_iter()
+            .map(|(plugin_id, entrypoint_id, _)| (plugin_id.clone(), entrypoint_id.clone()))
+            .filter(|key| {
+                self.inline_view_generations
+                    .get(key)
+                    .is_none_or(|rendered_generation| *rendered_generation < generation)
+            })
+            .collect()
     }
 
-    pub fn get_first_inline_view_action_panel(&self) -> Option<ActionPanel> {
-        self.get_first_inline_view_container()
+    pub fn get_focused_inline_view_action_panel(&self) -> Option<ActionPanel> {
+        self.get_focused_inline_view_container()
             .map(|container| {
-                match self.inline_view_shortcuts.get(&container.plugin_id()) {
+                match self
+                    .inline_view_shortcuts
+                    .get(&(container.plugin_id(), container.entrypoint_id()))
+                {
                     None => container.get_action_panel(&HashMap::new()),
                     Some(shortcuts) => container.get_action_panel(shortcuts),
                 }
@@ -47,11 +123,26 @@ impl ClientContext {
             .flatten()
     }
 
-    pub fn get_inline_view_containers(&self) -> Vec<&(PluginId, PluginViewContainer)> {
-        self.views
+    pub fn get_inline_view_containers(&self) -> Vec<&(PluginId, EntrypointId, PluginViewContainer)> {
+        let mut containers: Vec<_> = self
+            .views
             .iter()
-            .filter(|(_, container)| matches!(container.render_location(), UiRenderLocation::InlineView))
-            .collect()
+            .filter(|(_, _, container)| matches!(container.render_location(), UiRenderLocation::InlineView))
+            .collect();
+
+        let usage = |plugin_id: &PluginId, entrypoint_id: &EntrypointId| {
+            self.inline_view_usage
+                .get(&(plugin_id.clone(), entrypoint_id.clone()))
+                .copied()
+                .unwrap_or_default()
+        };
+
+        // stable sort, views with same usage keep order in which they were first shown
+        containers.sort_by(|(plugin_id_a, entrypoint_id_a, _), (plugin_id_b, entrypoint_id_b, _)| {
+            usage(plugin_id_b, entrypoint_id_b).total_cmp(&usage(plugin_id_a, entrypoint_id_a))
+        });
+
+        containers
     }
 
     pub fn get_mut_or_create_any_view_container(
@@ -60,40 +151,65 @@ impl ClientContext {
         plugin_id: &PluginId,
         entrypoint_id: &EntrypointId,
     ) -> &mut PluginViewContainer {
-        if let Some(index) = self.views.iter().position(|(id, _)| id == plugin_id) {
-            let (_, container) = &mut self.views[index];
+        if let Some(index) = self.position(plugin_id, entrypoint_id) {
+            let (_, _, container) = &mut self.views[index];
             container
         } else {
             let container = PluginViewContainer::new(render_location, plugin_id.clone(), entrypoint_id.clone());
-            self.views.push((plugin_id.clone(), container));
-            let (_, container) = self.views.last_mut().expect("getting just pushed item");
+            self.views.push((plugin_id.clone(), entrypoint_id.clone(), container));
+            let (_, _, container) = self.views.last_mut().expect("getting just pushed item");
             container
         }
     }
 
+    pub fn get_inline_view_container(
+        &self,
+        plugin_id: &PluginId,
+        entrypoint_id: &EntrypointId,
+    ) -> Option<&PluginViewContainer> {
+        self.views
+            .iter()
+            .find(|(id, entrypoint, _)| id == plugin_id && entrypoint == entrypoint_id)
+            .filter(|(_, _, container)| matches!(container.render_location(), UiRenderLocation::InlineView))
+            .map(|(_, _, container)| container)
+    }
+
+    // plugin has at most one view opened, while it can have multiple inline views shown at the same time
     pub fn get_view_container(&self, plugin_id: &PluginId) -> Option<&PluginViewContainer> {
         self.views
             .iter()
-            .find(|(id, _)| id == plugin_id)
-            .filter(|(_, container)| matches!(container.render_location(), UiRenderLocation::View))
-            .map(|(_, container)| container)
+            .find(|(id, _, container)| id == plugin_id && matches!(container.render_location(), UiRenderLocation::View))
+            .map(|(_, _, container)| container)
     }
 
     pub fn get_mut_view_container(&mut self, plugin_id: &PluginId) -> Option<&mut PluginViewContainer> {
-        self.get_mut_any_view_container(plugin_id)
-            .filter(|container| matches!(container.render_location(), UiRenderLocation::View))
+        self.views
+            .iter_mut()
+            .find(|(id, _, container)| id == plugin_id && matches!(container.render_location(), UiRenderLocation::View))
+            .map(|(_, _, container)| container)
     }
 
-    pub fn get_mut_any_view_container(&mut self, plugin_id: &PluginId) -> Option<&mut PluginViewContainer> {
+    pub fn get_mut_any_view_container(
+        &mut self,
+        plugin_id: &PluginId,
+        entrypoint_id: &EntrypointId,
+    ) -> Option<&mut PluginViewContainer> {
         self.views
             .iter_mut()
-            .find(|(id, _)| id == plugin_id)
-            .map(|(_, container)| container)
+            .find(|(id, entrypoint, _)| id == plugin_id && entrypoint == entrypoint_id)
+            .map(|(_, _, container)| container)
+    }
+
+    fn position(&self, plugin_id: &PluginId, entrypoint_id: &EntrypointId) -> Option<usize> {
+        self.views
+            .iter()
+            .position(|(id, entrypoint, _)| id == plugin_id && entrypoint == entrypoint_id)
     }
 
     pub fn render_ui(
         &mut self,
         render_location: UiRenderLocation,
+        inline_view_generation: Option<u64>,
         container: Arc<RootWidget>,
         data: HashMap<UiWidgetId, Vec<u8>>,
         plugin_id: &PluginId,
@@ -101,31 +217,89 @@ impl ClientContext {
         entrypoint_id: &EntrypointId,
         entrypoint_name: &str,
     ) -> AppMsg {
+        if let Some(generation) = inline_view_generation {
+            // late render for previous search prompt, view could have already been expired
+            if generation < self.inline_view_generation {
+                return AppMsg::Noop;
+            }
+
+            self.inline_view_generations
+                .insert((plugin_id.clone(), entrypoint_id.clone()), generation);
+        }
+
         self.get_mut_or_create_any_view_container(render_location, plugin_id, entrypoint_id)
             .replace_view(container, data, plugin_name, entrypoint_name)
     }
 
-    pub fn handle_event(&mut self, plugin_id: &PluginId, event: ComponentWidgetEvent) -> Option<UiViewEvent> {
-        self.get_mut_any_view_container(plugin_id)
+    pub fn patch_ui(
+        &mut self,
+        patch: Vec<UiWidgetPatchOp>,
+        data: HashMap<UiWidgetId, Vec<u8>>,
+        plugin_id: &PluginId,
+        plugin_name: &str,
+        entrypoint_id: &EntrypointId,
+        entrypoint_name: &str,
+    ) -> anyhow::Result<bool> {
+        // view could have been closed while plugin was rendering
+        let Some(container) = self.get_mut_any_view_container(plugin_id, entrypoint_id) else {
+            return Ok(false);
+        };
+
+        // inline views are always sent whole, so patches don't change their generation
+        container.patch_view(patch, data, plugin_name, entrypoint_name)?;
+
+        Ok(true)
+    }
+
+    pub fn load_more_event(&self, plugin_id: &PluginId, entrypoint_id: &EntrypointId) -> Task<AppMsg> {
+        self.views
+            .iter()
+            .find(|(id, entrypoint, _)| id == plugin_id && entrypoint == entrypoint_id)
+            .map(|(_, _, container)| container.load_more_event())
+            .unwrap_or(Task::none())
+    }
+
+    pub fn handle_event(
+        &mut self,
+        plugin_id: &PluginId,
+        entrypoint_id: &EntrypointId,
+        event: ComponentWidgetEvent,
+    ) -> Option<UiViewEvent> {
+        self.get_mut_any_view_container(plugin_id, entrypoint_id)
             .and_then(|view| view.handle_event(plugin_id.clone(), event))
     }
 
-    pub fn set_inline_view_shortcuts(&mut self, shortcuts: HashMap<PluginId, HashMap<String, PhysicalShortcut>>) {
+    pub fn set_inline_view_shortcuts(
+        &mut self,
+        shortcuts: HashMap<(PluginId, EntrypointId), HashMap<String, PhysicalShortcut>>,
+    ) {
         self.inline_view_shortcuts = shortcuts;
     }
 
     pub fn clear_all_views(&mut self) {
-        self.views.clear()
+        self.views.clear();
+        self.inline_view_generations.clear();
+        self.focused_inline_view = None;
     }
 
+    // removes view and all inline views of the plugin
     pub fn clear_view(&mut self, plugin_id: &PluginId) {
-        if let Some(index) = self.views.iter().position(|(id, _)| id == plugin_id) {
+        self.views.retain(|(id, _, _)| id != plugin_id);
+
+        self.inline_view_generations.retain(|(id, _), _| id != plugin_id);
+    }
+
+    pub fn clear_inline_view(&mut self, plugin_id: &PluginId, entrypoint_id: &EntrypointId) {
+        if let Some(index) = self.position(plugin_id, entrypoint_id) {
             self.views.remove(index);
         }
+
+        self.inline_view_generations
+            .remove(&(plugin_id.clone(), entrypoint_id.clone()));
     }
 
     pub fn set_current_focused_item(&mut self, plugin_id: PluginId, target_id: Option<container::Id>) -> Task<AppMsg> {
-        self.get_mut_any_view_container(&plugin_id)
+        self.get_mut_view_container(&plugin_id)
             .map(|view| view.set_focused_item_id(target_id))
             .unwrap_or(Task::none())
     }
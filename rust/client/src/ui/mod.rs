@@ -1,10 +1,15 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::time::Duration;
 
 use client_context::ClientContext;
+use gauntlet_common::model::DeepLink;
+use gauntlet_common::model::DeepLinkAction;
 use gauntlet_common::model::EntrypointId;
+use gauntlet_common::model::FocusedWindow;
 use gauntlet_common::model::KeyboardEventOrigin;
 use gauntlet_common::model::PhysicalKey;
 use gauntlet_common::model::PhysicalShortcut;
@@ -12,10 +17,14 @@ use gauntlet_common::model::PluginId;
 use gauntlet_common::model::RootWidget;
 use gauntlet_common::model::SearchResult;
 use gauntlet_common::model::SearchResultEntrypointActionType;
+use gauntlet_common::model::SearchResultEntrypointArgumentKind;
 use gauntlet_common::model::SearchResultEntrypointType;
+use gauntlet_common::model::SearchScope;
+use gauntlet_common::model::SearchScopeType;
 use gauntlet_common::model::UiRenderLocation;
 use gauntlet_common::model::UiTheme;
 use gauntlet_common::model::UiWidgetId;
+use gauntlet_common::model::UiWidgetPatchOp;
 use gauntlet_common::rpc::server_grpc_api::ServerGrpcApiRequestData;
 use gauntlet_common::rpc::server_grpc_api::ServerGrpcApiResponseData;
 use gauntlet_common_ui::physical_key_model;
@@ -48,22 +57,28 @@ use iced::widget::column;
 use iced::widget::container;
 use iced::widget::horizontal_rule;
 use iced::widget::horizontal_space;
+use iced::widget::pick_list;
+use iced::widget::row;
 use iced::widget::scrollable;
 use iced::widget::text;
 use iced::widget::text::Shaping;
 use iced::widget::text_input;
 use iced::widget::text_input::focus;
 use iced::widget::themer;
+use iced::widget::vertical_space;
 use iced::window;
 use iced_fonts::BOOTSTRAP_FONT_BYTES;
 use iced_fonts::LUCIDE_FONT_BYTES;
+use itertools::Itertools;
 
 use crate::model::UiViewEvent;
 use crate::ui::search_list::search_list;
 use crate::ui::theme::Element;
 use crate::ui::theme::ThemableWidget;
+use crate::ui::theme::button::ButtonStyle;
 use crate::ui::theme::container::ContainerStyle;
 use crate::ui::theme::container::ContainerStyleInner;
+use crate::ui::theme::pick_list::PickListStyle;
 use crate::ui::theme::text_input::TextInputStyle;
 
 mod client_context;
@@ -74,6 +89,7 @@ mod settings;
 mod state;
 #[cfg(any(target_os = "macos", target_os = "windows"))]
 mod sys_tray;
+mod tasks;
 mod theme;
 mod view_container;
 mod widget;
@@ -107,15 +123,25 @@ use crate::ui::state::LoadingBarState;
 use crate::ui::state::MainViewState;
 use crate::ui::state::PluginViewData;
 use crate::ui::state::PluginViewState;
+use crate::ui::state::main_view::EntrypointArgumentValues;
+use crate::ui::state::main_view::SearchResultManageAction;
+use crate::ui::state::main_view::entrypoint_argument_field_id;
 use crate::ui::state::main_view::search_result_action_panel;
 use crate::ui::state::main_view::search_result_bot_panel_right_info;
+use crate::ui::state::main_view::search_result_manage_action;
+use crate::ui::tasks::TaskOutcome;
+use crate::ui::tasks::Tasks;
 use crate::ui::widget::events::ComponentWidgetEvent;
 use crate::ui::widget::root::render_root;
 use crate::ui::windows::MainWindowState;
 use crate::ui::windows::WindowActionMsg;
 #[cfg(target_os = "linux")]
+use crate::ui::windows::wayland_focus::wayland_focus_change_subscription;
+#[cfg(target_os = "linux")]
 use crate::ui::windows::x11_focus::x11_linux_focus_change_subscription;
 
+const INLINE_VIEW_TIMEOUT: Duration = Duration::from_millis(500);
+
 pub struct AppModel {
     // logic
     application_manager: Arc<ApplicationManager>,
@@ -128,12 +154,15 @@ pub struct AppModel {
 
     // ephemeral state
     prompt: String,
+    entrypoint_argument_values: EntrypointArgumentValues,
+    search_scope: Option<SearchScope>,
 
     // state
     client_context: ClientContext,
     global_state: GlobalState,
     search_results: ScrollContent<SearchResult>,
     loading_bar_state: HashSet<(PluginId, EntrypointId)>,
+    tasks: Tasks,
 }
 
 #[derive(Debug, Clone)]
@@ -141,6 +170,8 @@ pub enum AppMsg {
     OpenView {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
+        arguments: HashMap<String, String>,
+        search_text: Option<String>,
     },
     OpenGeneratedView {
         plugin_id: PluginId,
@@ -150,6 +181,8 @@ pub enum AppMsg {
     OpenNewView {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
+        arguments: HashMap<String, String>,
+        search_text: Option<String>,
     },
     OpenNewGeneratedView {
         plugin_id: PluginId,
@@ -159,19 +192,31 @@ pub enum AppMsg {
     RunCommand {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
+        arguments: HashMap<String, String>,
     },
     RunGeneratedEntrypoint(PluginId, EntrypointId, usize),
-    RequestPluginViewOpen(PluginId, EntrypointId),
+    RequestPluginViewOpen(PluginId, EntrypointId, HashMap<String, String>, Option<String>),
     RequestPluginViewPop(PluginId, EntrypointId),
     RunSearchItemAction(SearchResult, usize),
+    RunSearchItemManageAction {
+        search_result: SearchResult,
+        action: SearchResultManageAction,
+    },
     RunPluginAction {
         render_location: UiRenderLocation,
         plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
         widget_id: UiWidgetId,
         id: Option<String>,
     },
     PromptChanged(String),
     PromptSubmit,
+    EntrypointArgumentChanged {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        argument_id: String,
+        value: String,
+    },
     UpdateSearchResults,
     SetSearchResults(Vec<SearchResult>),
     RenderPluginUI {
@@ -181,9 +226,20 @@ pub enum AppMsg {
         entrypoint_name: String,
         render_location: UiRenderLocation,
         top_level_view: bool,
+        inline_view_generation: Option<u64>,
         container: Arc<RootWidget>,
         data: HashMap<UiWidgetId, Vec<u8>>,
     },
+    PatchPluginUI {
+        plugin_id: PluginId,
+        plugin_name: String,
+        entrypoint_id: EntrypointId,
+        entrypoint_name: String,
+        render_location: UiRenderLocation,
+        top_level_view: bool,
+        patch: Arc<Vec<UiWidgetPatchOp>>,
+        data: HashMap<UiWidgetId, Vec<u8>>,
+    },
     HandleRenderPluginUI {
         top_level_view: bool,
         has_children: bool,
@@ -192,6 +248,7 @@ pub enum AppMsg {
     IcedEvent(window::Id, Event),
     WidgetEvent {
         plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
         widget_event: ComponentWidgetEvent,
     },
     Noop,
@@ -214,11 +271,26 @@ pub enum AppMsg {
         entrypoint_id: EntrypointId,
     },
     ShowBackendError(RequestError),
+    OpenDeepLink {
+        deep_link: DeepLink,
+    },
+    RunDeepLink {
+        deep_link: DeepLink,
+    },
     CloseAllReactViews,
     RequestReactViewClose(PluginId),
     SetInlineViewShortcuts {
-        shortcuts: HashMap<PluginId, HashMap<String, PhysicalShortcut>>,
+        shortcuts: HashMap<(PluginId, EntrypointId), HashMap<String, PhysicalShortcut>>,
+    },
+    SetInlineViewUsage {
+        usage: HashMap<(PluginId, EntrypointId), f64>,
+    },
+    ExpireStaleInlineViews {
+        generation: u64,
     },
+    FocusNextInlineView,
+    FocusPreviousInlineView,
+    ExitSearchScope,
     OnPrimaryActionMainViewNoPanelKeyboardWithoutFocus,
     OnPrimaryActionMainViewNoPanel {
         search_result: SearchResult,
@@ -262,6 +334,28 @@ pub enum AppMsg {
         entrypoint_id: EntrypointId,
         show: bool,
     },
+    StartTask {
+        plugin_id: PluginId,
+        plugin_name: String,
+        task_id: String,
+        title: String,
+    },
+    UpdateTask {
+        plugin_id: PluginId,
+        task_id: String,
+        progress: Option<f64>,
+        status: Option<String>,
+    },
+    FinishTask {
+        plugin_id: PluginId,
+        task_id: String,
+        error: Option<String>,
+    },
+    CancelTask {
+        plugin_id: PluginId,
+        task_id: String,
+    },
+    ClearFinishedTasks,
     PendingPluginViewLoadingBar,
     ShowPluginViewLoadingBar,
     FocusPluginViewSearchBar {
@@ -280,6 +374,9 @@ pub enum AppMsg {
         responder: Arc<Mutex<Option<Responder<ServerGrpcApiResponseData>>>>,
     },
     WindowAction(WindowActionMsg),
+    CaptureLaunchContext {
+        focused_window: Option<FocusedWindow>,
+    },
     ResetWindowState,
     ResetMainWindowItemFocus,
     HandleScenario(ScenarioRunnerMsg),
@@ -345,6 +442,8 @@ fn new(minimized: bool, #[allow(unused)] scenario_runner_data: Option<ScenarioRu
         setup_data.window_position_file,
         setup_data.close_on_unfocus,
         setup_data.window_position_mode,
+        setup_data.window_size,
+        setup_data.compact_mode,
         #[cfg(target_os = "linux")]
         wayland,
         #[cfg(target_os = "linux")]
@@ -371,12 +470,15 @@ fn new(minimized: bool, #[allow(unused)] scenario_runner_data: Option<ScenarioRu
 
             // ephemeral state
             prompt: "".to_string(),
+            entrypoint_argument_values: EntrypointArgumentValues::default(),
+            search_scope: None,
 
             // state
             global_state,
             client_context,
             search_results: ScrollContent::new(vec![]),
             loading_bar_state: HashSet::new(),
+            tasks: Tasks::default(),
         },
         Task::batch(tasks),
     )
@@ -392,10 +494,20 @@ fn title(state: &AppModel, window: window::Id) -> String {
 }
 
 fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
+    let task = update_state(state, message);
+
+    let expanded = state.main_window_expanded();
+
+    Task::batch([task, state.main_window_state.set_expanded(expanded)])
+}
+
+fn update_state(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
     match message {
         AppMsg::OpenView {
             plugin_id,
             entrypoint_id,
+            arguments,
+            search_text,
         } => {
             Task::batch([
                 GlobalState::pending_plugin_main_view(
@@ -403,17 +515,29 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                     plugin_id.clone(),
                     entrypoint_id.clone(),
                 ),
-                Task::done(AppMsg::RequestPluginViewOpen(plugin_id, entrypoint_id)),
+                Task::done(AppMsg::RequestPluginViewOpen(
+                    plugin_id,
+                    entrypoint_id,
+                    arguments,
+                    search_text,
+                )),
                 Task::done(AppMsg::PendingPluginViewLoadingBar),
             ])
         }
         AppMsg::OpenNewView {
             plugin_id,
             entrypoint_id,
+            arguments,
+            search_text,
         } => {
             Task::batch([
                 GlobalState::pending_plugin(&mut state.global_state, plugin_id.clone(), entrypoint_id.clone()),
-                Task::done(AppMsg::RequestPluginViewOpen(plugin_id, entrypoint_id)),
+                Task::done(AppMsg::RequestPluginViewOpen(
+                    plugin_id,
+                    entrypoint_id,
+                    arguments,
+                    search_text,
+                )),
                 Task::done(AppMsg::WindowAction(WindowActionMsg::ShowWindow)),
             ])
         }
@@ -446,19 +570,20 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
         AppMsg::RunCommand {
             plugin_id,
             entrypoint_id,
+            arguments,
         } => {
             Task::batch([
                 Task::done(AppMsg::WindowAction(WindowActionMsg::HideWindow)),
-                state.run_command(plugin_id, entrypoint_id),
+                state.run_command(plugin_id, entrypoint_id, arguments),
             ])
         }
         AppMsg::RunGeneratedEntrypoint(plugin_id, entrypoint_id, action_index) => {
             state.run_generated_entrypoint(plugin_id, entrypoint_id, action_index)
         }
-        AppMsg::RequestPluginViewOpen(plugin_id, entrypoint_id) => {
+        AppMsg::RequestPluginViewOpen(plugin_id, entrypoint_id, arguments, search_text) => {
             let msg = state
                 .application_manager
-                .request_render_view(plugin_id, entrypoint_id)
+                .request_render_view(plugin_id, entrypoint_id, arguments, search_text)
                 .map(|action_shortcuts| AppMsg::OnOpenView { action_shortcuts })
                 .unwrap_or_else(|err| AppMsg::ShowBackendError(err.into()));
 
@@ -481,8 +606,15 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                 state.application_manager.request_view_close(plugin_id);
             }
 
-            for (plugin_id, _) in state.client_context.get_inline_view_containers() {
-                state.application_manager.request_view_close(plugin_id.clone());
+            let inline_view_plugin_ids: HashSet<_> = state
+                .client_context
+                .get_inline_view_containers()
+                .into_iter()
+                .map(|(plugin_id, _, _)| plugin_id.clone())
+                .collect();
+
+            for plugin_id in inline_view_plugin_ids {
+                state.application_manager.request_view_close(plugin_id);
             }
             state.client_context.clear_all_views();
 
@@ -491,6 +623,7 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
         AppMsg::RunPluginAction {
             render_location,
             plugin_id,
+            entrypoint_id,
             widget_id,
             id,
         } => {
@@ -498,11 +631,22 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
 
             match render_location {
                 UiRenderLocation::InlineView => {
+                    if state
+                        .client_context
+                        .get_inline_view_container(&plugin_id, &entrypoint_id)
+                        .is_some()
+                    {
+                        state
+                            .application_manager
+                            .mark_inline_view_usage(plugin_id.clone(), entrypoint_id.clone());
+                    }
+
                     Task::batch([
                         Task::done(AppMsg::WindowAction(WindowActionMsg::HideWindow)),
                         Task::done(AppMsg::WidgetEvent {
                             widget_event,
                             plugin_id,
+                            entrypoint_id,
                         }),
                     ])
                 }
@@ -510,17 +654,29 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                     Task::done(AppMsg::WidgetEvent {
                         widget_event,
                         plugin_id,
+                        entrypoint_id,
                     })
                 }
             }
         }
         AppMsg::RunSearchItemAction(search_result, action_index) => {
+            if let Some(action) = search_result_manage_action(&search_result, action_index) {
+                return Task::done(AppMsg::RunSearchItemManageAction { search_result, action });
+            }
+
             match search_result.entrypoint_type {
                 SearchResultEntrypointType::Command => {
                     if action_index == 0 {
+                        let values = &state.entrypoint_argument_values;
+
+                        if let Some(argument_id) = values.first_missing_required(&search_result) {
+                            return focus(entrypoint_argument_field_id(&argument_id));
+                        }
+
                         Task::done(AppMsg::RunCommand {
                             entrypoint_id: search_result.entrypoint_id.clone(),
                             plugin_id: search_result.plugin_id.clone(),
+                            arguments: values.values(&search_result),
                         })
                     } else {
                         Task::none()
@@ -528,9 +684,17 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                 }
                 SearchResultEntrypointType::View => {
                     if action_index == 0 {
+                        let values = &state.entrypoint_argument_values;
+
+                        if let Some(argument_id) = values.first_missing_required(&search_result) {
+                            return focus(entrypoint_argument_field_id(&argument_id));
+                        }
+
                         Task::done(AppMsg::OpenView {
                             plugin_id: search_result.plugin_id.clone(),
                             entrypoint_id: search_result.entrypoint_id.clone(),
+                            arguments: values.values(&search_result),
+                            search_text: None,
                         })
                     } else {
                         Task::none()
@@ -560,6 +724,43 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                 }
             }
         }
+        AppMsg::RunSearchItemManageAction { search_result, action } => {
+            let plugin_id = search_result.plugin_id;
+            let entrypoint_id = search_result.entrypoint_id;
+
+            let result = match action {
+                SearchResultManageAction::Pin => {
+                    state
+                        .application_manager
+                        .set_entrypoint_search_pinned(plugin_id, entrypoint_id, true)
+                }
+                SearchResultManageAction::Unpin => {
+                    state
+                        .application_manager
+                        .set_entrypoint_search_pinned(plugin_id, entrypoint_id, false)
+                }
+                SearchResultManageAction::MovePin(direction) => {
+                    state
+                        .application_manager
+                        .move_entrypoint_search_pin(plugin_id, entrypoint_id, direction)
+                }
+                SearchResultManageAction::Hide => {
+                    state
+                        .application_manager
+                        .set_entrypoint_search_hidden(plugin_id, entrypoint_id, true)
+                }
+                SearchResultManageAction::Unhide => {
+                    state
+                        .application_manager
+                        .set_entrypoint_search_hidden(plugin_id, entrypoint_id, false)
+                }
+            };
+
+            match result {
+                Ok(()) => state.search(state.prompt.clone(), false),
+                Err(err) => Task::done(AppMsg::ShowBackendError(err.into())),
+            }
+        }
         AppMsg::PromptChanged(mut new_prompt) => {
             let task = match &mut state.global_state {
                 GlobalState::MainView {
@@ -569,6 +770,27 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                 } => {
                     new_prompt.truncate(100); // search query uses regex so just to be safe truncate the prompt
 
+                    // search field reports prompt change on backspace even if prompt is already empty
+                    if new_prompt.is_empty() && state.prompt.is_empty() && state.search_scope.is_some() {
+                        return Task::done(AppMsg::ExitSearchScope);
+                    }
+
+                    if state.search_scope.is_none() {
+                        if let Some((alias, rest)) = new_prompt.split_once(' ') {
+                            match state.application_manager.search_scope(alias) {
+                                Ok(Some(scope)) => {
+                                    // results of previous prompt are not relevant in scoped mode
+                                    state.client_context.clear_all_views();
+
+                                    state.search_scope = Some(scope);
+                                    new_prompt = rest.to_owned();
+                                }
+                                Ok(None) => {}
+                                Err(err) => return Task::done(AppMsg::ShowBackendError(err.into())),
+                            }
+                        }
+                    }
+
                     state.prompt = new_prompt.clone();
 
                     MainViewState::initial(sub_state);
@@ -589,6 +811,18 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             }
         }
         AppMsg::PromptSubmit => state.global_state.primary(&state.client_context, &state.search_results),
+        AppMsg::EntrypointArgumentChanged {
+            plugin_id,
+            entrypoint_id,
+            argument_id,
+            value,
+        } => {
+            state
+                .entrypoint_argument_values
+                .set(plugin_id, entrypoint_id, argument_id, value);
+
+            Task::none()
+        }
         AppMsg::SetSearchResults(new_search_results) => {
             let first_focus = if state.search_results.items().is_empty() {
                 // this is supposed to only be useful only the first time main window is opened
@@ -609,39 +843,81 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             entrypoint_name,
             render_location,
             top_level_view,
+            inline_view_generation,
             container,
             data,
         } => {
             let has_children = container.content.is_some();
 
-            let close_task = if let UiRenderLocation::InlineView = render_location {
-                if !has_children {
-                    Task::done(AppMsg::RequestReactViewClose(plugin_id.clone()))
-                } else {
-                    Task::none()
-                }
-            } else {
-                Task::none()
-            };
+            let render_msg = if matches!(render_location, UiRenderLocation::InlineView) && !has_children {
+                // other inline views of the same plugin can still have content, so only this one is removed
+                state.client_context.clear_inline_view(&plugin_id, &entrypoint_id);
 
-            Task::batch([
-                Task::done(state.client_context.render_ui(
+                AppMsg::Noop
+            } else {
+                state.client_context.render_ui(
                     render_location,
+                    inline_view_generation,
                     container,
                     data,
                     &plugin_id,
                     &plugin_name,
                     &entrypoint_id,
                     &entrypoint_name,
-                )),
+                )
+            };
+
+            Task::batch([
+                Task::done(render_msg),
+                state.client_context.load_more_event(&plugin_id, &entrypoint_id),
                 Task::done(AppMsg::HandleRenderPluginUI {
                     top_level_view,
                     has_children,
                     render_location,
                 }),
-                close_task,
             ])
         }
+        AppMsg::PatchPluginUI {
+            plugin_id,
+            plugin_name,
+            entrypoint_id,
+            entrypoint_name,
+            render_location,
+            top_level_view,
+            patch,
+            data,
+        } => {
+            let result = state.client_context.patch_ui(
+                Arc::unwrap_or_clone(patch),
+                data,
+                &plugin_id,
+                &plugin_name,
+                &entrypoint_id,
+                &entrypoint_name,
+            );
+
+            match result {
+                Ok(true) => {
+                    // patch is only sent for views which already have content
+                    Task::batch([
+                        Task::done(AppMsg::HandleRenderPluginUI {
+                            top_level_view,
+                            has_children: true,
+                            render_location,
+                        }),
+                        state.client_context.load_more_event(&plugin_id, &entrypoint_id),
+                    ])
+                }
+                Ok(false) => Task::none(),
+                Err(err) => {
+                    tracing::error!("Unable to apply view patch, requesting whole view: {:?}", err);
+
+                    state.application_manager.request_view_replace(plugin_id);
+
+                    Task::none()
+                }
+            }
+        }
         AppMsg::HandleRenderPluginUI {
             top_level_view,
             has_children,
@@ -684,14 +960,14 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                     };
 
                     let shortcuts_task = if let UiRenderLocation::InlineView = render_location {
-                        state.request_inline_view_shortcuts()
+                        Task::batch([state.request_inline_view_shortcuts(), state.request_inline_view_usage()])
                     } else {
                         Task::none()
                     };
 
                     Task::batch([focus_task, state_task, shortcuts_task])
                 }
-                GlobalState::ErrorView { .. } => Task::none(),
+                GlobalState::ErrorView { .. } | GlobalState::DeepLinkConfirmation { .. } => Task::none(),
                 GlobalState::PluginView { plugin_view_data, .. } => {
                     plugin_view_data.top_level_view = top_level_view;
 
@@ -738,6 +1014,16 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                         modifiers.logo()
                     );
                     match key {
+                        Key::Named(Named::ArrowLeft)
+                            if modifiers.alt() && matches!(state.global_state, GlobalState::MainView { .. }) =>
+                        {
+                            Task::done(AppMsg::FocusPreviousInlineView)
+                        }
+                        Key::Named(Named::ArrowRight)
+                            if modifiers.alt() && matches!(state.global_state, GlobalState::MainView { .. }) =>
+                        {
+                            Task::done(AppMsg::FocusNextInlineView)
+                        }
                         Key::Named(Named::ArrowUp) => {
                             state.global_state.up(&mut state.client_context, &state.search_results)
                         }
@@ -783,6 +1069,13 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                                     ..
                                 } => {
                                     match sub_state {
+                                        MainViewState::None if state.prompt.is_empty() => {
+                                            // same as backspace in focused search field
+                                            Task::batch([
+                                                Task::done(AppMsg::PromptChanged(String::new())),
+                                                focus(search_field_id.clone()),
+                                            ])
+                                        }
                                         MainViewState::None => {
                                             AppModel::backspace_prompt(&mut state.prompt, search_field_id.clone())
                                         }
@@ -790,7 +1083,9 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                                         MainViewState::InlineViewActionPanel { .. } => Task::none(),
                                     }
                                 }
-                                GlobalState::ErrorView { .. } => Task::none(),
+                                GlobalState::ErrorView { .. } | GlobalState::DeepLinkConfirmation { .. } => {
+                                    Task::none()
+                                }
                                 GlobalState::PluginView {
                                     plugin_view_data,
                                     sub_state,
@@ -838,6 +1133,13 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                 Task::none()
             }
         }
+        AppMsg::IcedEvent(window_id, Event::Window(window::Event::FileDropped(path))) => {
+            if state.main_window_state.main_window_id == Some(window_id) {
+                state.handle_file_drop(path)
+            } else {
+                Task::none()
+            }
+        }
         AppMsg::IcedEvent(_, _) => Task::none(),
         AppMsg::WidgetEvent {
             widget_event: ComponentWidgetEvent::Noop,
@@ -850,7 +1152,8 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
         AppMsg::WidgetEvent {
             widget_event,
             plugin_id,
-        } => state.handle_plugin_event(widget_event, plugin_id),
+            entrypoint_id,
+        } => state.handle_plugin_event(widget_event, plugin_id, entrypoint_id),
         AppMsg::Noop => Task::none(),
         AppMsg::ShowPreferenceRequiredView {
             plugin_id,
@@ -894,6 +1197,17 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                 },
             )
         }
+        AppMsg::OpenDeepLink { deep_link } => {
+            if deep_link.trusted {
+                Task::done(AppMsg::RunDeepLink { deep_link })
+            } else {
+                Task::batch([
+                    GlobalState::deep_link_confirmation(&mut state.global_state, deep_link),
+                    Task::done(AppMsg::WindowAction(WindowActionMsg::ShowWindow)),
+                ])
+            }
+        }
+        AppMsg::RunDeepLink { deep_link } => state.run_deep_link(deep_link),
         AppMsg::OpenSettings(params) => Task::done(AppMsg::Settings(SettingsMsg::OpenSettings(params))),
         AppMsg::OnOpenView { action_shortcuts } => {
             match &mut state.global_state {
@@ -908,7 +1222,7 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                         }
                     };
                 }
-                GlobalState::ErrorView { .. } => {}
+                GlobalState::ErrorView { .. } | GlobalState::DeepLinkConfirmation { .. } => {}
                 GlobalState::PluginView { plugin_view_data, .. } => {
                     plugin_view_data.action_shortcuts = action_shortcuts;
                 }
@@ -927,11 +1241,9 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                     match sub_state {
                         MainViewState::None => {
                             if let Some(search_item) = focused_search_result.get(&state.search_results) {
-                                if search_item.entrypoint_actions.len() > 0 {
-                                    MainViewState::search_result_action_panel(sub_state, keyboard, search_item.clone());
-                                }
+                                MainViewState::search_result_action_panel(sub_state, keyboard, search_item.clone());
                             } else {
-                                if let Some(view) = state.client_context.get_first_inline_view_container() {
+                                if let Some(view) = state.client_context.get_focused_inline_view_container() {
                                     MainViewState::inline_result_action_panel(
                                         sub_state,
                                         keyboard,
@@ -964,14 +1276,29 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                         PluginViewState::ActionPanel { .. } => PluginViewState::initial(sub_state),
                     }
                 }
-                GlobalState::ErrorView { .. } => {}
+                GlobalState::ErrorView { .. } | GlobalState::DeepLinkConfirmation { .. } => {}
                 GlobalState::PendingPluginView { .. } => {}
             }
 
             Task::none()
         }
         AppMsg::OnPrimaryActionMainViewNoPanelKeyboardWithoutFocus => {
-            Task::done(AppMsg::OnAnyActionMainViewNoPanelKeyboardAtIndex { index: 0 })
+            match &state.search_scope {
+                Some(SearchScope {
+                    plugin_id,
+                    entrypoint_id,
+                    scope_type: SearchScopeType::View,
+                    ..
+                }) => {
+                    Task::done(AppMsg::OpenView {
+                        plugin_id: plugin_id.clone(),
+                        entrypoint_id: entrypoint_id.clone(),
+                        arguments: HashMap::new(),
+                        search_text: Some(state.prompt.clone()),
+                    })
+                }
+                _ => Task::done(AppMsg::OnAnyActionMainViewNoPanelKeyboardAtIndex { index: 0 }),
+            }
         }
         AppMsg::OnPrimaryActionMainViewNoPanel { search_result } => {
             Task::done(AppMsg::RunSearchItemAction(search_result, 0))
@@ -980,7 +1307,12 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             Task::done(AppMsg::OnAnyActionMainViewNoPanelKeyboardAtIndex { index: 1 })
         }
         AppMsg::OnSecondaryActionMainViewNoPanelKeyboardWithFocus { search_result } => {
-            Task::done(AppMsg::RunSearchItemAction(search_result, 1))
+            // secondary shortcut is only for actions provided by entrypoint
+            if search_result_manage_action(&search_result, 1).is_some() {
+                Task::none()
+            } else {
+                Task::done(AppMsg::RunSearchItemAction(search_result, 1))
+            }
         }
         AppMsg::OnAnyActionMainViewSearchResultPanelKeyboardWithFocus { search_result, index } => {
             Task::batch([
@@ -989,15 +1321,17 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             ])
         }
         AppMsg::OnAnyActionMainViewInlineViewPanelKeyboardWithFocus { index } => {
-            match state.client_context.get_first_inline_view_container() {
+            match state.client_context.get_focused_inline_view_container() {
                 Some(container) => {
                     let plugin_id = container.plugin_id();
+                    let entrypoint_id = container.entrypoint_id();
 
                     Task::batch([
                         Task::done(AppMsg::ToggleActionPanel { keyboard: true }),
                         Task::done(AppMsg::RunPluginAction {
                             render_location: UiRenderLocation::InlineView,
                             plugin_id,
+                            entrypoint_id,
                             widget_id: index,
                             id: None,
                         }),
@@ -1022,11 +1356,16 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             id,
             plugin_id,
         } => {
+            let Some(view) = state.client_context.get_view_container(&plugin_id) else {
+                return Task::none();
+            };
+
             Task::batch([
                 Task::done(AppMsg::ToggleActionPanel { keyboard: true }),
                 Task::done(AppMsg::RunPluginAction {
                     render_location: UiRenderLocation::View,
                     plugin_id,
+                    entrypoint_id: view.entrypoint_id(),
                     widget_id,
                     id,
                 }),
@@ -1045,13 +1384,14 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                     }
                 }
                 GlobalState::PluginView { .. } => Task::none(),
-                GlobalState::ErrorView { .. } => Task::none(),
+                GlobalState::ErrorView { .. } | GlobalState::DeepLinkConfirmation { .. } => Task::none(),
                 GlobalState::PendingPluginView { .. } => Task::none(),
             }
         }
         AppMsg::OnAnyActionMainViewNoPanelKeyboardAtIndex { index } => {
-            if let Some(container) = state.client_context.get_first_inline_view_container() {
+            if let Some(container) = state.client_context.get_focused_inline_view_container() {
                 let plugin_id = container.plugin_id();
+                let entrypoint_id = container.entrypoint_id();
                 let action_ids = container.get_action_widgets();
 
                 match action_ids.get(index) {
@@ -1061,6 +1401,7 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                         Task::done(AppMsg::RunPluginAction {
                             render_location: UiRenderLocation::InlineView,
                             plugin_id,
+                            entrypoint_id,
                             widget_id,
                             id: None,
                         })
@@ -1094,7 +1435,7 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                         MainViewState::InlineViewActionPanel { .. } => Task::none(),
                     }
                 }
-                GlobalState::ErrorView { .. } => Task::none(),
+                GlobalState::ErrorView { .. } | GlobalState::DeepLinkConfirmation { .. } => Task::none(),
                 GlobalState::PluginView { .. } => Task::none(),
                 GlobalState::PendingPluginView { .. } => Task::none(),
             }
@@ -1104,18 +1445,76 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             id,
             plugin_id,
         } => {
+            let Some(view) = state.client_context.get_view_container(&plugin_id) else {
+                return Task::none();
+            };
+
             Task::done(AppMsg::RunPluginAction {
                 render_location: UiRenderLocation::View,
                 plugin_id,
+                entrypoint_id: view.entrypoint_id(),
                 widget_id,
                 id,
             })
         }
+        AppMsg::ExpireStaleInlineViews { generation } => {
+            // plugin keeps rendering its other inline views, late renders of this one are dropped by generation
+            for (plugin_id, entrypoint_id) in state.client_context.get_stale_inline_views(generation) {
+                state.client_context.clear_inline_view(&plugin_id, &entrypoint_id);
+            }
+
+            Task::none()
+        }
+        AppMsg::FocusNextInlineView => {
+            state.client_context.focus_next_inline_view();
+
+            if let GlobalState::MainView { sub_state, .. } = &mut state.global_state {
+                MainViewState::initial(sub_state);
+            }
+
+            Task::none()
+        }
+        AppMsg::FocusPreviousInlineView => {
+            state.client_context.focus_previous_inline_view();
+
+            if let GlobalState::MainView { sub_state, .. } = &mut state.global_state {
+                MainViewState::initial(sub_state);
+            }
+
+            Task::none()
+        }
+        AppMsg::ExitSearchScope => {
+            if state.search_scope.take().is_none() {
+                return Task::none();
+            }
+
+            state.client_context.clear_all_views();
+
+            match &mut state.global_state {
+                GlobalState::MainView {
+                    sub_state,
+                    search_field_id,
+                    ..
+                } => {
+                    MainViewState::initial(sub_state);
+
+                    let search_field_id = search_field_id.clone();
+
+                    Task::batch([state.search(state.prompt.clone(), true), focus(search_field_id)])
+                }
+                _ => Task::none(),
+            }
+        }
         AppMsg::SetInlineViewShortcuts { shortcuts } => {
             state.client_context.set_inline_view_shortcuts(shortcuts);
 
             Task::none()
         }
+        AppMsg::SetInlineViewUsage { usage } => {
+            state.client_context.set_inline_view_usage(usage);
+
+            Task::none()
+        }
         AppMsg::ResetMainViewState => {
             match &mut state.global_state {
                 GlobalState::MainView { sub_state, .. } => {
@@ -1123,7 +1522,7 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
 
                     Task::none()
                 }
-                GlobalState::ErrorView { .. } => Task::none(),
+                GlobalState::ErrorView { .. } | GlobalState::DeepLinkConfirmation { .. } => Task::none(),
                 GlobalState::PluginView { .. } => Task::none(),
                 GlobalState::PendingPluginView { .. } => Task::none(),
             }
@@ -1141,6 +1540,60 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
 
             Task::none()
         }
+        AppMsg::StartTask {
+            plugin_id,
+            plugin_name,
+            task_id,
+            title,
+        } => {
+            state.tasks.start(plugin_id, plugin_name, task_id, title);
+
+            Task::none()
+        }
+        AppMsg::UpdateTask {
+            plugin_id,
+            task_id,
+            progress,
+            status,
+        } => {
+            state.tasks.update(&plugin_id, &task_id, progress, status);
+
+            Task::none()
+        }
+        AppMsg::FinishTask {
+            plugin_id,
+            task_id,
+            error,
+        } => {
+            let Some(task) = state.tasks.finish(&plugin_id, &task_id, error) else {
+                return Task::none();
+            };
+
+            // launcher was closed while task was running, outcome is shown in hud instead
+            if state.main_window_state.main_window_id.is_some() {
+                return Task::none();
+            }
+
+            let display = match task.outcome {
+                TaskOutcome::Failed { error } => format!("{} failed: {}", task.title, error),
+                TaskOutcome::Cancelled => format!("{} cancelled", task.title),
+                _ => format!("{} finished", task.title),
+            };
+
+            Task::done(AppMsg::WindowAction(WindowActionMsg::ShowHud { display }))
+        }
+        AppMsg::CancelTask { plugin_id, task_id } => {
+            if state.tasks.cancel(&plugin_id, &task_id) {
+                state.application_manager.cancel_task(plugin_id, task_id);
+            }
+
+            Task::none()
+        }
+        AppMsg::ClearFinishedTasks => {
+            state.tasks.clear_finished();
+
+            Task::none()
+        }
         AppMsg::PendingPluginViewLoadingBar => {
             if let GlobalState::MainView {
                 pending_plugin_view_loading_bar,
@@ -1180,6 +1633,9 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             view.focus_search_bar(widget_id)
         }
         AppMsg::WindowAction(action) => state.main_window_state.handle_action(action),
+        AppMsg::CaptureLaunchContext { focused_window } => {
+            capture_launch_context(state.application_manager.clone(), focused_window).map(|()| AppMsg::Noop)
+        }
         AppMsg::SetTheme { theme } => {
             state.theme = GauntletComplexTheme::new(theme);
 
@@ -1198,9 +1654,16 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                             plugin_id,
                             entrypoint_id,
                         } => {
-                            Task::done(AppMsg::RunEntrypoint {
-                                plugin_id,
-                                entrypoint_id,
+                            // entrypoint run by global shortcut doesn't open the window, but still needs the context
+                            capture_launch_context(
+                                state.application_manager.clone(),
+                                state.main_window_state.focused_window(),
+                            )
+                            .map(move |()| {
+                                AppMsg::RunEntrypoint {
+                                    plugin_id: plugin_id.clone(),
+                                    entrypoint_id: entrypoint_id.clone(),
+                                }
                             })
                         }
                         GlobalShortcutAction::Noop => Task::none(),
@@ -1221,7 +1684,7 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
 
             Task::future(async move {
                 application_manager
-                    .run_action(plugin_id, entrypoint_id, ":primary".to_string())
+                    .run_action(plugin_id, entrypoint_id, ":primary".to_string(), HashMap::new())
                     .await
                     .map(|()| AppMsg::Noop)
                     .unwrap_or_else(|err| AppMsg::ShowBackendError(err.into()))
@@ -1338,6 +1801,7 @@ fn view_hud(hud_display: &str) -> Element<'_, AppMsg> {
 
 fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
     match &state.global_state {
+        GlobalState::DeepLinkConfirmation { deep_link } => view_deep_link_confirmation(deep_link),
         GlobalState::ErrorView { error_view } => {
             match error_view {
                 ErrorViewData::PreferenceRequired {
@@ -1530,6 +1994,98 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                 .width(Length::Fill)
                 .themed(TextInputStyle::MainSearch);
 
+            let argument_fields: Vec<Element<_>> = match focused_search_result.get(&state.search_results) {
+                None => vec![],
+                Some(search_item) => {
+                    search_item
+                        .entrypoint_arguments
+                        .iter()
+                        .map(|argument| {
+                            let plugin_id = search_item.plugin_id.clone();
+                            let entrypoint_id = search_item.entrypoint_id.clone();
+                            let argument_id = argument.id.clone();
+
+                            let value = state.entrypoint_argument_values.get(
+                                &search_item.plugin_id,
+                                &search_item.entrypoint_id,
+                                &argument.id,
+                            );
+
+                            let on_change = move |value| {
+                                AppMsg::EntrypointArgumentChanged {
+                                    plugin_id: plugin_id.clone(),
+                                    entrypoint_id: entrypoint_id.clone(),
+                                    argument_id: argument_id.clone(),
+                                    value,
+                                }
+                            };
+
+                            match &argument.kind {
+                                SearchResultEntrypointArgumentKind::Text
+                                | SearchResultEntrypointArgumentKind::Password => {
+                                    let secure = matches!(argument.kind, SearchResultEntrypointArgumentKind::Password);
+
+                                    text_input(&argument.placeholder, value)
+                                        .on_input(on_change)
+                                        .on_submit(AppMsg::PromptSubmit)
+                                        .ignore_with_modifiers(true)
+                                        .secure(secure)
+                                        .id(entrypoint_argument_field_id(&argument.id))
+                                        .width(Length::Fixed(150.0))
+                                        .themed(TextInputStyle::FormInput)
+                                }
+                                SearchResultEntrypointArgumentKind::Dropdown { options } => {
+                                    let selected = options.iter().find(|option| option.value == value).cloned();
+
+                                    pick_list(options.as_slice(), selected, move |option| on_change(option.value))
+                                        .placeholder(&argument.placeholder)
+                                        .width(Length::Fixed(150.0))
+                                        .themed(PickListStyle::Default)
+                                }
+                            }
+                        })
+                        .collect()
+                }
+            };
+
+            let input: Element<_> = match &state.search_scope {
+                None => input,
+                Some(scope) => {
+                    let chip: Element<_> = text(scope.entrypoint_name.clone()).shaping(Shaping::Advanced).into();
+
+                    let chip: Element<_> = button(chip)
+                        .on_press(AppMsg::ExitSearchScope)
+                        .themed(ButtonStyle::MetadataTagItem);
+
+                    let chip: Element<_> = container(chip).themed(ContainerStyle::MetadataTagItem);
+
+                    row([chip, input]).spacing(8).align_y(Vertical::Center).into()
+                }
+            };
+
+            let input: Element<_> = if argument_fields.is_empty() {
+                input
+            } else {
+                let mut fields = vec![input];
+                fields.extend(argument_fields);
+
+                row(fields).spacing(8).align_y(Vertical::Center).into()
+            };
+
+            let input = container(input)
+                .width(Length::Fill)
+                .themed(ContainerStyle::MainSearchBar);
+
+            if state.main_window_state.is_collapsed() {
+                let root: Element<_> = container(input).width(Length::Fill).themed(ContainerStyle::Main);
+
+                return if state.main_window_state.keeps_full_size() {
+                    column([root, vertical_space().into()]).into()
+                } else {
+                    root
+                };
+            }
+
             let search_list = search_list(&state.search_results, &focused_search_result)
                 .map(|search_result| AppMsg::OnPrimaryActionMainViewNoPanel { search_result });
 
@@ -1547,32 +2103,37 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                 .height(Length::Fill)
                 .themed(ContainerStyle::MainList);
 
-            let input = container(input)
-                .width(Length::Fill)
-                .themed(ContainerStyle::MainSearchBar);
-
             let separator = if matches!(pending_plugin_view_loading_bar, LoadingBarState::On)
                 || !state.loading_bar_state.is_empty()
+                || state.tasks.is_running()
             {
                 LoadingBar::new().into()
             } else {
                 horizontal_rule(1).into()
             };
 
-            let inline_view = match state.client_context.get_inline_view_containers().first() {
+            let inline_view = match state.client_context.get_focused_inline_view_container() {
                 None => horizontal_space().into(),
-                Some((plugin_id, container)) => {
-                    let plugin_id = plugin_id.clone();
-                    container.render_inline_root_widget().map(move |widget_event| {
+                Some(container) => {
+                    let plugin_id = container.plugin_id();
+                    let entrypoint_id = container.entrypoint_id();
+                    let position = state.client_context.get_focused_inline_view_position();
+                    container.render_inline_root_widget(position).map(move |widget_event| {
                         AppMsg::WidgetEvent {
                             plugin_id: plugin_id.clone(),
+                            entrypoint_id: entrypoint_id.clone(),
                             widget_event,
                         }
                     })
                 }
             };
 
-            let content: Element<_> = column(vec![inline_view, list]).into();
+            // tasks are shown only when user is not searching for something
+            let content: Element<_> = if state.prompt.is_empty() && !state.tasks.is_empty() {
+                column(vec![inline_view, state.tasks.render(), horizontal_rule(1).into(), list]).into()
+            } else {
+                column(vec![inline_view, list]).into()
+            };
 
             let (primary_action, action_panel) =
                 if let Some(search_item) = focused_search_result.get(&state.search_results) {
@@ -1581,7 +2142,7 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                         search_result_action_panel(search_item),
                     )
                 } else {
-                    match state.client_context.get_first_inline_view_action_panel() {
+                    match state.client_context.get_focused_inline_view_action_panel() {
                         None => (None, None),
                         Some(action_panel) => {
                             match action_panel.find_first() {
@@ -1596,6 +2157,8 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
 
             let toast_text = if !state.loading_bar_state.is_empty() {
                 Some("Indexing...")
+            } else if state.tasks.is_running() {
+                Some("Running tasks...")
             } else {
                 None
             };
@@ -1684,11 +2247,14 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
             let container_element = match state.client_context.get_view_container(plugin_id) {
                 None => horizontal_space().into(),
                 Some(view_container) => {
+                    let entrypoint_id = view_container.entrypoint_id();
+
                     view_container
                         .render_root_widget(sub_state, action_shortcuts)
-                        .map(|widget_event| {
+                        .map(move |widget_event| {
                             AppMsg::WidgetEvent {
                                 plugin_id: plugin_id.clone(),
+                                entrypoint_id: entrypoint_id.clone(),
                                 widget_event,
                             }
                         })
@@ -1711,6 +2277,78 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
     }
 }
 
+fn view_deep_link_confirmation(deep_link: &DeepLink) -> Element<'_, AppMsg> {
+    let (description_text, button_label, arguments, search_text) = match &deep_link.action {
+        DeepLinkAction::RunAction { arguments, .. } => {
+            let description_text = format!(
+                "Link wants to run \"{}\" of plugin \"{}\"",
+                deep_link.entrypoint_name, deep_link.plugin_name
+            );
+            (description_text, "Run", arguments, None)
+        }
+        DeepLinkAction::OpenView { arguments, search_text } => {
+            let description_text = format!(
+                "Link wants to open \"{}\" of plugin \"{}\"",
+                deep_link.entrypoint_name, deep_link.plugin_name
+            );
+            (description_text, "Open", arguments, search_text.as_ref())
+        }
+    };
+
+    let description: Element<_> = text(description_text).shaping(Shaping::Advanced).into();
+
+    let description = container(description)
+        .width(Length::Fill)
+        .align_x(Horizontal::Center)
+        .themed(ContainerStyle::PluginErrorViewTitle);
+
+    let details = arguments
+        .iter()
+        .sorted_by(|(id_a, _), (id_b, _)| id_a.cmp(id_b))
+        .map(|(id, value)| format!("{}: {}", id, value))
+        .chain(search_text.map(|search_text| format!("Search text: {}", search_text)))
+        .join("\n");
+
+    let details: Element<_> = text(details).shaping(Shaping::Advanced).into();
+
+    let details = container(details)
+        .width(Length::Fill)
+        .align_x(Horizontal::Center)
+        .themed(ContainerStyle::PluginErrorViewDescription);
+
+    let cancel_button_label: Element<_> = text("Cancel").into();
+
+    let cancel_button: Element<_> = button(cancel_button_label)
+        .on_press(AppMsg::WindowAction(WindowActionMsg::HideWindow))
+        .into();
+
+    let confirm_button_label: Element<_> = text(button_label).into();
+
+    let confirm_button: Element<_> = button(confirm_button_label)
+        .on_press(AppMsg::RunDeepLink {
+            deep_link: deep_link.clone(),
+        })
+        .into();
+
+    let buttons: Element<_> = row([cancel_button, confirm_button]).spacing(8).into();
+
+    let buttons = container(buttons)
+        .width(Length::Fill)
+        .align_x(Horizontal::Center)
+        .into();
+
+    let content: Element<_> = column([description, details, buttons]).into();
+
+    let content: Element<_> = container(content)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .themed(ContainerStyle::Main);
+
+    content
+}
+
 fn subscription(#[allow(unused)] state: &AppModel) -> Subscription<AppMsg> {
     let mut subscriptions = vec![];
 
@@ -1745,26 +2383,95 @@ fn subscription(#[allow(unused)] state: &AppModel) -> Subscription<AppMsg> {
     #[cfg(target_os = "linux")]
     if !state.main_window_state.wayland {
         subscriptions.push(x11_linux_focus_change_subscription())
+    } else {
+        subscriptions.push(wayland_focus_change_subscription())
     }
 
     Subscription::batch(subscriptions)
 }
 
 impl AppModel {
+    fn main_window_expanded(&self) -> bool {
+        match &self.global_state {
+            GlobalState::MainView { .. } => {
+                !self.prompt.is_empty()
+                    || self.search_scope.is_some()
+                    || !self.client_context.get_inline_view_containers().is_empty()
+            }
+            GlobalState::ErrorView { .. } | GlobalState::DeepLinkConfirmation { .. } => true,
+            GlobalState::PluginView { .. } => true,
+            GlobalState::PendingPluginView { .. } => true,
+        }
+    }
+
     fn reset_window_state(&mut self) -> Task<AppMsg> {
         self.prompt = "".to_string();
+        self.entrypoint_argument_values.clear();
+        self.search_scope = None;
 
         self.client_context.clear_all_views();
 
         GlobalState::initial(&mut self.global_state)
     }
 
-    fn run_command(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Task<AppMsg> {
-        self.application_manager.run_command(plugin_id, entrypoint_id);
+    fn run_command(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        arguments: HashMap<String, String>,
+    ) -> Task<AppMsg> {
+        self.application_manager
+            .run_command(plugin_id, entrypoint_id, arguments);
 
         Task::none()
     }
 
+    fn run_deep_link(&self, deep_link: DeepLink) -> Task<AppMsg> {
+        let DeepLink {
+            plugin_id,
+            entrypoint_id,
+            action,
+            ..
+        } = deep_link;
+
+        match action {
+            DeepLinkAction::RunAction { action_id, arguments } => {
+                let application_manager = self.application_manager.clone();
+
+                let run_action = Task::future(async move {
+                    application_manager
+                        .run_action(plugin_id, entrypoint_id, action_id, arguments)
+                        .await
+                })
+                .then(|result| {
+                    match result {
+                        Ok(()) => Task::none(),
+                        Err(err) => {
+                            Task::batch([
+                                Task::done(AppMsg::ShowBackendError(err.into())),
+                                Task::done(AppMsg::WindowAction(WindowActionMsg::ShowWindow)),
+                            ])
+                        }
+                    }
+                });
+
+                // action may open a view which will show the window again
+                Task::batch([
+                    Task::done(AppMsg::WindowAction(WindowActionMsg::HideWindow)),
+                    run_action,
+                ])
+            }
+            DeepLinkAction::OpenView { arguments, search_text } => {
+                Task::done(AppMsg::OpenNewView {
+                    plugin_id,
+                    entrypoint_id,
+                    arguments,
+                    search_text,
+                })
+            }
+        }
+    }
+
     fn run_generated_entrypoint(
         &self,
         plugin_id: PluginId,
@@ -1777,10 +2484,51 @@ impl AppModel {
         Task::none()
     }
 
-    fn handle_plugin_event(&mut self, widget_event: ComponentWidgetEvent, plugin_id: PluginId) -> Task<AppMsg> {
+    // winit only reports dropped files, not text or links, and doesn't report drops on wayland at all
+    fn handle_file_drop(&mut self, path: PathBuf) -> Task<AppMsg> {
+        let Some(path) = path.to_str() else {
+            tracing::warn!("non-utf8 file paths are not supported: {:?}", path);
+            return Task::none();
+        };
+
+        match &self.global_state {
+            // path is inserted into search bar, so that it can be passed to scoped search or quicklinks
+            GlobalState::MainView { .. } => {
+                // every dropped file is reported separately, so prompt is updated right away
+                // to not lose paths which are dropped before prompt change is handled
+                if self.prompt.is_empty() {
+                    self.prompt = path.to_owned();
+                } else {
+                    self.prompt = format!("{} {}", self.prompt, path);
+                }
+
+                Task::done(AppMsg::PromptChanged(self.prompt.clone()))
+            }
+            GlobalState::PluginView { plugin_view_data, .. } => {
+                let Some(view) = self.client_context.get_view_container(&plugin_view_data.plugin_id) else {
+                    return Task::none();
+                };
+
+                match view.drop_file_event(path.to_owned()) {
+                    Some(msg) => Task::done(msg),
+                    None => Task::none(),
+                }
+            }
+            _ => Task::none(),
+        }
+    }
+
+    fn handle_plugin_event(
+        &mut self,
+        widget_event: ComponentWidgetEvent,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+    ) -> Task<AppMsg> {
         let application_manager = self.application_manager.clone();
 
-        let event = self.client_context.handle_event(&plugin_id, widget_event.clone());
+        let event = self
+            .client_context
+            .handle_event(&plugin_id, &entrypoint_id, widget_event.clone());
 
         if let Some(event) = event {
             match event {
@@ -1866,7 +2614,7 @@ impl AppModel {
         modifier_alt: bool,
         modifier_meta: bool,
     ) -> Task<AppMsg> {
-        let (plugin_id, entrypoint_id) = match self.client_context.get_first_inline_view_container() {
+        let (plugin_id, entrypoint_id) = match self.client_context.get_focused_inline_view_container() {
             None => return Task::none(),
             Some(container) => (container.plugin_id(), container.entrypoint_id()),
         };
@@ -1885,14 +2633,65 @@ impl AppModel {
         Task::none()
     }
 
-    fn search(&self, new_prompt: String, render_inline_view: bool) -> Task<AppMsg> {
-        let msg = self
+    fn search(&mut self, new_prompt: String, render_inline_view: bool) -> Task<AppMsg> {
+        // plugins send generation back with rendered inline view,
+        // so that views rendered for older prompts are not considered up to date
+        let inline_view_generation = if render_inline_view {
+            Some(self.client_context.next_inline_view_generation())
+        } else {
+            None
+        };
+
+        let msg = match &self.search_scope {
+            None => {
+                self.application_manager
+                    .search(&new_prompt, inline_view_generation)
+                    .map(|search_result| AppMsg::SetSearchResults(search_result))
+                    .unwrap_or_else(|err| AppMsg::ShowBackendError(err.into()))
+            }
+            Some(scope) => {
+                // whole query belongs to the scoped entrypoint
+                if let Some(generation) = inline_view_generation {
+                    if scope.scope_type == SearchScopeType::InlineView {
+                        self.application_manager.render_scoped_inline_view(
+                            scope.plugin_id.clone(),
+                            scope.entrypoint_id.clone(),
+                            &new_prompt,
+                            generation,
+                        );
+                    }
+                }
+
+                AppMsg::SetSearchResults(vec![])
+            }
+        };
+
+        let expire_task = if let Some(generation) = inline_view_generation {
+            // inline views are rendered by plugins concurrently,
+            // the ones which didn't respond to latest prompt in time are removed
+            Task::perform(
+                async move {
+                    tokio::time::sleep(INLINE_VIEW_TIMEOUT).await;
+
+                    generation
+                },
+                |generation| AppMsg::ExpireStaleInlineViews { generation },
+            )
+        } else {
+            Task::none()
+        };
+
+        Task::batch([Task::done(msg), expire_task])
+    }
+
+    fn request_inline_view_usage(&self) -> Task<AppMsg> {
+        let result = self
             .application_manager
-            .search(&new_prompt, render_inline_view)
-            .map(|search_result| AppMsg::SetSearchResults(search_result))
+            .inline_view_usage()
+            .map(|usage| AppMsg::SetInlineViewUsage { usage })
             .unwrap_or_else(|err| AppMsg::ShowBackendError(err.into()));
 
-        Task::done(msg)
+        Task::done(result)
     }
 
     fn request_inline_view_shortcuts(&self) -> Task<AppMsg> {
@@ -1939,6 +2738,30 @@ impl AppModel {
                                 modifier_alt: false,
                                 modifier_meta: cfg!(target_os = "macos"),
                             }) => Task::done(AppMsg::ToggleActionPanel { keyboard: true }),
+                            // tasks panel is shown only while prompt is empty
+                            Some(PhysicalShortcut {
+                                physical_key: PhysicalKey::KeyX,
+                                modifier_shift: true,
+                                modifier_control: cfg!(not(target_os = "macos")),
+                                modifier_alt: false,
+                                modifier_meta: cfg!(target_os = "macos"),
+                            }) if self.prompt.is_empty() && self.tasks.last_cancellable().is_some() => {
+                                let task = self.tasks.last_cancellable().expect("checked above");
+
+                                Task::done(AppMsg::CancelTask {
+                                    plugin_id: task.plugin_id.clone(),
+                                    task_id: task.task_id.clone(),
+                                })
+                            }
+                            Some(PhysicalShortcut {
+                                physical_key: PhysicalKey::Delete,
+                                modifier_shift: true,
+                                modifier_control: cfg!(not(target_os = "macos")),
+                                modifier_alt: false,
+                                modifier_meta: cfg!(target_os = "macos"),
+                            }) if self.prompt.is_empty() && self.tasks.has_finished() => {
+                                Task::done(AppMsg::ClearFinishedTasks)
+                            }
                             Some(PhysicalShortcut {
                                 physical_key,
                                 modifier_shift,
@@ -2051,7 +2874,7 @@ impl AppModel {
                     }
                 }
             }
-            GlobalState::ErrorView { .. } => Task::none(),
+            GlobalState::ErrorView { .. } | GlobalState::DeepLinkConfirmation { .. } => Task::none(),
             GlobalState::PluginView {
                 plugin_view_data,
                 sub_state,
@@ -2072,6 +2895,32 @@ impl AppModel {
                         modifier_alt: false,
                         modifier_meta: cfg!(target_os = "macos"),
                     }) => Task::done(AppMsg::ToggleActionPanel { keyboard: true }),
+                    Some(PhysicalShortcut {
+                        physical_key: PhysicalKey::KeyP,
+                        modifier_shift,
+                        modifier_control: cfg!(not(target_os = "macos")),
+                        modifier_alt: false,
+                        modifier_meta: cfg!(target_os = "macos"),
+                    }) => {
+                        // select next or, with shift, previous item of search bar dropdown,
+                        // if view doesn't have a dropdown shortcut is handled by plugin.
+                        // if it does, plugin actions with the same shortcut can't be run with it,
+                        // this is documented in SearchBarDropdown description
+                        match view.search_bar_dropdown_select_event(!modifier_shift) {
+                            Some(msg) => Task::done(msg),
+                            None => {
+                                self.handle_plugin_view_keyboard_event(
+                                    plugin_id,
+                                    entrypoint_id,
+                                    PhysicalKey::KeyP,
+                                    modifier_shift,
+                                    cfg!(not(target_os = "macos")),
+                                    false,
+                                    cfg!(target_os = "macos"),
+                                )
+                            }
+                        }
+                    }
                     Some(PhysicalShortcut {
                         physical_key,
                         modifier_shift,
@@ -2144,6 +2993,26 @@ impl AppModel {
     }
 }
 
+// reading selection can block for a while waiting for the selection owner to respond,
+// so it is done outside of ui thread, and skipped entirely when no plugin can use it
+fn capture_launch_context(
+    application_manager: Arc<ApplicationManager>,
+    focused_window: Option<FocusedWindow>,
+) -> Task<()> {
+    if !application_manager.launch_context_needs_capture() {
+        return Task::done(());
+    }
+
+    Task::future(async move {
+        let result =
+            tokio::task::spawn_blocking(move || application_manager.capture_launch_context(focused_window)).await;
+
+        if let Err(err) = result {
+            tracing::error!("unable to capture launch context: {:?}", err);
+        }
+    })
+}
+
 pub fn primary_shortcut() -> PhysicalShortcut {
     PhysicalShortcut {
         physical_key: PhysicalKey::Enter,
@@ -1,4 +1,6 @@
+use std::collections::HashMap;
 use std::mem;
+use std::sync::Arc;
 
 use iced::Rectangle;
 use iced::Task;
@@ -51,10 +53,37 @@ impl<T> ScrollContent<T> {
     }
 }
 
+// until first scroll event is received, actual height of the viewport is not known
+const INITIAL_VIEWPORT_HEIGHT: f32 = 1200.0;
+
+#[derive(Clone, Debug)]
+pub struct ScrollViewport {
+    pub offset_y: f32,
+    pub height: f32,
+}
+
+impl Default for ScrollViewport {
+    fn default() -> Self {
+        Self {
+            offset_y: 0.0,
+            height: INITIAL_VIEWPORT_HEIGHT,
+        }
+    }
+}
+
+// position of item inside scrollable content, used to scroll to items which are not rendered
+#[derive(Clone, Debug)]
+pub struct ItemBounds {
+    pub top: f32,
+    pub height: f32,
+}
+
 #[derive(Clone, Debug)]
 pub struct ScrollHandle {
     pub scrollable_id: scrollable::Id,
     pub current_item_id: Option<container::Id>,
+    pub viewport: ScrollViewport,
+    item_bounds: Option<Arc<HashMap<container::Id, ItemBounds>>>,
 }
 
 impl ScrollHandle {
@@ -62,6 +91,8 @@ impl ScrollHandle {
         ScrollHandle {
             scrollable_id: scrollable::Id::unique(),
             current_item_id,
+            viewport: ScrollViewport::default(),
+            item_bounds: None,
         }
     }
 
@@ -69,9 +100,20 @@ impl ScrollHandle {
         ScrollHandle {
             scrollable_id: scroll_handle.scrollable_id.clone(),
             current_item_id,
+            viewport: scroll_handle.viewport.clone(),
+            item_bounds: scroll_handle.item_bounds.clone(),
         }
     }
 
+    pub fn with_item_bounds(mut self, item_bounds: HashMap<container::Id, ItemBounds>) -> ScrollHandle {
+        self.item_bounds = Some(Arc::new(item_bounds));
+        self
+    }
+
+    pub fn set_viewport(&mut self, viewport: ScrollViewport) {
+        self.viewport = viewport;
+    }
+
     pub fn get<'a, T>(&self, items: &'a ScrollContent<T>) -> Option<&'a T> {
         self.get_by_id(items, self.current_item_id.clone())
     }
@@ -150,8 +192,19 @@ impl ScrollHandle {
             return None;
         }
 
-        let task = focus_target(self.scrollable_id.clone(), target_item_id.clone(), 40.)
-            .chain(Task::done(AppMsg::SetCurrentFocusedItem(target_item_id)));
+        let target_item_bounds = target_item_id
+            .as_ref()
+            .and_then(|target_item_id| self.item_bounds.as_ref()?.get(target_item_id));
+
+        let scroll_task = match target_item_bounds {
+            // item may not be rendered, so its position cannot be looked up in widget tree
+            Some(target_item_bounds) => {
+                focus_target_by_bounds(self.scrollable_id.clone(), &self.viewport, target_item_bounds, 40.)
+            }
+            None => focus_target(self.scrollable_id.clone(), target_item_id.clone(), 40.),
+        };
+
+        let task = scroll_task.chain(Task::done(AppMsg::SetCurrentFocusedItem(target_item_id)));
 
         Some(task)
     }
@@ -283,6 +336,26 @@ impl ScrollBehavior for RightScrollBehavior {
     }
 }
 
+fn focus_target_by_bounds(
+    scrollable_id: scrollable::Id,
+    viewport: &ScrollViewport,
+    target_bounds: &ItemBounds,
+    padding: f32,
+) -> Task<AppMsg> {
+    let offset_y = offset_to_show(viewport, target_bounds, padding);
+
+    scroll_to(scrollable_id, AbsoluteOffset { x: 0.0, y: offset_y })
+}
+
+// smallest scroll from current offset which makes the target visible together with padding around it
+fn offset_to_show(viewport: &ScrollViewport, target_bounds: &ItemBounds, padding: f32) -> f32 {
+    viewport
+        .offset_y
+        .max(target_bounds.top + target_bounds.height - viewport.height + padding)
+        .min(target_bounds.top - padding)
+        .max(0.0)
+}
+
 fn focus_target(scrollable_id: scrollable::Id, target_item_id: Option<container::Id>, padding: f32) -> Task<AppMsg> {
     let Some(target_item_id) = target_item_id else {
         return scroll_to(scrollable_id.clone(), AbsoluteOffset::default());
@@ -375,3 +448,110 @@ fn focus_target(scrollable_id: scrollable::Id, target_item_id: Option<container:
     let scrollable_id = scrollable_id.clone();
     operate(operation).then(move |offset| scroll_to(scrollable_id.clone(), offset))
 }
+
+#[derive(Clone, Debug)]
+pub struct ScrollableBounds {
+    pub offset_y: f32,
+    pub viewport_height: f32,
+    pub content_height: f32,
+}
+
+// same values as in scroll event, which is only received after the content is scrolled
+pub fn scrollable_bounds(scrollable_id: scrollable::Id) -> Task<ScrollableBounds> {
+    struct QueryScrollableBounds {
+        scrollable: widget::Id,
+        bounds: Option<ScrollableBounds>,
+    }
+
+    impl Operation<ScrollableBounds> for QueryScrollableBounds {
+        fn container(
+            &mut self,
+            _id: Option<&widget::Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<ScrollableBounds>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn scrollable(
+            &mut self,
+            id: Option<&widget::Id>,
+            bounds: Rectangle,
+            content_bounds: Rectangle,
+            translation: Vector,
+            _state: &mut dyn Scrollable,
+        ) {
+            if Some(&self.scrollable) == id {
+                self.bounds = Some(ScrollableBounds {
+                    offset_y: translation.y,
+                    viewport_height: bounds.height,
+                    content_height: content_bounds.height,
+                });
+            }
+        }
+
+        fn finish(&self) -> Outcome<ScrollableBounds> {
+            match &self.bounds {
+                Some(bounds) => Outcome::Some(bounds.clone()),
+                None => Outcome::None,
+            }
+        }
+    }
+
+    operate(QueryScrollableBounds {
+        scrollable: scrollable_id.into(),
+        bounds: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn viewport(offset_y: f32) -> ScrollViewport {
+        ScrollViewport {
+            offset_y,
+            height: 500.0,
+        }
+    }
+
+    fn bounds(top: f32) -> ItemBounds {
+        ItemBounds { top, height: 50.0 }
+    }
+
+    #[test]
+    fn visible_target_does_not_scroll() {
+        assert_eq!(offset_to_show(&viewport(1000.0), &bounds(1200.0), 40.0), 1000.0);
+    }
+
+    #[test]
+    fn target_below_viewport_is_scrolled_to_bottom_edge() {
+        // bottom of target with padding is at 2090, so viewport has to start at 1590
+        assert_eq!(offset_to_show(&viewport(1000.0), &bounds(2000.0), 40.0), 1590.0);
+    }
+
+    #[test]
+    fn target_partially_below_viewport_is_scrolled_into_view() {
+        assert_eq!(offset_to_show(&viewport(1000.0), &bounds(1430.0), 40.0), 1020.0);
+    }
+
+    #[test]
+    fn target_above_viewport_is_scrolled_to_top_edge() {
+        assert_eq!(offset_to_show(&viewport(1000.0), &bounds(300.0), 40.0), 260.0);
+    }
+
+    #[test]
+    fn offset_is_never_negative() {
+        assert_eq!(offset_to_show(&viewport(100.0), &bounds(10.0), 40.0), 0.0);
+    }
+
+    #[test]
+    fn target_taller_than_viewport_is_aligned_to_top() {
+        let target = ItemBounds {
+            top: 2000.0,
+            height: 800.0,
+        };
+
+        assert_eq!(offset_to_show(&viewport(1000.0), &target, 40.0), 1960.0);
+    }
+}
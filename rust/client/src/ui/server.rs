@@ -124,6 +124,7 @@ async fn request_loop(
                 entrypoint_name,
                 render_location,
                 top_level_view,
+                inline_view_generation,
                 container,
                 data,
             } => {
@@ -136,10 +137,34 @@ async fn request_loop(
                     entrypoint_name,
                     render_location,
                     top_level_view,
+                    inline_view_generation,
                     container: Arc::new(container),
                     data,
                 }
             }
+            FrontendApiRequestData::PatchView {
+                plugin_id,
+                plugin_name,
+                entrypoint_id,
+                entrypoint_name,
+                render_location,
+                top_level_view,
+                patch,
+                data,
+            } => {
+                responder.respond(Ok(FrontendApiResponseData::PatchView { data: () }));
+
+                AppMsg::PatchPluginUI {
+                    plugin_id,
+                    plugin_name,
+                    entrypoint_id,
+                    entrypoint_name,
+                    render_location,
+                    top_level_view,
+                    patch: Arc::new(patch),
+                    data,
+                }
+            }
             FrontendApiRequestData::ToggleWindow {} => {
                 responder.respond(Ok(FrontendApiResponseData::ToggleWindow { data: () }));
 
@@ -200,6 +225,49 @@ async fn request_loop(
                     show,
                 }
             }
+            FrontendApiRequestData::StartTask {
+                plugin_id,
+                plugin_name,
+                task_id,
+                title,
+            } => {
+                responder.respond(Ok(FrontendApiResponseData::StartTask { data: () }));
+
+                AppMsg::StartTask {
+                    plugin_id,
+                    plugin_name,
+                    task_id,
+                    title,
+                }
+            }
+            FrontendApiRequestData::UpdateTask {
+                plugin_id,
+                task_id,
+                progress,
+                status,
+            } => {
+                responder.respond(Ok(FrontendApiResponseData::UpdateTask { data: () }));
+
+                AppMsg::UpdateTask {
+                    plugin_id,
+                    task_id,
+                    progress,
+                    status,
+                }
+            }
+            FrontendApiRequestData::FinishTask {
+                plugin_id,
+                task_id,
+                error,
+            } => {
+                responder.respond(Ok(FrontendApiResponseData::FinishTask { data: () }));
+
+                AppMsg::FinishTask {
+                    plugin_id,
+                    task_id,
+                    error,
+                }
+            }
             FrontendApiRequestData::SetTheme { theme } => {
                 responder.respond(Ok(FrontendApiResponseData::SetTheme { data: () }));
 
@@ -210,15 +278,28 @@ async fn request_loop(
 
                 AppMsg::WindowAction(WindowActionMsg::SetWindowPositionMode { mode })
             }
+            FrontendApiRequestData::SetWindowSize { size } => {
+                responder.respond(Ok(FrontendApiResponseData::SetWindowSize { data: () }));
+
+                AppMsg::WindowAction(WindowActionMsg::SetWindowSize { size })
+            }
+            FrontendApiRequestData::SetCompactMode { enabled } => {
+                responder.respond(Ok(FrontendApiResponseData::SetCompactMode { data: () }));
+
+                AppMsg::WindowAction(WindowActionMsg::SetCompactMode { enabled })
+            }
             FrontendApiRequestData::OpenPluginView {
                 plugin_id,
                 entrypoint_id,
+                arguments,
             } => {
                 responder.respond(Ok(FrontendApiResponseData::OpenPluginView { data: () }));
 
                 AppMsg::OpenNewView {
                     plugin_id,
                     entrypoint_id,
+                    arguments,
+                    search_text: None,
                 }
             }
             FrontendApiRequestData::OpenGeneratedPluginView {
@@ -271,15 +352,17 @@ pub fn handle_server_message(
             plugin_id,
             entrypoint_id,
             action_id,
+            arguments,
         } => {
             let application_manager = state.application_manager.clone();
             let plugin_id = plugin_id.clone();
             let entrypoint_id = entrypoint_id.clone();
             let action_id = action_id.clone();
+            let arguments = arguments.clone();
 
             Task::future(async move {
                 let result = application_manager
-                    .run_action(plugin_id, entrypoint_id, action_id)
+                    .run_action(plugin_id, entrypoint_id, action_id, arguments)
                     .await
                     .map(|data| ServerGrpcApiResponseData::RunAction { data });
 
@@ -288,6 +371,20 @@ pub fn handle_server_message(
                 AppMsg::Noop
             })
         }
+        ServerGrpcApiRequestData::OpenDeepLink { url } => {
+            match state.application_manager.resolve_deep_link(url) {
+                Ok(deep_link) => {
+                    responder.respond(Ok(ServerGrpcApiResponseData::OpenDeepLink { data: () }));
+
+                    Task::done(AppMsg::OpenDeepLink { deep_link })
+                }
+                Err(err) => {
+                    responder.respond(Err(err));
+
+                    Task::none()
+                }
+            }
+        }
         ServerGrpcApiRequestData::SaveLocalPlugin { path } => {
             let result = state
                 .application_manager
@@ -296,6 +393,36 @@ pub fn handle_server_message(
 
             responder.respond(result);
 
+            Task::none()
+        }
+        ServerGrpcApiRequestData::ListQuicklinks {} => {
+            let result = state
+                .application_manager
+                .list_quicklinks()
+                .map(|data| ServerGrpcApiResponseData::ListQuicklinks { data });
+
+            responder.respond(result);
+
+            Task::none()
+        }
+        ServerGrpcApiRequestData::SaveQuicklink { quicklink } => {
+            let result = state
+                .application_manager
+                .save_quicklink(quicklink.clone())
+                .map(|data| ServerGrpcApiResponseData::SaveQuicklink { data });
+
+            responder.respond(result);
+
+            Task::none()
+        }
+        ServerGrpcApiRequestData::RemoveQuicklink { quicklink_id } => {
+            let result = state
+                .application_manager
+                .remove_quicklink(quicklink_id.clone())
+                .map(|data| ServerGrpcApiResponseData::RemoveQuicklink { data });
+
+            responder.respond(result);
+
             Task::none()
         }
     }
@@ -33,6 +33,7 @@ use iced::widget::text;
 use iced::window;
 use iced_fonts::bootstrap::exclamation_triangle_fill;
 use iced_fonts::bootstrap::gear_fill;
+use iced_fonts::bootstrap::link_45deg;
 use iced_fonts::bootstrap::patch_check_fill;
 use iced_fonts::bootstrap::puzzle_fill;
 use itertools::Itertools;
@@ -45,10 +46,15 @@ use crate::ui::settings::theme::text::TextStyle;
 use crate::ui::settings::views::general::SettingsGeneralMsgIn;
 use crate::ui::settings::views::general::SettingsGeneralMsgOut;
 use crate::ui::settings::views::general::SettingsGeneralState;
+use crate::ui::settings::views::plugins::EntrypointSearchSettings;
 use crate::ui::settings::views::plugins::SelectedItem;
 use crate::ui::settings::views::plugins::SettingsPluginMsgIn;
 use crate::ui::settings::views::plugins::SettingsPluginMsgOut;
 use crate::ui::settings::views::plugins::SettingsPluginsState;
+use crate::ui::settings::views::quicklinks::SettingsQuicklinksMsgIn;
+use crate::ui::settings::views::quicklinks::SettingsQuicklinksMsgOut;
+use crate::ui::settings::views::quicklinks::SettingsQuicklinksState;
+use crate::ui::windows::monitors::query_monitor_layout;
 
 pub struct SettingsWindowState {
     pub settings_window_id: Option<window::Id>,
@@ -60,6 +66,7 @@ pub struct SettingsWindowState {
     current_settings_view: SettingsView,
     general_state: SettingsGeneralState,
     plugins_state: SettingsPluginsState,
+    quicklinks_state: SettingsQuicklinksState,
 }
 
 impl SettingsWindowState {
@@ -74,6 +81,7 @@ impl SettingsWindowState {
             current_settings_view: SettingsView::Plugins,
             general_state: SettingsGeneralState::new(application_manager.clone()),
             plugins_state: SettingsPluginsState::new(application_manager.clone()),
+            quicklinks_state: SettingsQuicklinksState::new(application_manager.clone()),
         }
     }
 }
@@ -99,6 +107,7 @@ pub enum SettingsMsg {
     WindowDestroyed,
     General(SettingsGeneralMsgIn),
     Plugin(SettingsPluginMsgIn),
+    Quicklinks(SettingsQuicklinksMsgIn),
     SwitchView(SettingsView),
     DownloadStatus { plugins: HashMap<PluginId, DownloadStatus> },
     HandleBackendError(RequestError),
@@ -112,6 +121,7 @@ pub enum SettingsMsg {
 pub enum SettingsView {
     General,
     Plugins,
+    Quicklinks,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -149,6 +159,14 @@ pub fn update_settings(
                 }
             })
         }
+        SettingsMsg::Quicklinks(message) => {
+            state.quicklinks_state.update(message).map(|msg| {
+                match msg {
+                    SettingsQuicklinksMsgOut::Inner(msg) => SettingsMsg::Quicklinks(msg),
+                    SettingsQuicklinksMsgOut::Outer(msg) => msg,
+                }
+            })
+        }
         SettingsMsg::SwitchView(view) => {
             state.current_settings_view = view;
 
@@ -190,18 +208,18 @@ pub fn update_settings(
                 async move {
                     let plugins = application_manager.plugins()?;
                     let global_entrypoint_shortcuts = application_manager.get_global_entrypoint_shortcuts()?;
-                    let entrypoint_search_aliases = application_manager.get_entrypoint_search_aliases()?;
+                    let entrypoint_search_settings = EntrypointSearchSettings::fetch(&application_manager)?;
 
-                    Ok((plugins, global_entrypoint_shortcuts, entrypoint_search_aliases))
+                    Ok((plugins, global_entrypoint_shortcuts, entrypoint_search_settings))
                 },
                 |result| {
                     handle_backend_error(
                         result,
-                        |(plugins, global_entrypoint_shortcuts, entrypoint_search_aliases)| {
+                        |(plugins, global_entrypoint_shortcuts, entrypoint_search_settings)| {
                             SettingsMsg::Plugin(SettingsPluginMsgIn::PluginsReloaded(
                                 plugins,
                                 global_entrypoint_shortcuts,
-                                entrypoint_search_aliases,
+                                entrypoint_search_settings,
                             ))
                         },
                     )
@@ -251,12 +269,20 @@ pub fn update_settings(
                 let theme = state.application_manager.get_theme()?;
 
                 let window_position_mode = state.application_manager.get_window_position_mode()?;
+                let window_size = state.application_manager.get_window_size()?;
+                let compact_mode = state.application_manager.get_compact_mode()?;
+                let compact_mode_in_config = state.application_manager.config()?.compact_mode_in_config;
                 let wayland_global_shortcuts_enabled = state.application_manager.config()?.wayland_use_legacy_x11_api;
 
                 Ok(Task::batch([
                     Task::done(SettingsMsg::General(SettingsGeneralMsgIn::InitSetting {
                         theme,
                         window_position_mode,
+                        // wayland doesn't allow clients to position their windows
+                        window_position_unsupported: state.wayland,
+                        window_size,
+                        compact_mode,
+                        compact_mode_in_config,
                         shortcut: global_shortcut,
                         shortcut_error: global_shortcut_error,
                         global_shortcuts_unsupported: state.wayland && !wayland_global_shortcuts_enabled,
@@ -265,6 +291,15 @@ pub fn update_settings(
                         global_entrypoint_shortcuts,
                         show_global_shortcuts: !state.wayland || wayland_global_shortcuts_enabled,
                     })),
+                    query_monitor_layout(
+                        #[cfg(target_os = "linux")]
+                        state.wayland,
+                    )
+                    .map(|layout| {
+                        let monitors = layout.monitors.into_iter().map(|monitor| monitor.name).collect();
+
+                        SettingsMsg::General(SettingsGeneralMsgIn::MonitorsChanged(monitors))
+                    }),
                 ]))
             }
 
@@ -317,6 +352,7 @@ pub fn update_settings(
             Task::batch([
                 open,
                 Task::done(SettingsMsg::Plugin(SettingsPluginMsgIn::FetchPlugins)),
+                Task::done(SettingsMsg::Quicklinks(SettingsQuicklinksMsgIn::FetchQuicklinks)),
                 Task::done(SettingsMsg::Refresh),
                 Task::done(SettingsMsg::SwitchView(SettingsView::Plugins)),
                 Task::done(SettingsMsg::Plugin(SettingsPluginMsgIn::SelectItem(item))),
@@ -399,6 +435,7 @@ pub fn view_settings(state: &SettingsWindowState) -> Element<'_, SettingsMsg> {
     let content = match state.current_settings_view {
         SettingsView::General => state.general_state.view().map(|msg| SettingsMsg::General(msg)),
         SettingsView::Plugins => state.plugins_state.view().map(|msg| SettingsMsg::Plugin(msg)),
+        SettingsView::Quicklinks => state.quicklinks_state.view().map(|msg| SettingsMsg::Quicklinks(msg)),
     };
 
     let icon_general: Element<_> = gear_fill()
@@ -469,7 +506,41 @@ pub fn view_settings(state: &SettingsWindowState) -> Element<'_, SettingsMsg> {
 
     let plugins_button: Element<_> = container(plugins_button).padding(8.0).into();
 
-    let top_bar_buttons: Element<_> = row(vec![general_button, plugins_button]).into();
+    let icon_quicklinks: Element<_> = link_45deg()
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_y(alignment::Vertical::Center)
+        .align_x(alignment::Horizontal::Center)
+        .into();
+
+    let text_quicklinks: Element<_> = text("Quicklinks")
+        .height(Length::Fill)
+        .align_y(alignment::Vertical::Center)
+        .align_x(alignment::Horizontal::Center)
+        .into();
+
+    let quicklinks_button: Element<_> = column(vec![icon_quicklinks, text_quicklinks])
+        .align_x(Alignment::Center)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .into();
+
+    let quicklinks_button: Element<_> = button(quicklinks_button)
+        .on_press(SettingsMsg::SwitchView(SettingsView::Quicklinks))
+        .height(Length::Fill)
+        .width(80)
+        .class(
+            if state.current_settings_view == SettingsView::Quicklinks {
+                ButtonStyle::ViewSwitcherSelected
+            } else {
+                ButtonStyle::ViewSwitcher
+            },
+        )
+        .into();
+
+    let quicklinks_button: Element<_> = container(quicklinks_button).padding(8.0).into();
+
+    let top_bar_buttons: Element<_> = row(vec![general_button, plugins_button, quicklinks_button]).into();
 
     let top_bar_buttons: Element<_> = container(top_bar_buttons)
         .width(Length::Fill)
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use gauntlet_common::model::PhysicalShortcut;
 use gauntlet_common::model::SettingsTheme;
 use gauntlet_common::model::WindowPositionMode;
+use gauntlet_common::model::WindowSize;
 use gauntlet_server::global_hotkey::GlobalHotKeyManager;
 use gauntlet_server::plugins::ApplicationManager;
 use gauntlet_utils::channel::RequestResult;
@@ -15,24 +16,33 @@ use iced::alignment;
 use iced::alignment::Horizontal;
 use iced::font::Style;
 use iced::widget::Space;
+use iced::widget::checkbox;
 use iced::widget::column;
 use iced::widget::container;
 use iced::widget::pick_list;
 use iced::widget::row;
 use iced::widget::text;
 use iced::widget::text::Shaping;
+use iced::widget::text_input;
 
 use crate::ui::settings::components::shortcut_selector::ShortcutData;
 use crate::ui::settings::components::shortcut_selector::render_shortcut_error;
 use crate::ui::settings::components::shortcut_selector::shortcut_selector;
 use crate::ui::settings::theme::Element;
 use crate::ui::settings::theme::container::ContainerStyle;
+use crate::ui::settings::theme::text_input::TextInputStyle;
 use crate::ui::settings::ui::SettingsMsg;
 
 pub struct SettingsGeneralState {
     application_manager: Arc<ApplicationManager>,
     theme: SettingsTheme,
     window_position_mode: WindowPositionMode,
+    window_position_unsupported: bool,
+    monitors: Vec<String>,
+    window_width: String,
+    window_height: String,
+    compact_mode: bool,
+    compact_mode_in_config: bool,
     current_shortcut: ShortcutData,
     global_shortcuts_unsupported: bool,
 }
@@ -42,6 +52,11 @@ pub enum SettingsGeneralMsgIn {
     ShortcutCaptured(Option<PhysicalShortcut>),
     ThemeChanged(SettingsTheme),
     WindowPositionModeChanged(WindowPositionMode),
+    WindowWidthChanged(String),
+    WindowHeightChanged(String),
+    WindowSizeSubmit,
+    CompactModeToggled(bool),
+    MonitorsChanged(Vec<String>),
     HandleShortcutResponse {
         shortcut: Option<PhysicalShortcut>,
         shortcut_error: Option<String>,
@@ -49,6 +64,10 @@ pub enum SettingsGeneralMsgIn {
     InitSetting {
         theme: SettingsTheme,
         window_position_mode: WindowPositionMode,
+        window_position_unsupported: bool,
+        window_size: WindowSize,
+        compact_mode: bool,
+        compact_mode_in_config: bool,
         shortcut: Option<PhysicalShortcut>,
         shortcut_error: Option<String>,
         global_shortcuts_unsupported: bool,
@@ -67,6 +86,12 @@ impl SettingsGeneralState {
             application_manager,
             theme: SettingsTheme::AutoDetect,
             window_position_mode: WindowPositionMode::Static,
+            window_position_unsupported: false,
+            monitors: vec![],
+            window_width: WindowSize::default().width.to_string(),
+            window_height: WindowSize::default().height.to_string(),
+            compact_mode: false,
+            compact_mode_in_config: false,
             current_shortcut: ShortcutData {
                 shortcut: None,
                 error: None,
@@ -100,12 +125,21 @@ impl SettingsGeneralState {
             SettingsGeneralMsgIn::InitSetting {
                 theme,
                 window_position_mode,
+                window_position_unsupported,
+                window_size,
+                compact_mode,
+                compact_mode_in_config,
                 shortcut,
                 shortcut_error,
                 global_shortcuts_unsupported,
             } => {
                 self.theme = theme;
                 self.window_position_mode = window_position_mode;
+                self.window_position_unsupported = window_position_unsupported;
+                self.window_width = window_size.width.to_string();
+                self.window_height = window_size.height.to_string();
+                self.compact_mode = compact_mode;
+                self.compact_mode_in_config = compact_mode_in_config;
                 self.current_shortcut = ShortcutData {
                     shortcut,
                     error: shortcut_error,
@@ -142,6 +176,54 @@ impl SettingsGeneralState {
                     |result| handle_backend_error(result, |()| SettingsGeneralMsgOut::Outer(SettingsMsg::Noop)),
                 )
             }
+            SettingsGeneralMsgIn::WindowWidthChanged(value) => {
+                self.window_width = value;
+
+                Task::none()
+            }
+            SettingsGeneralMsgIn::WindowHeightChanged(value) => {
+                self.window_height = value;
+
+                Task::none()
+            }
+            SettingsGeneralMsgIn::WindowSizeSubmit => {
+                let (Ok(width), Ok(height)) = (self.window_width.parse::<f32>(), self.window_height.parse::<f32>())
+                else {
+                    return Task::none();
+                };
+
+                let application_manager = self.application_manager.clone();
+
+                Task::perform(
+                    async move {
+                        application_manager
+                            .set_window_size(WindowSize { width, height })
+                            .await?;
+
+                        Ok(())
+                    },
+                    |result| handle_backend_error(result, |()| SettingsGeneralMsgOut::Outer(SettingsMsg::Noop)),
+                )
+            }
+            SettingsGeneralMsgIn::CompactModeToggled(enabled) => {
+                self.compact_mode = enabled;
+
+                let application_manager = self.application_manager.clone();
+
+                Task::perform(
+                    async move {
+                        application_manager.set_compact_mode(enabled).await?;
+
+                        Ok(())
+                    },
+                    |result| handle_backend_error(result, |()| SettingsGeneralMsgOut::Outer(SettingsMsg::Noop)),
+                )
+            }
+            SettingsGeneralMsgIn::MonitorsChanged(monitors) => {
+                self.monitors = monitors;
+
+                Task::none()
+            }
             SettingsGeneralMsgIn::HandleShortcutResponse {
                 shortcut,
                 shortcut_error,
@@ -192,14 +274,16 @@ impl SettingsGeneralState {
 
         let theme_field = self.theme_field();
 
-        #[allow(unused_mut)]
         let mut content = vec![global_shortcut_field, theme_field];
 
-        #[cfg(target_os = "macos")]
-        {
+        if !self.window_position_unsupported {
             content.push(self.window_position_mode_field())
         }
 
+        content.push(self.window_size_field());
+
+        content.push(self.compact_mode_field());
+
         let content: Element<_> = column(content).into();
 
         let content: Element<_> = container(content).width(Length::Fill).into();
@@ -251,9 +335,22 @@ impl SettingsGeneralState {
         theme_field
     }
 
-    #[allow(unused)]
     fn window_position_mode_field(&self) -> Element<SettingsGeneralMsgIn> {
-        let items = [WindowPositionMode::Static, WindowPositionMode::ActiveMonitor];
+        let mut items = vec![
+            WindowPositionMode::Static,
+            WindowPositionMode::ActiveMonitor,
+            WindowPositionMode::MonitorUnderCursor,
+            WindowPositionMode::TopThirdCentered,
+        ];
+
+        for name in &self.monitors {
+            items.push(WindowPositionMode::FixedMonitor { name: name.clone() })
+        }
+
+        // keep currently selected monitor in the list even if it is not connected right now
+        if !items.contains(&self.window_position_mode) {
+            items.push(self.window_position_mode.clone())
+        }
 
         let field: Element<_> = pick_list(items, Some(self.window_position_mode.clone()), move |item| {
             SettingsGeneralMsgIn::WindowPositionModeChanged(item)
@@ -267,6 +364,53 @@ impl SettingsGeneralState {
         field
     }
 
+    fn window_size_field(&self) -> Element<SettingsGeneralMsgIn> {
+        let width: Element<_> = text_input("Width", &self.window_width)
+            .class(TextInputStyle::FormInput)
+            .on_input(SettingsGeneralMsgIn::WindowWidthChanged)
+            .on_submit(SettingsGeneralMsgIn::WindowSizeSubmit)
+            .into();
+
+        let separator: Element<_> = text("x").into();
+
+        let height: Element<_> = text_input("Height", &self.window_height)
+            .class(TextInputStyle::FormInput)
+            .on_input(SettingsGeneralMsgIn::WindowHeightChanged)
+            .on_submit(SettingsGeneralMsgIn::WindowSizeSubmit)
+            .into();
+
+        let field: Element<_> = row([width, separator, height])
+            .spacing(8)
+            .align_y(Alignment::Center)
+            .into();
+
+        let field: Element<_> = container(field).width(Length::Fill).into();
+
+        let field = self.view_field("Window Size", field, None);
+
+        field
+    }
+
+    fn compact_mode_field(&self) -> Element<SettingsGeneralMsgIn> {
+        let field: Element<_> = if self.compact_mode_in_config {
+            text("Unable to change because value is defined in config")
+                .shaping(Shaping::Advanced)
+                .align_x(Horizontal::Center)
+                .width(Length::Fill)
+                .into()
+        } else {
+            checkbox("Show only search bar until there are results", self.compact_mode)
+                .on_toggle(SettingsGeneralMsgIn::CompactModeToggled)
+                .into()
+        };
+
+        let field: Element<_> = container(field).width(Length::Fill).into();
+
+        let field = self.view_field("Compact Mode", field, None);
+
+        field
+    }
+
     fn view_field<'a>(
         &'a self,
         label: &'a str,
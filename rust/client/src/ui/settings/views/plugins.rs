@@ -1,5 +1,6 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::rc::Rc;
 use std::sync::Arc;
 
@@ -54,7 +55,7 @@ pub enum SettingsPluginMsgIn {
     PluginsReloaded(
         HashMap<PluginId, SettingsPlugin>,
         HashMap<(PluginId, EntrypointId), (PhysicalShortcut, Option<String>)>,
-        HashMap<(PluginId, EntrypointId), String>,
+        EntrypointSearchSettings,
     ),
     RemovePlugin {
         plugin_id: PluginId,
@@ -72,6 +73,23 @@ pub enum SettingsPluginMsgIn {
     SelectItem(SelectedItem),
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct EntrypointSearchSettings {
+    pub aliases: HashMap<(PluginId, EntrypointId), String>,
+    pub pins: Vec<(PluginId, EntrypointId)>,
+    pub hidden: HashSet<(PluginId, EntrypointId)>,
+}
+
+impl EntrypointSearchSettings {
+    pub fn fetch(application_manager: &ApplicationManager) -> anyhow::Result<Self> {
+        Ok(Self {
+            aliases: application_manager.get_entrypoint_search_aliases()?,
+            pins: application_manager.get_entrypoint_search_pins()?,
+            hidden: application_manager.get_entrypoint_search_hidden()?,
+        })
+    }
+}
+
 pub enum SettingsPluginMsgOut {
     Inner(SettingsPluginMsgIn),
     Outer(SettingsMsg),
@@ -84,7 +102,7 @@ pub struct SettingsPluginsState {
     preference_user_data: HashMap<(PluginId, Option<EntrypointId>, String), PluginPreferenceUserDataState>,
     selected_item: SelectedItem,
     global_entrypoint_shortcuts: HashMap<(PluginId, EntrypointId), (PhysicalShortcut, Option<String>)>,
-    entrypoint_search_aliases: HashMap<(PluginId, EntrypointId), String>,
+    entrypoint_search_settings: EntrypointSearchSettings,
 }
 
 impl SettingsPluginsState {
@@ -96,7 +114,7 @@ impl SettingsPluginsState {
             selected_item: SelectedItem::None,
             table_state: PluginTableState::new(),
             global_entrypoint_shortcuts: HashMap::new(),
-            entrypoint_search_aliases: HashMap::new(),
+            entrypoint_search_settings: EntrypointSearchSettings::default(),
         }
     }
 
@@ -129,18 +147,18 @@ impl SettingsPluginsState {
                                 let plugins = application_manager.plugins()?;
                                 let global_entrypoint_shortcuts =
                                     application_manager.get_global_entrypoint_shortcuts()?;
-                                let entrypoint_aliases = application_manager.get_entrypoint_search_aliases()?;
+                                let entrypoint_search_settings = EntrypointSearchSettings::fetch(&application_manager)?;
 
-                                Ok((plugins, global_entrypoint_shortcuts, entrypoint_aliases))
+                                Ok((plugins, global_entrypoint_shortcuts, entrypoint_search_settings))
                             },
                             |result| {
                                 handle_backend_error(
                                     result,
-                                    |(plugins, global_entrypoint_shortcuts, entrypoint_aliases)| {
+                                    |(plugins, global_entrypoint_shortcuts, entrypoint_search_settings)| {
                                         SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::PluginsReloaded(
                                             plugins,
                                             global_entrypoint_shortcuts,
-                                            entrypoint_aliases,
+                                            entrypoint_search_settings,
                                         ))
                                     },
                                 )
@@ -161,18 +179,18 @@ impl SettingsPluginsState {
                                 let plugins = application_manager.plugins()?;
                                 let global_entrypoint_shortcuts =
                                     application_manager.get_global_entrypoint_shortcuts()?;
-                                let entrypoint_aliases = application_manager.get_entrypoint_search_aliases()?;
+                                let entrypoint_search_settings = EntrypointSearchSettings::fetch(&application_manager)?;
 
-                                Ok((plugins, global_entrypoint_shortcuts, entrypoint_aliases))
+                                Ok((plugins, global_entrypoint_shortcuts, entrypoint_search_settings))
                             },
                             |result| {
                                 handle_backend_error(
                                     result,
-                                    |(plugins, global_entrypoint_shortcuts, entrypoint_aliases)| {
+                                    |(plugins, global_entrypoint_shortcuts, entrypoint_search_settings)| {
                                         SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::PluginsReloaded(
                                             plugins,
                                             global_entrypoint_shortcuts,
-                                            entrypoint_aliases,
+                                            entrypoint_search_settings,
                                         ))
                                     },
                                 )
@@ -221,12 +239,12 @@ impl SettingsPluginsState {
 
                             let plugins = application_manager.plugins()?;
                             let global_entrypoint_shortcuts = application_manager.get_global_entrypoint_shortcuts()?;
-                            let entrypoint_aliases = application_manager.get_entrypoint_search_aliases()?;
+                            let entrypoint_search_settings = EntrypointSearchSettings::fetch(&application_manager)?;
 
                             Ok(SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::PluginsReloaded(
                                 plugins,
                                 global_entrypoint_shortcuts,
-                                entrypoint_aliases,
+                                entrypoint_search_settings,
                             )))
                         }
 
@@ -251,18 +269,102 @@ impl SettingsPluginsState {
                                 let plugins = application_manager.plugins()?;
                                 let global_entrypoint_shortcuts =
                                     application_manager.get_global_entrypoint_shortcuts()?;
-                                let entrypoint_aliases = application_manager.get_entrypoint_search_aliases()?;
+                                let entrypoint_search_settings = EntrypointSearchSettings::fetch(&application_manager)?;
+
+                                Ok((plugins, global_entrypoint_shortcuts, entrypoint_search_settings))
+                            },
+                            |result| {
+                                handle_backend_error(
+                                    result,
+                                    |(plugins, global_entrypoint_shortcuts, entrypoint_search_settings)| {
+                                        SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::PluginsReloaded(
+                                            plugins,
+                                            global_entrypoint_shortcuts,
+                                            entrypoint_search_settings,
+                                        ))
+                                    },
+                                )
+                            },
+                        )
+                    }
+                    PluginTableMsgOut::PinnedChanged(plugin_id, entrypoint_id, pinned) => {
+                        let application_manager = application_manager.clone();
+
+                        Task::perform(
+                            async move {
+                                application_manager.set_entrypoint_search_pinned(plugin_id, entrypoint_id, pinned)?;
+
+                                let plugins = application_manager.plugins()?;
+                                let global_entrypoint_shortcuts =
+                                    application_manager.get_global_entrypoint_shortcuts()?;
+                                let entrypoint_search_settings = EntrypointSearchSettings::fetch(&application_manager)?;
 
-                                Ok((plugins, global_entrypoint_shortcuts, entrypoint_aliases))
+                                Ok((plugins, global_entrypoint_shortcuts, entrypoint_search_settings))
                             },
                             |result| {
                                 handle_backend_error(
                                     result,
-                                    |(plugins, global_entrypoint_shortcuts, entrypoint_aliases)| {
+                                    |(plugins, global_entrypoint_shortcuts, entrypoint_search_settings)| {
                                         SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::PluginsReloaded(
                                             plugins,
                                             global_entrypoint_shortcuts,
-                                            entrypoint_aliases,
+                                            entrypoint_search_settings,
+                                        ))
+                                    },
+                                )
+                            },
+                        )
+                    }
+                    PluginTableMsgOut::PinMoved(plugin_id, entrypoint_id, direction) => {
+                        let application_manager = application_manager.clone();
+
+                        Task::perform(
+                            async move {
+                                application_manager.move_entrypoint_search_pin(plugin_id, entrypoint_id, direction)?;
+
+                                let plugins = application_manager.plugins()?;
+                                let global_entrypoint_shortcuts =
+                                    application_manager.get_global_entrypoint_shortcuts()?;
+                                let entrypoint_search_settings = EntrypointSearchSettings::fetch(&application_manager)?;
+
+                                Ok((plugins, global_entrypoint_shortcuts, entrypoint_search_settings))
+                            },
+                            |result| {
+                                handle_backend_error(
+                                    result,
+                                    |(plugins, global_entrypoint_shortcuts, entrypoint_search_settings)| {
+                                        SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::PluginsReloaded(
+                                            plugins,
+                                            global_entrypoint_shortcuts,
+                                            entrypoint_search_settings,
+                                        ))
+                                    },
+                                )
+                            },
+                        )
+                    }
+                    PluginTableMsgOut::HiddenChanged(plugin_id, entrypoint_id, hidden) => {
+                        let application_manager = application_manager.clone();
+
+                        Task::perform(
+                            async move {
+                                application_manager.set_entrypoint_search_hidden(plugin_id, entrypoint_id, hidden)?;
+
+                                let plugins = application_manager.plugins()?;
+                                let global_entrypoint_shortcuts =
+                                    application_manager.get_global_entrypoint_shortcuts()?;
+                                let entrypoint_search_settings = EntrypointSearchSettings::fetch(&application_manager)?;
+
+                                Ok((plugins, global_entrypoint_shortcuts, entrypoint_search_settings))
+                            },
+                            |result| {
+                                handle_backend_error(
+                                    result,
+                                    |(plugins, global_entrypoint_shortcuts, entrypoint_search_settings)| {
+                                        SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::PluginsReloaded(
+                                            plugins,
+                                            global_entrypoint_shortcuts,
+                                            entrypoint_search_settings,
                                         ))
                                     },
                                 )
@@ -283,7 +385,7 @@ impl SettingsPluginsState {
                 self.apply_plugin_fetch(
                     plugins,
                     self.global_entrypoint_shortcuts.clone(),
-                    self.entrypoint_search_aliases.clone(),
+                    self.entrypoint_search_settings.clone(),
                 );
 
                 Task::none()
@@ -308,7 +410,7 @@ impl SettingsPluginsState {
                 self.apply_plugin_fetch(
                     plugins,
                     self.global_entrypoint_shortcuts.clone(),
-                    self.entrypoint_search_aliases.clone(),
+                    self.entrypoint_search_settings.clone(),
                 );
 
                 Task::none()
@@ -351,23 +453,26 @@ impl SettingsPluginsState {
                     async move {
                         let plugins = application_manager.plugins()?;
                         let global_entrypoint_shortcuts = application_manager.get_global_entrypoint_shortcuts()?;
-                        let entrypoint_aliases = application_manager.get_entrypoint_search_aliases()?;
+                        let entrypoint_search_settings = EntrypointSearchSettings::fetch(&application_manager)?;
 
-                        Ok((plugins, global_entrypoint_shortcuts, entrypoint_aliases))
+                        Ok((plugins, global_entrypoint_shortcuts, entrypoint_search_settings))
                     },
                     |result| {
-                        handle_backend_error(result, |(plugins, global_entrypoint_shortcuts, entrypoint_aliases)| {
-                            SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::PluginsReloaded(
-                                plugins,
-                                global_entrypoint_shortcuts,
-                                entrypoint_aliases,
-                            ))
-                        })
+                        handle_backend_error(
+                            result,
+                            |(plugins, global_entrypoint_shortcuts, entrypoint_search_settings)| {
+                                SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::PluginsReloaded(
+                                    plugins,
+                                    global_entrypoint_shortcuts,
+                                    entrypoint_search_settings,
+                                ))
+                            },
+                        )
                     },
                 )
             }
-            SettingsPluginMsgIn::PluginsReloaded(plugins, shortcuts, entrypoint_aliases) => {
-                self.apply_plugin_fetch(plugins, shortcuts, entrypoint_aliases);
+            SettingsPluginMsgIn::PluginsReloaded(plugins, shortcuts, entrypoint_search_settings) => {
+                self.apply_plugin_fetch(plugins, shortcuts, entrypoint_search_settings);
 
                 Task::none()
             }
@@ -382,18 +487,21 @@ impl SettingsPluginsState {
 
                         let plugins = application_manager.plugins()?;
                         let global_entrypoint_shortcuts = application_manager.get_global_entrypoint_shortcuts()?;
-                        let entrypoint_aliases = application_manager.get_entrypoint_search_aliases()?;
+                        let entrypoint_search_settings = EntrypointSearchSettings::fetch(&application_manager)?;
 
-                        Ok((plugins, global_entrypoint_shortcuts, entrypoint_aliases))
+                        Ok((plugins, global_entrypoint_shortcuts, entrypoint_search_settings))
                     },
                     |result| {
-                        handle_backend_error(result, |(plugins, global_entrypoint_shortcuts, entrypoint_aliases)| {
-                            SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::PluginsReloaded(
-                                plugins,
-                                global_entrypoint_shortcuts,
-                                entrypoint_aliases,
-                            ))
-                        })
+                        handle_backend_error(
+                            result,
+                            |(plugins, global_entrypoint_shortcuts, entrypoint_search_settings)| {
+                                SettingsPluginMsgOut::Inner(SettingsPluginMsgIn::PluginsReloaded(
+                                    plugins,
+                                    global_entrypoint_shortcuts,
+                                    entrypoint_search_settings,
+                                ))
+                            },
+                        )
                     },
                 )
             }
@@ -412,9 +520,10 @@ impl SettingsPluginsState {
         &mut self,
         plugins: HashMap<PluginId, SettingsPlugin>,
         global_entrypoint_shortcuts: HashMap<(PluginId, EntrypointId), (PhysicalShortcut, Option<String>)>,
-        entrypoint_search_aliases: HashMap<(PluginId, EntrypointId), String>,
+        entrypoint_search_settings: EntrypointSearchSettings,
     ) {
         self.global_entrypoint_shortcuts = global_entrypoint_shortcuts.clone();
+        self.entrypoint_search_settings = entrypoint_search_settings.clone();
 
         self.preference_user_data = plugins
             .iter()
@@ -494,7 +603,7 @@ impl SettingsPluginsState {
             self.plugin_data.clone(),
             plugin_refs,
             global_entrypoint_shortcuts,
-            entrypoint_search_aliases,
+            entrypoint_search_settings,
         )
     }
 
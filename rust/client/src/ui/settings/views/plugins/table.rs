@@ -5,6 +5,7 @@ use std::rc::Rc;
 use gauntlet_common::model::EntrypointId;
 use gauntlet_common::model::PhysicalShortcut;
 use gauntlet_common::model::PluginId;
+use gauntlet_common::model::SearchPinMove;
 use gauntlet_common::model::SettingsEntrypointType;
 use gauntlet_common::model::SettingsPlugin;
 use iced::Alignment;
@@ -21,8 +22,14 @@ use iced::widget::row;
 use iced::widget::scrollable;
 use iced::widget::text;
 use iced::widget::text_input;
+use iced_fonts::bootstrap::arrow_down;
+use iced_fonts::bootstrap::arrow_up;
 use iced_fonts::bootstrap::caret_down;
 use iced_fonts::bootstrap::caret_right;
+use iced_fonts::bootstrap::eye;
+use iced_fonts::bootstrap::eye_slash;
+use iced_fonts::bootstrap::star;
+use iced_fonts::bootstrap::star_fill;
 
 use crate::ui::settings::components::shortcut_selector::ShortcutData;
 use crate::ui::settings::components::shortcut_selector::shortcut_selector;
@@ -30,6 +37,7 @@ use crate::ui::settings::theme::Element;
 use crate::ui::settings::theme::button::ButtonStyle;
 use crate::ui::settings::theme::container::ContainerStyle;
 use crate::ui::settings::theme::text_input::TextInputStyle;
+use crate::ui::settings::views::plugins::EntrypointSearchSettings;
 use crate::ui::settings::views::plugins::PluginDataContainer;
 use crate::ui::settings::views::plugins::SelectedItem;
 use crate::ui::settings::views::plugins::SettingsPluginData;
@@ -47,6 +55,9 @@ pub enum PluginTableMsgIn {
     },
     ShortcutCaptured(PluginId, EntrypointId, Option<PhysicalShortcut>),
     AliasChanged(PluginId, EntrypointId, String),
+    PinnedChanged(PluginId, EntrypointId, bool),
+    PinMoved(PluginId, EntrypointId, SearchPinMove),
+    HiddenChanged(PluginId, EntrypointId, bool),
 }
 
 pub enum PluginTableMsgOut {
@@ -69,6 +80,9 @@ pub enum PluginTableMsgOut {
     },
     ShortcutCaptured(PluginId, EntrypointId, Option<PhysicalShortcut>),
     AliasChanged(PluginId, EntrypointId, Option<String>),
+    PinnedChanged(PluginId, EntrypointId, bool),
+    PinMoved(PluginId, EntrypointId, SearchPinMove),
+    HiddenChanged(PluginId, EntrypointId, bool),
 }
 
 pub struct PluginTableState {
@@ -126,6 +140,15 @@ impl PluginTableState {
 
                 PluginTableMsgOut::AliasChanged(plugin_id, entrypoint_id, alias)
             }
+            PluginTableMsgIn::PinnedChanged(plugin_id, entrypoint_id, pinned) => {
+                PluginTableMsgOut::PinnedChanged(plugin_id, entrypoint_id, pinned)
+            }
+            PluginTableMsgIn::PinMoved(plugin_id, entrypoint_id, direction) => {
+                PluginTableMsgOut::PinMoved(plugin_id, entrypoint_id, direction)
+            }
+            PluginTableMsgIn::HiddenChanged(plugin_id, entrypoint_id, hidden) => {
+                PluginTableMsgOut::HiddenChanged(plugin_id, entrypoint_id, hidden)
+            }
         }
     }
 
@@ -134,7 +157,7 @@ impl PluginTableState {
         plugin_data: Rc<RefCell<PluginDataContainer>>,
         plugin_refs: Vec<(&SettingsPlugin, &SettingsPluginData)>,
         global_entrypoint_shortcuts: HashMap<(PluginId, EntrypointId), (PhysicalShortcut, Option<String>)>,
-        entrypoint_search_aliases: HashMap<(PluginId, EntrypointId), String>,
+        entrypoint_search_settings: EntrypointSearchSettings,
     ) {
         self.rows = plugin_refs
             .iter()
@@ -157,16 +180,16 @@ impl PluginTableState {
                         let shortcut = global_entrypoint_shortcut.map(|(shortcut, _)| shortcut).cloned();
                         let error = global_entrypoint_shortcut.map(|(_, error)| error).cloned().flatten();
 
-                        let search_alias = entrypoint_search_aliases
-                            .get(&(plugin.plugin_id.clone(), entrypoint.entrypoint_id.clone()))
-                            .cloned();
+                        let key = (plugin.plugin_id.clone(), entrypoint.entrypoint_id.clone());
 
                         let entrypoint_row = Row::Entrypoint {
                             plugin_data: plugin_data.clone(),
                             plugin_id: plugin.plugin_id.clone(),
                             entrypoint_id: entrypoint.entrypoint_id.clone(),
                             shortcut_data: ShortcutData { shortcut, error },
-                            search_alias,
+                            search_alias: entrypoint_search_settings.aliases.get(&key).cloned(),
+                            search_pinned: entrypoint_search_settings.pins.contains(&key),
+                            search_hidden: entrypoint_search_settings.hidden.contains(&key),
                         };
 
                         result.push(entrypoint_row);
@@ -192,9 +215,7 @@ impl PluginTableState {
                                 let shortcut = global_entrypoint_shortcut.map(|(shortcut, _)| shortcut).cloned();
                                 let error = global_entrypoint_shortcut.map(|(_, error)| error).cloned().flatten();
 
-                                let search_alias = entrypoint_search_aliases
-                                    .get(&(plugin.plugin_id.clone(), data.entrypoint_id.clone()))
-                                    .cloned();
+                                let key = (plugin.plugin_id.clone(), data.entrypoint_id.clone());
 
                                 let generated_entrypoint_row = Row::GeneratedEntrypoint {
                                     plugin_data: plugin_data.clone(),
@@ -202,7 +223,9 @@ impl PluginTableState {
                                     generator_entrypoint_id: entrypoint.entrypoint_id.clone(),
                                     generated_entrypoint_id: data.entrypoint_id.clone(),
                                     shortcut_data: ShortcutData { shortcut, error },
-                                    search_alias,
+                                    search_alias: entrypoint_search_settings.aliases.get(&key).cloned(),
+                                    search_pinned: entrypoint_search_settings.pins.contains(&key),
+                                    search_hidden: entrypoint_search_settings.hidden.contains(&key),
                                 };
 
                                 result.push(generated_entrypoint_row);
@@ -238,6 +261,7 @@ impl PluginTableState {
                         None
                     },
                     alias_cell(row_item),
+                    search_cell(row_item),
                     enable_cell(row_item),
                 )
             })
@@ -253,6 +277,7 @@ impl PluginTableState {
                 None
             },
             header_cell("Alias", false),
+            header_cell("Search", false),
             header_cell("Enabled", false),
         );
 
@@ -288,6 +313,8 @@ enum Row {
         entrypoint_id: EntrypointId,
         shortcut_data: ShortcutData,
         search_alias: Option<String>,
+        search_pinned: bool,
+        search_hidden: bool,
     },
     GeneratedEntrypoint {
         plugin_data: Rc<RefCell<PluginDataContainer>>,
@@ -296,6 +323,8 @@ enum Row {
         generated_entrypoint_id: EntrypointId,
         shortcut_data: ShortcutData,
         search_alias: Option<String>,
+        search_pinned: bool,
+        search_hidden: bool,
     },
 }
 
@@ -305,13 +334,15 @@ fn table_row<'a>(
     type_cell: Element<'a, PluginTableMsgIn>,
     shortcut_cell: Option<Element<'a, PluginTableMsgIn>>,
     alias_cell: Element<'a, PluginTableMsgIn>,
+    search_cell: Element<'a, PluginTableMsgIn>,
     enable_cell: Element<'a, PluginTableMsgIn>,
 ) -> Element<'a, PluginTableMsgIn> {
     let content = row([])
-        .push(container(name_cell).width(Length::FillPortion(38)))
+        .push(container(name_cell).width(Length::FillPortion(30)))
         .push(container(type_cell).width(Length::FillPortion(12)))
         .push_maybe(shortcut_cell.map(|item| container(item).width(Length::FillPortion(24))))
         .push(container(alias_cell).width(Length::FillPortion(15)))
+        .push(container(search_cell).width(Length::FillPortion(12)))
         .push(container(enable_cell).width(Length::FillPortion(10)));
 
     container(content).class(style).into()
@@ -682,6 +713,89 @@ fn alias_cell<'a>(row_entry: &Row) -> Element<'a, PluginTableMsgIn> {
     }
 }
 
+fn search_cell<'a>(row_entry: &Row) -> Element<'a, PluginTableMsgIn> {
+    match &row_entry {
+        Row::Plugin { .. } => horizontal_space().into(),
+        Row::Entrypoint {
+            plugin_data,
+            plugin_id,
+            entrypoint_id,
+            search_pinned,
+            search_hidden,
+            ..
+        } => {
+            let plugin_data = plugin_data.borrow();
+            let plugin = plugin_data.plugins.get(&plugin_id).unwrap();
+            let entrypoint = plugin.entrypoints.get(&entrypoint_id).unwrap();
+
+            if let SettingsEntrypointType::View | SettingsEntrypointType::Command = entrypoint.entrypoint_type {
+                search_buttons(plugin_id, entrypoint_id, *search_pinned, *search_hidden)
+            } else {
+                horizontal_space().into()
+            }
+        }
+        Row::GeneratedEntrypoint {
+            plugin_id,
+            generated_entrypoint_id,
+            search_pinned,
+            search_hidden,
+            ..
+        } => search_buttons(plugin_id, generated_entrypoint_id, *search_pinned, *search_hidden),
+    }
+}
+
+fn search_buttons<'a>(
+    plugin_id: &PluginId,
+    entrypoint_id: &EntrypointId,
+    pinned: bool,
+    hidden: bool,
+) -> Element<'a, PluginTableMsgIn> {
+    let search_button = |icon: Element<'a, PluginTableMsgIn>, msg: Option<PluginTableMsgIn>| -> Element<'a, _> {
+        button(icon)
+            .on_press_maybe(msg)
+            .width(Length::Shrink)
+            .height(Length::Fixed(40.0))
+            .padding(8.0)
+            .class(ButtonStyle::TableRow)
+            .into()
+    };
+
+    let pin_icon: Element<_> = if pinned { star_fill().into() } else { star().into() };
+    let pin_button = search_button(
+        pin_icon,
+        Some(PluginTableMsgIn::PinnedChanged(
+            plugin_id.clone(),
+            entrypoint_id.clone(),
+            !pinned,
+        )),
+    );
+
+    let hidden_icon: Element<_> = if hidden { eye_slash().into() } else { eye().into() };
+    let hidden_button = search_button(
+        hidden_icon,
+        Some(PluginTableMsgIn::HiddenChanged(
+            plugin_id.clone(),
+            entrypoint_id.clone(),
+            !hidden,
+        )),
+    );
+
+    // pinned items can be reordered, order is used when showing them at the top of the search results
+    let move_up_button = search_button(
+        arrow_up().into(),
+        pinned.then(|| PluginTableMsgIn::PinMoved(plugin_id.clone(), entrypoint_id.clone(), SearchPinMove::Up)),
+    );
+
+    let move_down_button = search_button(
+        arrow_down().into(),
+        pinned.then(|| PluginTableMsgIn::PinMoved(plugin_id.clone(), entrypoint_id.clone(), SearchPinMove::Down)),
+    );
+
+    row(vec![pin_button, move_up_button, move_down_button, hidden_button])
+        .align_y(Alignment::Center)
+        .into()
+}
+
 fn enable_cell<'a>(row: &Row) -> Element<'a, PluginTableMsgIn> {
     let (enabled, show_checkbox, plugin_id, entrypoint_id) = match row {
         Row::Plugin { plugin_data, plugin_id } => {
@@ -0,0 +1,355 @@
+use std::sync::Arc;
+
+use gauntlet_common::model::Quicklink;
+use gauntlet_common::model::QuicklinkKind;
+use gauntlet_server::plugins::ApplicationManager;
+use gauntlet_utils::channel::RequestResult;
+use iced::Alignment;
+use iced::Font;
+use iced::Length;
+use iced::Task;
+use iced::alignment::Horizontal;
+use iced::font::Style;
+use iced::widget::Space;
+use iced::widget::button;
+use iced::widget::column;
+use iced::widget::container;
+use iced::widget::horizontal_rule;
+use iced::widget::pick_list;
+use iced::widget::row;
+use iced::widget::scrollable;
+use iced::widget::text;
+use iced::widget::text::Shaping;
+use iced::widget::text_input;
+use iced_fonts::bootstrap::pencil_fill;
+use iced_fonts::bootstrap::trash_fill;
+
+use crate::ui::settings::theme::Element;
+use crate::ui::settings::theme::button::ButtonStyle;
+use crate::ui::settings::theme::text::TextStyle;
+use crate::ui::settings::theme::text_input::TextInputStyle;
+use crate::ui::settings::ui::SettingsMsg;
+
+pub struct SettingsQuicklinksState {
+    application_manager: Arc<ApplicationManager>,
+    quicklinks: Vec<Quicklink>,
+    form: QuicklinkForm,
+}
+
+struct QuicklinkForm {
+    // empty when creating new quicklink
+    id: String,
+    name: String,
+    icon_path: String,
+    template: String,
+    kind: QuicklinkKind,
+    error: Option<String>,
+}
+
+impl QuicklinkForm {
+    fn new() -> Self {
+        Self {
+            id: "".to_string(),
+            name: "".to_string(),
+            icon_path: "".to_string(),
+            template: "".to_string(),
+            kind: QuicklinkKind::Url,
+            error: None,
+        }
+    }
+
+    fn from_quicklink(quicklink: &Quicklink) -> Self {
+        Self {
+            id: quicklink.id.clone(),
+            name: quicklink.name.clone(),
+            icon_path: quicklink.icon_path.clone().unwrap_or_default(),
+            template: quicklink.template.clone(),
+            kind: quicklink.kind,
+            error: None,
+        }
+    }
+
+    fn to_quicklink(&self) -> Quicklink {
+        let icon_path = self.icon_path.trim();
+
+        Quicklink {
+            id: self.id.clone(),
+            name: self.name.trim().to_string(),
+            icon_path: if icon_path.is_empty() {
+                None
+            } else {
+                Some(icon_path.to_string())
+            },
+            template: self.template.trim().to_string(),
+            kind: self.kind,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum SettingsQuicklinksMsgIn {
+    FetchQuicklinks,
+    QuicklinksReloaded(Vec<Quicklink>),
+    NameChanged(String),
+    IconPathChanged(String),
+    TemplateChanged(String),
+    KindChanged(QuicklinkKind),
+    Edit(String),
+    Remove(String),
+    Save,
+    Cancel,
+}
+
+#[derive(Debug, Clone)]
+pub enum SettingsQuicklinksMsgOut {
+    Inner(SettingsQuicklinksMsgIn),
+    Outer(SettingsMsg),
+}
+
+impl SettingsQuicklinksState {
+    pub fn new(application_manager: Arc<ApplicationManager>) -> Self {
+        Self {
+            application_manager,
+            quicklinks: vec![],
+            form: QuicklinkForm::new(),
+        }
+    }
+
+    pub fn update(&mut self, message: SettingsQuicklinksMsgIn) -> Task<SettingsQuicklinksMsgOut> {
+        match message {
+            SettingsQuicklinksMsgIn::FetchQuicklinks => {
+                let application_manager = self.application_manager.clone();
+
+                Task::perform(
+                    async move {
+                        let quicklinks = application_manager.list_quicklinks()?;
+
+                        Ok(quicklinks)
+                    },
+                    |result| {
+                        handle_backend_error(result, |quicklinks| {
+                            SettingsQuicklinksMsgOut::Inner(SettingsQuicklinksMsgIn::QuicklinksReloaded(quicklinks))
+                        })
+                    },
+                )
+            }
+            SettingsQuicklinksMsgIn::QuicklinksReloaded(quicklinks) => {
+                self.quicklinks = quicklinks;
+
+                Task::none()
+            }
+            SettingsQuicklinksMsgIn::NameChanged(value) => {
+                self.form.name = value;
+
+                Task::none()
+            }
+            SettingsQuicklinksMsgIn::IconPathChanged(value) => {
+                self.form.icon_path = value;
+
+                Task::none()
+            }
+            SettingsQuicklinksMsgIn::TemplateChanged(value) => {
+                self.form.template = value;
+
+                Task::none()
+            }
+            SettingsQuicklinksMsgIn::KindChanged(kind) => {
+                self.form.kind = kind;
+
+                Task::none()
+            }
+            SettingsQuicklinksMsgIn::Edit(id) => {
+                if let Some(quicklink) = self.quicklinks.iter().find(|quicklink| quicklink.id == id) {
+                    self.form = QuicklinkForm::from_quicklink(quicklink);
+                }
+
+                Task::none()
+            }
+            SettingsQuicklinksMsgIn::Cancel => {
+                self.form = QuicklinkForm::new();
+
+                Task::none()
+            }
+            SettingsQuicklinksMsgIn::Save => {
+                match self.application_manager.save_quicklink(self.form.to_quicklink()) {
+                    Ok(_) => {
+                        self.form = QuicklinkForm::new();
+
+                        Task::done(SettingsQuicklinksMsgOut::Inner(
+                            SettingsQuicklinksMsgIn::FetchQuicklinks,
+                        ))
+                    }
+                    Err(err) => {
+                        self.form.error = Some(err.to_string());
+
+                        Task::none()
+                    }
+                }
+            }
+            SettingsQuicklinksMsgIn::Remove(id) => {
+                if self.form.id == id {
+                    self.form = QuicklinkForm::new();
+                }
+
+                let application_manager = self.application_manager.clone();
+
+                Task::perform(
+                    async move {
+                        application_manager.remove_quicklink(id)?;
+
+                        let quicklinks = application_manager.list_quicklinks()?;
+
+                        Ok(quicklinks)
+                    },
+                    |result| {
+                        handle_backend_error(result, |quicklinks| {
+                            SettingsQuicklinksMsgOut::Inner(SettingsQuicklinksMsgIn::QuicklinksReloaded(quicklinks))
+                        })
+                    },
+                )
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<SettingsQuicklinksMsgIn> {
+        let list: Element<_> = if self.quicklinks.is_empty() {
+            text("No quicklinks yet")
+                .font(Font {
+                    style: Style::Italic,
+                    ..Font::DEFAULT
+                })
+                .align_x(Horizontal::Center)
+                .width(Length::Fill)
+                .into()
+        } else {
+            let rows: Vec<Element<_>> = self
+                .quicklinks
+                .iter()
+                .map(|quicklink| self.quicklink_row(quicklink))
+                .collect();
+
+            column(rows).spacing(4).into()
+        };
+
+        let list: Element<_> = scrollable(list).width(Length::Fill).height(Length::Fill).into();
+
+        let list: Element<_> = container(list).padding(12).height(Length::Fill).into();
+
+        let content: Element<_> = column([list, horizontal_rule(1).into(), self.form_view()]).into();
+
+        container(content).width(Length::Fill).into()
+    }
+
+    fn quicklink_row<'a>(&'a self, quicklink: &'a Quicklink) -> Element<'a, SettingsQuicklinksMsgIn> {
+        let name: Element<_> = text(&quicklink.name)
+            .shaping(Shaping::Advanced)
+            .width(Length::FillPortion(3))
+            .into();
+
+        let kind: Element<_> = text(quicklink.kind.to_string())
+            .class(TextStyle::Subtitle)
+            .width(Length::FillPortion(1))
+            .into();
+
+        let template: Element<_> = text(&quicklink.template)
+            .shaping(Shaping::Advanced)
+            .class(TextStyle::Subtitle)
+            .width(Length::FillPortion(6))
+            .into();
+
+        let edit: Element<_> = button(pencil_fill())
+            .on_press(SettingsQuicklinksMsgIn::Edit(quicklink.id.clone()))
+            .class(ButtonStyle::TableRow)
+            .into();
+
+        let remove: Element<_> = button(trash_fill())
+            .on_press(SettingsQuicklinksMsgIn::Remove(quicklink.id.clone()))
+            .class(ButtonStyle::Destructive)
+            .into();
+
+        row([name, kind, template, edit, remove])
+            .spacing(8)
+            .align_y(Alignment::Center)
+            .into()
+    }
+
+    fn form_view(&self) -> Element<SettingsQuicklinksMsgIn> {
+        let name: Element<_> = text_input("Name", &self.form.name)
+            .class(TextInputStyle::FormInput)
+            .on_input(SettingsQuicklinksMsgIn::NameChanged)
+            .on_submit(SettingsQuicklinksMsgIn::Save)
+            .into();
+
+        let icon_path: Element<_> = text_input("Path to icon (optional)", &self.form.icon_path)
+            .class(TextInputStyle::FormInput)
+            .on_input(SettingsQuicklinksMsgIn::IconPathChanged)
+            .on_submit(SettingsQuicklinksMsgIn::Save)
+            .into();
+
+        let kind: Element<_> = pick_list(
+            [QuicklinkKind::Url, QuicklinkKind::Command],
+            Some(self.form.kind),
+            SettingsQuicklinksMsgIn::KindChanged,
+        )
+        .into();
+
+        let template: Element<_> = text_input("https://example.com/search?q={query}", &self.form.template)
+            .class(TextInputStyle::FormInput)
+            .on_input(SettingsQuicklinksMsgIn::TemplateChanged)
+            .on_submit(SettingsQuicklinksMsgIn::Save)
+            .into();
+
+        let hint: Element<_> = text("Template can contain {query}, {clipboard} and {selection} placeholders")
+            .class(TextStyle::Subtitle)
+            .size(12)
+            .into();
+
+        let save_label = if self.form.id.is_empty() {
+            "Add Quicklink"
+        } else {
+            "Save Quicklink"
+        };
+
+        let save: Element<_> = button(text(save_label)).on_press(SettingsQuicklinksMsgIn::Save).into();
+
+        let cancel: Element<_> = if self.form.id.is_empty() {
+            Space::with_width(Length::Shrink).into()
+        } else {
+            button(text("Cancel"))
+                .on_press(SettingsQuicklinksMsgIn::Cancel)
+                .class(ButtonStyle::TableRow)
+                .into()
+        };
+
+        let error: Element<_> = match &self.form.error {
+            None => Space::with_width(Length::Shrink).into(),
+            Some(error) => {
+                text(error)
+                    .shaping(Shaping::Advanced)
+                    .class(TextStyle::Destructive)
+                    .into()
+            }
+        };
+
+        let buttons: Element<_> = row([error, Space::with_width(Length::Fill).into(), cancel, save])
+            .spacing(8)
+            .align_y(Alignment::Center)
+            .into();
+
+        let first_row: Element<_> = row([name, icon_path, kind]).spacing(8).into();
+
+        let content: Element<_> = column([first_row, template, hint, buttons]).spacing(8).into();
+
+        container(content).padding(12).width(Length::Fill).into()
+    }
+}
+
+fn handle_backend_error<T>(
+    result: RequestResult<T>,
+    convert: impl FnOnce(T) -> SettingsQuicklinksMsgOut,
+) -> SettingsQuicklinksMsgOut {
+    match result {
+        Ok(val) => convert(val),
+        Err(err) => SettingsQuicklinksMsgOut::Outer(SettingsMsg::HandleBackendError(err)),
+    }
+}
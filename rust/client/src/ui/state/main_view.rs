@@ -1,8 +1,13 @@
+use std::collections::HashMap;
+
+use gauntlet_common::model::EntrypointId;
 use gauntlet_common::model::PhysicalShortcut;
+use gauntlet_common::model::PluginId;
+use gauntlet_common::model::SearchPinMove;
 use gauntlet_common::model::SearchResult;
-use gauntlet_common::model::SearchResultEntrypointAction;
 use gauntlet_common::model::SearchResultEntrypointType;
 use gauntlet_common::model::UiWidgetId;
+use iced::widget::text_input;
 
 use crate::ui::primary_shortcut;
 use crate::ui::scroll_handle::ScrollContent;
@@ -16,7 +21,7 @@ pub enum MainViewState {
     None,
     SearchResultActionPanel {
         search_result: SearchResult,
-        entrypoint_actions: ScrollContent<SearchResultEntrypointAction>,
+        actions: ScrollContent<UiWidgetId>,
         scroll_handle: ScrollHandle,
     },
     InlineViewActionPanel {
@@ -41,16 +46,14 @@ impl MainViewState {
             None
         };
 
-        let items = search_result
-            .entrypoint_actions
-            .iter()
-            .enumerate()
-            .map(|(index, action)| (action_item_container_id(index), action.clone()))
+        // action panel for search result uses index of the action as both widget id and container id
+        let items = (0..search_result_action_count(&search_result))
+            .map(|index| (action_item_container_id(index), index))
             .collect();
 
         *prev_state = Self::SearchResultActionPanel {
             search_result: search_result.clone(),
-            entrypoint_actions: ScrollContent::new_with_ids(items),
+            actions: ScrollContent::new_with_ids(items),
             scroll_handle: ScrollHandle::new(first_action_item),
         }
     }
@@ -89,7 +92,7 @@ pub fn search_result_action_panel(search_item: &SearchResult) -> Option<ActionPa
                 ActionPanelItem::Action {
                     label: action.label.clone(),
                     container_id: action_item_container_id(index + 1),
-                    widget_id: index,
+                    widget_id: index + 1,
                     physical_shortcut,
                 }
             })
@@ -97,31 +100,29 @@ pub fn search_result_action_panel(search_item: &SearchResult) -> Option<ActionPa
 
         let primary_action_widget_id = 0;
 
-        if actions.is_empty() {
-            None
-        } else {
-            let label = label.to_string();
+        let label = label.to_string();
 
-            let primary_action = ActionPanelItem::Action {
-                label: label.clone(),
-                container_id: action_item_container_id(0),
-                widget_id: primary_action_widget_id,
-                physical_shortcut: Some(primary_shortcut()),
-            };
+        let primary_action = ActionPanelItem::Action {
+            label: label.clone(),
+            container_id: action_item_container_id(0),
+            widget_id: primary_action_widget_id,
+            physical_shortcut: Some(primary_shortcut()),
+        };
 
-            actions.insert(0, primary_action);
+        actions.insert(0, primary_action);
 
-            let action_panel = ActionPanel {
-                title: Some(search_item.entrypoint_name.clone()),
-                items: actions,
-            };
+        actions.push(create_manage_section(search_item, actions.len()));
 
-            Some(action_panel)
-        }
+        let action_panel = ActionPanel {
+            title: Some(search_item.entrypoint_name.clone()),
+            items: actions,
+        };
+
+        Some(action_panel)
     }
 
     fn create_generated(search_item: &SearchResult) -> Option<ActionPanel> {
-        let actions: Vec<_> = search_item
+        let mut actions: Vec<_> = search_item
             .entrypoint_actions
             .iter()
             .enumerate()
@@ -141,6 +142,8 @@ pub fn search_result_action_panel(search_item: &SearchResult) -> Option<ActionPa
             })
             .collect();
 
+        actions.push(create_manage_section(search_item, actions.len()));
+
         let action_panel = ActionPanel {
             title: Some(search_item.entrypoint_name.clone()),
             items: actions,
@@ -149,6 +152,23 @@ pub fn search_result_action_panel(search_item: &SearchResult) -> Option<ActionPa
         Some(action_panel)
     }
 
+    fn create_manage_section(search_item: &SearchResult, first_index: usize) -> ActionPanelItem {
+        let items = search_result_manage_actions(search_item)
+            .into_iter()
+            .enumerate()
+            .map(|(index, action)| {
+                ActionPanelItem::Action {
+                    label: action.label().to_string(),
+                    container_id: action_item_container_id(first_index + index),
+                    widget_id: first_index + index,
+                    physical_shortcut: None,
+                }
+            })
+            .collect();
+
+        ActionPanelItem::ActionSection { title: None, items }
+    }
+
     match search_item.entrypoint_type {
         SearchResultEntrypointType::Command => create_static(search_item, "Run Command"),
         SearchResultEntrypointType::View => create_static(search_item, "Open View"),
@@ -187,3 +207,138 @@ pub fn search_result_bot_panel_right_info(search_item: &SearchResult) -> (String
         SearchResultEntrypointType::Generated => create_generated(search_item, "Run Command"),
     }
 }
+
+#[derive(Debug, Clone)]
+pub enum SearchResultManageAction {
+    Pin,
+    Unpin,
+    MovePin(SearchPinMove),
+    Hide,
+    Unhide,
+}
+
+impl SearchResultManageAction {
+    fn label(&self) -> &'static str {
+        match self {
+            SearchResultManageAction::Pin => "Pin to Favorites",
+            SearchResultManageAction::Unpin => "Remove from Favorites",
+            SearchResultManageAction::MovePin(SearchPinMove::Up) => "Move Up in Favorites",
+            SearchResultManageAction::MovePin(SearchPinMove::Down) => "Move Down in Favorites",
+            SearchResultManageAction::Hide => "Hide from Root Search",
+            SearchResultManageAction::Unhide => "Show in Root Search",
+        }
+    }
+}
+
+fn search_result_manage_actions(search_item: &SearchResult) -> Vec<SearchResultManageAction> {
+    let mut actions = vec![];
+
+    if search_item.entrypoint_pinned {
+        actions.push(SearchResultManageAction::Unpin);
+        actions.push(SearchResultManageAction::MovePin(SearchPinMove::Up));
+        actions.push(SearchResultManageAction::MovePin(SearchPinMove::Down));
+    } else {
+        actions.push(SearchResultManageAction::Pin);
+    }
+
+    if search_item.entrypoint_hidden {
+        actions.push(SearchResultManageAction::Unhide);
+    } else {
+        actions.push(SearchResultManageAction::Hide);
+    }
+
+    actions
+}
+
+// number of actions provided by the entrypoint itself, manage actions are placed after them
+fn search_result_entrypoint_action_count(search_item: &SearchResult) -> usize {
+    match search_item.entrypoint_type {
+        SearchResultEntrypointType::Command | SearchResultEntrypointType::View => {
+            search_item.entrypoint_actions.len() + 1
+        }
+        SearchResultEntrypointType::Generated => search_item.entrypoint_actions.len(),
+    }
+}
+
+fn search_result_action_count(search_item: &SearchResult) -> usize {
+    search_result_entrypoint_action_count(search_item) + search_result_manage_actions(search_item).len()
+}
+
+pub fn search_result_manage_action(
+    search_item: &SearchResult,
+    action_index: usize,
+) -> Option<SearchResultManageAction> {
+    let index = action_index.checked_sub(search_result_entrypoint_action_count(search_item))?;
+
+    search_result_manage_actions(search_item).get(index).cloned()
+}
+
+// values typed into argument fields shown next to the search bar,
+// they belong to a single entrypoint and are dropped when another one receives input
+#[derive(Default)]
+pub struct EntrypointArgumentValues {
+    entrypoint: Option<(PluginId, EntrypointId)>,
+    values: HashMap<String, String>,
+}
+
+impl EntrypointArgumentValues {
+    pub fn get(&self, plugin_id: &PluginId, entrypoint_id: &EntrypointId, argument_id: &str) -> &str {
+        match &self.entrypoint {
+            Some((current_plugin_id, current_entrypoint_id))
+                if current_plugin_id == plugin_id && current_entrypoint_id == entrypoint_id =>
+            {
+                self.values
+                    .get(argument_id)
+                    .map(|value| value.as_str())
+                    .unwrap_or_default()
+            }
+            _ => "",
+        }
+    }
+
+    pub fn set(&mut self, plugin_id: PluginId, entrypoint_id: EntrypointId, argument_id: String, value: String) {
+        let entrypoint = Some((plugin_id, entrypoint_id));
+
+        if self.entrypoint != entrypoint {
+            self.entrypoint = entrypoint;
+            self.values.clear();
+        }
+
+        self.values.insert(argument_id, value);
+    }
+
+    pub fn values(&self, search_item: &SearchResult) -> HashMap<String, String> {
+        search_item
+            .entrypoint_arguments
+            .iter()
+            .map(|argument| {
+                let value = self.get(&search_item.plugin_id, &search_item.entrypoint_id, &argument.id);
+
+                (argument.id.clone(), value.to_string())
+            })
+            .filter(|(_, value)| !value.is_empty())
+            .collect()
+    }
+
+    pub fn first_missing_required(&self, search_item: &SearchResult) -> Option<String> {
+        search_item
+            .entrypoint_arguments
+            .iter()
+            .find(|argument| {
+                argument.required
+                    && self
+                        .get(&search_item.plugin_id, &search_item.entrypoint_id, &argument.id)
+                        .is_empty()
+            })
+            .map(|argument| argument.id.clone())
+    }
+
+    pub fn clear(&mut self) {
+        self.entrypoint = None;
+        self.values.clear();
+    }
+}
+
+pub fn entrypoint_argument_field_id(argument_id: &str) -> text_input::Id {
+    text_input::Id::new(format!("entrypoint-argument-{}", argument_id))
+}
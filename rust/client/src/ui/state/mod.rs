@@ -3,11 +3,14 @@ mod plugin_view;
 
 use std::collections::HashMap;
 
+use gauntlet_common::model::DeepLink;
 use gauntlet_common::model::EntrypointId;
 use gauntlet_common::model::PhysicalShortcut;
 use gauntlet_common::model::PluginId;
 use gauntlet_common::model::SearchResult;
 use iced::Task;
+use iced::widget::focus_next;
+use iced::widget::focus_previous;
 use iced::widget::text_input;
 use iced::widget::text_input::focus;
 
@@ -38,6 +41,10 @@ pub enum GlobalState {
     ErrorView {
         error_view: ErrorViewData,
     },
+    // deep link which didn't come from Gauntlet itself has to be confirmed by user before it is run
+    DeepLinkConfirmation {
+        deep_link: DeepLink,
+    },
     PluginView {
         // state
         plugin_view_data: PluginViewData,
@@ -125,6 +132,12 @@ impl GlobalState {
         Task::none()
     }
 
+    pub fn deep_link_confirmation(prev_global_state: &mut GlobalState, deep_link: DeepLink) -> Task<AppMsg> {
+        *prev_global_state = GlobalState::DeepLinkConfirmation { deep_link };
+
+        Task::none()
+    }
+
     pub fn plugin(
         prev_global_state: &mut GlobalState,
         plugin_view_data: PluginViewData,
@@ -206,16 +219,16 @@ impl Focus<SearchResult> for GlobalState {
                     }
                     MainViewState::SearchResultActionPanel {
                         search_result,
-                        entrypoint_actions,
+                        actions,
                         scroll_handle,
                     } => {
-                        let Some(index) = scroll_handle.get_index(entrypoint_actions) else {
+                        let Some(index) = scroll_handle.get(actions) else {
                             return Task::none();
                         };
 
                         Task::done(AppMsg::OnAnyActionMainViewSearchResultPanelKeyboardWithFocus {
                             search_result: search_result.clone(),
-                            index,
+                            index: *index,
                         })
                     }
                     MainViewState::InlineViewActionPanel { scroll_handle, actions } => {
@@ -264,7 +277,7 @@ impl Focus<SearchResult> for GlobalState {
                     }
                 }
             }
-            GlobalState::ErrorView { .. } => Task::none(),
+            GlobalState::ErrorView { .. } | GlobalState::DeepLinkConfirmation { .. } => Task::none(),
             GlobalState::PendingPluginView { .. } => Task::none(),
         }
     }
@@ -322,7 +335,7 @@ impl Focus<SearchResult> for GlobalState {
                     }
                 }
             }
-            GlobalState::ErrorView { .. } => Task::none(),
+            GlobalState::ErrorView { .. } | GlobalState::DeepLinkConfirmation { .. } => Task::none(),
             GlobalState::PendingPluginView { .. } => Task::none(),
         }
     }
@@ -374,23 +387,38 @@ impl Focus<SearchResult> for GlobalState {
                     PluginViewState::ActionPanel { .. } => Task::done(AppMsg::ToggleActionPanel { keyboard: true }),
                 }
             }
-            GlobalState::ErrorView { .. } => Task::done(AppMsg::WindowAction(WindowActionMsg::HideWindow)),
+            GlobalState::ErrorView { .. } | GlobalState::DeepLinkConfirmation { .. } => {
+                Task::done(AppMsg::WindowAction(WindowActionMsg::HideWindow))
+            }
             GlobalState::PendingPluginView { .. } => Task::none(),
         }
     }
     fn next(&mut self, _client_context: &ClientContext) -> Task<AppMsg> {
         match self {
-            GlobalState::MainView { .. } => Task::none(),
+            GlobalState::MainView { sub_state, .. } => {
+                match sub_state {
+                    // moves between search field and entrypoint argument fields
+                    MainViewState::None => focus_next(),
+                    MainViewState::SearchResultActionPanel { .. } => Task::none(),
+                    MainViewState::InlineViewActionPanel { .. } => Task::none(),
+                }
+            }
             GlobalState::PluginView { .. } => Task::none(),
-            GlobalState::ErrorView { .. } => Task::none(),
+            GlobalState::ErrorView { .. } | GlobalState::DeepLinkConfirmation { .. } => Task::none(),
             GlobalState::PendingPluginView { .. } => Task::none(),
         }
     }
     fn previous(&mut self, _client_context: &ClientContext) -> Task<AppMsg> {
         match self {
-            GlobalState::MainView { .. } => Task::none(),
+            GlobalState::MainView { sub_state, .. } => {
+                match sub_state {
+                    MainViewState::None => focus_previous(),
+                    MainViewState::SearchResultActionPanel { .. } => Task::none(),
+                    MainViewState::InlineViewActionPanel { .. } => Task::none(),
+                }
+            }
             GlobalState::PluginView { .. } => Task::none(),
-            GlobalState::ErrorView { .. } => Task::none(),
+            GlobalState::ErrorView { .. } | GlobalState::DeepLinkConfirmation { .. } => Task::none(),
             GlobalState::PendingPluginView { .. } => Task::none(),
         }
     }
@@ -408,11 +436,9 @@ impl Focus<SearchResult> for GlobalState {
                         task.unwrap_or(Task::none())
                     }
                     MainViewState::SearchResultActionPanel {
-                        entrypoint_actions,
-                        scroll_handle,
-                        ..
+                        actions, scroll_handle, ..
                     } => {
-                        let (_, task) = scroll_handle.list_focus_up(entrypoint_actions.ids());
+                        let (_, task) = scroll_handle.list_focus_up(actions.ids());
 
                         task.unwrap_or(Task::none())
                     }
@@ -423,7 +449,7 @@ impl Focus<SearchResult> for GlobalState {
                     }
                 }
             }
-            GlobalState::ErrorView { .. } => Task::none(),
+            GlobalState::ErrorView { .. } | GlobalState::DeepLinkConfirmation { .. } => Task::none(),
             GlobalState::PluginView {
                 plugin_view_data,
                 sub_state,
@@ -461,11 +487,9 @@ impl Focus<SearchResult> for GlobalState {
                         task.unwrap_or(Task::none())
                     }
                     MainViewState::SearchResultActionPanel {
-                        entrypoint_actions,
-                        scroll_handle,
-                        ..
+                        actions, scroll_handle, ..
                     } => {
-                        let (_, task) = scroll_handle.list_focus_down(entrypoint_actions.ids());
+                        let (_, task) = scroll_handle.list_focus_down(actions.ids());
 
                         task.unwrap_or(Task::none())
                     }
@@ -476,7 +500,7 @@ impl Focus<SearchResult> for GlobalState {
                     }
                 }
             }
-            GlobalState::ErrorView { .. } => Task::none(),
+            GlobalState::ErrorView { .. } | GlobalState::DeepLinkConfirmation { .. } => Task::none(),
             GlobalState::PluginView {
                 plugin_view_data,
                 sub_state,
@@ -517,7 +541,7 @@ impl Focus<SearchResult> for GlobalState {
                 }
             }
             GlobalState::MainView { .. } => Task::none(),
-            GlobalState::ErrorView { .. } => Task::none(),
+            GlobalState::ErrorView { .. } | GlobalState::DeepLinkConfirmation { .. } => Task::none(),
             GlobalState::PendingPluginView { .. } => Task::none(),
         }
     }
@@ -538,7 +562,7 @@ impl Focus<SearchResult> for GlobalState {
                 }
             }
             GlobalState::MainView { .. } => Task::none(),
-            GlobalState::ErrorView { .. } => Task::none(),
+            GlobalState::ErrorView { .. } | GlobalState::DeepLinkConfirmation { .. } => Task::none(),
             GlobalState::PendingPluginView { .. } => Task::none(),
         }
     }
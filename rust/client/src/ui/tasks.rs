@@ -0,0 +1,478 @@
+use gauntlet_common::model::PhysicalKey;
+use gauntlet_common::model::PhysicalShortcut;
+use gauntlet_common::model::PluginId;
+use iced::Alignment;
+use iced::Length;
+use iced::advanced::text::Shaping;
+use iced::widget::button;
+use iced::widget::column;
+use iced::widget::container;
+use iced::widget::horizontal_space;
+use iced::widget::progress_bar;
+use iced::widget::row;
+use iced::widget::scrollable;
+use iced::widget::text;
+
+use crate::ui::AppMsg;
+use crate::ui::custom_widgets::loading_bar::LoadingBar;
+use crate::ui::theme::Element;
+use crate::ui::theme::ThemableWidget;
+use crate::ui::theme::button::ButtonStyle;
+use crate::ui::theme::container::ContainerStyle;
+use crate::ui::theme::progress_bar::ProgressBarStyle;
+use crate::ui::theme::text::TextStyle;
+use crate::ui::widget::action_panel::render_shortcut;
+
+const MAX_FINISHED_TASKS: usize = 10;
+// panel is scrollable above this height so that search results stay visible
+const MAX_PANEL_HEIGHT: f32 = 180.0;
+
+#[derive(Debug, Clone)]
+pub struct TaskState {
+    pub plugin_id: PluginId,
+    pub plugin_name: String,
+    pub task_id: String,
+    pub title: String,
+    // from 0.0 to 1.0, None means progress is indeterminate
+    pub progress: Option<f64>,
+    pub status: Option<String>,
+    pub outcome: TaskOutcome,
+}
+
+#[derive(Debug, Clone)]
+pub enum TaskOutcome {
+    Running,
+    Cancelling,
+    Succeeded,
+    Failed { error: String },
+    Cancelled,
+}
+
+#[derive(Debug, Default)]
+pub struct Tasks {
+    running: Vec<TaskState>,
+    // most recent first
+    finished: Vec<TaskState>,
+}
+
+impl Tasks {
+    pub fn start(&mut self, plugin_id: PluginId, plugin_name: String, task_id: String, title: String) {
+        self.running
+            .retain(|task| !(task.plugin_id == plugin_id && task.task_id == task_id));
+
+        self.running.push(TaskState {
+            plugin_id,
+            plugin_name,
+            task_id,
+            title,
+            progress: None,
+            status: None,
+            outcome: TaskOutcome::Running,
+        })
+    }
+
+    pub fn update(&mut self, plugin_id: &PluginId, task_id: &str, progress: Option<f64>, status: Option<String>) {
+        let Some(task) = self.find_running_mut(plugin_id, task_id) else {
+            return;
+        };
+
+        if let Some(progress) = progress {
+            task.progress = Some(progress.clamp(0.0, 1.0));
+        }
+
+        if let Some(status) = status {
+            task.status = Some(status);
+        }
+    }
+
+    pub fn cancel(&mut self, plugin_id: &PluginId, task_id: &str) -> bool {
+        let Some(task) = self.find_running_mut(plugin_id, task_id) else {
+            return false;
+        };
+
+        match task.outcome {
+            TaskOutcome::Running => {
+                task.outcome = TaskOutcome::Cancelling;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // returns finished task so that its outcome can be shown to the user
+    pub fn finish(&mut self, plugin_id: &PluginId, task_id: &str, error: Option<String>) -> Option<TaskState> {
+        let index = self
+            .running
+            .iter()
+            .position(|task| &task.plugin_id == plugin_id && task.task_id == task_id)?;
+
+        let mut task = self.running.remove(index);
+
+        task.outcome = match (task.outcome, error) {
+            // abort signal usually results in an error being thrown, it is still considered a cancellation
+            (TaskOutcome::Cancelling, _) => TaskOutcome::Cancelled,
+            (_, Some(error)) => TaskOutcome::Failed { error },
+            (_, None) => {
+                task.progress = task.progress.map(|_| 1.0);
+                TaskOutcome::Succeeded
+            }
+        };
+
+        self.finished.insert(0, task.clone());
+        self.finished.truncate(MAX_FINISHED_TASKS);
+
+        Some(task)
+    }
+
+    pub fn clear_finished(&mut self) {
+        self.finished.clear()
+    }
+
+    pub fn is_running(&self) -> bool {
+        !self.running.is_empty()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.running.is_empty() && self.finished.is_empty()
+    }
+
+    pub fn has_finished(&self) -> bool {
+        !self.finished.is_empty()
+    }
+
+    // most recently started task, target of the cancel shortcut
+    pub fn last_cancellable(&self) -> Option<&TaskState> {
+        self.running
+            .iter()
+            .rev()
+            .find(|task| matches!(task.outcome, TaskOutcome::Running))
+    }
+
+    fn find_running_mut(&mut self, plugin_id: &PluginId, task_id: &str) -> Option<&mut TaskState> {
+        self.running
+            .iter_mut()
+            .find(|task| &task.plugin_id == plugin_id && task.task_id == task_id)
+    }
+
+    pub fn render<'a>(&self) -> Element<'a, AppMsg> {
+        let last_cancellable = self
+            .last_cancellable()
+            .map(|task| (task.plugin_id.clone(), task.task_id.clone()));
+
+        let mut items: Vec<Element<_>> = self
+            .running
+            .iter()
+            .map(|task| {
+                let show_shortcut = last_cancellable
+                    .as_ref()
+                    .is_some_and(|(plugin_id, task_id)| &task.plugin_id == plugin_id && &task.task_id == task_id);
+
+                render_task(task, show_shortcut)
+            })
+            .collect();
+
+        if !self.finished.is_empty() {
+            let title: Element<_> = text("Recent Tasks")
+                .size(13)
+                .shaping(Shaping::Advanced)
+                .themed(TextStyle::ListSectionTitle);
+
+            let clear: Element<_> = text("Clear").size(13).into();
+
+            let clear: Element<_> = button(clear)
+                .on_press(AppMsg::ClearFinishedTasks)
+                .themed(ButtonStyle::MetadataTagItem);
+
+            let clear: Element<_> = container(clear).themed(ContainerStyle::MetadataTagItem);
+
+            let header: Element<_> = row([
+                title,
+                horizontal_space().into(),
+                render_shortcut(&clear_finished_tasks_shortcut()),
+                clear,
+            ])
+            .spacing(8)
+            .align_y(Alignment::Center)
+            .into();
+
+            items.push(header);
+
+            items.extend(self.finished.iter().map(|task| render_task(task, false)));
+        }
+
+        let content: Element<_> = column(items).spacing(8).width(Length::Fill).into();
+
+        let content: Element<_> = container(content).padding([8, 16]).width(Length::Fill).into();
+
+        let content: Element<_> = scrollable(content).width(Length::Fill).into();
+
+        container(content)
+            .max_height(MAX_PANEL_HEIGHT)
+            .width(Length::Fill)
+            .into()
+    }
+}
+
+pub fn cancel_task_shortcut() -> PhysicalShortcut {
+    PhysicalShortcut {
+        physical_key: PhysicalKey::KeyX,
+        modifier_shift: true,
+        modifier_control: cfg!(not(target_os = "macos")),
+        modifier_alt: false,
+        modifier_meta: cfg!(target_os = "macos"),
+    }
+}
+
+pub fn clear_finished_tasks_shortcut() -> PhysicalShortcut {
+    PhysicalShortcut {
+        physical_key: PhysicalKey::Delete,
+        modifier_shift: true,
+        modifier_control: cfg!(not(target_os = "macos")),
+        modifier_alt: false,
+        modifier_meta: cfg!(target_os = "macos"),
+    }
+}
+
+fn render_task<'a>(task: &TaskState, show_shortcut: bool) -> Element<'a, AppMsg> {
+    let title: Element<_> = text(format!("{} - {}", task.plugin_name, task.title))
+        .size(15)
+        .shaping(Shaping::Advanced)
+        .into();
+
+    let status = match &task.outcome {
+        TaskOutcome::Running => task.status.clone(),
+        TaskOutcome::Cancelling => Some("Cancelling...".to_string()),
+        TaskOutcome::Succeeded => Some(task.status.clone().unwrap_or("Done".to_string())),
+        TaskOutcome::Failed { error } => Some(format!("Failed: {}", error)),
+        TaskOutcome::Cancelled => Some("Cancelled".to_string()),
+    };
+
+    let mut header = vec![title];
+
+    if let Some(status) = status {
+        let status: Element<_> = text(status)
+            .size(15)
+            .shaping(Shaping::Advanced)
+            .themed(TextStyle::MainListItemSubtext);
+
+        header.push(status);
+    }
+
+    header.push(horizontal_space().into());
+
+    if let TaskOutcome::Running = task.outcome {
+        let cancel: Element<_> = text("Cancel").size(13).into();
+
+        let cancel: Element<_> = button(cancel)
+            .on_press(AppMsg::CancelTask {
+                plugin_id: task.plugin_id.clone(),
+                task_id: task.task_id.clone(),
+            })
+            .themed(ButtonStyle::MetadataTagItem);
+
+        let cancel: Element<_> = container(cancel).themed(ContainerStyle::MetadataTagItem);
+
+        if show_shortcut {
+            header.push(render_shortcut(&cancel_task_shortcut()));
+        }
+
+        header.push(cancel);
+    }
+
+    let header: Element<_> = row(header).spacing(8).align_y(Alignment::Center).into();
+
+    match task.outcome {
+        TaskOutcome::Running | TaskOutcome::Cancelling => {
+            let progress: Element<_> = match task.progress {
+                Some(progress) => {
+                    progress_bar(0.0..=1.0, progress as f32)
+                        .girth(4)
+                        .themed(ProgressBarStyle::Default)
+                }
+                None => LoadingBar::new().into(),
+            };
+
+            column([header, progress]).spacing(4).into()
+        }
+        _ => header,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plugin_id() -> PluginId {
+        PluginId::from_string("bundled://test")
+    }
+
+    fn start(tasks: &mut Tasks, task_id: &str) {
+        tasks.start(
+            plugin_id(),
+            "Test".to_string(),
+            task_id.to_string(),
+            format!("Task {}", task_id),
+        )
+    }
+
+    fn finished_ids(tasks: &Tasks) -> Vec<&str> {
+        tasks.finished.iter().map(|task| task.task_id.as_str()).collect()
+    }
+
+    #[test]
+    fn finished_task_succeeds_with_full_progress() {
+        let mut tasks = Tasks::default();
+
+        start(&mut tasks, "1");
+        tasks.update(&plugin_id(), "1", Some(0.4), Some("Downloading".to_string()));
+
+        let task = tasks.finish(&plugin_id(), "1", None).unwrap();
+
+        assert!(matches!(task.outcome, TaskOutcome::Succeeded));
+        assert_eq!(task.progress, Some(1.0));
+        assert_eq!(task.status.as_deref(), Some("Downloading"));
+        assert!(!tasks.is_running());
+        assert_eq!(finished_ids(&tasks), vec!["1"]);
+    }
+
+    #[test]
+    fn indeterminate_progress_stays_indeterminate_after_success() {
+        let mut tasks = Tasks::default();
+
+        start(&mut tasks, "1");
+
+        let task = tasks.finish(&plugin_id(), "1", None).unwrap();
+
+        assert_eq!(task.progress, None);
+    }
+
+    #[test]
+    fn progress_is_clamped() {
+        let mut tasks = Tasks::default();
+
+        start(&mut tasks, "1");
+
+        tasks.update(&plugin_id(), "1", Some(1.5), None);
+        assert_eq!(tasks.running[0].progress, Some(1.0));
+
+        tasks.update(&plugin_id(), "1", Some(-0.5), None);
+        assert_eq!(tasks.running[0].progress, Some(0.0));
+
+        // missing values keep previous ones
+        tasks.update(&plugin_id(), "1", None, None);
+        assert_eq!(tasks.running[0].progress, Some(0.0));
+    }
+
+    #[test]
+    fn error_fails_task() {
+        let mut tasks = Tasks::default();
+
+        start(&mut tasks, "1");
+
+        let task = tasks.finish(&plugin_id(), "1", Some("boom".to_string())).unwrap();
+
+        assert!(matches!(task.outcome, TaskOutcome::Failed { error } if error == "boom"));
+    }
+
+    #[test]
+    fn cancelled_task_is_cancelled_even_if_it_errors() {
+        let mut tasks = Tasks::default();
+
+        start(&mut tasks, "1");
+
+        assert!(tasks.cancel(&plugin_id(), "1"));
+        assert!(matches!(tasks.running[0].outcome, TaskOutcome::Cancelling));
+
+        // second cancel doesn't send another abort signal
+        assert!(!tasks.cancel(&plugin_id(), "1"));
+
+        let task = tasks.finish(&plugin_id(), "1", Some("aborted".to_string())).unwrap();
+
+        assert!(matches!(task.outcome, TaskOutcome::Cancelled));
+    }
+
+    #[test]
+    fn unknown_task_is_ignored() {
+        let mut tasks = Tasks::default();
+
+        tasks.update(&plugin_id(), "1", Some(0.5), None);
+
+        assert!(!tasks.cancel(&plugin_id(), "1"));
+        assert!(tasks.finish(&plugin_id(), "1", None).is_none());
+        assert!(tasks.is_empty());
+
+        start(&mut tasks, "1");
+        tasks.finish(&plugin_id(), "1", None);
+
+        // already finished task can't be finished or cancelled again
+        assert!(!tasks.cancel(&plugin_id(), "1"));
+        assert!(tasks.finish(&plugin_id(), "1", None).is_none());
+        assert_eq!(finished_ids(&tasks), vec!["1"]);
+    }
+
+    #[test]
+    fn restarted_task_replaces_running_one() {
+        let mut tasks = Tasks::default();
+
+        start(&mut tasks, "1");
+        tasks.update(&plugin_id(), "1", Some(0.5), None);
+        start(&mut tasks, "1");
+
+        assert_eq!(tasks.running.len(), 1);
+        assert_eq!(tasks.running[0].progress, None);
+
+        // same task id of another plugin is a different task
+        tasks.start(
+            PluginId::from_string("bundled://other"),
+            "Other".to_string(),
+            "1".to_string(),
+            "Task 1".to_string(),
+        );
+
+        assert_eq!(tasks.running.len(), 2);
+    }
+
+    #[test]
+    fn finished_tasks_are_truncated_keeping_most_recent() {
+        let mut tasks = Tasks::default();
+
+        for index in 0..MAX_FINISHED_TASKS + 3 {
+            let task_id = index.to_string();
+            start(&mut tasks, &task_id);
+            tasks.finish(&plugin_id(), &task_id, None);
+        }
+
+        let expected: Vec<String> = (3..MAX_FINISHED_TASKS + 3)
+            .rev()
+            .map(|index| index.to_string())
+            .collect();
+
+        assert_eq!(finished_ids(&tasks), expected);
+
+        tasks.clear_finished();
+
+        assert!(!tasks.has_finished());
+        assert!(tasks.is_empty());
+    }
+
+    #[test]
+    fn last_cancellable_skips_cancelling_tasks() {
+        let mut tasks = Tasks::default();
+
+        assert!(tasks.last_cancellable().is_none());
+
+        start(&mut tasks, "1");
+        start(&mut tasks, "2");
+
+        assert_eq!(tasks.last_cancellable().unwrap().task_id, "2");
+
+        tasks.cancel(&plugin_id(), "2");
+
+        assert_eq!(tasks.last_cancellable().unwrap().task_id, "1");
+
+        tasks.cancel(&plugin_id(), "1");
+
+        assert!(tasks.last_cancellable().is_none());
+        assert!(tasks.is_running());
+    }
+}
@@ -18,7 +18,9 @@ pub mod grid;
 pub mod image;
 mod loading_bar;
 pub mod pick_list;
+pub mod progress_bar;
 pub mod row;
+pub mod row_height;
 pub mod rule;
 pub mod scrollable;
 pub mod space;
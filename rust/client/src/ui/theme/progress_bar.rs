@@ -0,0 +1,40 @@
+use iced::Border;
+use iced::widget::ProgressBar;
+use iced::widget::progress_bar;
+use iced::widget::progress_bar::Style;
+
+use crate::ui::theme::Element;
+use crate::ui::theme::GauntletComplexTheme;
+use crate::ui::theme::ThemableWidget;
+
+pub enum ProgressBarStyle {
+    Default,
+}
+
+impl progress_bar::Catalog for GauntletComplexTheme {
+    type Class<'a> = ProgressBarStyle;
+
+    fn default<'a>() -> Self::Class<'a> {
+        ProgressBarStyle::Default
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        match class {
+            ProgressBarStyle::Default => {
+                Style {
+                    background: self.loading_bar.background_color.into(),
+                    bar: self.loading_bar.loading_bar_color.into(),
+                    border: Border::default().rounded(2.0),
+                }
+            }
+        }
+    }
+}
+
+impl<'a, Message: 'a> ThemableWidget<'a, Message> for ProgressBar<'a, GauntletComplexTheme> {
+    type Kind = ProgressBarStyle;
+
+    fn themed(self, kind: ProgressBarStyle) -> Element<'a, Message> {
+        self.class(kind).into()
+    }
+}
@@ -0,0 +1,98 @@
+use iced::Pixels;
+use iced::widget::text::LineHeight;
+
+use crate::ui::theme::get_theme;
+
+// text in rows of list and grid is rendered with these sizes and line height,
+// so that the height of the row is known without rendering it
+pub const ITEM_TEXT_SIZE: f32 = 15.0;
+pub const SECTION_TITLE_TEXT_SIZE: f32 = 14.0;
+pub const ROW_LINE_HEIGHT: LineHeight = LineHeight::Relative(1.3);
+
+// heights of rows in list and grid views, only visible rows are rendered,
+// so these are used to calculate position of rows which are not rendered
+
+pub fn item_line_height() -> f32 {
+    line_height(ITEM_TEXT_SIZE)
+}
+
+pub fn section_title_line_height() -> f32 {
+    line_height(SECTION_TITLE_TEXT_SIZE)
+}
+
+pub fn list_inner_padding_top() -> f32 {
+    get_theme().list_inner.padding.to_iced().top
+}
+
+pub fn list_item_height() -> f32 {
+    let theme = get_theme();
+
+    let item_padding = theme.list_item.padding.to_iced();
+
+    // all elements of the row are one line high, row is as high as the element with the largest padding
+    let content_padding = [
+        theme.list_item_title.padding.to_iced().vertical(),
+        theme.list_item_subtitle.padding.to_iced().vertical(),
+        theme.list_item_icon.padding.to_iced().vertical(),
+        theme.text_accessory.padding.to_iced().vertical(),
+        theme.icon_accessory.padding.to_iced().vertical(),
+    ]
+    .into_iter()
+    .fold(0.0, f32::max);
+
+    item_padding.vertical() + content_padding + item_line_height()
+}
+
+pub fn list_section_title_height(first_section: bool) -> f32 {
+    let padding = get_theme().list_section_title.padding.to_iced();
+
+    // see RowStyle::ListFirstSectionTitle
+    let padding_top = if first_section { padding.bottom } else { padding.top };
+
+    padding_top + padding.bottom + section_title_line_height()
+}
+
+pub fn grid_inner_padding_top() -> f32 {
+    get_theme().grid_inner.padding.to_iced().top
+}
+
+pub fn grid_item_height(content_height: f32, text_lines: usize, accessory: bool) -> f32 {
+    let theme = get_theme();
+
+    let item_padding = theme.grid_item.padding.to_iced();
+    let title_padding = theme.grid_item_title.padding.to_iced();
+
+    let text_height = text_lines as f32 * item_line_height();
+
+    // accessory is placed next to title and subtitle
+    let title_height = if accessory {
+        let accessory_height = theme.icon_accessory.padding.to_iced().vertical() + item_line_height();
+
+        text_height.max(accessory_height)
+    } else {
+        text_height
+    };
+
+    item_padding.vertical() + content_height + title_padding.vertical() + title_height
+}
+
+pub fn grid_row_spacing() -> f32 {
+    get_theme().grid.spacing
+}
+
+pub fn grid_section_padding_bottom() -> f32 {
+    get_theme().grid_section.padding.to_iced().bottom
+}
+
+pub fn grid_section_title_height(first_section: bool) -> f32 {
+    let padding = get_theme().grid_section_title.padding.to_iced();
+
+    // see RowStyle::GridFirstSectionTitle
+    let padding_top = if first_section { 0.0 } else { padding.top };
+
+    padding_top + padding.bottom + section_title_line_height()
+}
+
+fn line_height(text_size: f32) -> f32 {
+    ROW_LINE_HEIGHT.to_absolute(Pixels(text_size)).0
+}
@@ -1,14 +1,20 @@
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::collections::hash_map::Entry;
 use std::mem;
 use std::sync::Arc;
 
+use anyhow::anyhow;
 use gauntlet_common::model::EntrypointId;
+use gauntlet_common::model::ImageLike;
 use gauntlet_common::model::PhysicalShortcut;
 use gauntlet_common::model::PluginId;
 use gauntlet_common::model::RootWidget;
+use gauntlet_common::model::SvgWidget;
 use gauntlet_common::model::UiRenderLocation;
 use gauntlet_common::model::UiWidgetId;
+use gauntlet_common::model::UiWidgetPatchOp;
+use gauntlet_common::model::WidgetVisitor;
 use iced::Task;
 use iced::widget::container;
 use indexmap::IndexMap;
@@ -75,9 +81,59 @@ impl PluginViewContainer {
         self.entrypoint_name = Some(entrypoint_name.to_string());
         self.data = data;
 
+        self.update_state(&container);
+
+        let first_open = match self.root_widget.as_ref() {
+            None => true,
+            Some(root_widget) => root_widget.content.is_none(),
+        };
+
+        self.root_widget = Some(container);
+
+        if first_open {
+            ComponentWidgets::new(&self.root_widget, &self.state, &self.data).first_open(self.plugin_id.clone())
+        } else {
+            AppMsg::Noop
+        }
+    }
+
+    pub fn patch_view(
+        &mut self,
+        patch: Vec<UiWidgetPatchOp>,
+        data: HashMap<UiWidgetId, Vec<u8>>,
+        plugin_name: &str,
+        entrypoint_name: &str,
+    ) -> anyhow::Result<()> {
+        tracing::trace!("patch_view is called. patch: {:?}", patch);
+
+        let Some(root_widget) = &mut self.root_widget else {
+            return Err(anyhow!("View was not rendered before it was patched"));
+        };
+
+        // tree is only copied if it is still shared with something else
+        Arc::make_mut(root_widget).apply_patch(patch)?;
+
+        self.plugin_name = Some(plugin_name.to_string());
+        self.entrypoint_name = Some(entrypoint_name.to_string());
+
+        // data is only sent for changed widgets, and data of removed widgets is dropped
+        self.data.extend(data);
+
+        let root_widget = self.root_widget.clone().expect("checked above");
+
+        let data_widget_ids = DataWidgetIds::collect(&root_widget);
+
+        self.data.retain(|widget_id, _| data_widget_ids.contains(widget_id));
+
+        self.update_state(&root_widget);
+
+        Ok(())
+    }
+
+    fn update_state(&mut self, root_widget: &RootWidget) {
         // use new state with values from old state but only widget ids which exists in new state
         // so this way we use already existing values but remove state for removed widgets
-        let old_state = mem::replace(&mut self.state, create_state(&container));
+        let old_state = mem::replace(&mut self.state, create_state(root_widget));
 
         for (key, value) in old_state.0.into_iter() {
             match self.state.0.entry(key) {
@@ -91,19 +147,6 @@ impl PluginViewContainer {
                 Entry::Vacant(_) => {}
             }
         }
-
-        let first_open = match self.root_widget.as_ref() {
-            None => true,
-            Some(root_widget) => root_widget.content.is_none(),
-        };
-
-        self.root_widget = Some(container);
-
-        if first_open {
-            ComponentWidgets::new(&self.root_widget, &self.state, &self.data).first_open(self.plugin_id.clone())
-        } else {
-            AppMsg::Noop
-        }
     }
 
     pub fn handle_event(&mut self, plugin_id: PluginId, event: ComponentWidgetEvent) -> Option<UiViewEvent> {
@@ -124,28 +167,59 @@ impl PluginViewContainer {
         )
     }
 
-    pub fn render_inline_root_widget<'a>(&self) -> Element<'a, ComponentWidgetEvent> {
-        ComponentWidgets::new(&self.root_widget, &self.state, &self.data)
-            .render_root_inline_widget(self.plugin_name.as_deref(), self.entrypoint_name.as_deref())
+    pub fn render_inline_root_widget<'a>(&self, position: Option<(usize, usize)>) -> Element<'a, ComponentWidgetEvent> {
+        ComponentWidgets::new(&self.root_widget, &self.state, &self.data).render_root_inline_widget(
+            self.plugin_name.as_deref(),
+            self.entrypoint_name.as_deref(),
+            position,
+        )
+    }
+
+    fn widgets_mut(&mut self) -> ComponentWidgetsMut<'_> {
+        ComponentWidgetsMut::new(
+            &mut self.root_widget,
+            &mut self.state,
+            &mut self.data,
+            &self.plugin_id,
+            &self.entrypoint_id,
+        )
     }
 
     pub fn append_text(&mut self, text: &str) -> Task<AppMsg> {
-        ComponentWidgetsMut::new(&mut self.root_widget, &mut self.state, &mut self.data, &self.plugin_id)
-            .append_text(text)
+        self.widgets_mut().append_text(text)
     }
 
     pub fn backspace_text(&mut self) -> Task<AppMsg> {
-        ComponentWidgetsMut::new(&mut self.root_widget, &mut self.state, &mut self.data, &self.plugin_id)
-            .backspace_text()
+        self.widgets_mut().backspace_text()
     }
 
     pub fn focus_search_bar(&self, widget_id: UiWidgetId) -> Task<AppMsg> {
         ComponentWidgets::new(&self.root_widget, &self.state, &self.data).focus_search_bar(widget_id)
     }
 
+    pub fn drop_file_event(&self, path: String) -> Option<AppMsg> {
+        ComponentWidgets::new(&self.root_widget, &self.state, &self.data).drop_file_event(
+            self.plugin_id.clone(),
+            self.entrypoint_id.clone(),
+            path,
+        )
+    }
+
+    pub fn load_more_event(&self) -> Task<AppMsg> {
+        ComponentWidgets::new(&self.root_widget, &self.state, &self.data)
+            .load_more_event(self.plugin_id.clone(), self.entrypoint_id.clone())
+    }
+
+    pub fn search_bar_dropdown_select_event(&self, forward: bool) -> Option<AppMsg> {
+        ComponentWidgets::new(&self.root_widget, &self.state, &self.data).search_bar_dropdown_select_event(
+            self.plugin_id.clone(),
+            self.entrypoint_id.clone(),
+            forward,
+        )
+    }
+
     pub fn toggle_action_panel(&mut self) {
-        ComponentWidgetsMut::new(&mut self.root_widget, &mut self.state, &mut self.data, &self.plugin_id)
-            .toggle_action_panel()
+        self.widgets_mut().toggle_action_panel()
     }
 
     pub fn get_action_widgets_with_ids(&self) -> IndexMap<container::Id, UiWidgetId> {
@@ -173,23 +247,48 @@ impl PluginViewContainer {
     }
 
     pub fn focus_up(&mut self) -> Task<AppMsg> {
-        ComponentWidgetsMut::new(&mut self.root_widget, &mut self.state, &mut self.data, &self.plugin_id).focus_up()
+        self.widgets_mut().focus_up()
     }
 
     pub fn focus_down(&mut self) -> Task<AppMsg> {
-        ComponentWidgetsMut::new(&mut self.root_widget, &mut self.state, &mut self.data, &self.plugin_id).focus_down()
+        self.widgets_mut().focus_down()
     }
 
     pub fn focus_left(&mut self) -> Task<AppMsg> {
-        ComponentWidgetsMut::new(&mut self.root_widget, &mut self.state, &mut self.data, &self.plugin_id).focus_left()
+        self.widgets_mut().focus_left()
     }
 
     pub fn focus_right(&mut self) -> Task<AppMsg> {
-        ComponentWidgetsMut::new(&mut self.root_widget, &mut self.state, &mut self.data, &self.plugin_id).focus_right()
+        self.widgets_mut().focus_right()
     }
 
     pub fn set_focused_item_id(&mut self, target_id: Option<container::Id>) -> Task<AppMsg> {
-        ComponentWidgetsMut::new(&mut self.root_widget, &mut self.state, &mut self.data, &self.plugin_id)
-            .set_focused_item_id(target_id)
+        self.widgets_mut().set_focused_item_id(target_id)
+    }
+}
+
+// same widgets which server gathers binary data for
+struct DataWidgetIds(HashSet<UiWidgetId>);
+
+impl DataWidgetIds {
+    fn collect(root_widget: &RootWidget) -> HashSet<UiWidgetId> {
+        let mut visitor = DataWidgetIds(HashSet::new());
+
+        // visitor is async only because of the server, nothing is awaited here
+        futures::executor::block_on(visitor.root_widget(root_widget));
+
+        visitor.0
+    }
+}
+
+impl WidgetVisitor for DataWidgetIds {
+    async fn image(&mut self, widget_id: UiWidgetId, widget: &ImageLike) {
+        if let ImageLike::DataSource(_) = widget {
+            self.0.insert(widget_id);
+        }
+    }
+
+    async fn svg_widget(&mut self, widget: &SvgWidget) {
+        self.0.insert(widget.__id__);
     }
 }
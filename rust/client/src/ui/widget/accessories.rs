@@ -16,6 +16,8 @@ use iced::widget::tooltip::Position;
 use crate::ui::theme::Element;
 use crate::ui::theme::ThemableWidget;
 use crate::ui::theme::container::ContainerStyle;
+use crate::ui::theme::row_height::ITEM_TEXT_SIZE;
+use crate::ui::theme::row_height::ROW_LINE_HEIGHT;
 use crate::ui::theme::text::TextStyle;
 use crate::ui::theme::tooltip::TooltipStyle;
 use crate::ui::widget::images::render_image;
@@ -55,7 +57,8 @@ pub fn render_text_accessory<'a, T: 'a + Clone>(
 
     let text_content: Element<_> = text(widget.text.to_string())
         .shaping(Shaping::Advanced)
-        .size(15)
+        .size(ITEM_TEXT_SIZE)
+        .line_height(ROW_LINE_HEIGHT)
         .themed(TextStyle::TextAccessory);
 
     let mut content: Vec<Element<_>> = vec![];
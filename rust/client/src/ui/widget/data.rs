@@ -3,6 +3,7 @@ use std::sync::Arc;
 
 use gauntlet_common::model::ActionPanelSectionWidgetOrderedMembers;
 use gauntlet_common::model::ActionPanelWidgetOrderedMembers;
+use gauntlet_common::model::EntrypointId;
 use gauntlet_common::model::GridSectionWidgetOrderedMembers;
 use gauntlet_common::model::GridWidget;
 use gauntlet_common::model::GridWidgetOrderedMembers;
@@ -23,11 +24,15 @@ use indexmap::IndexMap;
 use crate::ui::AppMsg;
 use crate::ui::scroll_handle::ScrollContent;
 use crate::ui::scroll_handle::ScrollHandle;
+use crate::ui::scroll_handle::scrollable_bounds;
 use crate::ui::widget::action_panel::ActionPanel;
 use crate::ui::widget::action_panel::convert_action_panel;
 use crate::ui::widget::data_mut::ComponentWidgetsMut;
 use crate::ui::widget::events::ComponentWidgetEvent;
+use crate::ui::widget::search_bar::SearchBarDropdownItem;
+use crate::ui::widget::search_bar::search_bar_dropdown_items;
 use crate::ui::widget::state::ComponentWidgetStateContainer;
+use crate::ui::widget::state::SelectState;
 use crate::ui::widget::state::TextFieldState;
 
 #[derive(Debug)]
@@ -152,9 +157,131 @@ impl<'b> ComponentWidgets<'b> {
 
         text_input::focus(text_input_id.clone())
     }
+
+    pub fn drop_file_event(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, path: String) -> Option<AppMsg> {
+        let root_widget = self.root_widget.as_ref()?;
+
+        let root_widget_id = match root_widget.content.as_ref()? {
+            RootWidgetMembers::Detail(widget) => widget.__id__,
+            RootWidgetMembers::Form(widget) => widget.__id__,
+            RootWidgetMembers::List(widget) => widget.__id__,
+            RootWidgetMembers::Grid(widget) => widget.__id__,
+            RootWidgetMembers::Inline(_) => return None,
+        };
+
+        Some(AppMsg::WidgetEvent {
+            plugin_id,
+            entrypoint_id,
+            widget_event: ComponentWidgetEvent::DropFile { root_widget_id, path },
+        })
+    }
+
+    // returns None if view doesn't have a dropdown
+    pub fn search_bar_dropdown_select_event(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        forward: bool,
+    ) -> Option<AppMsg> {
+        let root_widget = self.root_widget.as_ref()?;
+
+        let dropdown = match root_widget.content.as_ref()? {
+            RootWidgetMembers::List(widget) => widget.content.dropdown.as_ref()?,
+            RootWidgetMembers::Grid(widget) => widget.content.dropdown.as_ref()?,
+            _ => return None,
+        };
+
+        let values: Vec<_> = search_bar_dropdown_items(dropdown)
+            .into_iter()
+            .filter_map(|item| {
+                match item {
+                    SearchBarDropdownItem::Section { .. } => None,
+                    SearchBarDropdownItem::Item { value, .. } => Some(value),
+                }
+            })
+            .collect();
+
+        if values.is_empty() {
+            return Some(AppMsg::Noop);
+        }
+
+        let SelectState { state_value } = self.state.select_state(dropdown.__id__);
+
+        let current_value = dropdown.value.as_ref().or(state_value.as_ref());
+
+        let current_index = current_value
+            .map(|current_value| values.iter().position(|value| value == current_value))
+            .flatten();
+
+        let next_index = match (current_index, forward) {
+            (None, true) => 0,
+            (None, false) => values.len() - 1,
+            (Some(index), true) => (index + 1) % values.len(),
+            (Some(index), false) => (index + values.len() - 1) % values.len(),
+        };
+
+        Some(AppMsg::WidgetEvent {
+            plugin_id,
+            entrypoint_id,
+            widget_event: ComponentWidgetEvent::SelectSearchBarDropdown {
+                widget_id: dropdown.__id__,
+                value: values[next_index].clone(),
+            },
+        })
+    }
 }
 
 impl<'b> ComponentWidgets<'b> {
+    // plugin is asked to load more items on scroll, but content which fits into the viewport can't be scrolled,
+    // so the same check is done after every render using actual bounds of the scrollable
+    pub fn load_more_event(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Task<AppMsg> {
+        let Some(root_widget) = &self.root_widget else {
+            return Task::none();
+        };
+
+        let Some(content) = &root_widget.content else {
+            return Task::none();
+        };
+
+        match content {
+            RootWidgetMembers::List(widget) => {
+                let list_widget_id = widget.__id__;
+                let state = self.state.scrollable_root_state(list_widget_id);
+
+                scrollable_bounds(state.scroll_handle.scrollable_id.clone()).map(move |bounds| {
+                    AppMsg::WidgetEvent {
+                        plugin_id: plugin_id.clone(),
+                        entrypoint_id: entrypoint_id.clone(),
+                        widget_event: ComponentWidgetEvent::ScrollList {
+                            list_widget_id,
+                            offset_y: bounds.offset_y,
+                            viewport_height: bounds.viewport_height,
+                            content_height: bounds.content_height,
+                        },
+                    }
+                })
+            }
+            RootWidgetMembers::Grid(widget) => {
+                let grid_widget_id = widget.__id__;
+                let state = self.state.scrollable_root_state(grid_widget_id);
+
+                scrollable_bounds(state.scroll_handle.scrollable_id.clone()).map(move |bounds| {
+                    AppMsg::WidgetEvent {
+                        plugin_id: plugin_id.clone(),
+                        entrypoint_id: entrypoint_id.clone(),
+                        widget_event: ComponentWidgetEvent::ScrollGrid {
+                            grid_widget_id,
+                            offset_y: bounds.offset_y,
+                            viewport_height: bounds.viewport_height,
+                            content_height: bounds.content_height,
+                        },
+                    }
+                })
+            }
+            _ => Task::none(),
+        }
+    }
+
     pub fn first_open(&self, plugin_id: PluginId) -> AppMsg {
         let Some(root_widget) = &self.root_widget else {
             return AppMsg::Noop;
@@ -219,6 +346,7 @@ impl<'b> ComponentWidgets<'b> {
     pub fn list_item_focus_event(
         &self,
         plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
         scroll_handle: &ScrollHandle,
         target_item_id: Option<container::Id>,
         widget: &ListWidget,
@@ -240,6 +368,7 @@ impl<'b> ComponentWidgets<'b> {
 
         Task::done(AppMsg::WidgetEvent {
             plugin_id,
+            entrypoint_id,
             widget_event,
         })
     }
@@ -280,6 +409,7 @@ impl<'b> ComponentWidgets<'b> {
     pub fn grid_item_focus_event(
         &self,
         plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
         scroll_handle: &ScrollHandle,
         target_item_id: Option<container::Id>,
         widget: &GridWidget,
@@ -301,6 +431,7 @@ impl<'b> ComponentWidgets<'b> {
 
         Task::done(AppMsg::WidgetEvent {
             plugin_id,
+            entrypoint_id,
             widget_event,
         })
     }
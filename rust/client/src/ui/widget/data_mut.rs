@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::mem;
 use std::sync::Arc;
 
+use gauntlet_common::model::EntrypointId;
 use gauntlet_common::model::GridSectionWidgetOrderedMembers;
 use gauntlet_common::model::GridWidget;
 use gauntlet_common::model::GridWidgetOrderedMembers;
@@ -23,8 +24,13 @@ use itertools::Itertools;
 use crate::ui::AppMsg;
 use crate::ui::scroll_handle::ScrollHandle;
 use crate::ui::widget::data::ComponentWidgets;
+use crate::ui::widget::grid::GridRow;
+use crate::ui::widget::grid::grid_rows;
 use crate::ui::widget::grid::grid_width;
+use crate::ui::widget::list::ListRow;
+use crate::ui::widget::list::list_rows;
 use crate::ui::widget::state::ComponentWidgetStateContainer;
+use crate::ui::widget::virtualized::collect_item_bounds;
 
 #[derive(Debug)]
 pub struct ComponentWidgetsMut<'b> {
@@ -32,6 +38,7 @@ pub struct ComponentWidgetsMut<'b> {
     pub state: &'b mut ComponentWidgetStateContainer,
     pub data: &'b HashMap<UiWidgetId, Vec<u8>>,
     pub plugin_id: PluginId,
+    pub entrypoint_id: EntrypointId,
 }
 
 impl<'b> ComponentWidgetsMut<'b> {
@@ -40,12 +47,14 @@ impl<'b> ComponentWidgetsMut<'b> {
         state: &'b mut ComponentWidgetStateContainer,
         data: &'b mut HashMap<UiWidgetId, Vec<u8>>,
         plugin_id: &PluginId,
+        entrypoint_id: &EntrypointId,
     ) -> ComponentWidgetsMut<'b> {
         Self {
             root_widget,
             state,
             data,
             plugin_id: plugin_id.clone(),
+            entrypoint_id: entrypoint_id.clone(),
         }
     }
 }
@@ -182,6 +191,7 @@ impl<'b> ComponentWidgetsMut<'b> {
                 let state = self.state.scrollable_root_state(widget.__id__);
                 let focus_event = ComponentWidgets::from_mut(&self).list_item_focus_event(
                     self.plugin_id.clone(),
+                    self.entrypoint_id.clone(),
                     &state.scroll_handle,
                     next_item,
                     widget,
@@ -199,6 +209,7 @@ impl<'b> ComponentWidgetsMut<'b> {
                 let state = self.state.scrollable_root_state(widget.__id__);
                 let focus_event = ComponentWidgets::from_mut(&self).grid_item_focus_event(
                     self.plugin_id.clone(),
+                    self.entrypoint_id.clone(),
                     &state.scroll_handle,
                     next_item,
                     widget,
@@ -231,6 +242,7 @@ impl<'b> ComponentWidgetsMut<'b> {
                 let state = self.state.scrollable_root_state(widget.__id__);
                 let focus_event = ComponentWidgets::from_mut(&self).list_item_focus_event(
                     self.plugin_id.clone(),
+                    self.entrypoint_id.clone(),
                     &state.scroll_handle,
                     next_item,
                     widget,
@@ -253,6 +265,7 @@ impl<'b> ComponentWidgetsMut<'b> {
                 let state = self.state.scrollable_root_state(widget.__id__);
                 let focus_event = ComponentWidgets::from_mut(&self).grid_item_focus_event(
                     self.plugin_id.clone(),
+                    self.entrypoint_id.clone(),
                     &state.scroll_handle,
                     next_item,
                     widget,
@@ -288,6 +301,7 @@ impl<'b> ComponentWidgetsMut<'b> {
                 let state = self.state.scrollable_root_state(widget.__id__);
                 let focus_event = ComponentWidgets::from_mut(&self).grid_item_focus_event(
                     self.plugin_id.clone(),
+                    self.entrypoint_id.clone(),
                     &state.scroll_handle,
                     next_item,
                     widget,
@@ -322,6 +336,7 @@ impl<'b> ComponentWidgetsMut<'b> {
                 let state = self.state.scrollable_root_state(widget.__id__);
                 let focus_event = ComponentWidgets::from_mut(&self).grid_item_focus_event(
                     self.plugin_id.clone(),
+                    self.entrypoint_id.clone(),
                     &state.scroll_handle,
                     next_item,
                     widget,
@@ -502,23 +517,46 @@ impl<'b> ComponentWidgetsMut<'b> {
 
     fn grid_scroll_handle(&self, widget: &GridWidget) -> ScrollHandle {
         let state = self.state.scrollable_root_state(widget.__id__);
-        match &widget.focused_item_id {
+        let scroll_handle = match &widget.focused_item_id {
             JsOption::Undefined => state.scroll_handle.clone(),
             JsOption::Null => ScrollHandle::from(&state.scroll_handle, None),
             JsOption::Value(focused_item_id) => {
                 ScrollHandle::from(&state.scroll_handle, self.grid_id_for_id(widget, focused_item_id))
             }
-        }
+        };
+
+        let item_bounds = collect_item_bounds(&grid_rows(widget), |row| {
+            match row {
+                GridRow::SectionTitle { .. } => vec![],
+                GridRow::Items { items, .. } => {
+                    items
+                        .iter()
+                        .map(|widget| self.state.scrollable_item_state(widget.__id__).id.clone())
+                        .collect()
+                }
+            }
+        });
+
+        scroll_handle.with_item_bounds(item_bounds)
     }
 
     fn list_scroll_handle(&self, widget: &ListWidget) -> ScrollHandle {
         let state = self.state.scrollable_root_state(widget.__id__);
-        match &widget.focused_item_id {
+        let scroll_handle = match &widget.focused_item_id {
             JsOption::Undefined => state.scroll_handle.clone(),
             JsOption::Null => ScrollHandle::from(&state.scroll_handle, None),
             JsOption::Value(focused_item_id) => {
                 ScrollHandle::from(&state.scroll_handle, self.list_id_for_id(widget, focused_item_id))
             }
-        }
+        };
+
+        let item_bounds = collect_item_bounds(&list_rows(widget), |row| {
+            match row {
+                ListRow::SectionTitle { .. } => vec![],
+                ListRow::Item(widget) => vec![self.state.scrollable_item_state(widget.__id__).id.clone()],
+            }
+        });
+
+        scroll_handle.with_item_bounds(item_bounds)
     }
 }
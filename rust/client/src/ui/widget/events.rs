@@ -1,4 +1,5 @@
 use gauntlet_common::model::PluginId;
+use gauntlet_common::model::UiPropertyValue;
 use gauntlet_common::model::UiWidgetId;
 
 use crate::model::UiViewEvent;
@@ -47,6 +48,10 @@ pub enum ComponentWidgetEvent {
         widget_id: UiWidgetId,
         value: String,
     },
+    SelectSearchBarDropdown {
+        widget_id: UiWidgetId,
+        value: String,
+    },
     ToggleActionPanel {
         widget_id: UiWidgetId,
     },
@@ -58,6 +63,22 @@ pub enum ComponentWidgetEvent {
         grid_widget_id: UiWidgetId,
         item_id: Option<String>,
     },
+    ScrollList {
+        list_widget_id: UiWidgetId,
+        offset_y: f32,
+        viewport_height: f32,
+        content_height: f32,
+    },
+    ScrollGrid {
+        grid_widget_id: UiWidgetId,
+        offset_y: f32,
+        viewport_height: f32,
+        content_height: f32,
+    },
+    DropFile {
+        root_widget_id: UiWidgetId,
+        path: String,
+    },
     PreviousView,
     RunPrimaryAction {
         widget_id: UiWidgetId,
@@ -100,6 +121,19 @@ impl ComponentWidgetEvent {
 
                 Some(create_select_on_change_event(widget_id, value))
             }
+            ComponentWidgetEvent::SelectSearchBarDropdown { widget_id, value } => {
+                let Some(state) = state else {
+                    return None;
+                };
+
+                let ComponentWidgetState::Select(SelectState { state_value }) = state else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                };
+
+                *state_value = Some(value.clone());
+
+                Some(create_search_bar_dropdown_on_change_event(widget_id, value))
+            }
             ComponentWidgetEvent::OnChangeTextField { widget_id, value } => {
                 let Some(state) = state else {
                     return None;
@@ -152,6 +186,60 @@ impl ComponentWidgetEvent {
                 grid_widget_id,
                 item_id,
             } => Some(create_grid_on_item_focus_change_event(grid_widget_id, item_id)),
+            ComponentWidgetEvent::ScrollList {
+                list_widget_id,
+                offset_y,
+                viewport_height,
+                content_height,
+            } => {
+                let Some(state) = state else {
+                    return None;
+                };
+
+                let ComponentWidgetState::ScrollableRoot(state) = state else {
+                    panic!(
+                        "unexpected state kind, widget_id: {:?} state: {:?}",
+                        list_widget_id, state
+                    )
+                };
+
+                if state.scroll(offset_y, viewport_height, content_height) {
+                    Some(create_list_on_load_more_event(list_widget_id))
+                } else {
+                    None
+                }
+            }
+            ComponentWidgetEvent::ScrollGrid {
+                grid_widget_id,
+                offset_y,
+                viewport_height,
+                content_height,
+            } => {
+                let Some(state) = state else {
+                    return None;
+                };
+
+                let ComponentWidgetState::ScrollableRoot(state) = state else {
+                    panic!(
+                        "unexpected state kind, widget_id: {:?} state: {:?}",
+                        grid_widget_id, state
+                    )
+                };
+
+                if state.scroll(offset_y, viewport_height, content_height) {
+                    Some(create_grid_on_load_more_event(grid_widget_id))
+                } else {
+                    None
+                }
+            }
+            ComponentWidgetEvent::DropFile { root_widget_id, path } => {
+                // onDrop has the same signature on all root components
+                Some(UiViewEvent::View {
+                    widget_id: root_widget_id,
+                    event_name: "onDrop".to_owned(),
+                    event_arguments: vec![UiPropertyValue::String(path)],
+                })
+            }
             ComponentWidgetEvent::Noop | ComponentWidgetEvent::PreviousView => {
                 panic!("widget_id on these events is not supposed to be called")
             }
@@ -175,12 +263,16 @@ impl ComponentWidgetEvent {
             ComponentWidgetEvent::TagClick { widget_id, .. } => widget_id,
             ComponentWidgetEvent::ToggleCheckbox { widget_id, .. } => widget_id,
             ComponentWidgetEvent::SelectPickList { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::SelectSearchBarDropdown { widget_id, .. } => widget_id,
             ComponentWidgetEvent::OnChangeTextField { widget_id, .. } => widget_id,
             ComponentWidgetEvent::OnChangePasswordField { widget_id, .. } => widget_id,
             ComponentWidgetEvent::OnChangeSearchBar { widget_id, .. } => widget_id,
             ComponentWidgetEvent::ToggleActionPanel { widget_id } => widget_id,
             ComponentWidgetEvent::FocusListItem { list_widget_id, .. } => list_widget_id,
             ComponentWidgetEvent::FocusGridItem { grid_widget_id, .. } => grid_widget_id,
+            ComponentWidgetEvent::ScrollList { list_widget_id, .. } => list_widget_id,
+            ComponentWidgetEvent::ScrollGrid { grid_widget_id, .. } => grid_widget_id,
+            ComponentWidgetEvent::DropFile { root_widget_id, .. } => root_widget_id,
             ComponentWidgetEvent::RunPrimaryAction { widget_id, .. } => widget_id,
             ComponentWidgetEvent::Noop | ComponentWidgetEvent::PreviousView => {
                 panic!("widget_id on these events is not supposed to be called")
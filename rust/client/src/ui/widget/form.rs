@@ -184,6 +184,7 @@ impl<'b> ComponentWidgets<'b> {
             widget_id,
             None,
             &None,
+            &None,
             &widget.content.actions,
             content,
             widget.is_loading.unwrap_or(false),
@@ -1,5 +1,5 @@
-use std::cell::Cell;
 use std::collections::HashMap;
+use std::mem;
 
 use gauntlet_common::model::GridItemWidget;
 use gauntlet_common::model::GridSectionWidget;
@@ -26,10 +26,20 @@ use crate::ui::theme::button::ButtonStyle;
 use crate::ui::theme::container::ContainerStyle;
 use crate::ui::theme::grid::GridStyle;
 use crate::ui::theme::row::RowStyle;
+use crate::ui::theme::row_height::ITEM_TEXT_SIZE;
+use crate::ui::theme::row_height::ROW_LINE_HEIGHT;
+use crate::ui::theme::row_height::SECTION_TITLE_TEXT_SIZE;
+use crate::ui::theme::row_height::grid_inner_padding_top;
+use crate::ui::theme::row_height::grid_item_height;
+use crate::ui::theme::row_height::grid_row_spacing;
+use crate::ui::theme::row_height::grid_section_padding_bottom;
+use crate::ui::theme::row_height::grid_section_title_height;
+use crate::ui::theme::row_height::section_title_line_height;
 use crate::ui::theme::text::TextStyle;
 use crate::ui::widget::accessories::render_icon_accessory;
 use crate::ui::widget::data::ComponentWidgets;
 use crate::ui::widget::events::ComponentWidgetEvent;
+use crate::ui::widget::virtualized::VirtualizedRows;
 
 impl<'b> ComponentWidgets<'b> {
     pub fn render_grid_widget<'a>(
@@ -39,64 +49,39 @@ impl<'b> ComponentWidgets<'b> {
         entrypoint_name: &str,
         action_shortcuts: &HashMap<String, PhysicalShortcut>,
     ) -> Element<'a, ComponentWidgetEvent> {
-        let state = self.state.scrollable_root_state(grid_widget.__id__);
+        let widget_id = grid_widget.__id__;
+        let state = self.state.scrollable_root_state(widget_id);
         let focused_item_id = self.get_focused_item_id();
 
-        let content = if grid_widget.content.ordered_members.is_empty() {
+        let rows = grid_rows(grid_widget);
+
+        let content = if rows.rows().is_empty() {
             match &grid_widget.content.empty_view {
                 Some(widget) => self.render_empty_view_widget(widget),
                 None => horizontal_space().into(),
             }
         } else {
-            let mut pending: Vec<&GridItemWidget> = vec![];
-            let mut items: Vec<Element<_>> = vec![];
-            let index_counter = &Cell::new(0);
-            let mut first_section = true;
-
-            for members in &grid_widget.content.ordered_members {
-                match &members {
-                    GridWidgetOrderedMembers::GridItem(widget) => {
-                        first_section = false;
-                        pending.push(widget)
-                    }
-                    GridWidgetOrderedMembers::GridSection(widget) => {
-                        if !pending.is_empty() {
-                            let content = self.render_grid_section(
-                                &pending,
-                                &grid_widget.columns,
-                                &focused_item_id,
-                                index_counter,
-                            );
-
-                            items.push(content);
-
-                            pending = vec![];
-                        }
-
-                        items.push(self.render_grid_section_widget(
-                            widget,
-                            &focused_item_id,
-                            index_counter,
-                            first_section,
-                        ));
-
-                        first_section = false;
+            let content = rows.render(&state.scroll_handle.viewport, |row| {
+                match row {
+                    GridRow::SectionTitle { widget, first_section } => {
+                        self.render_grid_section_title(widget, *first_section)
                     }
+                    GridRow::Items { items, columns } => self.render_grid_row(items, *columns, &focused_item_id),
                 }
-            }
-
-            if !pending.is_empty() {
-                let content = self.render_grid_section(&pending, &grid_widget.columns, &focused_item_id, index_counter);
-
-                items.push(content);
-            }
-
-            let content: Element<_> = column(items).into();
+            });
 
             let content: Element<_> = container(content).width(Length::Fill).themed(ContainerStyle::GridInner);
 
             let content: Element<_> = scrollable(content)
                 .id(state.scroll_handle.scrollable_id.clone())
+                .on_scroll(move |viewport| {
+                    ComponentWidgetEvent::ScrollGrid {
+                        grid_widget_id: widget_id,
+                        offset_y: viewport.absolute_offset().y,
+                        viewport_height: viewport.bounds().height,
+                        content_height: viewport.content_bounds().height,
+                    }
+                })
                 .width(Length::Fill)
                 .into();
 
@@ -107,9 +92,10 @@ impl<'b> ComponentWidgets<'b> {
 
         self.render_plugin_root(
             state.show_action_panel,
-            grid_widget.__id__,
+            widget_id,
             focused_item_id,
             &grid_widget.content.search_bar,
+            &grid_widget.content.dropdown,
             &grid_widget.content.actions,
             content,
             grid_widget.is_loading.unwrap_or(false),
@@ -119,34 +105,18 @@ impl<'b> ComponentWidgets<'b> {
         )
     }
 
-    fn render_grid_section_widget<'a>(
+    fn render_grid_section_title<'a>(
         &self,
         widget: &GridSectionWidget,
-        item_focused_id: &Option<String>,
-        index_counter: &Cell<usize>,
         first_section: bool,
     ) -> Element<'a, ComponentWidgetEvent> {
-        let items: Vec<_> = widget
-            .content
-            .ordered_members
-            .iter()
-            .map(|members| {
-                match members {
-                    GridSectionWidgetOrderedMembers::GridItem(widget) => widget,
-                }
-            })
-            .collect();
-
-        let content = self.render_grid_section(&items, &widget.columns, item_focused_id, index_counter);
-
         let section_title_style = if first_section {
             RowStyle::GridFirstSectionTitle
         } else {
             RowStyle::GridSectionTitle
         };
 
-        render_section(
-            content,
+        render_section_title(
             Some(&widget.title),
             &widget.subtitle,
             section_title_style,
@@ -159,18 +129,9 @@ impl<'b> ComponentWidgets<'b> {
         &self,
         widget: &GridItemWidget,
         item_focused_id: &Option<String>,
-        index_counter: &Cell<usize>,
         grid_width: usize,
     ) -> Element<'a, ComponentWidgetEvent> {
-        let height = match grid_width {
-            ..4 => 130,
-            4 => 150,
-            5 => 130,
-            6 => 110,
-            7 => 90,
-            8 => 70,
-            8.. => 50,
-        };
+        let height = grid_item_content_height(grid_width);
 
         let content: Element<_> = container(self.render_content_widget(&widget.content.content, true))
             .height(height)
@@ -187,8 +148,6 @@ impl<'b> ComponentWidgets<'b> {
             }
         };
 
-        index_counter.set(index_counter.get() + 1);
-
         let action_ids = self.get_action_widgets();
         let primary_action = action_ids.first();
 
@@ -209,7 +168,8 @@ impl<'b> ComponentWidgets<'b> {
         if let Some(title) = &widget.title {
             // TODO text truncation when iced supports it
             let title = text(title.to_string())
-                .size(15)
+                .size(ITEM_TEXT_SIZE)
+                .line_height(ROW_LINE_HEIGHT)
                 .shaping(Shaping::Advanced)
                 .themed(TextStyle::GridItemTitle);
 
@@ -218,7 +178,8 @@ impl<'b> ComponentWidgets<'b> {
 
         if let Some(subtitle) = &widget.subtitle {
             let subtitle = text(subtitle.to_string())
-                .size(15)
+                .size(ITEM_TEXT_SIZE)
+                .line_height(ROW_LINE_HEIGHT)
                 .shaping(Shaping::Advanced)
                 .themed(TextStyle::GridItemSubTitle);
 
@@ -244,42 +205,124 @@ impl<'b> ComponentWidgets<'b> {
         content
     }
 
-    fn render_grid_section<'a>(
+    fn render_grid_row<'a>(
         &self,
         items: &[&GridItemWidget],
-        /*aspect_ratio: Option<&str>,*/
-        columns: &Option<f64>,
+        columns: usize,
         item_focused_id: &Option<String>,
-        index_counter: &Cell<usize>,
     ) -> Element<'a, ComponentWidgetEvent> {
-        // TODO
-        // let (width, height) = match aspect_ratio {
-        //     None => (1, 1),
-        //     Some("1") => (1, 1),
-        //     Some("3/2") => (3, 2),
-        //     Some("2/3") => (2, 3),
-        //     Some("4/3") => (4, 3),
-        //     Some("3/4") => (3, 4),
-        //     Some("16/9") => (16, 9),
-        //     Some("9/16") => (9, 16),
-        //     Some(value) => panic!("unsupported aspect_ratio {:?}", value)
-        // };
-
-        let columns = grid_width(columns);
-
-        let rows: Vec<Element<_>> = items
+        // TODO aspect ratio
+        let items: Vec<Element<_>> = items
             .iter()
-            .map(|widget| self.render_grid_item_widget(widget, item_focused_id, index_counter, columns))
-            .chunks(columns)
-            .into_iter()
-            .flat_map(|row_items| row_items)
+            .map(|widget| self.render_grid_item_widget(widget, item_focused_id, columns))
             .collect();
 
-        let grid = grid(rows).columns(columns).themed(GridStyle::Default);
+        grid(items).columns(columns).themed(GridStyle::Default)
+    }
+}
 
-        let grid = container(grid).themed(ContainerStyle::GridSection);
+pub enum GridRow<'a> {
+    SectionTitle {
+        widget: &'a GridSectionWidget,
+        first_section: bool,
+    },
+    Items {
+        items: Vec<&'a GridItemWidget>,
+        columns: usize,
+    },
+}
+
+pub fn grid_rows(grid_widget: &GridWidget) -> VirtualizedRows<GridRow<'_>> {
+    fn push_section<'a>(rows: &mut VirtualizedRows<GridRow<'a>>, items: Vec<&'a GridItemWidget>, columns: usize) {
+        let row_count = items.len().div_ceil(columns);
+
+        for (index, row_items) in items.into_iter().chunks(columns).into_iter().enumerate() {
+            let row_items: Vec<_> = row_items.collect();
+
+            let item_height = row_items
+                .iter()
+                .map(|widget| {
+                    let text_lines = widget.title.iter().count() + widget.subtitle.iter().count();
+                    let accessory = widget.content.accessory.is_some();
+
+                    grid_item_height(grid_item_content_height(columns), text_lines, accessory)
+                })
+                .fold(0.0, f32::max);
+
+            // spacing between rows and padding after the section are part of the row
+            let height = if index + 1 == row_count {
+                item_height + grid_section_padding_bottom()
+            } else {
+                item_height + grid_row_spacing()
+            };
+
+            rows.push(
+                height,
+                GridRow::Items {
+                    items: row_items,
+                    columns,
+                },
+            );
+        }
+    }
+
+    let global_columns = grid_width(&grid_widget.columns);
+
+    let mut rows = VirtualizedRows::new(grid_inner_padding_top());
+    let mut pending = vec![];
+    let mut first_section = true;
+
+    for members in &grid_widget.content.ordered_members {
+        match members {
+            GridWidgetOrderedMembers::GridItem(widget) => {
+                first_section = false;
+                pending.push(widget)
+            }
+            GridWidgetOrderedMembers::GridSection(widget) => {
+                if !pending.is_empty() {
+                    let pending = mem::replace(&mut pending, vec![]);
+                    push_section(&mut rows, pending, global_columns)
+                }
+
+                rows.push(
+                    grid_section_title_height(first_section),
+                    GridRow::SectionTitle { widget, first_section },
+                );
+
+                first_section = false;
 
-        grid
+                let items = widget
+                    .content
+                    .ordered_members
+                    .iter()
+                    .map(|members| {
+                        match members {
+                            GridSectionWidgetOrderedMembers::GridItem(widget) => widget,
+                        }
+                    })
+                    .collect();
+
+                push_section(&mut rows, items, grid_width(&widget.columns))
+            }
+        }
+    }
+
+    if !pending.is_empty() {
+        push_section(&mut rows, pending, global_columns)
+    }
+
+    rows
+}
+
+fn grid_item_content_height(grid_width: usize) -> f32 {
+    match grid_width {
+        ..4 => 130.0,
+        4 => 150.0,
+        5 => 130.0,
+        6 => 110.0,
+        7 => 90.0,
+        8 => 70.0,
+        8.. => 50.0,
     }
 }
 
@@ -287,8 +330,7 @@ pub fn grid_width(columns: &Option<f64>) -> usize {
     columns.map(|value| value.trunc() as usize).unwrap_or(5)
 }
 
-pub fn render_section<'a>(
-    content: Element<'a, ComponentWidgetEvent>,
+pub fn render_section_title<'a>(
     title: Option<&str>,
     subtitle: &Option<String>,
     theme_kind_title: RowStyle,
@@ -300,7 +342,8 @@ pub fn render_section<'a>(
     if let Some(title) = title {
         let title: Element<_> = text(title.to_string())
             .shaping(Shaping::Advanced)
-            .size(14)
+            .size(SECTION_TITLE_TEXT_SIZE)
+            .line_height(ROW_LINE_HEIGHT)
             .themed(theme_kind_title_text);
 
         title_content.push(title)
@@ -309,19 +352,19 @@ pub fn render_section<'a>(
     if let Some(subtitle) = subtitle {
         let subtitle: Element<_> = text(subtitle.to_string())
             .shaping(Shaping::Advanced)
-            .size(14)
+            .size(SECTION_TITLE_TEXT_SIZE)
+            .line_height(ROW_LINE_HEIGHT)
             .themed(theme_kind_subtitle_text);
 
         title_content.push(subtitle)
     }
 
     if title_content.is_empty() {
-        let space: Element<_> = horizontal_space().height(40).into();
+        // section without title and subtitle is still one line high, see row_height.rs
+        let space: Element<_> = horizontal_space().height(section_title_line_height()).into();
 
         title_content.push(space)
     }
 
-    let title_content = row(title_content).themed(theme_kind_title);
-
-    column([title_content, content]).into()
+    row(title_content).themed(theme_kind_title)
 }
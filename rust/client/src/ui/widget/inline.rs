@@ -7,6 +7,7 @@ use iced::advanced::text::Shaping;
 use iced::alignment::Horizontal;
 use iced::widget::column;
 use iced::widget::container;
+use iced::widget::horizontal_space;
 use iced::widget::row;
 use iced::widget::text;
 use iced::widget::vertical_rule;
@@ -44,12 +45,26 @@ impl<'b> ComponentWidgets<'b> {
         widget: &InlineWidget,
         plugin_name: &str,
         entrypoint_name: &str,
+        position: Option<(usize, usize)>,
     ) -> Element<'a, ComponentWidgetEvent> {
         let name: Element<_> = text(format!("{} - {}", plugin_name, entrypoint_name))
             .size(15)
             .shaping(Shaping::Advanced)
             .themed(TextStyle::InlineName);
 
+        // only shown when there are multiple inline views to switch between
+        let name: Element<_> = match position {
+            Some((index, total)) if total > 1 => {
+                let position: Element<_> = text(format!("{} of {}  Alt+←/→", index + 1, total))
+                    .size(15)
+                    .shaping(Shaping::Advanced)
+                    .themed(TextStyle::InlineName);
+
+                row(vec![name, horizontal_space().into(), position]).into()
+            }
+            _ => name,
+        };
+
         let name: Element<_> = container(name).themed(ContainerStyle::InlineName);
 
         let content: Vec<Element<_>> = widget
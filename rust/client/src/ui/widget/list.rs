@@ -1,6 +1,6 @@
-use std::cell::Cell;
 use std::collections::HashMap;
 
+use gauntlet_common::model::DetailWidget;
 use gauntlet_common::model::ListItemAccessories;
 use gauntlet_common::model::ListItemWidget;
 use gauntlet_common::model::ListSectionWidget;
@@ -12,7 +12,6 @@ use iced::Alignment;
 use iced::Length;
 use iced::advanced::text::Shaping;
 use iced::widget::button;
-use iced::widget::column;
 use iced::widget::container;
 use iced::widget::horizontal_space;
 use iced::widget::row;
@@ -26,13 +25,19 @@ use crate::ui::theme::ThemableWidget;
 use crate::ui::theme::button::ButtonStyle;
 use crate::ui::theme::container::ContainerStyle;
 use crate::ui::theme::row::RowStyle;
+use crate::ui::theme::row_height::ITEM_TEXT_SIZE;
+use crate::ui::theme::row_height::ROW_LINE_HEIGHT;
+use crate::ui::theme::row_height::list_inner_padding_top;
+use crate::ui::theme::row_height::list_item_height;
+use crate::ui::theme::row_height::list_section_title_height;
 use crate::ui::theme::text::TextStyle;
 use crate::ui::widget::accessories::render_icon_accessory;
 use crate::ui::widget::accessories::render_text_accessory;
 use crate::ui::widget::data::ComponentWidgets;
 use crate::ui::widget::events::ComponentWidgetEvent;
-use crate::ui::widget::grid::render_section;
+use crate::ui::widget::grid::render_section_title;
 use crate::ui::widget::images::render_image;
+use crate::ui::widget::virtualized::VirtualizedRows;
 
 impl<'b> ComponentWidgets<'b> {
     pub fn render_list_widget<'a>(
@@ -46,61 +51,35 @@ impl<'b> ComponentWidgets<'b> {
         let state = self.state.scrollable_root_state(widget_id);
         let focused_item_id = self.get_focused_item_id();
 
-        let mut pending: Vec<&ListItemWidget> = vec![];
-        let mut items: Vec<Element<_>> = vec![];
-        let index_counter = &Cell::new(0);
-        let mut first_section = true;
+        let rows = list_rows(list_widget);
 
-        for members in &list_widget.content.ordered_members {
-            match &members {
-                ListWidgetOrderedMembers::ListItem(widget) => {
-                    first_section = false;
-                    pending.push(widget)
-                }
-                ListWidgetOrderedMembers::ListSection(widget) => {
-                    if !pending.is_empty() {
-                        let content: Vec<_> = pending
-                            .iter()
-                            .map(|widget| self.render_list_item_widget(widget, &focused_item_id, index_counter))
-                            .collect();
-
-                        let content: Element<_> = column(content).into();
-
-                        items.push(content);
-
-                        pending = vec![];
-                    }
-
-                    items.push(self.render_list_section_widget(widget, &focused_item_id, index_counter, first_section));
-
-                    first_section = false;
-                }
-            }
-        }
-
-        if !pending.is_empty() {
-            let content: Vec<_> = pending
-                .iter()
-                .map(|widget| self.render_list_item_widget(widget, &focused_item_id, index_counter))
-                .collect();
-
-            let content: Element<_> = column(content).into();
-
-            items.push(content);
-        }
-
-        let content = if items.is_empty() {
+        let content = if rows.rows().is_empty() {
             match &list_widget.content.empty_view {
                 Some(widget) => self.render_empty_view_widget(widget),
                 None => horizontal_space().into(),
             }
         } else {
-            let content: Element<_> = column(items).width(Length::Fill).into();
+            let content = rows.render(&state.scroll_handle.viewport, |row| {
+                match row {
+                    ListRow::SectionTitle { widget, first_section } => {
+                        self.render_list_section_title(widget, *first_section)
+                    }
+                    ListRow::Item(widget) => self.render_list_item_widget(widget, &focused_item_id),
+                }
+            });
 
             let content: Element<_> = container(content).width(Length::Fill).themed(ContainerStyle::ListInner);
 
             let content: Element<_> = scrollable(content)
                 .id(state.scroll_handle.scrollable_id.clone())
+                .on_scroll(move |viewport| {
+                    ComponentWidgetEvent::ScrollList {
+                        list_widget_id: widget_id,
+                        offset_y: viewport.absolute_offset().y,
+                        viewport_height: viewport.bounds().height,
+                        content_height: viewport.content_bounds().height,
+                    }
+                })
                 .width(Length::Fill)
                 .into();
 
@@ -113,7 +92,12 @@ impl<'b> ComponentWidgets<'b> {
 
         let mut elements = vec![content];
 
-        if let Some(detail) = &list_widget.content.detail {
+        let detail = match list_widget.is_showing_detail {
+            Some(false) => None,
+            _ => list_item_detail(&rows, &focused_item_id).or(list_widget.content.detail.as_ref()),
+        };
+
+        if let Some(detail) = detail {
             let detail = self.render_detail_widget(detail, true);
 
             let detail: Element<_> = container(detail).width(Length::FillPortion(5)).into();
@@ -132,6 +116,7 @@ impl<'b> ComponentWidgets<'b> {
             widget_id,
             focused_item_id,
             &list_widget.content.search_bar,
+            &list_widget.content.dropdown,
             &list_widget.content.actions,
             content,
             list_widget.is_loading.unwrap_or(false),
@@ -141,36 +126,18 @@ impl<'b> ComponentWidgets<'b> {
         )
     }
 
-    fn render_list_section_widget<'a>(
+    fn render_list_section_title<'a>(
         &self,
         widget: &ListSectionWidget,
-        item_focused_id: &Option<String>,
-        index_counter: &Cell<usize>,
         first_section: bool,
     ) -> Element<'a, ComponentWidgetEvent> {
-        let content: Vec<_> = widget
-            .content
-            .ordered_members
-            .iter()
-            .map(|members| {
-                match members {
-                    ListSectionWidgetOrderedMembers::ListItem(widget) => {
-                        self.render_list_item_widget(widget, item_focused_id, index_counter)
-                    }
-                }
-            })
-            .collect();
-
-        let content = column(content).into();
-
         let section_title_style = if first_section {
             RowStyle::ListFirstSectionTitle
         } else {
             RowStyle::ListSectionTitle
         };
 
-        render_section(
-            content,
+        render_section_title(
             Some(&widget.title),
             &widget.subtitle,
             section_title_style,
@@ -183,7 +150,6 @@ impl<'b> ComponentWidgets<'b> {
         &self,
         widget: &ListItemWidget,
         item_focused_id: &Option<String>,
-        index_counter: &Cell<usize>,
     ) -> Element<'a, ComponentWidgetEvent> {
         let icon: Option<Element<_>> = widget
             .icon
@@ -192,7 +158,8 @@ impl<'b> ComponentWidgets<'b> {
 
         let title: Element<_> = text(widget.title.to_string())
             .shaping(Shaping::Advanced)
-            .size(15)
+            .size(ITEM_TEXT_SIZE)
+            .line_height(ROW_LINE_HEIGHT)
             .into();
         let title: Element<_> = container(title).themed(ContainerStyle::ListItemTitle);
 
@@ -206,7 +173,8 @@ impl<'b> ComponentWidgets<'b> {
 
         if let Some(subtitle) = &widget.subtitle {
             let subtitle: Element<_> = text(subtitle.to_string())
-                .size(15)
+                .size(ITEM_TEXT_SIZE)
+                .line_height(ROW_LINE_HEIGHT)
                 .shaping(Shaping::Advanced)
                 .themed(TextStyle::ListItemSubtitle);
             let subtitle: Element<_> = container(subtitle).themed(ContainerStyle::ListItemSubtitle);
@@ -248,8 +216,6 @@ impl<'b> ComponentWidgets<'b> {
             }
         };
 
-        index_counter.set(index_counter.get() + 1);
-
         let action_ids = self.get_action_widgets();
         let primary_action = action_ids.first();
 
@@ -263,10 +229,75 @@ impl<'b> ComponentWidgets<'b> {
             }
         };
 
-        let element = button(content).on_press(on_press_msg).width(Length::Fill).themed(style);
+        let element = button(content)
+            .on_press(on_press_msg)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .themed(style);
 
         let state = self.state.scrollable_item_state(widget.__id__);
 
         container(element).id(state.id.clone()).into()
     }
 }
+
+pub enum ListRow<'a> {
+    SectionTitle {
+        widget: &'a ListSectionWidget,
+        first_section: bool,
+    },
+    Item(&'a ListItemWidget),
+}
+
+// detail of focused item is looked up on every render,
+// so it is swapped when focus changes without plugin having to re-render
+fn list_item_detail<'a>(
+    rows: &VirtualizedRows<ListRow<'a>>,
+    focused_item_id: &Option<String>,
+) -> Option<&'a DetailWidget> {
+    let focused_item_id = focused_item_id.as_ref()?;
+
+    rows.rows()
+        .iter()
+        .find_map(|row| {
+            match row.content {
+                ListRow::Item(widget) if &widget.id == focused_item_id => Some(widget),
+                _ => None,
+            }
+        })
+        .and_then(|widget| widget.content.detail.as_ref())
+}
+
+pub fn list_rows(list_widget: &ListWidget) -> VirtualizedRows<ListRow<'_>> {
+    let item_height = list_item_height();
+
+    let mut rows = VirtualizedRows::new(list_inner_padding_top());
+    let mut first_section = true;
+
+    for members in &list_widget.content.ordered_members {
+        match members {
+            ListWidgetOrderedMembers::ListItem(widget) => {
+                first_section = false;
+                rows.push(item_height, ListRow::Item(widget))
+            }
+            ListWidgetOrderedMembers::ListSection(widget) => {
+                rows.push(
+                    list_section_title_height(first_section),
+                    ListRow::SectionTitle { widget, first_section },
+                );
+
+                first_section = false;
+
+                for members in &widget.content.ordered_members {
+                    match members {
+                        ListSectionWidgetOrderedMembers::ListItem(widget) => {
+                            rows.push(item_height, ListRow::Item(widget))
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    rows
+}
@@ -16,3 +16,4 @@ pub mod root;
 mod search_bar;
 pub mod state;
 mod text;
+mod virtualized;
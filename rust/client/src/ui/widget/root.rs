@@ -4,6 +4,7 @@ use gauntlet_common::model::ActionPanelWidget;
 use gauntlet_common::model::PhysicalKey;
 use gauntlet_common::model::PhysicalShortcut;
 use gauntlet_common::model::RootWidgetMembers;
+use gauntlet_common::model::SearchBarDropdownWidget;
 use gauntlet_common::model::SearchBarWidget;
 use gauntlet_common::model::UiWidgetId;
 use iced::Alignment;
@@ -67,6 +68,7 @@ impl<'b> ComponentWidgets<'b> {
                                     widget.__id__,
                                     None,
                                     &None,
+                                    &None,
                                     &widget.content.actions,
                                     content,
                                     widget.is_loading.unwrap_or(false),
@@ -98,6 +100,7 @@ impl<'b> ComponentWidgets<'b> {
         &self,
         plugin_name: Option<&str>,
         entrypoint_name: Option<&str>,
+        position: Option<(usize, usize)>,
     ) -> Element<'a, ComponentWidgetEvent> {
         match &self.root_widget {
             None => horizontal_space().into(),
@@ -110,7 +113,7 @@ impl<'b> ComponentWidgets<'b> {
                                 let entrypoint_name = entrypoint_name.unwrap_or_default();
                                 let plugin_name = plugin_name.unwrap_or_default();
 
-                                self.render_inline_widget(widget, plugin_name, entrypoint_name)
+                                self.render_inline_widget(widget, plugin_name, entrypoint_name, position)
                             }
                             _ => {
                                 panic!("used non-inline widget in inline place")
@@ -122,7 +125,11 @@ impl<'b> ComponentWidgets<'b> {
         }
     }
 
-    fn render_top_panel<'a>(&self, search_bar: &Option<SearchBarWidget>) -> Element<'a, ComponentWidgetEvent> {
+    fn render_top_panel<'a>(
+        &self,
+        search_bar: &Option<SearchBarWidget>,
+        search_bar_dropdown: &Option<SearchBarDropdownWidget>,
+    ) -> Element<'a, ComponentWidgetEvent> {
         let icon = arrow_left();
 
         let back_button: Element<_> = button(icon)
@@ -134,9 +141,13 @@ impl<'b> ComponentWidgets<'b> {
             .map(|widget| self.render_search_bar_widget(widget))
             .unwrap_or_else(|| Space::with_width(Length::FillPortion(3)).into());
 
-        let top_panel: Element<_> = row(vec![back_button, search_bar_element])
-            .align_y(Alignment::Center)
-            .themed(RowStyle::RootTopPanel);
+        let mut elements = vec![back_button, search_bar_element];
+
+        if let Some(widget) = search_bar_dropdown {
+            elements.push(self.render_search_bar_dropdown_widget(widget));
+        }
+
+        let top_panel: Element<_> = row(elements).align_y(Alignment::Center).themed(RowStyle::RootTopPanel);
 
         let top_panel: Element<_> = container(top_panel)
             .width(Length::Fill)
@@ -151,6 +162,7 @@ impl<'b> ComponentWidgets<'b> {
         root_widget_id: UiWidgetId,
         focused_item_id: Option<String>,
         search_bar: &Option<SearchBarWidget>,
+        search_bar_dropdown: &Option<SearchBarDropdownWidget>,
         action_panel: &Option<ActionPanelWidget>,
         content: Element<'a, ComponentWidgetEvent>,
         is_loading: bool,
@@ -158,7 +170,7 @@ impl<'b> ComponentWidgets<'b> {
         entrypoint_name: &str,
         action_shortcuts: &HashMap<String, PhysicalShortcut>,
     ) -> Element<'a, ComponentWidgetEvent> {
-        let top_panel = self.render_top_panel(search_bar);
+        let top_panel = self.render_top_panel(search_bar, search_bar_dropdown);
 
         let top_separator = if is_loading {
             LoadingBar::new().into()
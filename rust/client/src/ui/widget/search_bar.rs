@@ -1,11 +1,19 @@
+use std::fmt::Display;
+
+use gauntlet_common::model::SearchBarDropdownSectionWidgetOrderedMembers;
+use gauntlet_common::model::SearchBarDropdownWidget;
+use gauntlet_common::model::SearchBarDropdownWidgetOrderedMembers;
 use gauntlet_common::model::SearchBarWidget;
+use iced::widget::pick_list;
 use iced::widget::text_input;
 
 use crate::ui::theme::Element;
 use crate::ui::theme::ThemableWidget;
+use crate::ui::theme::pick_list::PickListStyle;
 use crate::ui::theme::text_input::TextInputStyle;
 use crate::ui::widget::data::ComponentWidgets;
 use crate::ui::widget::events::ComponentWidgetEvent;
+use crate::ui::widget::state::SelectState;
 use crate::ui::widget::state::TextFieldState;
 
 impl<'b> ComponentWidgets<'b> {
@@ -22,4 +30,87 @@ impl<'b> ComponentWidgets<'b> {
             .on_input(move |value| ComponentWidgetEvent::OnChangeSearchBar { widget_id, value })
             .themed(TextInputStyle::PluginSearchBar)
     }
+
+    pub fn render_search_bar_dropdown_widget<'a>(
+        &self,
+        widget: &SearchBarDropdownWidget,
+    ) -> Element<'a, ComponentWidgetEvent> {
+        let widget_id = widget.__id__;
+        let SelectState { state_value } = self.state.select_state(widget_id);
+
+        let items = search_bar_dropdown_items(widget);
+
+        // controlled dropdown, value is owned by plugin
+        let selected_value = widget.value.as_ref().or(state_value.as_ref());
+
+        let selected_item = selected_value
+            .map(|value| {
+                items.iter().find(
+                    |item| matches!(item, SearchBarDropdownItem::Item { value: item_value, .. } if item_value == value),
+                )
+            })
+            .flatten()
+            .cloned();
+
+        pick_list(items, selected_item, move |item| {
+            match item {
+                SearchBarDropdownItem::Section { .. } => ComponentWidgetEvent::Noop,
+                SearchBarDropdownItem::Item { value, .. } => {
+                    ComponentWidgetEvent::SelectSearchBarDropdown { widget_id, value }
+                }
+            }
+        })
+        .placeholder(widget.placeholder.as_deref().unwrap_or_default())
+        .themed(PickListStyle::Default)
+    }
+}
+
+// iced pick list doesn't support sections,
+// so section titles are shown as items which cannot be selected
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SearchBarDropdownItem {
+    Section { title: String },
+    Item { value: String, title: String },
+}
+
+impl Display for SearchBarDropdownItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SearchBarDropdownItem::Section { title } => write!(f, "{}", title.to_uppercase()),
+            SearchBarDropdownItem::Item { title, .. } => write!(f, "{}", title),
+        }
+    }
+}
+
+pub fn search_bar_dropdown_items(widget: &SearchBarDropdownWidget) -> Vec<SearchBarDropdownItem> {
+    let mut items = vec![];
+
+    for members in &widget.content.ordered_members {
+        match members {
+            SearchBarDropdownWidgetOrderedMembers::SearchBarDropdownItem(widget) => {
+                items.push(SearchBarDropdownItem::Item {
+                    value: widget.value.clone(),
+                    title: widget.title.clone(),
+                })
+            }
+            SearchBarDropdownWidgetOrderedMembers::SearchBarDropdownSection(widget) => {
+                if let Some(title) = &widget.title {
+                    items.push(SearchBarDropdownItem::Section { title: title.clone() })
+                }
+
+                for members in &widget.content.ordered_members {
+                    match members {
+                        SearchBarDropdownSectionWidgetOrderedMembers::SearchBarDropdownItem(widget) => {
+                            items.push(SearchBarDropdownItem::Item {
+                                value: widget.value.clone(),
+                                title: widget.title.clone(),
+                            })
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    items
 }
@@ -12,6 +12,8 @@ use iced::widget::container;
 use iced::widget::text_input;
 
 use crate::ui::scroll_handle::ScrollHandle;
+use crate::ui::scroll_handle::ScrollViewport;
+use crate::ui::widget::virtualized::LOAD_MORE_THRESHOLD;
 
 pub fn create_state(root_widget: &RootWidget) -> ComponentWidgetStateContainer {
     let mut result = HashMap::new();
@@ -49,6 +51,11 @@ pub fn create_state(root_widget: &RootWidget) -> ComponentWidgetStateContainer {
                         result.insert(widget.__id__, ComponentWidgetState::text_field(&widget.value));
                     }
 
+                    if let Some(widget) = &widget.content.dropdown {
+                        let value = widget.value.clone().or(widget.default_value.clone());
+                        result.insert(widget.__id__, ComponentWidgetState::select(&value));
+                    }
+
                     for members in &widget.content.ordered_members {
                         match members {
                             ListWidgetOrderedMembers::ListItem(widget) => {
@@ -73,6 +80,11 @@ pub fn create_state(root_widget: &RootWidget) -> ComponentWidgetStateContainer {
                         result.insert(widget.__id__, ComponentWidgetState::text_field(&widget.value));
                     }
 
+                    if let Some(widget) = &widget.content.dropdown {
+                        let value = widget.value.clone().or(widget.default_value.clone());
+                        result.insert(widget.__id__, ComponentWidgetState::select(&value));
+                    }
+
                     for members in &widget.content.ordered_members {
                         match members {
                             GridWidgetOrderedMembers::GridItem(widget) => {
@@ -135,6 +147,28 @@ pub struct RootState {
 pub struct ScrollableRootState {
     pub show_action_panel: bool,
     pub scroll_handle: ScrollHandle,
+    // height of the content when plugin was last asked to load more items,
+    // so that it is asked again only after new items are added
+    pub load_more_content_height: Option<f32>,
+}
+
+impl ScrollableRootState {
+    // returns true if plugin should be asked to load more items
+    pub fn scroll(&mut self, offset_y: f32, viewport_height: f32, content_height: f32) -> bool {
+        self.scroll_handle.set_viewport(ScrollViewport {
+            offset_y,
+            height: viewport_height,
+        });
+
+        let near_end = offset_y + viewport_height >= content_height - LOAD_MORE_THRESHOLD;
+
+        if near_end && self.load_more_content_height != Some(content_height) {
+            self.load_more_content_height = Some(content_height);
+            true
+        } else {
+            false
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -153,6 +187,7 @@ impl ComponentWidgetState {
         ComponentWidgetState::ScrollableRoot(ScrollableRootState {
             show_action_panel: false,
             scroll_handle: ScrollHandle::new(None),
+            load_more_content_height: None,
         })
     }
 
@@ -309,3 +344,56 @@ impl ComponentWidgetStateContainer {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state() -> ScrollableRootState {
+        ScrollableRootState {
+            show_action_panel: false,
+            scroll_handle: ScrollHandle::new(None),
+            load_more_content_height: None,
+        }
+    }
+
+    #[test]
+    fn scroll_updates_viewport() {
+        let mut state = state();
+
+        state.scroll(300.0, 500.0, 5000.0);
+
+        assert_eq!(state.scroll_handle.viewport.offset_y, 300.0);
+        assert_eq!(state.scroll_handle.viewport.height, 500.0);
+    }
+
+    #[test]
+    fn load_more_is_not_requested_far_from_end() {
+        let mut state = state();
+
+        assert!(!state.scroll(0.0, 500.0, 5000.0));
+        assert!(!state.scroll(4000.0, 500.0, 5000.0));
+    }
+
+    #[test]
+    fn load_more_is_requested_once_per_content_height() {
+        let mut state = state();
+
+        assert!(state.scroll(4200.0, 500.0, 5000.0));
+        assert!(!state.scroll(4300.0, 500.0, 5000.0));
+        assert!(!state.scroll(4500.0, 500.0, 5000.0));
+
+        // plugin added more items
+        assert!(!state.scroll(4500.0, 500.0, 10000.0));
+        assert!(state.scroll(9500.0, 500.0, 10000.0));
+        assert!(!state.scroll(9400.0, 500.0, 10000.0));
+    }
+
+    #[test]
+    fn load_more_is_requested_when_content_fits_into_viewport() {
+        let mut state = state();
+
+        assert!(state.scroll(0.0, 500.0, 300.0));
+        assert!(!state.scroll(0.0, 500.0, 300.0));
+    }
+}
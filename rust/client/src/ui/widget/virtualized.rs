@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use iced::Length;
+use iced::widget::Space;
+use iced::widget::column;
+use iced::widget::container;
+
+use crate::ui::scroll_handle::ItemBounds;
+use crate::ui::scroll_handle::ScrollViewport;
+use crate::ui::theme::Element;
+use crate::ui::widget::events::ComponentWidgetEvent;
+
+// rows above and below the viewport are rendered as well,
+// so that rows do not appear with delay when scrolling fast
+const BUFFER_HEIGHT: f32 = 400.0;
+
+// how close to the end of content the viewport needs to be for plugin to be asked to load more items
+pub const LOAD_MORE_THRESHOLD: f32 = 400.0;
+
+pub struct VirtualizedRow<T> {
+    pub top: f32,
+    pub height: f32,
+    pub content: T,
+}
+
+// all rows have fixed height, so that only rows inside the viewport have to be rendered,
+// while the rest is replaced with empty space of the same height
+pub struct VirtualizedRows<T> {
+    rows: Vec<VirtualizedRow<T>>,
+    padding_top: f32,
+    bottom: f32,
+}
+
+impl<T> VirtualizedRows<T> {
+    pub fn new(padding_top: f32) -> Self {
+        Self {
+            rows: vec![],
+            padding_top,
+            bottom: padding_top,
+        }
+    }
+
+    pub fn push(&mut self, height: f32, content: T) {
+        self.rows.push(VirtualizedRow {
+            top: self.bottom,
+            height,
+            content,
+        });
+
+        self.bottom += height;
+    }
+
+    pub fn rows(&self) -> &[VirtualizedRow<T>] {
+        &self.rows
+    }
+
+    pub fn render<'a>(
+        &self,
+        viewport: &ScrollViewport,
+        render_row: impl Fn(&T) -> Element<'a, ComponentWidgetEvent>,
+    ) -> Element<'a, ComponentWidgetEvent> {
+        let visible = self.visible_rows(viewport);
+
+        let mut elements: Vec<Element<_>> = vec![];
+
+        elements.push(Space::new(Length::Fill, visible.space_before).into());
+
+        for row in &self.rows[visible.range] {
+            let content: Element<_> = container(render_row(&row.content))
+                .width(Length::Fill)
+                .height(row.height)
+                .clip(true)
+                .into();
+
+            elements.push(content);
+        }
+
+        elements.push(Space::new(Length::Fill, visible.space_after).into());
+
+        column(elements).width(Length::Fill).into()
+    }
+
+    fn visible_rows(&self, viewport: &ScrollViewport) -> VisibleRows {
+        let visible_top = viewport.offset_y - BUFFER_HEIGHT;
+        let visible_bottom = viewport.offset_y + viewport.height + BUFFER_HEIGHT;
+
+        // rows are sorted by position
+        let start = self.rows.partition_point(|row| row.top + row.height < visible_top);
+        let end = self.rows.partition_point(|row| row.top < visible_bottom).max(start);
+
+        let space_before = match self.rows.get(start) {
+            Some(row) => row.top - self.padding_top,
+            None => self.bottom - self.padding_top,
+        };
+
+        let space_after = match self.rows.get(end) {
+            Some(row) => self.bottom - row.top,
+            None => 0.0,
+        };
+
+        VisibleRows {
+            range: start..end,
+            space_before,
+            space_after,
+        }
+    }
+}
+
+struct VisibleRows {
+    range: Range<usize>,
+    space_before: f32,
+    space_after: f32,
+}
+
+pub fn collect_item_bounds<T>(
+    rows: &VirtualizedRows<T>,
+    item_ids: impl Fn(&T) -> Vec<container::Id>,
+) -> HashMap<container::Id, ItemBounds> {
+    rows.rows()
+        .iter()
+        .flat_map(|row| {
+            item_ids(&row.content).into_iter().map(|id| {
+                let bounds = ItemBounds {
+                    top: row.top,
+                    height: row.height,
+                };
+
+                (id, bounds)
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PADDING_TOP: f32 = 10.0;
+    const ROW_HEIGHT: f32 = 50.0;
+
+    // 100 rows of 50px, so content is 5000px high without padding
+    fn rows() -> VirtualizedRows<usize> {
+        let mut rows = VirtualizedRows::new(PADDING_TOP);
+
+        for index in 0..100 {
+            rows.push(ROW_HEIGHT, index);
+        }
+
+        rows
+    }
+
+    fn viewport(offset_y: f32, height: f32) -> ScrollViewport {
+        ScrollViewport { offset_y, height }
+    }
+
+    fn assert_fills_content(rows: &VirtualizedRows<usize>, visible: &VisibleRows) {
+        let rendered: f32 = rows.rows()[visible.range.clone()].iter().map(|row| row.height).sum();
+
+        assert_eq!(
+            visible.space_before + rendered + visible.space_after,
+            rows.rows().len() as f32 * ROW_HEIGHT
+        );
+    }
+
+    #[test]
+    fn rows_are_positioned_after_padding() {
+        let rows = rows();
+
+        assert_eq!(rows.rows()[0].top, PADDING_TOP);
+        assert_eq!(rows.rows()[3].top, PADDING_TOP + 3.0 * ROW_HEIGHT);
+    }
+
+    #[test]
+    fn only_rows_in_viewport_and_buffer_are_rendered_at_top() {
+        let rows = rows();
+
+        let visible = rows.visible_rows(&viewport(0.0, 500.0));
+
+        // viewport with buffer ends at 900px, last row starting before that is the one at 860px
+        assert_eq!(visible.range, 0..18);
+        assert_eq!(visible.space_before, 0.0);
+        assert_fills_content(&rows, &visible);
+    }
+
+    #[test]
+    fn rows_above_buffer_are_replaced_with_space() {
+        let rows = rows();
+
+        let visible = rows.visible_rows(&viewport(2000.0, 500.0));
+
+        // visible range with buffer is from 1600px to 2900px
+        assert_eq!(visible.range, 31..58);
+        assert_eq!(visible.space_before, 31.0 * ROW_HEIGHT);
+        assert_eq!(visible.space_after, (100.0 - 58.0) * ROW_HEIGHT);
+        assert_fills_content(&rows, &visible);
+    }
+
+    #[test]
+    fn no_space_is_added_after_last_row() {
+        let rows = rows();
+
+        let visible = rows.visible_rows(&viewport(4600.0, 500.0));
+
+        assert_eq!(visible.range.end, 100);
+        assert_eq!(visible.space_after, 0.0);
+        assert_fills_content(&rows, &visible);
+    }
+
+    #[test]
+    fn viewport_past_content_renders_only_space() {
+        let rows = rows();
+
+        let visible = rows.visible_rows(&viewport(10000.0, 500.0));
+
+        assert!(visible.range.is_empty());
+        assert_eq!(visible.space_before, 100.0 * ROW_HEIGHT);
+        assert_eq!(visible.space_after, 0.0);
+    }
+
+    #[test]
+    fn empty_rows_render_nothing() {
+        let rows = VirtualizedRows::<usize>::new(PADDING_TOP);
+
+        let visible = rows.visible_rows(&viewport(0.0, 500.0));
+
+        assert!(visible.range.is_empty());
+        assert_eq!(visible.space_before, 0.0);
+        assert_eq!(visible.space_after, 0.0);
+    }
+
+    #[test]
+    fn item_bounds_are_collected_for_every_item_in_row() {
+        let mut rows = VirtualizedRows::new(PADDING_TOP);
+
+        let first = container::Id::new("first");
+        let second = container::Id::new("second");
+        let third = container::Id::new("third");
+
+        rows.push(100.0, vec![first.clone(), second.clone()]);
+        rows.push(80.0, vec![third.clone()]);
+
+        let bounds = collect_item_bounds(&rows, |ids| ids.clone());
+
+        assert_eq!(bounds.len(), 3);
+        assert_eq!(bounds[&second].top, PADDING_TOP);
+        assert_eq!(bounds[&second].height, 100.0);
+        assert_eq!(bounds[&third].top, PADDING_TOP + 100.0);
+        assert_eq!(bounds[&third].height, 80.0);
+    }
+}
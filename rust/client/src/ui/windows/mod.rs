@@ -1,8 +1,9 @@
 use std::collections::HashMap;
-use std::fs;
 use std::path::PathBuf;
 
+use gauntlet_common::model::FocusedWindow;
 use gauntlet_common::model::WindowPositionMode;
+use gauntlet_common::model::WindowSize;
 use iced::Point;
 use iced::Size;
 use iced::Task;
@@ -12,8 +13,15 @@ use iced::window::Position;
 
 use crate::ui::AppMsg;
 use crate::ui::windows::hud::show_hud_window;
+use crate::ui::windows::monitors::MonitorLayout;
+use crate::ui::windows::monitors::query_monitor_layout;
+use crate::ui::windows::positions::WindowPositions;
 
 pub mod hud;
+pub mod monitors;
+mod positions;
+#[cfg(target_os = "linux")]
+pub mod wayland_focus;
 #[cfg(target_os = "linux")]
 pub mod x11_focus;
 
@@ -25,11 +33,17 @@ pub struct MainWindowState {
     #[cfg(target_os = "linux")]
     pub layer_shell: bool,
     window_position_mode: WindowPositionMode,
+    window_size: WindowSize,
+    compact_mode: bool,
+    expanded: bool,
     close_on_unfocus: bool,
-    window_position_file: Option<PathBuf>,
+    window_positions: WindowPositions,
+    monitor_configuration_key: String,
     #[cfg(target_os = "linux")]
     x11_active_window: Option<u32>,
-    open_position: Position,
+    // last focused window that is not gauntlet
+    #[cfg(target_os = "linux")]
+    focused_window: Option<FocusedWindow>,
     pub hud_windows: HashMap<window::Id, String>,
 }
 
@@ -38,26 +52,11 @@ impl MainWindowState {
         window_position_file: Option<PathBuf>,
         close_on_unfocus: bool,
         window_position_mode: WindowPositionMode,
+        window_size: WindowSize,
+        compact_mode: bool,
         #[cfg(target_os = "linux")] wayland: bool,
         #[cfg(target_os = "linux")] layer_shell: bool,
     ) -> MainWindowState {
-        let open_position = window_position_file
-            .as_ref()
-            .map(|window_position_file| fs::read_to_string(window_position_file).ok())
-            .flatten()
-            .map(|data| {
-                if let Some((x, y)) = data.split_once(":") {
-                    match (x.parse(), y.parse()) {
-                        (Ok(x), Ok(y)) => Some(Position::Specific(Point::new(x, y))),
-                        _ => None,
-                    }
-                } else {
-                    None
-                }
-            })
-            .unwrap_or(None)
-            .unwrap_or(Position::Centered);
-
         Self {
             main_window_id: None,
             focused: false,
@@ -66,11 +65,16 @@ impl MainWindowState {
             #[cfg(target_os = "linux")]
             wayland,
             window_position_mode,
+            window_size,
+            compact_mode,
+            expanded: false,
             close_on_unfocus,
-            window_position_file,
+            window_positions: WindowPositions::read(window_position_file),
+            monitor_configuration_key: MonitorLayout::default().configuration_key(),
             #[cfg(target_os = "linux")]
             x11_active_window: None,
-            open_position,
+            #[cfg(target_os = "linux")]
+            focused_window: None,
             hud_windows: HashMap::new(),
         }
     }
@@ -84,8 +88,29 @@ impl MainWindowState {
 
                 Task::none()
             }
+            WindowActionMsg::SetWindowSize { size } => {
+                self.window_size = size;
+
+                self.resize_window()
+            }
+            WindowActionMsg::SetCompactMode { enabled } => {
+                self.compact_mode = enabled;
+
+                self.resize_window()
+            }
             #[cfg(target_os = "linux")]
-            WindowActionMsg::X11ActiveWindowChanged { window, wm_name } => {
+            WindowActionMsg::X11ActiveWindowChanged {
+                window,
+                wm_name,
+                wm_title,
+            } => {
+                if wm_name.as_deref() != Some("gauntlet") {
+                    self.focused_window = Some(FocusedWindow {
+                        app_id: wm_name.clone(),
+                        title: wm_title,
+                    });
+                }
+
                 if self.x11_active_window != Some(window) {
                     self.x11_active_window = Some(window);
                     if let Some(wm_name) = &wm_name {
@@ -101,8 +126,17 @@ impl MainWindowState {
                     Task::none()
                 }
             }
+            #[cfg(target_os = "linux")]
+            WindowActionMsg::WaylandActiveWindowChanged { app_id, title } => {
+                if app_id.as_deref() != Some("gauntlet") {
+                    self.focused_window = Some(FocusedWindow { app_id, title });
+                }
+
+                Task::none()
+            }
             WindowActionMsg::ToggleWindow => self.toggle_window(),
             WindowActionMsg::ShowWindow => self.show_window(),
+            WindowActionMsg::OpenWindow { layout } => self.open_window(layout),
             WindowActionMsg::HideWindow => self.hide_window(true),
             WindowActionMsg::ShowHud { display } => {
                 let show_hud = show_hud_window(
@@ -176,16 +210,65 @@ impl MainWindowState {
             return Task::none();
         }
 
-        if let Some(window_position_file) = &self.window_position_file {
-            let _ = fs::create_dir_all(window_position_file.parent().unwrap());
-            let _ = fs::write(&window_position_file, format!("{}:{}", point.x, point.y));
+        // other modes calculate position every time the window is opened
+        if let WindowPositionMode::Static = self.window_position_mode {
+            self.window_positions
+                .save(self.monitor_configuration_key.clone(), Point::new(point.x, point.y));
         }
 
-        self.open_position = Position::Specific(Point::new(point.x, point.y));
-
         Task::none()
     }
 
+    // in compact mode only search bar is shown until there is something to show below it
+    pub fn set_expanded(&mut self, expanded: bool) -> Task<AppMsg> {
+        if self.expanded == expanded {
+            return Task::none();
+        }
+
+        self.expanded = expanded;
+
+        if self.compact_mode {
+            self.resize_window()
+        } else {
+            Task::none()
+        }
+    }
+
+    pub fn is_collapsed(&self) -> bool {
+        self.compact_mode && !self.expanded
+    }
+
+    // compositor centers layer shell surface, so resizing it would move the search bar.
+    // instead the surface always has full size and space below collapsed search bar is left transparent
+    pub fn keeps_full_size(&self) -> bool {
+        #[cfg(target_os = "linux")]
+        return self.layer_shell;
+
+        #[cfg(not(target_os = "linux"))]
+        return false;
+    }
+
+    fn current_window_size(&self) -> WindowSize {
+        if self.is_collapsed() && !self.keeps_full_size() {
+            WindowSize {
+                width: self.window_size.width,
+                height: COMPACT_WINDOW_HEIGHT,
+            }
+        } else {
+            self.window_size
+        }
+    }
+
+    fn resize_window(&self) -> Task<AppMsg> {
+        let Some(main_window_id) = self.main_window_id else {
+            return Task::none();
+        };
+
+        let size = self.current_window_size();
+
+        window::resize(main_window_id, Size::new(size.width, size.height))
+    }
+
     fn on_focused(&mut self) -> Task<AppMsg> {
         self.focused = true;
         Task::none()
@@ -236,18 +319,55 @@ impl MainWindowState {
             return Task::none();
         };
 
+        let layout = query_monitor_layout(
+            #[cfg(target_os = "linux")]
+            self.wayland,
+        );
+
+        // has to be captured before gauntlet window takes focus
+        let capture = Task::done(AppMsg::CaptureLaunchContext {
+            focused_window: self.focused_window(),
+        });
+
+        Task::batch([
+            capture,
+            layout.map(|layout| AppMsg::WindowAction(WindowActionMsg::OpenWindow { layout })),
+        ])
+    }
+
+    pub fn focused_window(&self) -> Option<FocusedWindow> {
+        #[cfg(target_os = "linux")]
+        return self.focused_window.clone();
+
+        #[cfg(target_os = "macos")]
+        return macos_frontmost_app();
+
+        #[cfg(target_os = "windows")]
+        return None;
+    }
+
+    fn open_window(&mut self, layout: MonitorLayout) -> Task<AppMsg> {
+        if let Some(_) = self.main_window_id {
+            return Task::none();
+        };
+
+        let open_position = self.open_position(&layout);
+
+        self.monitor_configuration_key = layout.configuration_key();
+
         let (main_window_id, open_task) = window::open(window_settings(
             #[cfg(target_os = "linux")]
             self.layer_shell,
-            self.open_position,
+            open_position,
+            self.current_window_size(),
         ));
 
         let task = Task::batch([
             open_task.map(|id| WindowActionMsg::SetMainWindowId(Some(id))),
             #[cfg(target_os = "macos")]
             match self.window_position_mode {
-                WindowPositionMode::Static => Task::none(),
                 WindowPositionMode::ActiveMonitor => window::move_to_active_monitor(main_window_id),
+                _ => Task::none(),
             },
             window::gain_focus(main_window_id),
             window::set_level(main_window_id, Level::AlwaysOnTop),
@@ -258,6 +378,47 @@ impl MainWindowState {
             Task::done(AppMsg::ResetMainWindowItemFocus),
         ])
     }
+
+    fn open_position(&self, layout: &MonitorLayout) -> Position {
+        let window_size = Size::new(self.window_size.width, self.window_size.height);
+
+        let position = match &self.window_position_mode {
+            WindowPositionMode::Static => None,
+            WindowPositionMode::ActiveMonitor => {
+                if cfg!(target_os = "macos") {
+                    // window is moved to active monitor right after it is opened
+                    None
+                } else {
+                    layout
+                        .under_cursor()
+                        .map(|monitor| monitor.centered_position(window_size))
+                }
+            }
+            WindowPositionMode::MonitorUnderCursor => {
+                layout
+                    .under_cursor()
+                    .map(|monitor| monitor.centered_position(window_size))
+            }
+            WindowPositionMode::FixedMonitor { name } => {
+                layout
+                    .find_by_name(name)
+                    .map(|monitor| monitor.centered_position(window_size))
+            }
+            WindowPositionMode::TopThirdCentered => {
+                layout
+                    .under_cursor()
+                    .or_else(|| layout.primary())
+                    .map(|monitor| monitor.top_third_position(window_size))
+            }
+        };
+
+        let position = position.or_else(|| self.window_positions.get(&layout.configuration_key()));
+
+        match position {
+            Some(position) => Position::Specific(position),
+            None => Position::Centered,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -266,12 +427,27 @@ pub enum WindowActionMsg {
     SetWindowPositionMode {
         mode: WindowPositionMode,
     },
+    SetWindowSize {
+        size: WindowSize,
+    },
+    SetCompactMode {
+        enabled: bool,
+    },
     #[cfg(target_os = "linux")]
     X11ActiveWindowChanged {
         window: u32,
         wm_name: Option<String>,
+        wm_title: Option<String>,
+    },
+    #[cfg(target_os = "linux")]
+    WaylandActiveWindowChanged {
+        app_id: Option<String>,
+        title: Option<String>,
     },
     ShowWindow,
+    OpenWindow {
+        layout: MonitorLayout,
+    },
     HideWindow,
     ToggleWindow,
     ShowHud {
@@ -283,13 +459,16 @@ pub enum WindowActionMsg {
     },
 }
 
-const WINDOW_WIDTH: f32 = 750.0;
-const WINDOW_HEIGHT: f32 = 450.0;
+const COMPACT_WINDOW_HEIGHT: f32 = 60.0;
 
 #[cfg(not(target_os = "macos"))]
-fn window_settings(#[cfg(target_os = "linux")] layer_shell: bool, position: Position) -> window::Settings {
+fn window_settings(
+    #[cfg(target_os = "linux")] layer_shell: bool,
+    position: Position,
+    window_size: WindowSize,
+) -> window::Settings {
     window::Settings {
-        size: Size::new(WINDOW_WIDTH, WINDOW_HEIGHT),
+        size: Size::new(window_size.width, window_size.height),
         position,
         resizable: false,
         decorations: false,
@@ -312,9 +491,9 @@ fn window_settings(#[cfg(target_os = "linux")] layer_shell: bool, position: Posi
 }
 
 #[cfg(target_os = "macos")]
-fn window_settings(position: Position) -> window::Settings {
+fn window_settings(position: Position, window_size: WindowSize) -> window::Settings {
     window::Settings {
-        size: Size::new(WINDOW_WIDTH, WINDOW_HEIGHT),
+        size: Size::new(window_size.width, window_size.height),
         position,
         resizable: false,
         decorations: true,
@@ -346,6 +525,19 @@ pub fn macos_focus_previous_app() {
     }
 }
 
+#[cfg(target_os = "macos")]
+fn macos_frontmost_app() -> Option<FocusedWindow> {
+    unsafe {
+        // window title of other application is only available with accessibility permission
+        let app = objc2_app_kit::NSWorkspace::sharedWorkspace().frontmostApplication()?;
+
+        Some(FocusedWindow {
+            app_id: app.bundleIdentifier().map(|id| id.to_string()),
+            title: None,
+        })
+    }
+}
+
 #[cfg(target_os = "linux")]
 fn layer_shell_settings() -> window::settings::LayerShellSettings {
     window::settings::LayerShellSettings {
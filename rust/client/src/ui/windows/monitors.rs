@@ -0,0 +1,210 @@
+use iced::Point;
+use iced::Rectangle;
+use iced::Size;
+use iced::Task;
+
+#[derive(Debug, Clone)]
+pub struct MonitorInfo {
+    pub name: String,
+    pub bounds: Rectangle,
+    pub primary: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MonitorLayout {
+    pub monitors: Vec<MonitorInfo>,
+    pub cursor: Option<Point>,
+}
+
+impl MonitorLayout {
+    // identifies current set of connected monitors and their arrangement,
+    // used to remember separate window position for each monitor configuration
+    pub fn configuration_key(&self) -> String {
+        if self.monitors.is_empty() {
+            return "default".to_string();
+        }
+
+        let mut monitors: Vec<_> = self
+            .monitors
+            .iter()
+            .map(|monitor| {
+                format!(
+                    "{}@{},{},{}x{}",
+                    monitor.name, monitor.bounds.x, monitor.bounds.y, monitor.bounds.width, monitor.bounds.height
+                )
+            })
+            .collect();
+
+        monitors.sort();
+
+        monitors.join(";")
+    }
+
+    pub fn find_by_name(&self, name: &str) -> Option<&MonitorInfo> {
+        self.monitors.iter().find(|monitor| monitor.name == name)
+    }
+
+    pub fn under_cursor(&self) -> Option<&MonitorInfo> {
+        let cursor = self.cursor?;
+
+        self.monitors.iter().find(|monitor| monitor.bounds.contains(cursor))
+    }
+
+    pub fn primary(&self) -> Option<&MonitorInfo> {
+        self.monitors
+            .iter()
+            .find(|monitor| monitor.primary)
+            .or_else(|| self.monitors.first())
+    }
+}
+
+impl MonitorInfo {
+    pub fn centered_position(&self, window_size: Size) -> Point {
+        Point::new(
+            self.bounds.x + (self.bounds.width - window_size.width) / 2.0,
+            self.bounds.y + (self.bounds.height - window_size.height) / 2.0,
+        )
+    }
+
+    pub fn top_third_position(&self, window_size: Size) -> Point {
+        Point::new(
+            self.bounds.x + (self.bounds.width - window_size.width) / 2.0,
+            self.bounds.y + (self.bounds.height - window_size.height) / 3.0,
+        )
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub fn query_monitor_layout(wayland: bool) -> Task<MonitorLayout> {
+    if wayland {
+        // wayland clients are not allowed to know about global positions
+        return Task::done(MonitorLayout::default());
+    }
+
+    Task::perform(x11::query_monitor_layout(), |result| {
+        result.unwrap_or_else(|err| {
+            tracing::warn!("Unable to query x11 monitor layout: {:?}", err);
+
+            MonitorLayout::default()
+        })
+    })
+}
+
+#[cfg(target_os = "macos")]
+pub fn query_monitor_layout() -> Task<MonitorLayout> {
+    // NSScreen can only be accessed from main thread, so it is not done inside the future
+    Task::done(macos::query_monitor_layout())
+}
+
+#[cfg(target_os = "windows")]
+pub fn query_monitor_layout() -> Task<MonitorLayout> {
+    // TODO monitor enumeration on windows, window will be positioned as in static mode
+    Task::done(MonitorLayout::default())
+}
+
+#[cfg(target_os = "linux")]
+mod x11 {
+    use iced::Point;
+    use iced::Rectangle;
+    use x11rb_async::connection::Connection;
+    use x11rb_async::protocol::randr::ConnectionExt as RandrConnectionExt;
+    use x11rb_async::protocol::xproto::ConnectionExt;
+    use x11rb_async::rust_connection::RustConnection;
+
+    use crate::ui::windows::monitors::MonitorInfo;
+    use crate::ui::windows::monitors::MonitorLayout;
+
+    pub async fn query_monitor_layout() -> anyhow::Result<MonitorLayout> {
+        let (conn, screen_num, drive) = RustConnection::connect(None).await?;
+
+        let drive = tokio::spawn(async move {
+            let Err(e) = drive.await;
+            tracing::debug!("Error while driving the x11 connection: {}", e);
+        });
+
+        let root = conn.setup().roots[screen_num].root;
+
+        let monitors_reply = conn.randr_get_monitors(root, true).await?.reply().await?;
+
+        let mut monitors = vec![];
+        for monitor in monitors_reply.monitors {
+            let name = conn.get_atom_name(monitor.name).await?.reply().await?;
+            let name = String::from_utf8_lossy(&name.name).to_string();
+
+            monitors.push(MonitorInfo {
+                name,
+                bounds: Rectangle {
+                    x: monitor.x as f32,
+                    y: monitor.y as f32,
+                    width: monitor.width as f32,
+                    height: monitor.height as f32,
+                },
+                primary: monitor.primary,
+            })
+        }
+
+        let pointer = conn.query_pointer(root).await?.reply().await?;
+        let cursor = Point::new(pointer.root_x as f32, pointer.root_y as f32);
+
+        drive.abort();
+
+        Ok(MonitorLayout {
+            monitors,
+            cursor: Some(cursor),
+        })
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod macos {
+    use iced::Point;
+    use iced::Rectangle;
+    use objc2_app_kit::NSEvent;
+    use objc2_app_kit::NSScreen;
+    use objc2_foundation::MainThreadMarker;
+
+    use crate::ui::windows::monitors::MonitorInfo;
+    use crate::ui::windows::monitors::MonitorLayout;
+
+    pub fn query_monitor_layout() -> MonitorLayout {
+        let Some(mtm) = MainThreadMarker::new() else {
+            tracing::warn!("Monitor layout was requested outside of main thread");
+            return MonitorLayout::default();
+        };
+
+        let screens = NSScreen::screens(mtm);
+
+        // appkit uses bottom-left origin, with primary screen at (0, 0)
+        let Some(primary_height) = screens.iter().next().map(|screen| screen.frame().size.height) else {
+            return MonitorLayout::default();
+        };
+
+        let monitors = screens
+            .iter()
+            .enumerate()
+            .map(|(index, screen)| {
+                let frame = screen.frame();
+                let name = unsafe { screen.localizedName() }.to_string();
+
+                MonitorInfo {
+                    name,
+                    bounds: Rectangle {
+                        x: frame.origin.x as f32,
+                        y: (primary_height - frame.origin.y - frame.size.height) as f32,
+                        width: frame.size.width as f32,
+                        height: frame.size.height as f32,
+                    },
+                    primary: index == 0,
+                }
+            })
+            .collect();
+
+        let cursor = unsafe { NSEvent::mouseLocation() };
+        let cursor = Point::new(cursor.x as f32, (primary_height - cursor.y) as f32);
+
+        MonitorLayout {
+            monitors,
+            cursor: Some(cursor),
+        }
+    }
+}
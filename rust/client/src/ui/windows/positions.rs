@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use iced::Point;
+
+// file contains one line per monitor configuration, in format "<configuration key>=<x>:<y>"
+// line without configuration key is a position saved by an older version, used as a fallback
+pub struct WindowPositions {
+    file: Option<PathBuf>,
+    fallback: Option<Point>,
+    positions: HashMap<String, Point>,
+}
+
+impl WindowPositions {
+    pub fn read(file: Option<PathBuf>) -> Self {
+        let content = file.as_ref().map(|file| fs::read_to_string(file).ok()).flatten();
+
+        let mut fallback = None;
+        let mut positions = HashMap::new();
+
+        for line in content.as_deref().unwrap_or_default().lines() {
+            match line.rsplit_once("=") {
+                Some((key, point)) => {
+                    if let Some(point) = parse_point(point) {
+                        positions.insert(key.to_string(), point);
+                    }
+                }
+                None => fallback = parse_point(line),
+            }
+        }
+
+        Self {
+            file,
+            fallback,
+            positions,
+        }
+    }
+
+    pub fn get(&self, configuration_key: &str) -> Option<Point> {
+        self.positions.get(configuration_key).cloned().or(self.fallback)
+    }
+
+    pub fn save(&mut self, configuration_key: String, point: Point) {
+        self.positions.insert(configuration_key, point);
+
+        let Some(file) = &self.file else {
+            return;
+        };
+
+        let mut content = String::new();
+
+        if let Some(fallback) = &self.fallback {
+            content.push_str(&format!("{}:{}\n", fallback.x, fallback.y));
+        }
+
+        for (key, point) in &self.positions {
+            content.push_str(&format!("{}={}:{}\n", key, point.x, point.y));
+        }
+
+        let _ = fs::create_dir_all(file.parent().unwrap());
+        let _ = fs::write(file, content);
+    }
+}
+
+fn parse_point(data: &str) -> Option<Point> {
+    let (x, y) = data.trim().split_once(":")?;
+
+    match (x.parse(), y.parse()) {
+        (Ok(x), Ok(y)) => Some(Point::new(x, y)),
+        _ => None,
+    }
+}
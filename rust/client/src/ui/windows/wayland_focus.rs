@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+
+use cosmic_protocols::toplevel_info::v1::client::zcosmic_toplevel_handle_v1;
+use cosmic_protocols::toplevel_info::v1::client::zcosmic_toplevel_info_v1;
+use iced::Subscription;
+use iced::futures::channel::mpsc::Sender;
+use iced::stream;
+use smithay_client_toolkit::reexports::client::Connection;
+use smithay_client_toolkit::reexports::client::Dispatch;
+use smithay_client_toolkit::reexports::client::Proxy;
+use smithay_client_toolkit::reexports::client::QueueHandle;
+use smithay_client_toolkit::reexports::client::backend::ObjectId;
+use smithay_client_toolkit::reexports::client::event_created_child;
+use smithay_client_toolkit::reexports::client::globals::GlobalListContents;
+use smithay_client_toolkit::reexports::client::globals::registry_queue_init;
+use smithay_client_toolkit::reexports::client::protocol::wl_registry;
+use wayland_protocols_wlr::foreign_toplevel::v1::client::zwlr_foreign_toplevel_handle_v1;
+use wayland_protocols_wlr::foreign_toplevel::v1::client::zwlr_foreign_toplevel_manager_v1;
+
+use crate::ui::AppMsg;
+use crate::ui::windows::WindowActionMsg;
+
+// https://wayland.app/protocols/wlr-foreign-toplevel-management-unstable-v1#zwlr_foreign_toplevel_handle_v1:enum:state
+// cosmic toplevel info protocol uses the same values
+const TOPLEVEL_STATE_ACTIVATED: u32 = 2;
+
+pub fn wayland_focus_change_subscription() -> Subscription<AppMsg> {
+    Subscription::run(|| {
+        stream::channel(100, async move |sender| {
+            // wayland event queue dispatch is blocking
+            let result = tokio::task::spawn_blocking(move || listen_on_wayland_active_window_change(sender)).await;
+
+            match result {
+                Ok(Err(err)) => tracing::warn!("unable to track focused wayland window: {:?}", err),
+                Err(err) => tracing::error!("error occurred when listening on wayland events: {:?}", err),
+            }
+        })
+    })
+}
+
+fn listen_on_wayland_active_window_change(sender: Sender<AppMsg>) -> anyhow::Result<Infallible> {
+    let conn = Connection::connect_to_env()?;
+    let (globals, mut event_queue) = registry_queue_init::<WaylandFocusState>(&conn)?;
+    let queue_handle = event_queue.handle();
+
+    // only wlroots based compositors (and some others) and cosmic expose other application windows
+    let wlr_manager =
+        globals.bind::<zwlr_foreign_toplevel_manager_v1::ZwlrForeignToplevelManagerV1, _, _>(&queue_handle, 1..=3, ());
+
+    if wlr_manager.is_err() {
+        globals.bind::<zcosmic_toplevel_info_v1::ZcosmicToplevelInfoV1, _, _>(&queue_handle, 1..=1, ())?;
+    }
+
+    let mut state = WaylandFocusState {
+        sender,
+        toplevels: HashMap::new(),
+    };
+
+    loop {
+        event_queue.blocking_dispatch(&mut state)?;
+    }
+}
+
+struct WaylandFocusState {
+    sender: Sender<AppMsg>,
+    toplevels: HashMap<ObjectId, WaylandToplevel>,
+}
+
+impl WaylandFocusState {
+    fn toplevel_mut(&mut self, id: &ObjectId) -> Option<&mut WaylandToplevel> {
+        let toplevel = self.toplevels.get_mut(id);
+
+        if toplevel.is_none() {
+            tracing::warn!("Received event for wayland toplevel that doesn't exist in state");
+        }
+
+        toplevel
+    }
+
+    // sent after all properties of the toplevel were changed
+    fn toplevel_done(&mut self, id: &ObjectId) {
+        let Some(toplevel) = self.toplevels.get(id) else {
+            return;
+        };
+
+        if toplevel.activated {
+            let message = AppMsg::WindowAction(WindowActionMsg::WaylandActiveWindowChanged {
+                app_id: toplevel.app_id.clone(),
+                title: toplevel.title.clone(),
+            });
+
+            if let Err(err) = self.sender.try_send(message) {
+                tracing::warn!("unable to send wayland active window change: {:?}", err);
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct WaylandToplevel {
+    app_id: Option<String>,
+    title: Option<String>,
+    activated: bool,
+}
+
+fn is_activated(raw_state: &[u8]) -> bool {
+    raw_state
+        .chunks_exact(4)
+        .any(|value| u32::from_ne_bytes([value[0], value[1], value[2], value[3]]) == TOPLEVEL_STATE_ACTIVATED)
+}
+
+impl Dispatch<zwlr_foreign_toplevel_manager_v1::ZwlrForeignToplevelManagerV1, ()> for WaylandFocusState {
+    fn event(
+        state: &mut Self,
+        _proxy: &zwlr_foreign_toplevel_manager_v1::ZwlrForeignToplevelManagerV1,
+        event: <zwlr_foreign_toplevel_manager_v1::ZwlrForeignToplevelManagerV1 as Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        match event {
+            zwlr_foreign_toplevel_manager_v1::Event::Toplevel { toplevel } => {
+                state.toplevels.insert(toplevel.id(), WaylandToplevel::default());
+            }
+            _ => {}
+        }
+    }
+
+    event_created_child!(WaylandFocusState, zwlr_foreign_toplevel_manager_v1::ZwlrForeignToplevelManagerV1, [
+        zwlr_foreign_toplevel_manager_v1::EVT_TOPLEVEL_OPCODE => (zwlr_foreign_toplevel_handle_v1::ZwlrForeignToplevelHandleV1, ()),
+    ]);
+}
+
+impl Dispatch<zwlr_foreign_toplevel_handle_v1::ZwlrForeignToplevelHandleV1, ()> for WaylandFocusState {
+    fn event(
+        state: &mut Self,
+        proxy: &zwlr_foreign_toplevel_handle_v1::ZwlrForeignToplevelHandleV1,
+        event: <zwlr_foreign_toplevel_handle_v1::ZwlrForeignToplevelHandleV1 as Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        let id = proxy.id();
+
+        match event {
+            zwlr_foreign_toplevel_handle_v1::Event::Title { title } => {
+                if let Some(toplevel) = state.toplevel_mut(&id) {
+                    toplevel.title = Some(title);
+                }
+            }
+            zwlr_foreign_toplevel_handle_v1::Event::AppId { app_id } => {
+                if let Some(toplevel) = state.toplevel_mut(&id) {
+                    toplevel.app_id = Some(app_id);
+                }
+            }
+            zwlr_foreign_toplevel_handle_v1::Event::State { state: raw_state } => {
+                if let Some(toplevel) = state.toplevel_mut(&id) {
+                    toplevel.activated = is_activated(&raw_state);
+                }
+            }
+            zwlr_foreign_toplevel_handle_v1::Event::Done => state.toplevel_done(&id),
+            zwlr_foreign_toplevel_handle_v1::Event::Closed => {
+                state.toplevels.remove(&id);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Dispatch<zcosmic_toplevel_info_v1::ZcosmicToplevelInfoV1, ()> for WaylandFocusState {
+    fn event(
+        state: &mut Self,
+        _proxy: &zcosmic_toplevel_info_v1::ZcosmicToplevelInfoV1,
+        event: <zcosmic_toplevel_info_v1::ZcosmicToplevelInfoV1 as Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        match event {
+            zcosmic_toplevel_info_v1::Event::Toplevel { toplevel } => {
+                state.toplevels.insert(toplevel.id(), WaylandToplevel::default());
+            }
+            _ => {}
+        }
+    }
+
+    event_created_child!(WaylandFocusState, zcosmic_toplevel_info_v1::ZcosmicToplevelInfoV1, [
+        zcosmic_toplevel_info_v1::EVT_TOPLEVEL_OPCODE => (zcosmic_toplevel_handle_v1::ZcosmicToplevelHandleV1, ()),
+    ]);
+}
+
+impl Dispatch<zcosmic_toplevel_handle_v1::ZcosmicToplevelHandleV1, ()> for WaylandFocusState {
+    fn event(
+        state: &mut Self,
+        proxy: &zcosmic_toplevel_handle_v1::ZcosmicToplevelHandleV1,
+        event: <zcosmic_toplevel_handle_v1::ZcosmicToplevelHandleV1 as Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        let id = proxy.id();
+
+        match event {
+            zcosmic_toplevel_handle_v1::Event::Title { title } => {
+                if let Some(toplevel) = state.toplevel_mut(&id) {
+                    toplevel.title = Some(title);
+                }
+            }
+            zcosmic_toplevel_handle_v1::Event::AppId { app_id } => {
+                if let Some(toplevel) = state.toplevel_mut(&id) {
+                    toplevel.app_id = Some(app_id);
+                }
+            }
+            zcosmic_toplevel_handle_v1::Event::State { state: raw_state } => {
+                if let Some(toplevel) = state.toplevel_mut(&id) {
+                    toplevel.activated = is_activated(&raw_state);
+                }
+            }
+            zcosmic_toplevel_handle_v1::Event::Done => state.toplevel_done(&id),
+            zcosmic_toplevel_handle_v1::Event::Closed => {
+                state.toplevels.remove(&id);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Dispatch<wl_registry::WlRegistry, GlobalListContents> for WaylandFocusState {
+    fn event(
+        _state: &mut WaylandFocusState,
+        _proxy: &wl_registry::WlRegistry,
+        _event: wl_registry::Event,
+        _data: &GlobalListContents,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<WaylandFocusState>,
+    ) {
+    }
+}
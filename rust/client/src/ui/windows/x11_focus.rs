@@ -53,6 +53,7 @@ async fn listen_on_x11_active_window_change(sender: Sender<AppMsg>, handle: Hand
                 };
 
                 let wm_name = fetch_app_wm_name(&conn, window).await.ok();
+                let wm_title = fetch_window_title(&conn, window, &atoms).await.ok();
 
                 let mut sender = sender.clone();
                 handle.spawn(async move {
@@ -60,6 +61,7 @@ async fn listen_on_x11_active_window_change(sender: Sender<AppMsg>, handle: Hand
                         .send(AppMsg::WindowAction(WindowActionMsg::X11ActiveWindowChanged {
                             window,
                             wm_name,
+                            wm_title,
                         }))
                         .await
                 });
@@ -93,6 +95,31 @@ async fn fetch_app_wm_name(conn: &impl Connection, window_id: Window) -> anyhow:
     Ok(class.to_string())
 }
 
+async fn fetch_window_title(conn: &impl Connection, window_id: Window, atoms: &atoms::Atoms) -> anyhow::Result<String> {
+    let net_wm_name = conn
+        .get_property(false, window_id, atoms._NET_WM_NAME, atoms.UTF8_STRING, 0, u32::MAX)
+        .await?
+        .reply()
+        .await?;
+
+    if !net_wm_name.value.is_empty() {
+        return Ok(String::from_utf8(net_wm_name.value)?);
+    }
+
+    // fallback for windows that don't set ewmh title
+    let wm_name = conn
+        .get_property(false, window_id, AtomEnum::WM_NAME, AtomEnum::STRING, 0, u32::MAX)
+        .await?
+        .reply()
+        .await?;
+
+    if wm_name.value.is_empty() {
+        return Err(anyhow!("window doesn't have a title"));
+    }
+
+    Ok(String::from_utf8_lossy(&wm_name.value).into_owned())
+}
+
 mod atoms {
     gauntlet_utils::atom_manager! {
         pub Atoms:
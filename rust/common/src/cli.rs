@@ -1,7 +1,13 @@
+use std::collections::HashMap;
+use std::time::Duration;
+use std::time::Instant;
+
 use gauntlet_utils::channel::RequestError;
 
 use crate::model::EntrypointId;
 use crate::model::PluginId;
+use crate::model::Quicklink;
+use crate::model::QuicklinkKind;
 use crate::rpc::backend_api::BackendForCliApi;
 use crate::rpc::backend_api::BackendForCliApiProxy;
 use crate::rpc::backend_api::GrpcBackendApi;
@@ -72,7 +78,7 @@ pub fn open_settings_window() {
         })
 }
 
-pub fn run_action(plugin_id: String, entrypoint_id: String, action_id: String) {
+pub fn run_action(plugin_id: String, entrypoint_id: String, action_id: String, arguments: HashMap<String, String>) {
     tokio::runtime::Builder::new_current_thread()
         .enable_all()
         .build()
@@ -87,7 +93,10 @@ pub fn run_action(plugin_id: String, entrypoint_id: String, action_id: String) {
                     let plugin_id = PluginId::from_string(plugin_id);
                     let entrypoint_id = EntrypointId::from_string(entrypoint_id);
 
-                    if let Err(err) = backend_api.run_action(plugin_id, entrypoint_id, action_id).await {
+                    if let Err(err) = backend_api
+                        .run_action(plugin_id, entrypoint_id, action_id, arguments)
+                        .await
+                    {
                         match err {
                             RequestError::Timeout => {
                                 tracing::error!("Timeout occurred when handling command");
@@ -107,3 +116,139 @@ pub fn run_action(plugin_id: String, entrypoint_id: String, action_id: String) {
             }
         })
 }
+
+// server may have been started to handle this deep link, so it is given some time to start accepting requests
+const DEEP_LINK_SERVER_START_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub fn open_deep_link(url: String) {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("unable to start server tokio runtime")
+        .block_on(async {
+            let started_at = Instant::now();
+
+            let result = loop {
+                let result = GrpcBackendApi::new().await;
+
+                if result.is_ok() || started_at.elapsed() > DEEP_LINK_SERVER_START_TIMEOUT {
+                    break result;
+                }
+
+                tokio::time::sleep(Duration::from_millis(200)).await;
+            };
+
+            match result {
+                Ok(backend_api) => {
+                    let backend_api = BackendForCliApiProxy::new(backend_api);
+
+                    if let Err(err) = backend_api.open_deep_link(url).await {
+                        log_request_error(err)
+                    }
+                }
+                Err(_) => {
+                    tracing::error!("Unable to connect to server. Please check if you have Gauntlet running on your PC")
+                }
+            }
+        })
+}
+
+pub fn list_quicklinks() {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("unable to start server tokio runtime")
+        .block_on(async {
+            let result = GrpcBackendApi::new().await;
+
+            match result {
+                Ok(backend_api) => {
+                    let backend_api = BackendForCliApiProxy::new(backend_api);
+
+                    match backend_api.list_quicklinks().await {
+                        Ok(quicklinks) => {
+                            for quicklink in quicklinks {
+                                println!(
+                                    "{}\t{}\t{}\t{}",
+                                    quicklink.id, quicklink.name, quicklink.kind, quicklink.template
+                                );
+                            }
+                        }
+                        Err(err) => log_request_error(err),
+                    }
+                }
+                Err(_) => {
+                    tracing::error!("Unable to connect to server. Please check if you have Gauntlet running on your PC")
+                }
+            }
+        })
+}
+
+pub fn add_quicklink(name: String, template: String, kind: QuicklinkKind, icon_path: Option<String>) {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("unable to start server tokio runtime")
+        .block_on(async {
+            let result = GrpcBackendApi::new().await;
+
+            match result {
+                Ok(backend_api) => {
+                    let backend_api = BackendForCliApiProxy::new(backend_api);
+
+                    let quicklink = Quicklink {
+                        id: "".to_string(),
+                        name,
+                        icon_path,
+                        template,
+                        kind,
+                    };
+
+                    match backend_api.save_quicklink(quicklink).await {
+                        Ok(id) => println!("{}", id),
+                        Err(err) => log_request_error(err),
+                    }
+                }
+                Err(_) => {
+                    tracing::error!("Unable to connect to server. Please check if you have Gauntlet running on your PC")
+                }
+            }
+        })
+}
+
+pub fn remove_quicklink(quicklink_id: String) {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("unable to start server tokio runtime")
+        .block_on(async {
+            let result = GrpcBackendApi::new().await;
+
+            match result {
+                Ok(backend_api) => {
+                    let backend_api = BackendForCliApiProxy::new(backend_api);
+
+                    if let Err(err) = backend_api.remove_quicklink(quicklink_id).await {
+                        log_request_error(err)
+                    }
+                }
+                Err(_) => {
+                    tracing::error!("Unable to connect to server. Please check if you have Gauntlet running on your PC")
+                }
+            }
+        })
+}
+
+fn log_request_error(err: RequestError) {
+    match err {
+        RequestError::Timeout => {
+            tracing::error!("Timeout occurred when handling command");
+        }
+        RequestError::Other { display: value } => {
+            tracing::error!("Error occurred when handling command: {}", value);
+        }
+        RequestError::OtherSideWasDropped => {
+            tracing::error!("Error occurred when handling command: Other side was dropped");
+        }
+    }
+}
@@ -65,6 +65,10 @@ impl Dirs {
         config_dir
     }
 
+    pub fn calculator_modules_dir(&self) -> PathBuf {
+        self.config_dir().join("calculator")
+    }
+
     pub fn icon_cache_dir(&self) -> PathBuf {
         self.cache_dir().join("icons")
     }
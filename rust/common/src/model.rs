@@ -99,6 +99,29 @@ pub struct LocalSaveData {
     pub stderr_file_path: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct DeepLink {
+    pub plugin_id: PluginId,
+    pub plugin_name: String,
+    pub entrypoint_id: EntrypointId,
+    pub entrypoint_name: String,
+    pub action: DeepLinkAction,
+    // plugin opted in to being run by deep links without confirmation
+    pub trusted: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum DeepLinkAction {
+    RunAction {
+        action_id: String,
+        arguments: HashMap<String, String>,
+    },
+    OpenView {
+        arguments: HashMap<String, String>,
+        search_text: Option<String>,
+    },
+}
+
 #[derive(Debug, Clone)]
 pub struct SearchResult {
     pub plugin_id: PluginId,
@@ -111,6 +134,89 @@ pub struct SearchResult {
     pub entrypoint_actions: Vec<SearchResultEntrypointAction>,
     pub entrypoint_accessories: Vec<SearchResultAccessory>,
     pub entrypoint_alias: Option<String>,
+    pub entrypoint_pinned: bool,
+    pub entrypoint_hidden: bool,
+    pub entrypoint_arguments: Vec<SearchResultEntrypointArgument>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchScope {
+    pub plugin_id: PluginId,
+    pub entrypoint_id: EntrypointId,
+    pub entrypoint_name: String,
+    pub scope_type: SearchScopeType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchScopeType {
+    View,
+    InlineView,
+}
+
+// window that was focused right before gauntlet was opened
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FocusedWindow {
+    pub app_id: Option<String>,
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchResultEntrypointArgument {
+    pub id: String,
+    pub kind: SearchResultEntrypointArgumentKind,
+    pub placeholder: String,
+    pub required: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum SearchResultEntrypointArgumentKind {
+    Text,
+    Password,
+    Dropdown {
+        options: Vec<SearchResultEntrypointArgumentOption>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchResultEntrypointArgumentOption {
+    pub label: String,
+    pub value: String,
+}
+
+impl Display for SearchResultEntrypointArgumentOption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SearchPinMove {
+    Up,
+    Down,
+}
+
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct Quicklink {
+    pub id: String,
+    pub name: String,
+    pub icon_path: Option<String>,
+    pub template: String,
+    pub kind: QuicklinkKind,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Encode, Decode)]
+pub enum QuicklinkKind {
+    Url,
+    Command,
+}
+
+impl Display for QuicklinkKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuicklinkKind::Url => write!(f, "URL"),
+            QuicklinkKind::Command => write!(f, "Command"),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -156,16 +262,35 @@ pub enum UiThemeMode {
 pub enum WindowPositionMode {
     Static,
     ActiveMonitor,
+    MonitorUnderCursor,
+    FixedMonitor { name: String },
+    TopThirdCentered,
 }
 
 impl Display for WindowPositionMode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let label = match self {
-            WindowPositionMode::Static => "Static",
-            WindowPositionMode::ActiveMonitor => "Active Monitor",
-        };
+        match self {
+            WindowPositionMode::Static => write!(f, "Static"),
+            WindowPositionMode::ActiveMonitor => write!(f, "Active Monitor"),
+            WindowPositionMode::MonitorUnderCursor => write!(f, "Monitor Under Cursor"),
+            WindowPositionMode::FixedMonitor { name } => write!(f, "Fixed Monitor: {}", name),
+            WindowPositionMode::TopThirdCentered => write!(f, "Top Third Centered"),
+        }
+    }
+}
 
-        write!(f, "{}", label)
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode)]
+pub struct WindowSize {
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Default for WindowSize {
+    fn default() -> Self {
+        Self {
+            width: 750.0,
+            height: 450.0,
+        }
     }
 }
 
@@ -216,6 +341,9 @@ pub struct UiSetupData {
     pub theme: UiTheme,
     pub close_on_unfocus: bool,
     pub window_position_mode: WindowPositionMode,
+    pub window_size: WindowSize,
+    pub compact_mode: bool,
+    pub compact_mode_in_config: bool,
     pub layer_shell: bool,
     pub wayland_use_legacy_x11_api: bool,
 }
@@ -233,13 +361,58 @@ pub enum KeyboardEventOrigin {
 
 gauntlet_utils_macros::widget_model_gen!();
 
-#[derive(Debug, Encode, Decode)]
+#[derive(Debug, Clone, Encode, Decode)]
 pub enum JsOption<T> {
     Undefined,
     Null,
     Value(T),
 }
 
+// describes how previously rendered view changed, so that the whole tree doesn't need to be sent on every render
+#[derive(Debug, Clone, Encode, Decode)]
+pub enum UiWidgetPatchOp {
+    // widget with the same id is replaced together with all its children
+    Replace {
+        widget: UiWidget,
+    },
+    // widget with the same id is replaced, but its ordered children are kept as is
+    UpdateProps {
+        widget: UiWidget,
+    },
+    // ordered children of widget are set to the specified list, children not present in the list are removed
+    UpdateChildren {
+        widget_id: UiWidgetId,
+        children: Vec<UiWidgetPatchChild>,
+    },
+}
+
+#[derive(Debug, Clone, Encode, Decode)]
+pub enum UiWidgetPatchChild {
+    Existing(UiWidgetId),
+    New(UiWidget),
+}
+
+impl UiWidgetPatchOp {
+    pub fn widget_id(&self) -> UiWidgetId {
+        match self {
+            UiWidgetPatchOp::Replace { widget } => widget.widget_id(),
+            UiWidgetPatchOp::UpdateProps { widget } => widget.widget_id(),
+            UiWidgetPatchOp::UpdateChildren { widget_id, .. } => *widget_id,
+        }
+    }
+}
+
+impl RootWidget {
+    pub fn apply_patch(&mut self, patch: Vec<UiWidgetPatchOp>) -> anyhow::Result<()> {
+        for op in patch {
+            self.apply_patch_op(op)
+                .map_err(|op| anyhow!("Unable to apply patch to widget with id: {}", op.widget_id()))?;
+        }
+
+        Ok(())
+    }
+}
+
 // TODO generate this
 #[allow(async_fn_in_trait)]
 pub trait WidgetVisitor {
@@ -462,6 +635,53 @@ pub trait WidgetVisitor {
         }
     }
 
+    async fn widget(&mut self, widget: &UiWidget) {
+        match widget {
+            UiWidget::Action(widget) => self.action_widget(widget).await,
+            UiWidget::ActionPanelSection(widget) => self.action_panel_section_widget(widget).await,
+            UiWidget::ActionPanel(widget) => self.action_panel_widget(widget).await,
+            UiWidget::MetadataLink(widget) => self.metadata_link_widget(widget).await,
+            UiWidget::MetadataTagItem(widget) => self.metadata_tag_item_widget(widget).await,
+            UiWidget::MetadataTagList(widget) => self.metadata_tag_list_widget(widget).await,
+            UiWidget::MetadataSeparator(widget) => self.metadata_separator_widget(widget).await,
+            UiWidget::MetadataIcon(widget) => self.metadata_icon_widget(widget).await,
+            UiWidget::MetadataValue(widget) => self.metadata_value_widget(widget).await,
+            UiWidget::Metadata(widget) => self.metadata_widget(widget).await,
+            UiWidget::Image(widget) => self.image_widget(widget).await,
+            UiWidget::Svg(widget) => self.svg_widget(widget).await,
+            UiWidget::H1(widget) => self.h1_widget(widget).await,
+            UiWidget::H2(widget) => self.h2_widget(widget).await,
+            UiWidget::H3(widget) => self.h3_widget(widget).await,
+            UiWidget::H4(widget) => self.h4_widget(widget).await,
+            UiWidget::H5(widget) => self.h5_widget(widget).await,
+            UiWidget::H6(widget) => self.h6_widget(widget).await,
+            UiWidget::HorizontalBreak(widget) => self.horizontal_break_widget(widget).await,
+            UiWidget::CodeBlock(widget) => self.code_block_widget(widget).await,
+            UiWidget::Paragraph(widget) => self.paragraph_widget(widget).await,
+            UiWidget::Content(widget) => self.content_widget(widget).await,
+            UiWidget::Detail(widget) => self.detail_widget(widget).await,
+            UiWidget::TextField(widget) => self.text_field_widget(widget).await,
+            UiWidget::PasswordField(widget) => self.password_field_widget(widget).await,
+            UiWidget::Checkbox(widget) => self.checkbox_widget(widget).await,
+            UiWidget::SelectItem(widget) => self.select_item_widget(widget).await,
+            UiWidget::Select(widget) => self.select_widget(widget).await,
+            UiWidget::Separator(widget) => self.separator_widget(widget).await,
+            UiWidget::Form(widget) => self.form_widget(widget).await,
+            UiWidget::InlineSeparator(widget) => self.inline_separator_widget(widget).await,
+            UiWidget::Inline(widget) => self.inline_widget(widget).await,
+            UiWidget::EmptyView(widget) => self.empty_view_widget(widget).await,
+            UiWidget::TextAccessory(widget) => self.text_accessory_widget(widget).await,
+            UiWidget::IconAccessory(widget) => self.icon_accessory_widget(widget).await,
+            UiWidget::SearchBar(widget) => self.search_bar_widget(widget).await,
+            UiWidget::ListItem(widget) => self.list_item_widget(widget).await,
+            UiWidget::ListSection(widget) => self.list_section_widget(widget).await,
+            UiWidget::List(widget) => self.list_widget(widget).await,
+            UiWidget::GridItem(widget) => self.grid_item_widget(widget).await,
+            UiWidget::GridSection(widget) => self.grid_section_widget(widget).await,
+            UiWidget::Grid(widget) => self.grid_widget(widget).await,
+        }
+    }
+
     async fn root_widget(&mut self, root_widget: &RootWidget) {
         if let Some(members) = &root_widget.content {
             match members {
@@ -1180,3 +1400,297 @@ impl PhysicalKey {
         .to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag_item(id: UiWidgetId, text: &str) -> MetadataTagItemWidget {
+        MetadataTagItemWidget {
+            __id__: id,
+            content: MetadataTagItemWidgetContent {
+                text: vec![text.to_string()],
+            },
+        }
+    }
+
+    fn tag_list(id: UiWidgetId, label: &str, items: Vec<MetadataTagItemWidget>) -> MetadataTagListWidget {
+        MetadataTagListWidget {
+            __id__: id,
+            label: label.to_string(),
+            content: MetadataTagListWidgetContent {
+                ordered_members: items
+                    .into_iter()
+                    .map(MetadataTagListWidgetOrderedMembers::MetadataTagItem)
+                    .collect(),
+            },
+        }
+    }
+
+    fn separator(id: UiWidgetId) -> MetadataSeparatorWidget {
+        MetadataSeparatorWidget { __id__: id }
+    }
+
+    // metadata(2) with tag list(3) containing items a(4) and b(5), followed by separator(6)
+    fn metadata() -> MetadataWidget {
+        MetadataWidget {
+            __id__: 2,
+            content: MetadataWidgetContent {
+                ordered_members: vec![
+                    MetadataWidgetOrderedMembers::MetadataTagList(tag_list(
+                        3,
+                        "Tags",
+                        vec![tag_item(4, "a"), tag_item(5, "b")],
+                    )),
+                    MetadataWidgetOrderedMembers::MetadataSeparator(separator(6)),
+                ],
+            },
+        }
+    }
+
+    fn detail(id: UiWidgetId, is_loading: bool) -> DetailWidget {
+        DetailWidget {
+            __id__: id,
+            is_loading: Some(is_loading),
+            content: DetailWidgetContent {
+                actions: None,
+                metadata: Some(metadata()),
+                content: None,
+            },
+        }
+    }
+
+    fn member_ids(metadata: &MetadataWidget) -> Vec<UiWidgetId> {
+        metadata
+            .content
+            .ordered_members
+            .iter()
+            .map(|member| member.widget_id())
+            .collect()
+    }
+
+    fn tags(metadata: &MetadataWidget) -> (String, Vec<String>) {
+        let tag_list = metadata
+            .content
+            .ordered_members
+            .iter()
+            .find_map(|member| {
+                match member {
+                    MetadataWidgetOrderedMembers::MetadataTagList(widget) => Some(widget),
+                    _ => None,
+                }
+            })
+            .expect("tag list should be present");
+
+        let items = tag_list
+            .content
+            .ordered_members
+            .iter()
+            .map(|member| {
+                match member {
+                    MetadataTagListWidgetOrderedMembers::MetadataTagItem(widget) => widget.content.text.join(""),
+                }
+            })
+            .collect();
+
+        (tag_list.label.clone(), items)
+    }
+
+    #[test]
+    fn update_children_reorders_existing_members() {
+        let mut widget = metadata();
+
+        widget
+            .apply_patch_op(UiWidgetPatchOp::UpdateChildren {
+                widget_id: 2,
+                children: vec![UiWidgetPatchChild::Existing(6), UiWidgetPatchChild::Existing(3)],
+            })
+            .unwrap();
+
+        assert_eq!(member_ids(&widget), vec![6, 3]);
+        // moved members keep their own children
+        assert_eq!(
+            tags(&widget),
+            ("Tags".to_string(), vec!["a".to_string(), "b".to_string()])
+        );
+    }
+
+    #[test]
+    fn update_children_inserts_new_members() {
+        let mut widget = metadata();
+
+        widget
+            .apply_patch_op(UiWidgetPatchOp::UpdateChildren {
+                widget_id: 2,
+                children: vec![
+                    UiWidgetPatchChild::Existing(3),
+                    UiWidgetPatchChild::New(UiWidget::MetadataSeparator(separator(7))),
+                    UiWidgetPatchChild::Existing(6),
+                ],
+            })
+            .unwrap();
+
+        assert_eq!(member_ids(&widget), vec![3, 7, 6]);
+    }
+
+    #[test]
+    fn update_children_removes_members_not_listed() {
+        let mut widget = metadata();
+
+        widget
+            .apply_patch_op(UiWidgetPatchOp::UpdateChildren {
+                widget_id: 2,
+                children: vec![UiWidgetPatchChild::Existing(6)],
+            })
+            .unwrap();
+
+        assert_eq!(member_ids(&widget), vec![6]);
+    }
+
+    #[test]
+    fn update_children_with_unknown_existing_member_is_rejected() {
+        let mut widget = metadata();
+
+        let result = widget.apply_patch_op(UiWidgetPatchOp::UpdateChildren {
+            widget_id: 2,
+            children: vec![UiWidgetPatchChild::Existing(3), UiWidgetPatchChild::Existing(99)],
+        });
+
+        assert!(result.is_err());
+        assert_eq!(member_ids(&widget), vec![3, 6]);
+    }
+
+    #[test]
+    fn update_children_with_member_of_wrong_type_is_rejected() {
+        let mut widget = metadata();
+
+        let result = widget.apply_patch_op(UiWidgetPatchOp::UpdateChildren {
+            widget_id: 2,
+            children: vec![UiWidgetPatchChild::New(UiWidget::MetadataTagItem(tag_item(7, "c")))],
+        });
+
+        assert!(result.is_err());
+        assert_eq!(member_ids(&widget), vec![3, 6]);
+    }
+
+    #[test]
+    fn update_props_keeps_ordered_members() {
+        let mut widget = metadata();
+
+        widget
+            .apply_patch_op(UiWidgetPatchOp::UpdateProps {
+                widget: UiWidget::MetadataTagList(tag_list(3, "Labels", vec![])),
+            })
+            .unwrap();
+
+        assert_eq!(
+            tags(&widget),
+            ("Labels".to_string(), vec!["a".to_string(), "b".to_string()])
+        );
+    }
+
+    #[test]
+    fn nested_update_children_is_applied_to_matching_widget() {
+        let mut widget = metadata();
+
+        widget
+            .apply_patch_op(UiWidgetPatchOp::UpdateChildren {
+                widget_id: 3,
+                children: vec![
+                    UiWidgetPatchChild::Existing(5),
+                    UiWidgetPatchChild::New(UiWidget::MetadataTagItem(tag_item(7, "c"))),
+                ],
+            })
+            .unwrap();
+
+        assert_eq!(member_ids(&widget), vec![3, 6]);
+        assert_eq!(
+            tags(&widget),
+            ("Tags".to_string(), vec!["b".to_string(), "c".to_string()])
+        );
+    }
+
+    #[test]
+    fn nested_replace_is_applied_to_matching_widget() {
+        let mut widget = metadata();
+
+        widget
+            .apply_patch_op(UiWidgetPatchOp::Replace {
+                widget: UiWidget::MetadataTagItem(tag_item(5, "changed")),
+            })
+            .unwrap();
+
+        assert_eq!(
+            tags(&widget),
+            ("Tags".to_string(), vec!["a".to_string(), "changed".to_string()])
+        );
+    }
+
+    #[test]
+    fn replace_of_unknown_widget_is_rejected() {
+        let mut widget = metadata();
+
+        let result = widget.apply_patch_op(UiWidgetPatchOp::Replace {
+            widget: UiWidget::MetadataTagItem(tag_item(99, "c")),
+        });
+
+        assert!(matches!(result, Err(op) if op.widget_id() == 99));
+    }
+
+    #[test]
+    fn root_content_can_be_replaced() {
+        let mut root = RootWidget {
+            content: Some(RootWidgetMembers::Detail(detail(1, false))),
+        };
+
+        root.apply_patch(vec![UiWidgetPatchOp::Replace {
+            widget: UiWidget::Detail(detail(1, true)),
+        }])
+        .unwrap();
+
+        let Some(RootWidgetMembers::Detail(widget)) = &root.content else {
+            panic!("root should contain detail")
+        };
+
+        assert_eq!(widget.is_loading, Some(true));
+    }
+
+    #[test]
+    fn root_content_props_can_be_updated() {
+        let mut root = RootWidget {
+            content: Some(RootWidgetMembers::Detail(detail(1, false))),
+        };
+
+        root.apply_patch(vec![UiWidgetPatchOp::UpdateProps {
+            widget: UiWidget::Detail(detail(1, true)),
+        }])
+        .unwrap();
+
+        let Some(RootWidgetMembers::Detail(widget)) = &root.content else {
+            panic!("root should contain detail")
+        };
+
+        assert_eq!(widget.is_loading, Some(true));
+    }
+
+    #[test]
+    fn patch_for_unknown_widget_is_rejected() {
+        let mut root = RootWidget {
+            content: Some(RootWidgetMembers::Detail(detail(1, false))),
+        };
+
+        let result = root.apply_patch(vec![UiWidgetPatchOp::Replace {
+            widget: UiWidget::Detail(detail(99, true)),
+        }]);
+
+        assert!(result.is_err());
+
+        let mut empty = RootWidget { content: None };
+
+        let result = empty.apply_patch(vec![UiWidgetPatchOp::UpdateProps {
+            widget: UiWidget::Detail(detail(1, true)),
+        }]);
+
+        assert!(result.is_err());
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use gauntlet_utils::channel::RequestResult;
@@ -9,6 +10,7 @@ use tonic::transport::Channel;
 use crate::model::EntrypointId;
 use crate::model::LocalSaveData;
 use crate::model::PluginId;
+use crate::model::Quicklink;
 use crate::rpc::grpc::RpcBincode;
 use crate::rpc::grpc::RpcSaveLocalPluginRequest;
 use crate::rpc::grpc::rpc_backend_client::RpcBackendClient;
@@ -27,7 +29,16 @@ pub trait BackendForCliApi {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
         action_id: String,
+        arguments: HashMap<String, String>,
     ) -> RequestResult<()>;
+
+    async fn open_deep_link(&self, url: String) -> RequestResult<()>;
+
+    async fn list_quicklinks(&self) -> RequestResult<Vec<Quicklink>>;
+
+    async fn save_quicklink(&self, quicklink: Quicklink) -> RequestResult<String>;
+
+    async fn remove_quicklink(&self, quicklink_id: String) -> RequestResult<()>;
 }
 
 #[tonic::async_trait]
@@ -9,7 +9,9 @@ use crate::model::RootWidget;
 use crate::model::UiRenderLocation;
 use crate::model::UiTheme;
 use crate::model::UiWidgetId;
+use crate::model::UiWidgetPatchOp;
 use crate::model::WindowPositionMode;
+use crate::model::WindowSize;
 
 #[allow(async_fn_in_trait)]
 #[boundary_gen(in_process)]
@@ -24,10 +26,23 @@ pub trait FrontendApi {
         entrypoint_name: String,
         render_location: UiRenderLocation,
         top_level_view: bool,
+        inline_view_generation: Option<u64>,
         container: RootWidget,
         data: HashMap<UiWidgetId, Vec<u8>>,
     ) -> RequestResult<()>;
 
+    async fn patch_view(
+        &self,
+        plugin_id: PluginId,
+        plugin_name: String,
+        entrypoint_id: EntrypointId,
+        entrypoint_name: String,
+        render_location: UiRenderLocation,
+        top_level_view: bool,
+        patch: Vec<UiWidgetPatchOp>,
+        data: HashMap<UiWidgetId, Vec<u8>>,
+    ) -> RequestResult<()>;
+
     async fn toggle_window(&self) -> RequestResult<()>;
 
     async fn hide_window(&self) -> RequestResult<()>;
@@ -58,10 +73,32 @@ pub trait FrontendApi {
         show: bool,
     ) -> RequestResult<()>;
 
+    async fn start_task(
+        &self,
+        plugin_id: PluginId,
+        plugin_name: String,
+        task_id: String,
+        title: String,
+    ) -> RequestResult<()>;
+
+    async fn update_task(
+        &self,
+        plugin_id: PluginId,
+        task_id: String,
+        progress: Option<f64>,
+        status: Option<String>,
+    ) -> RequestResult<()>;
+
+    async fn finish_task(&self, plugin_id: PluginId, task_id: String, error: Option<String>) -> RequestResult<()>;
+
     async fn set_theme(&self, theme: UiTheme) -> RequestResult<()>;
 
     async fn set_window_position_mode(&self, mode: WindowPositionMode) -> RequestResult<()>;
 
+    async fn set_window_size(&self, size: WindowSize) -> RequestResult<()>;
+
+    async fn set_compact_mode(&self, enabled: bool) -> RequestResult<()>;
+
     async fn open_generated_plugin_view(
         &self,
         plugin_id: PluginId,
@@ -69,5 +106,10 @@ pub trait FrontendApi {
         action_index: usize,
     ) -> RequestResult<()>;
 
-    async fn open_plugin_view(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> RequestResult<()>;
+    async fn open_plugin_view(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        arguments: HashMap<String, String>,
+    ) -> RequestResult<()>;
 }
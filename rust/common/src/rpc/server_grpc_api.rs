@@ -1,9 +1,12 @@
+use std::collections::HashMap;
+
 use gauntlet_utils::channel::RequestResult;
 use gauntlet_utils_macros::boundary_gen;
 
 use crate::model::EntrypointId;
 use crate::model::LocalSaveData;
 use crate::model::PluginId;
+use crate::model::Quicklink;
 
 #[allow(async_fn_in_trait)]
 #[boundary_gen(in_process)]
@@ -17,7 +20,16 @@ pub trait ServerGrpcApi {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
         action_id: String,
+        arguments: HashMap<String, String>,
     ) -> RequestResult<()>;
 
+    async fn open_deep_link(&self, url: String) -> RequestResult<()>;
+
     async fn save_local_plugin(&self, path: String) -> RequestResult<LocalSaveData>;
+
+    async fn list_quicklinks(&self) -> RequestResult<Vec<Quicklink>>;
+
+    async fn save_quicklink(&self, quicklink: Quicklink) -> RequestResult<String>;
+
+    async fn remove_quicklink(&self, quicklink_id: String) -> RequestResult<()>;
 }
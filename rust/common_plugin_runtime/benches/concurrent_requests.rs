@@ -0,0 +1,203 @@
+// Compares how long ui renders take while slow asset requests are in flight,
+// when backend handles requests one by one (how it worked before request ids were added to the framing)
+// and when it handles them concurrently.
+//
+// cargo bench -p gauntlet-common-plugin-runtime --bench concurrent_requests
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use bincode::Decode;
+use bincode::Encode;
+use gauntlet_common_plugin_runtime::JsMessageSide;
+use gauntlet_common_plugin_runtime::PendingRequests;
+use gauntlet_common_plugin_runtime::recv_message;
+use gauntlet_common_plugin_runtime::send_message;
+use interprocess::local_socket::ListenerOptions;
+use interprocess::local_socket::tokio::SendHalf;
+use interprocess::local_socket::tokio::Stream;
+use interprocess::local_socket::tokio::prelude::*;
+use tokio::sync::Mutex;
+
+const ASSET_REQUESTS: usize = 20;
+const RENDER_REQUESTS: usize = 20;
+const ASSET_DELAY: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Encode, Decode)]
+enum BenchRequest {
+    GetAssetData,
+    UiRender,
+}
+
+#[derive(Debug, Encode, Decode)]
+struct BenchResponse;
+
+#[derive(Debug, Clone, Copy)]
+enum BackendMode {
+    Sequential,
+    Concurrent,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    for mode in [BackendMode::Sequential, BackendMode::Concurrent] {
+        run(mode).await?;
+    }
+
+    Ok(())
+}
+
+async fn run(mode: BackendMode) -> anyhow::Result<()> {
+    let (backend, runtime) = connect(mode).await?;
+
+    tokio::spawn(backend_loop(backend, mode));
+
+    let (mut recver, sender) = runtime.split();
+    let sender = Arc::new(Mutex::new(sender));
+
+    let pending_requests = Arc::new(PendingRequests::<BenchResponse>::new());
+
+    tokio::spawn({
+        let pending_requests = pending_requests.clone();
+
+        async move {
+            while let Ok((Some(request_id), response)) =
+                recv_message::<BenchResponse>(JsMessageSide::PluginRuntime, &mut recver).await
+            {
+                pending_requests
+                    .complete(request_id, response)
+                    .expect("unable to complete request");
+            }
+        }
+    });
+
+    let start = Instant::now();
+
+    let mut assets = vec![];
+    for _ in 0..ASSET_REQUESTS {
+        assets.push(tokio::spawn(request(
+            sender.clone(),
+            pending_requests.clone(),
+            BenchRequest::GetAssetData,
+        )));
+    }
+
+    let mut renders = vec![];
+    for _ in 0..RENDER_REQUESTS {
+        renders.push(tokio::spawn(request(
+            sender.clone(),
+            pending_requests.clone(),
+            BenchRequest::UiRender,
+        )));
+    }
+
+    let mut render_latencies = vec![];
+    for render in renders {
+        render_latencies.push(render.await??);
+    }
+
+    for asset in assets {
+        asset.await??;
+    }
+
+    let total = start.elapsed();
+
+    render_latencies.sort();
+
+    println!(
+        "{:?}: ui render latency p50 {:?}, max {:?}, total time {:?}",
+        mode,
+        render_latencies[render_latencies.len() / 2],
+        render_latencies[render_latencies.len() - 1],
+        total
+    );
+
+    Ok(())
+}
+
+async fn request(
+    sender: Arc<Mutex<SendHalf>>,
+    pending_requests: Arc<PendingRequests<BenchResponse>>,
+    request: BenchRequest,
+) -> anyhow::Result<Duration> {
+    let start = Instant::now();
+
+    let (request_id, rx) = pending_requests.register();
+
+    {
+        let mut sender = sender.lock().await;
+
+        send_message(JsMessageSide::PluginRuntime, &mut sender, Some(request_id), request).await?;
+    }
+
+    rx.await?;
+
+    Ok(start.elapsed())
+}
+
+async fn backend_loop(backend: Stream, mode: BackendMode) -> anyhow::Result<()> {
+    let (mut recver, sender) = backend.split();
+    let sender = Arc::new(Mutex::new(sender));
+
+    loop {
+        let (request_id, request) = recv_message::<BenchRequest>(JsMessageSide::Backend, &mut recver).await?;
+
+        let handle = {
+            let sender = sender.clone();
+
+            async move {
+                if let BenchRequest::GetAssetData = request {
+                    tokio::time::sleep(ASSET_DELAY).await;
+                }
+
+                let mut sender = sender.lock().await;
+
+                send_message(JsMessageSide::Backend, &mut sender, request_id, BenchResponse).await
+            }
+        };
+
+        match mode {
+            BackendMode::Sequential => handle.await?,
+            BackendMode::Concurrent => {
+                tokio::spawn(handle);
+            }
+        }
+    }
+}
+
+async fn connect(mode: BackendMode) -> anyhow::Result<(Stream, Stream)> {
+    let name_str = format!("gauntlet-bench-{:?}-{}", mode, std::process::id());
+
+    #[cfg(target_os = "windows")]
+    let name = {
+        use interprocess::local_socket::ToNsName;
+
+        name_str
+            .clone()
+            .to_ns_name::<interprocess::local_socket::GenericNamespaced>()?
+    };
+
+    #[cfg(unix)]
+    let socket_file = std::env::temp_dir().join(name_str);
+
+    #[cfg(unix)]
+    let name = {
+        use interprocess::local_socket::ToFsName;
+
+        let _ = std::fs::remove_file(&socket_file);
+
+        socket_file
+            .clone()
+            .to_fs_name::<interprocess::os::unix::local_socket::FilesystemUdSocket>()?
+    };
+
+    let listener = ListenerOptions::new().name(name.clone()).create_tokio()?;
+
+    let (backend, runtime) = tokio::try_join!(listener.accept(), Stream::connect(name))?;
+
+    #[cfg(unix)]
+    let _ = std::fs::remove_file(&socket_file);
+
+    Ok((backend, runtime))
+}
@@ -1,11 +1,14 @@
 use std::collections::HashMap;
 
 use gauntlet_common::model::EntrypointId;
+use gauntlet_common::model::PluginId;
 use gauntlet_common::model::RootWidget;
+use gauntlet_common::model::UiWidgetPatchOp;
 use gauntlet_utils::channel::RequestResult;
 use gauntlet_utils_macros::boundary_gen;
 
 use crate::model::JsClipboardData;
+use crate::model::JsFocusedWindow;
 use crate::model::JsGeneratedSearchItem;
 use crate::model::JsPreferenceUserData;
 use crate::model::JsUiRenderLocation;
@@ -32,7 +35,26 @@ pub trait BackendForPluginRuntimeApi {
     async fn clipboard_write(&self, data: JsClipboardData) -> RequestResult<()>;
     async fn clipboard_write_text(&self, data: String) -> RequestResult<()>;
     async fn clipboard_clear(&self) -> RequestResult<()>;
+    async fn clipboard_paste_text(&self, data: String) -> RequestResult<()>;
+    async fn launch_context_selected_text(&self) -> RequestResult<Option<String>>;
+    async fn launch_context_focused_window(&self) -> RequestResult<Option<JsFocusedWindow>>;
+    async fn plugin_command_call(
+        &self,
+        plugin_id: PluginId,
+        command_id: String,
+        arguments: String,
+    ) -> RequestResult<String>;
+    async fn plugin_command_result(
+        &self,
+        call_id: String,
+        result: Option<String>,
+        error: Option<String>,
+    ) -> RequestResult<()>;
     async fn ui_update_loading_bar(&self, entrypoint_id: EntrypointId, show: bool) -> RequestResult<()>;
+    async fn ui_task_start(&self, task_id: String, title: String) -> RequestResult<()>;
+    async fn ui_task_update(&self, task_id: String, progress: Option<f64>, status: Option<String>)
+    -> RequestResult<()>;
+    async fn ui_task_finish(&self, task_id: String, error: Option<String>) -> RequestResult<()>;
     async fn ui_show_hud(&self, display: String) -> RequestResult<()>;
     async fn ui_hide_window(&self) -> RequestResult<()>;
     async fn ui_show_settings(&self) -> RequestResult<()>;
@@ -51,8 +73,17 @@ pub trait BackendForPluginRuntimeApi {
         entrypoint_name: String,
         render_location: JsUiRenderLocation,
         top_level_view: bool,
+        inline_view_generation: Option<u64>,
         container: RootWidget,
     ) -> RequestResult<()>;
+    async fn ui_render_patch(
+        &self,
+        entrypoint_id: EntrypointId,
+        entrypoint_name: String,
+        render_location: JsUiRenderLocation,
+        top_level_view: bool,
+        patch: Vec<UiWidgetPatchOp>,
+    ) -> RequestResult<()>;
     async fn ui_show_plugin_error_view(
         &self,
         entrypoint_id: EntrypointId,
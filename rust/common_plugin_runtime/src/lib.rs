@@ -1,8 +1,11 @@
+use std::collections::HashMap;
 use std::fmt::Debug;
+use std::sync::Mutex;
 use std::sync::atomic::AtomicU32;
 use std::sync::atomic::Ordering;
 
 use anyhow::Context;
+use anyhow::anyhow;
 use bincode::Decode;
 use bincode::Encode;
 use interprocess::local_socket::tokio::RecvHalf;
@@ -11,6 +14,7 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use tokio::io::AsyncReadExt;
 use tokio::io::AsyncWriteExt;
+use tokio::sync::oneshot;
 
 pub mod api;
 pub mod model;
@@ -24,22 +28,28 @@ pub enum JsMessageSide {
     Backend,
 }
 
-static MESSAGE_ID: AtomicU32 = AtomicU32::new(0);
+// requests and responses carry id in message framing, so that multiple requests can be in flight at the same time
+// and responses can be sent in different order than requests were received.
+// messages that are not part of request-response pair use 0 as id
+const NO_REQUEST_ID: u32 = 0;
 
-pub async fn send_message<T: Encode + Debug>(side: JsMessageSide, send: &mut SendHalf, value: T) -> anyhow::Result<()> {
+pub async fn send_message<T: Encode + Debug>(
+    side: JsMessageSide,
+    send: &mut SendHalf,
+    request_id: Option<u32>,
+    value: T,
+) -> anyhow::Result<()> {
     let encoded: Vec<u8> = bincode::encode_to_vec(&value, bincode::config::standard())?;
 
-    let message_id = MESSAGE_ID.fetch_add(1, Ordering::SeqCst);
-
     tracing::trace!(
         side = debug(&side),
-        "Sending message with id {} and size of {} bytes: {:?}",
-        message_id,
+        "Sending message with request id {:?} and size of {} bytes: {:?}",
+        request_id,
         encoded.len(),
         &value
     );
 
-    send.write_u32(message_id).await?;
+    send.write_u32(request_id.unwrap_or(NO_REQUEST_ID)).await?;
 
     send.write_u32(encoded.len() as u32).await?;
 
@@ -47,20 +57,26 @@ pub async fn send_message<T: Encode + Debug>(side: JsMessageSide, send: &mut Sen
 
     tracing::trace!(
         side = debug(&side),
-        "Message with id {} and size of {} bytes has been sent",
-        message_id,
+        "Message with request id {:?} and size of {} bytes has been sent",
+        request_id,
         encoded.len()
     );
 
     Ok(())
 }
 
-pub async fn recv_message<T: Decode<()> + Debug>(side: JsMessageSide, recv: &mut RecvHalf) -> anyhow::Result<T> {
+pub async fn recv_message<T: Decode<()> + Debug>(
+    side: JsMessageSide,
+    recv: &mut RecvHalf,
+) -> anyhow::Result<(Option<u32>, T)> {
     tracing::trace!(side = debug(&side), "Waiting for next message...");
 
-    let message_id = recv.read_u32().await?;
+    let request_id = match recv.read_u32().await? {
+        NO_REQUEST_ID => None,
+        request_id => Some(request_id),
+    };
 
-    tracing::trace!(side = debug(&side), "Reading message with id: {}", message_id);
+    tracing::trace!(side = debug(&side), "Reading message with request id: {:?}", request_id);
 
     let buf_size = recv.read_u32().await?;
 
@@ -68,15 +84,189 @@ pub async fn recv_message<T: Decode<()> + Debug>(side: JsMessageSide, recv: &mut
 
     recv.read_exact(&mut buffer).await?;
 
-    let (decoded, _) = bincode::decode_from_slice(&buffer[..], bincode::config::standard())
-        .context(format!("Unable to deserialize message with id: {}", message_id))?;
+    let (decoded, _) = bincode::decode_from_slice(&buffer[..], bincode::config::standard()).context(format!(
+        "Unable to deserialize message with request id: {:?}",
+        request_id
+    ))?;
 
     tracing::trace!(
         side = debug(&side),
-        "Received message with id {}: {:?}",
-        message_id,
+        "Received message with request id {:?}: {:?}",
+        request_id,
         &decoded
     );
 
-    Ok(decoded)
+    Ok((request_id, decoded))
+}
+
+pub struct PendingRequests<T> {
+    next_request_id: AtomicU32,
+    pending: Mutex<HashMap<u32, oneshot::Sender<T>>>,
+}
+
+impl<T> PendingRequests<T> {
+    pub fn new() -> Self {
+        Self {
+            next_request_id: AtomicU32::new(NO_REQUEST_ID + 1),
+            pending: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn register(&self) -> (u32, oneshot::Receiver<T>) {
+        let request_id = loop {
+            let request_id = self.next_request_id.fetch_add(1, Ordering::SeqCst);
+
+            // skip reserved id after wrapping around
+            if request_id != NO_REQUEST_ID {
+                break request_id;
+            }
+        };
+
+        let (sender, receiver) = oneshot::channel();
+
+        let mut pending = self.pending.lock().expect("lock is poisoned");
+
+        pending.insert(request_id, sender);
+
+        (request_id, receiver)
+    }
+
+    pub fn complete(&self, request_id: u32, value: T) -> anyhow::Result<()> {
+        let sender = {
+            let mut pending = self.pending.lock().expect("lock is poisoned");
+
+            pending.remove(&request_id)
+        };
+
+        let Some(sender) = sender else {
+            return Err(anyhow!(
+                "Received response without corresponding request: {}",
+                request_id
+            ));
+        };
+
+        sender
+            .send(value)
+            .map_err(|_| anyhow!("Request {} was dropped before response arrived", request_id))
+    }
+
+    // dropping senders makes everyone waiting for response receive an error
+    pub fn cancel_all(&self) {
+        let mut pending = self.pending.lock().expect("lock is poisoned");
+
+        pending.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn request_ids_skip_reserved_id_after_wrapping_around() {
+        let requests = PendingRequests::<()>::new();
+
+        requests.next_request_id.store(u32::MAX, Ordering::SeqCst);
+
+        let (last_id, _last) = requests.register();
+        let (wrapped_id, _wrapped) = requests.register();
+
+        assert_eq!(last_id, u32::MAX);
+        assert_eq!(wrapped_id, NO_REQUEST_ID + 1);
+    }
+
+    #[test]
+    fn request_ids_are_unique() {
+        let requests = PendingRequests::<()>::new();
+
+        let (first_id, _first) = requests.register();
+        let (second_id, _second) = requests.register();
+
+        assert_ne!(first_id, NO_REQUEST_ID);
+        assert_ne!(first_id, second_id);
+    }
+
+    #[tokio::test]
+    async fn completed_request_receives_response() {
+        let requests = PendingRequests::new();
+
+        let (first_id, first) = requests.register();
+        let (second_id, second) = requests.register();
+
+        // responses can arrive in different order than requests were sent
+        requests.complete(second_id, "second").unwrap();
+        requests.complete(first_id, "first").unwrap();
+
+        assert_eq!(first.await.unwrap(), "first");
+        assert_eq!(second.await.unwrap(), "second");
+    }
+
+    #[test]
+    fn completing_unknown_request_fails() {
+        let requests = PendingRequests::new();
+
+        let (request_id, _receiver) = requests.register();
+
+        assert!(requests.complete(request_id + 1, ()).is_err());
+        assert!(requests.complete(NO_REQUEST_ID, ()).is_err());
+
+        // request is still waiting for its own response
+        assert!(requests.complete(request_id, ()).is_ok());
+    }
+
+    #[test]
+    fn completing_request_twice_fails() {
+        let requests = PendingRequests::new();
+
+        let (request_id, _receiver) = requests.register();
+
+        assert!(requests.complete(request_id, ()).is_ok());
+        assert!(requests.complete(request_id, ()).is_err());
+    }
+
+    #[test]
+    fn completing_request_which_is_no_longer_awaited_fails() {
+        let requests = PendingRequests::new();
+
+        let (request_id, receiver) = requests.register();
+
+        drop(receiver);
+
+        assert!(requests.complete(request_id, ()).is_err());
+    }
+
+    #[test]
+    fn completing_cancelled_request_fails() {
+        let requests = PendingRequests::new();
+
+        let (request_id, _receiver) = requests.register();
+
+        requests.cancel_all();
+
+        assert!(requests.complete(request_id, ()).is_err());
+    }
+
+    #[tokio::test]
+    async fn cancel_all_wakes_all_waiting_requests_with_error() {
+        let requests = PendingRequests::<()>::new();
+
+        let waiting: Vec<_> = (0..3)
+            .map(|_| {
+                let (_, receiver) = requests.register();
+
+                tokio::spawn(receiver)
+            })
+            .collect();
+
+        requests.cancel_all();
+
+        for handle in waiting {
+            assert!(handle.await.unwrap().is_err());
+        }
+
+        // ids keep increasing after cancellation, so late responses to cancelled requests are not mixed up
+        let (request_id, _receiver) = requests.register();
+
+        assert_eq!(request_id, NO_REQUEST_ID + 4);
+    }
 }
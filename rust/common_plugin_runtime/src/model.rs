@@ -19,6 +19,9 @@ pub enum JsEvent {
     OpenView {
         #[serde(rename = "entrypointId")]
         entrypoint_id: String,
+        arguments: HashMap<String, String>,
+        #[serde(rename = "searchText")]
+        search_text: Option<String>,
     },
     CloseView,
     PopView {
@@ -28,6 +31,7 @@ pub enum JsEvent {
     RunCommand {
         #[serde(rename = "entrypointId")]
         entrypoint_id: String,
+        arguments: HashMap<String, String>,
     },
     RunGeneratedEntrypoint {
         #[serde(rename = "entrypointId")]
@@ -60,8 +64,25 @@ pub enum JsEvent {
     OpenInlineView {
         #[serde(rename = "text")]
         text: String,
+        #[serde(rename = "generation")]
+        generation: u64,
+        #[serde(rename = "entrypointId")]
+        entrypoint_id: Option<String>,
     },
     RefreshSearchIndex,
+    CancelTask {
+        #[serde(rename = "taskId")]
+        task_id: String,
+    },
+    CallExportedCommand {
+        #[serde(rename = "callId")]
+        call_id: String,
+        #[serde(rename = "commandId")]
+        command_id: String,
+        // json object
+        arguments: String,
+    },
+    ReplaceView,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Encode, Decode)]
@@ -105,15 +126,24 @@ pub struct JsInit {
     pub plugin_uuid: String,
     pub code: JsPluginCode,
     pub permissions: JsPluginPermissions,
-    pub inline_view_entrypoint_id: Option<String>,
+    pub inline_view_entrypoint_ids: Vec<String>,
     pub entrypoint_names: HashMap<EntrypointId, String>,
     pub dev_plugin: bool,
     pub home_dir: String,
     pub local_storage_dir: String,
     pub plugin_cache_dir: String,
     pub plugin_data_dir: String,
+    pub icon_cache_dir: String,
+    pub calculator_modules_dir: String,
     pub stdout_file: Option<String>,
     pub stderr_file: Option<String>,
+    pub sandbox: Option<JsPluginSandbox>,
+}
+
+// os-level restrictions applied on top of deno permissions, only supported on linux
+#[derive(Debug, Encode, Decode)]
+pub struct JsPluginSandbox {
+    pub isolate_network: bool,
 }
 
 #[derive(Debug, Encode, Decode)]
@@ -158,6 +188,7 @@ pub struct JsGeneratedSearchItem {
     pub entrypoint_icon: Option<Vec<u8>>,
     pub entrypoint_actions: Vec<JsGeneratedSearchItemAction>,
     pub entrypoint_accessories: Vec<JsGeneratedSearchItemAccessory>,
+    pub entrypoint_keywords: Vec<String>,
 }
 
 impl fmt::Debug for JsGeneratedSearchItem {
@@ -170,6 +201,7 @@ impl fmt::Debug for JsGeneratedSearchItem {
             .field("entrypoint_uuid", &self.entrypoint_uuid)
             .field("entrypoint_actions", &self.entrypoint_actions)
             .field("entrypoint_accessories", &self.entrypoint_accessories)
+            .field("entrypoint_keywords", &self.entrypoint_keywords)
             .finish()
     }
 }
@@ -216,3 +248,9 @@ pub struct JsClipboardData {
     pub text_data: Option<String>,
     pub png_data: Option<Vec<u8>>,
 }
+
+#[derive(Debug, Encode, Decode)]
+pub struct JsFocusedWindow {
+    pub app_id: Option<String>,
+    pub title: Option<String>,
+}
@@ -968,6 +968,12 @@ pub fn create_component_model() -> Vec<Component> {
                 OptionalKind::Yes,
                 component_ref(&action_panel_component, Arity::ZeroOrOne),
             ),
+            event(
+                "onDrop",
+                mark_doc!("/detail/props/onDrop.md"),
+                OptionalKind::Yes,
+                [property("path", "".to_string(), OptionalKind::No, PropertyType::String)],
+            ),
         ],
         children_members(
             [],
@@ -1191,6 +1197,12 @@ pub fn create_component_model() -> Vec<Component> {
                 OptionalKind::Yes,
                 component_ref(&action_panel_component, Arity::ZeroOrOne),
             ),
+            event(
+                "onDrop",
+                mark_doc!("/form/props/onDrop.md"),
+                OptionalKind::Yes,
+                [property("path", "".to_string(), OptionalKind::No, PropertyType::String)],
+            ),
         ],
         children_members(
             [
@@ -1356,6 +1368,93 @@ pub fn create_component_model() -> Vec<Component> {
         children_none(),
     );
 
+    let search_bar_dropdown_item_component = component(
+        "search_bar_dropdown_item",
+        mark_doc!("/search_bar_dropdown_item/description.md"),
+        "SearchBarDropdownItem",
+        [
+            property(
+                "value",
+                mark_doc!("/search_bar_dropdown_item/props/value.md"),
+                OptionalKind::No,
+                PropertyType::String,
+            ),
+            property(
+                "title",
+                mark_doc!("/search_bar_dropdown_item/props/title.md"),
+                OptionalKind::No,
+                PropertyType::String,
+            ),
+        ],
+        children_none(),
+    );
+
+    let search_bar_dropdown_section_component = component(
+        "search_bar_dropdown_section",
+        mark_doc!("/search_bar_dropdown_section/description.md"),
+        "SearchBarDropdownSection",
+        [property(
+            "title",
+            mark_doc!("/search_bar_dropdown_section/props/title.md"),
+            OptionalKind::Yes,
+            PropertyType::String,
+        )],
+        children_members(
+            [member("Item", &search_bar_dropdown_item_component, Arity::ZeroOrMore)],
+            [],
+        ),
+    );
+
+    let search_bar_dropdown_component = component(
+        "search_bar_dropdown",
+        mark_doc!("/search_bar_dropdown/description.md"),
+        "SearchBarDropdown",
+        [
+            property(
+                "value",
+                mark_doc!("/search_bar_dropdown/props/value.md"),
+                OptionalKind::Yes,
+                PropertyType::String,
+            ),
+            property(
+                "defaultValue",
+                mark_doc!("/search_bar_dropdown/props/defaultValue.md"),
+                OptionalKind::Yes,
+                PropertyType::String,
+            ),
+            property(
+                "placeholder",
+                mark_doc!("/search_bar_dropdown/props/placeholder.md"),
+                OptionalKind::Yes,
+                PropertyType::String,
+            ),
+            property(
+                "storageKey",
+                mark_doc!("/search_bar_dropdown/props/storageKey.md"),
+                OptionalKind::Yes,
+                PropertyType::String,
+            ),
+            event(
+                "onChange",
+                mark_doc!("/search_bar_dropdown/props/onChange.md"),
+                OptionalKind::Yes,
+                [property(
+                    "value",
+                    "".to_string(),
+                    OptionalKind::No,
+                    PropertyType::String,
+                )],
+            ),
+        ],
+        children_members(
+            [
+                member("Item", &search_bar_dropdown_item_component, Arity::ZeroOrMore),
+                member("Section", &search_bar_dropdown_section_component, Arity::ZeroOrMore),
+            ],
+            [],
+        ),
+    );
+
     let list_item_component = component(
         "list_item",
         mark_doc!("/list_item/description.md"),
@@ -1401,7 +1500,7 @@ pub fn create_component_model() -> Vec<Component> {
                 },
             ),
         ],
-        children_none(),
+        children_members([], [member("Detail", &detail_component, Arity::ZeroOrOne)]),
     );
 
     let list_section_component = component(
@@ -1459,6 +1558,24 @@ pub fn create_component_model() -> Vec<Component> {
                 OptionalKind::YesButComplicated,
                 PropertyType::String,
             ),
+            event(
+                "onLoadMore",
+                mark_doc!("/list/props/onLoadMore.md"),
+                OptionalKind::Yes,
+                [],
+            ),
+            property(
+                "isShowingDetail",
+                mark_doc!("/list/props/isShowingDetail.md"),
+                OptionalKind::Yes,
+                PropertyType::Boolean,
+            ),
+            event(
+                "onDrop",
+                mark_doc!("/list/props/onDrop.md"),
+                OptionalKind::Yes,
+                [property("path", "".to_string(), OptionalKind::No, PropertyType::String)],
+            ),
         ],
         children_members(
             [
@@ -1467,6 +1584,7 @@ pub fn create_component_model() -> Vec<Component> {
             ],
             [
                 member("SearchBar", &search_bar_component, Arity::ZeroOrOne),
+                member("Dropdown", &search_bar_dropdown_component, Arity::ZeroOrOne),
                 member("EmptyView", &empty_view_component, Arity::ZeroOrOne),
                 member("Detail", &detail_component, Arity::ZeroOrOne),
             ],
@@ -1578,6 +1696,18 @@ pub fn create_component_model() -> Vec<Component> {
                 OptionalKind::YesButComplicated,
                 PropertyType::String,
             ),
+            event(
+                "onLoadMore",
+                mark_doc!("/grid/props/onLoadMore.md"),
+                OptionalKind::Yes,
+                [],
+            ),
+            event(
+                "onDrop",
+                mark_doc!("/grid/props/onDrop.md"),
+                OptionalKind::Yes,
+                [property("path", "".to_string(), OptionalKind::No, PropertyType::String)],
+            ),
         ],
         children_members(
             [
@@ -1586,6 +1716,7 @@ pub fn create_component_model() -> Vec<Component> {
             ],
             [
                 member("SearchBar", &search_bar_component, Arity::ZeroOrOne),
+                member("Dropdown", &search_bar_dropdown_component, Arity::ZeroOrOne),
                 member("EmptyView", &empty_view_component, Arity::ZeroOrOne),
             ],
         ),
@@ -1703,6 +1834,9 @@ pub fn create_component_model() -> Vec<Component> {
         accessory_icon_component,
         accessory_text_component,
         search_bar_component,
+        search_bar_dropdown_item_component,
+        search_bar_dropdown_section_component,
+        search_bar_dropdown_component,
         list_item_component,
         list_section_component,
         list_component,
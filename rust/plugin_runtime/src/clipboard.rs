@@ -65,6 +65,19 @@ pub async fn clipboard_write(
     api.clipboard_write(clipboard_data).await.map_err(Into::into)
 }
 
+#[op2(async)]
+pub async fn clipboard_paste_text(state: Rc<RefCell<OpState>>, #[string] data: String) -> Result<(), GauntletJsError> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.clipboard_paste_text(data).await.map_err(Into::into)
+}
+
 #[op2(async)]
 pub async fn clipboard_write_text(state: Rc<RefCell<OpState>>, #[string] data: String) -> Result<(), GauntletJsError> {
     let api = {
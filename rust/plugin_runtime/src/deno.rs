@@ -44,6 +44,7 @@ use tokio::sync::mpsc::Receiver;
 use crate::assets::asset_data;
 use crate::assets::asset_data_blocking;
 use crate::clipboard::clipboard_clear;
+use crate::clipboard::clipboard_paste_text;
 use crate::clipboard::clipboard_read;
 use crate::clipboard::clipboard_read_text;
 use crate::clipboard::clipboard_write;
@@ -56,18 +57,31 @@ use crate::environment::environment_plugin_cache_dir;
 use crate::environment::environment_plugin_data_dir;
 use crate::events::EventReceiver;
 use crate::events::op_plugin_get_pending_event;
+use crate::launch_context::launch_context_focused_window;
+use crate::launch_context::launch_context_selected_text;
 use crate::logs::op_log_debug;
 use crate::logs::op_log_error;
 use crate::logs::op_log_info;
 use crate::logs::op_log_trace;
 use crate::logs::op_log_warn;
 use crate::permissions::permissions_to_deno;
+use crate::plugin_commands::plugin_command_call;
+use crate::plugin_commands::plugin_command_result;
 use crate::plugin_data::PluginData;
 use crate::plugins::applications::ApplicationContext;
 use crate::plugins::applications::current_os;
 use crate::plugins::applications::wayland;
+use crate::plugins::files::FileSearchContext;
+use crate::plugins::files::file_search;
+use crate::plugins::files::file_search_configure;
+use crate::plugins::files::file_search_open;
+use crate::plugins::files::file_search_reveal;
+use crate::plugins::files::file_search_status;
 use crate::plugins::numbat::NumbatContext;
+use crate::plugins::numbat::clear_numbat_history;
+use crate::plugins::numbat::numbat_history;
 use crate::plugins::numbat::run_numbat;
+use crate::plugins::numbat::save_numbat;
 use crate::plugins::settings::open_settings;
 use crate::preferences::entrypoint_preferences_required;
 use crate::preferences::get_entrypoint_preferences;
@@ -78,11 +92,15 @@ use crate::ui::fetch_action_id_for_shortcut;
 use crate::ui::hide_window;
 use crate::ui::op_component_model;
 use crate::ui::op_entrypoint_names;
-use crate::ui::op_inline_view_entrypoint_id;
+use crate::ui::op_inline_view_entrypoint_ids;
+use crate::ui::op_react_patch_view;
 use crate::ui::op_react_replace_view;
 use crate::ui::show_hud;
 use crate::ui::show_plugin_error_view;
 use crate::ui::show_preferences_required_view;
+use crate::ui::task_finish;
+use crate::ui::task_start;
+use crate::ui::task_update;
 use crate::ui::update_loading_bar;
 
 pub struct CustomModuleLoader {
@@ -270,7 +288,8 @@ deno_core::extension!(
 
         // ui
         op_react_replace_view,
-        op_inline_view_entrypoint_id,
+        op_react_patch_view,
+        op_inline_view_entrypoint_ids,
         op_entrypoint_names,
         show_plugin_error_view,
         show_preferences_required_view,
@@ -279,6 +298,9 @@ deno_core::extension!(
         show_hud,
         hide_window,
         update_loading_bar,
+        task_start,
+        task_update,
+        task_finish,
 
         // preferences
         get_plugin_preferences,
@@ -295,6 +317,15 @@ deno_core::extension!(
         clipboard_write,
         clipboard_write_text,
         clipboard_clear,
+        clipboard_paste_text,
+
+        // launch context
+        launch_context_selected_text,
+        launch_context_focused_window,
+
+        // plugin commands
+        plugin_command_call,
+        plugin_command_result,
 
         // plugin environment
         environment_gauntlet_version,
@@ -359,11 +390,21 @@ deno_core::extension!(
     ops = [
         // plugins numbat
         run_numbat,
+        save_numbat,
+        numbat_history,
+        clear_numbat_history,
 
         // plugins applications
         current_os,
         wayland,
 
+        // plugins files
+        file_search_configure,
+        file_search,
+        file_search_status,
+        file_search_open,
+        file_search_reveal,
+
         // plugins settings
         open_settings,
     ],
@@ -375,10 +416,12 @@ deno_core::extension!(
     options = {
         numbat_context: NumbatContext,
         application_context: ApplicationContext,
+        file_search_context: FileSearchContext,
     },
     state = |state, options| {
         state.put(options.numbat_context);
         state.put(options.application_context);
+        state.put(options.file_search_context);
     },
 );
 
@@ -477,6 +520,10 @@ pub async fn start_js_runtime(
         dev::gauntlet_esm::init()
     };
 
+    let calculator_modules_dir = PathBuf::from(&init.calculator_modules_dir);
+    let plugin_data_dir = PathBuf::from(&init.plugin_data_dir);
+    let plugin_cache_dir = PathBuf::from(&init.plugin_cache_dir);
+
     let mut extensions = vec![
         gauntlet::init(
             EventReceiver::new(event_stream),
@@ -485,9 +532,10 @@ pub async fn start_js_runtime(
                 init.plugin_uuid,
                 init.plugin_cache_dir,
                 init.plugin_data_dir,
-                init.inline_view_entrypoint_id,
+                init.inline_view_entrypoint_ids,
                 init.entrypoint_names,
                 PathBuf::from(init.home_dir),
+                PathBuf::from(init.icon_cache_dir),
             ),
             ComponentModel::new(),
             api,
@@ -498,8 +546,9 @@ pub async fn start_js_runtime(
 
     if init.plugin_id.to_string() == "bundled://gauntlet" {
         extensions.push(gauntlet_internal_all::init(
-            NumbatContext::new(),
+            NumbatContext::new(calculator_modules_dir, plugin_data_dir, plugin_cache_dir.clone()),
             ApplicationContext::new()?,
+            FileSearchContext::new(plugin_cache_dir.join("file_index")),
         ));
 
         #[cfg(target_os = "macos")]
@@ -4,11 +4,15 @@ use std::rc::Rc;
 use anyhow::anyhow;
 use deno_core::OpState;
 use deno_core::op2;
+use deno_runtime::deno_permissions::PermissionsContainer;
 use gauntlet_common_plugin_runtime::model::JsEvent;
+use gauntlet_common_plugin_runtime::model::JsUiPropertyValue;
 use tokio::sync::mpsc::Receiver;
 
 use crate::deno::GauntletJsError;
 
+const PERMISSION_API_NAME: &str = "Gauntlet.onDrop";
+
 pub struct EventReceiver {
     event_stream: Rc<RefCell<Receiver<JsEvent>>>,
 }
@@ -27,12 +31,212 @@ pub async fn op_plugin_get_pending_event(state: Rc<RefCell<OpState>>) -> Result<
     let event_stream = { state.borrow().borrow::<EventReceiver>().event_stream.clone() };
 
     let mut event_stream = event_stream.borrow_mut();
-    let event = event_stream
-        .recv()
-        .await
-        .ok_or_else(|| anyhow!("event stream was suddenly closed"))?;
 
-    tracing::trace!("Received plugin event {:?}", event);
+    loop {
+        let event = event_stream
+            .recv()
+            .await
+            .ok_or_else(|| anyhow!("event stream was suddenly closed"))?;
+
+        tracing::trace!("Received plugin event {:?}", event);
+
+        let allowed = {
+            let mut state = state.borrow_mut();
+
+            is_drop_allowed(state.borrow_mut::<PermissionsContainer>(), &event)
+        };
+
+        if !allowed {
+            continue;
+        }
+
+        return Ok(event);
+    }
+}
+
+// only files inside locations allowed by filesystem.read permission
+// in plugin manifest are passed to the plugin when dropped onto the view
+fn is_drop_allowed(permissions: &mut PermissionsContainer, event: &JsEvent) -> bool {
+    let JsEvent::ViewEvent {
+        event_name,
+        event_arguments,
+        ..
+    } = event
+    else {
+        return true;
+    };
+
+    if event_name != "onDrop" {
+        return true;
+    }
+
+    let [JsUiPropertyValue::String { value: path }] = event_arguments.as_slice() else {
+        return false;
+    };
+
+    match permissions.check_read(path, PERMISSION_API_NAME) {
+        Ok(_) => true,
+        Err(err) => {
+            tracing::warn!("Dropped file is not allowed by plugin permissions: {:?}", err);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gauntlet_common_plugin_runtime::model::JsPluginPermissions;
+    use gauntlet_common_plugin_runtime::model::JsPluginPermissionsExec;
+    use gauntlet_common_plugin_runtime::model::JsPluginPermissionsFileSystem;
+
+    use super::*;
+    use crate::permissions::permissions_to_deno;
+
+    struct TestDirs {
+        root: std::path::PathBuf,
+    }
+
+    impl TestDirs {
+        fn new(name: &str) -> Self {
+            let root = std::env::temp_dir().join(format!("gauntlet-drop-test-{}-{}", std::process::id(), name));
+
+            let _ = std::fs::remove_dir_all(&root);
+
+            for dir in ["home", "data", "cache", "home/Documents", "home/Private"] {
+                std::fs::create_dir_all(root.join(dir)).unwrap();
+            }
+
+            Self { root }
+        }
+
+        fn path(&self, path: &str) -> String {
+            self.root.join(path).to_str().unwrap().to_owned()
+        }
+
+        fn permissions(&self, read: Vec<String>) -> PermissionsContainer {
+            let permissions = JsPluginPermissions {
+                environment: vec![],
+                network: vec![],
+                filesystem: JsPluginPermissionsFileSystem { read, write: vec![] },
+                exec: JsPluginPermissionsExec {
+                    command: vec![],
+                    executable: vec![],
+                },
+                system: vec![],
+                main_search_bar: vec![],
+            };
+
+            permissions_to_deno(
+                &permissions,
+                &self.root.join("home"),
+                &self.root.join("data"),
+                &self.root.join("cache"),
+            )
+            .unwrap()
+        }
+    }
+
+    impl Drop for TestDirs {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.root);
+        }
+    }
+
+    fn event(event_name: &str, event_arguments: Vec<JsUiPropertyValue>) -> JsEvent {
+        JsEvent::ViewEvent {
+            widget_id: 1,
+            event_name: event_name.to_owned(),
+            event_arguments,
+        }
+    }
+
+    fn drop_event(path: String) -> JsEvent {
+        event("onDrop", vec![JsUiPropertyValue::String { value: path }])
+    }
+
+    #[test]
+    fn drop_inside_allowed_location_is_passed() {
+        let dirs = TestDirs::new("allowed");
+
+        let mut permissions = dirs.permissions(vec![dirs.path("home/Documents")]);
+
+        assert!(is_drop_allowed(
+            &mut permissions,
+            &drop_event(dirs.path("home/Documents"))
+        ));
+        assert!(is_drop_allowed(
+            &mut permissions,
+            &drop_event(dirs.path("home/Documents/report.pdf"))
+        ));
+    }
+
+    #[test]
+    fn drop_outside_allowed_location_is_ignored() {
+        let dirs = TestDirs::new("denied");
+
+        let mut permissions = dirs.permissions(vec![dirs.path("home/Documents")]);
+
+        assert!(!is_drop_allowed(
+            &mut permissions,
+            &drop_event(dirs.path("home/Private/passwords.txt"))
+        ));
+        assert!(!is_drop_allowed(&mut permissions, &drop_event(dirs.path("home"))));
+    }
+
+    #[test]
+    fn drop_is_ignored_without_read_permission() {
+        let dirs = TestDirs::new("no-permission");
+
+        let mut permissions = dirs.permissions(vec![]);
+
+        assert!(!is_drop_allowed(
+            &mut permissions,
+            &drop_event(dirs.path("home/Documents/report.pdf"))
+        ));
+    }
+
+    #[test]
+    fn drop_inside_location_from_variable_is_passed() {
+        let dirs = TestDirs::new("variable");
+
+        let mut permissions = dirs.permissions(vec!["{common:plugin-data}".to_owned()]);
+
+        assert!(is_drop_allowed(
+            &mut permissions,
+            &drop_event(dirs.path("data/notes.txt"))
+        ));
+        assert!(!is_drop_allowed(
+            &mut permissions,
+            &drop_event(dirs.path("cache/notes.txt"))
+        ));
+    }
+
+    #[test]
+    fn drop_with_unexpected_arguments_is_ignored() {
+        let dirs = TestDirs::new("arguments");
+
+        let mut permissions = dirs.permissions(vec![dirs.path("home")]);
+
+        assert!(!is_drop_allowed(&mut permissions, &event("onDrop", vec![])));
+        assert!(!is_drop_allowed(
+            &mut permissions,
+            &event("onDrop", vec![JsUiPropertyValue::Number { value: 1.0 }])
+        ));
+    }
+
+    #[test]
+    fn other_events_are_always_passed() {
+        let dirs = TestDirs::new("other-events");
+
+        let mut permissions = dirs.permissions(vec![]);
 
-    Ok(event)
+        let other = event(
+            "onClick",
+            vec![JsUiPropertyValue::String {
+                value: dirs.path("home/Private/passwords.txt"),
+            }],
+        );
+
+        assert!(is_drop_allowed(&mut permissions, &other));
+    }
 }
@@ -0,0 +1,47 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use deno_core::OpState;
+use deno_core::op2;
+use gauntlet_common_plugin_runtime::api::BackendForPluginRuntimeApi;
+use gauntlet_common_plugin_runtime::api::BackendForPluginRuntimeApiProxy;
+
+use crate::deno::GauntletJsError;
+use crate::model::DenoOutFocusedWindow;
+
+#[op2(async)]
+#[string]
+pub async fn launch_context_selected_text(state: Rc<RefCell<OpState>>) -> Result<Option<String>, GauntletJsError> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.launch_context_selected_text().await.map_err(Into::into)
+}
+
+#[op2(async)]
+#[serde]
+pub async fn launch_context_focused_window(
+    state: Rc<RefCell<OpState>>,
+) -> Result<Option<DenoOutFocusedWindow>, GauntletJsError> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    let result = api.launch_context_focused_window().await?;
+
+    Ok(result.map(|window| {
+        DenoOutFocusedWindow {
+            app_id: window.app_id,
+            title: window.title,
+        }
+    }))
+}
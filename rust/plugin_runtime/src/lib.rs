@@ -5,21 +5,24 @@ mod deno;
 mod entrypoint_generators;
 mod environment;
 mod events;
+mod launch_context;
 mod logs;
 mod model;
 mod model_deserialization;
 mod permissions;
+mod plugin_commands;
 mod plugin_data;
 mod plugins;
 mod preferences;
+#[cfg(target_os = "linux")]
+mod sandbox;
 mod search;
 mod ui;
 
-use std::ops::Deref;
-
 use anyhow::Context;
 use anyhow::anyhow;
 use gauntlet_common_plugin_runtime::JsMessageSide;
+use gauntlet_common_plugin_runtime::PendingRequests;
 use gauntlet_common_plugin_runtime::api::BackendForPluginRuntimeApiProxy;
 use gauntlet_common_plugin_runtime::api::BackendForPluginRuntimeApiRequestData;
 use gauntlet_common_plugin_runtime::api::BackendForPluginRuntimeApiResponseData;
@@ -35,14 +38,14 @@ use interprocess::local_socket::tokio::SendHalf;
 use interprocess::local_socket::tokio::Stream;
 use interprocess::local_socket::tokio::prelude::*;
 use tokio::runtime::Handle;
-use tokio::sync::Mutex;
 use tokio::sync::mpsc::Receiver;
 use tokio::sync::mpsc::Sender;
 use tokio::sync::mpsc::channel;
-use tokio::sync::oneshot;
 use tokio_util::sync::CancellationToken;
 
 use crate::deno::start_js_runtime;
+#[cfg(target_os = "linux")]
+pub use crate::sandbox::isolate_network;
 
 pub fn run_plugin_runtime(socket_name: String) {
     #[cfg(target_os = "linux")]
@@ -81,12 +84,17 @@ async fn run_outer(socket_name: String) -> anyhow::Result<()> {
         BackendForPluginRuntimeApiResponseData,
     >();
     let (event_sender, event_receiver) = channel::<JsEvent>(10);
-    let response_oneshot = Mutex::new(None);
+    let pending_requests = PendingRequests::new();
 
-    let init = recv_message::<JsInit>(JsMessageSide::PluginRuntime, &mut recver).await?;
+    let (_, init) = recv_message::<JsInit>(JsMessageSide::PluginRuntime, &mut recver).await?;
 
     let plugin_id = init.plugin_id.clone();
 
+    #[cfg(target_os = "linux")]
+    if let Some(sandbox) = &init.sandbox {
+        crate::sandbox::apply_sandbox(&init, sandbox)?;
+    }
+
     let api = BackendForPluginRuntimeApiProxy::new(request_sender);
 
     let handle = Handle::current();
@@ -98,7 +106,7 @@ async fn run_outer(socket_name: String) -> anyhow::Result<()> {
         _ = {
              tokio::task::unconstrained(async {
                 loop {
-                    if let Err(err) = message_loop(&mut recver, &event_sender, &response_oneshot, stop_token.clone()).await {
+                    if let Err(err) = message_loop(&mut recver, &event_sender, &pending_requests, stop_token.clone()).await {
                         tracing::error!("Message loop has returned an error: {:?}", err);
                         break;
                     }
@@ -110,7 +118,7 @@ async fn run_outer(socket_name: String) -> anyhow::Result<()> {
         _ = {
              tokio::task::unconstrained(async {
                 loop {
-                    if let Err(err) = request_loop(&mut sender, &mut request_receiver, &response_oneshot).await {
+                    if let Err(err) = request_loop(&mut sender, &mut request_receiver, &pending_requests).await {
                         tracing::error!("Request loop has returned an error: {:?}", err);
                         break;
                     }
@@ -126,9 +134,13 @@ async fn run_outer(socket_name: String) -> anyhow::Result<()> {
         }
     }
 
+    // nobody is going to respond to requests which are still in flight
+    pending_requests.cancel_all();
+
     send_message(
         JsMessageSide::PluginRuntime,
         &mut sender,
+        None,
         JsPluginRuntimeMessage::Stopped,
     )
     .await?;
@@ -194,42 +206,35 @@ async fn request_loop(
         BackendForPluginRuntimeApiRequestData,
         BackendForPluginRuntimeApiResponseData,
     >,
-    response_oneshot: &Mutex<Option<oneshot::Sender<Result<BackendForPluginRuntimeApiResponseData, String>>>>,
+    pending_requests: &PendingRequests<Result<BackendForPluginRuntimeApiResponseData, String>>,
 ) -> anyhow::Result<()> {
     let (request, responder) = request_receiver.recv().await;
 
     tracing::trace!("Received request {:?}", &request);
 
-    let rx = {
-        let mut response_oneshot = response_oneshot.lock().await;
-
-        let None = response_oneshot.deref() else {
-            return Err(anyhow!(
-                "Trying to set response one shot while previous is not fulfilled"
-            ));
-        };
-
-        let (tx, rx) = oneshot::channel::<Result<BackendForPluginRuntimeApiResponseData, String>>();
-
-        *response_oneshot = Some(tx);
-
-        rx
-    };
+    let (request_id, rx) = pending_requests.register();
 
     send_message(
         JsMessageSide::PluginRuntime,
         send,
+        Some(request_id),
         JsPluginRuntimeMessage::Request(request),
     )
     .await?;
 
-    tracing::trace!("Waiting for oneshot response...");
+    // response is awaited separately, so that next requests can be sent while this one is being handled
+    tokio::spawn(async move {
+        tracing::trace!("Waiting for response to request with id {}...", request_id);
 
-    let response = rx.await?;
+        let response = match rx.await {
+            Ok(response) => response.map_err(|err| anyhow!("{}", err)),
+            Err(_) => Err(anyhow!("Request was cancelled because plugin runtime is stopping")),
+        };
 
-    tracing::trace!("Sending response request {:?}", &response);
+        tracing::trace!("Sending response request {:?}", &response);
 
-    responder.respond(response.map_err(|err| anyhow::anyhow!("{}", err)));
+        responder.respond(response);
+    });
 
     Ok(())
 }
@@ -237,7 +242,7 @@ async fn request_loop(
 async fn message_loop(
     recv: &mut RecvHalf,
     event_sender: &Sender<JsEvent>,
-    response_oneshot: &Mutex<Option<oneshot::Sender<Result<BackendForPluginRuntimeApiResponseData, String>>>>,
+    pending_requests: &PendingRequests<Result<BackendForPluginRuntimeApiResponseData, String>>,
     stop_token: CancellationToken,
 ) -> anyhow::Result<()> {
     match recv_message::<JsMessage>(JsMessageSide::PluginRuntime, recv).await {
@@ -245,7 +250,7 @@ async fn message_loop(
             tracing::error!("Unable to handle message: {:?}", e);
             Err(e)
         }
-        Ok(msg) => {
+        Ok((request_id, msg)) => {
             match msg {
                 JsMessage::Event(event) => {
                     tracing::trace!("Received plugin event from backend {:?}", event);
@@ -259,21 +264,17 @@ async fn message_loop(
                     Ok(())
                 }
                 JsMessage::Response(response) => {
-                    let mut response_oneshot = response_oneshot.lock().await;
-
-                    match response_oneshot.take() {
-                        Some(oneshot) => {
-                            match oneshot.send(response) {
-                                Err(_) => {
-                                    tracing::error!("Dropped oneshot receiving side");
-                                }
-                                Ok(_) => {
-                                    tracing::trace!("Oneshot response sent");
-                                }
-                            }
+                    let Some(request_id) = request_id else {
+                        tracing::error!("Received response without request id: {:?}", response);
+                        return Ok(());
+                    };
+
+                    match pending_requests.complete(request_id, response) {
+                        Err(err) => {
+                            tracing::error!("Unable to handle response: {:?}", err);
                         }
-                        None => {
-                            tracing::error!("Received response without corresponding request: {:?}", response);
+                        Ok(_) => {
+                            tracing::trace!("Oneshot response sent");
                         }
                     }
 
@@ -14,6 +14,7 @@ pub struct DenoOutGeneratedSearchItem {
     pub entrypoint_icon: Option<ToJsBuffer>,
     pub entrypoint_actions: Vec<JsGeneratedSearchItemAction>,
     pub entrypoint_accessories: Vec<JsGeneratedSearchItemAccessory>,
+    pub entrypoint_keywords: Vec<String>,
 }
 
 #[derive(Deserialize)]
@@ -25,6 +26,7 @@ pub struct DenoInGeneratedSearchItem {
     pub entrypoint_icon: Option<JsBuffer>,
     pub entrypoint_actions: Vec<JsGeneratedSearchItemAction>,
     pub entrypoint_accessories: Vec<JsGeneratedSearchItemAccessory>,
+    pub entrypoint_keywords: Vec<String>,
 }
 
 #[derive(Serialize)]
@@ -38,3 +40,10 @@ pub struct DenoInClipboardData {
     pub text_data: Option<String>,
     pub png_data: Option<JsBuffer>,
 }
+
+#[derive(Serialize)]
+pub struct DenoOutFocusedWindow {
+    #[serde(rename = "appId")]
+    pub app_id: Option<String>,
+    pub title: Option<String>,
+}
@@ -235,4 +235,100 @@ fn extract_object_keys(scope: &mut v8::HandleScope, object: v8::Local<v8::Object
     Ok(keys)
 }
 
+pub fn deserialize_widget_patch(
+    scope: &mut v8::HandleScope,
+    patch: v8::Local<v8::Value>,
+) -> Result<Vec<UiWidgetPatchOp>> {
+    let patch: v8::Local<v8::Array> = patch
+        .try_into()
+        .map_err(|_| error_internal!("invalid patch, expected 'array', got: '{}'", patch.type_repr()))?;
+
+    let mut result = vec![];
+
+    for index in 0..patch.length() {
+        let op = patch.get_index(scope, index).ok_or(error_internal!(
+            "unable to get item from patch array at index {}",
+            index
+        ))?;
+
+        let op: v8::Local<v8::Object> = op
+            .try_into()
+            .map_err(|_| error_internal!("invalid patch operation, expected 'object', got: '{}'", op.type_repr()))?;
+
+        let op_type = extract_object_value(scope, op, "type")
+            .ok_or(error_internal!("'type' field is not present on patch operation"))?;
+
+        let op = match deserialize_string(scope, op_type)?.as_str() {
+            "replace" => {
+                UiWidgetPatchOp::Replace {
+                    widget: deserialize_patch_widget(scope, op)?,
+                }
+            }
+            "updateProps" => {
+                UiWidgetPatchOp::UpdateProps {
+                    widget: deserialize_patch_widget(scope, op)?,
+                }
+            }
+            "updateChildren" => {
+                let widget_id = deserialize_patch_widget_id(scope, op)?;
+
+                let children = extract_object_value(scope, op, "children")
+                    .ok_or(error_internal!("'children' field is not present on patch operation"))?;
+
+                let children: v8::Local<v8::Array> = children.try_into().map_err(|_| {
+                    error_internal!("invalid 'children', expected 'array', got: '{}'", children.type_repr())
+                })?;
+
+                let mut result = vec![];
+
+                for index in 0..children.length() {
+                    let child = children.get_index(scope, index).ok_or(error_internal!(
+                        "unable to get item from 'children' array at index {}",
+                        index
+                    ))?;
+
+                    let child: v8::Local<v8::Object> = child.try_into().map_err(|_| {
+                        error_internal!("invalid patch child, expected 'object', got: '{}'", child.type_repr())
+                    })?;
+
+                    let child_type = extract_object_value(scope, child, "type")
+                        .ok_or(error_internal!("'type' field is not present on patch child"))?;
+
+                    let child = match deserialize_string(scope, child_type)?.as_str() {
+                        "existing" => UiWidgetPatchChild::Existing(deserialize_patch_widget_id(scope, child)?),
+                        "new" => UiWidgetPatchChild::New(deserialize_patch_widget(scope, child)?),
+                        child_type @ _ => return Err(error_internal!("unknown patch child type: '{}'", child_type)),
+                    };
+
+                    result.push(child);
+                }
+
+                UiWidgetPatchOp::UpdateChildren {
+                    widget_id,
+                    children: result,
+                }
+            }
+            op_type @ _ => return Err(error_internal!("unknown patch operation type: '{}'", op_type)),
+        };
+
+        result.push(op);
+    }
+
+    Ok(result)
+}
+
+fn deserialize_patch_widget(scope: &mut v8::HandleScope, op: v8::Local<v8::Object>) -> Result<UiWidget> {
+    let widget = extract_object_value(scope, op, "widget")
+        .ok_or(error_internal!("'widget' field is not present on patch operation"))?;
+
+    deserialize_widget(scope, widget)
+}
+
+fn deserialize_patch_widget_id(scope: &mut v8::HandleScope, op: v8::Local<v8::Object>) -> Result<UiWidgetId> {
+    let widget_id = extract_object_value(scope, op, "widgetId")
+        .ok_or(error_internal!("'widgetId' field is not present on patch operation"))?;
+
+    Ok(deserialize_number(widget_id)? as UiWidgetId)
+}
+
 gauntlet_utils_macros::widget_deserialization_gen!();
@@ -157,7 +157,7 @@ fn run_permission(
     Ok(Permissions::new_unary(allow_list, None, false))
 }
 
-fn augment_path(
+pub(crate) fn augment_path(
     path: &String,
     home_dir: &Path,
     plugin_data_dir: &Path,
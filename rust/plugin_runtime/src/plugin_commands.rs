@@ -0,0 +1,52 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use deno_core::OpState;
+use deno_core::op2;
+use gauntlet_common::model::PluginId;
+use gauntlet_common_plugin_runtime::api::BackendForPluginRuntimeApi;
+use gauntlet_common_plugin_runtime::api::BackendForPluginRuntimeApiProxy;
+
+use crate::deno::GauntletJsError;
+
+// arguments and result are passed as json strings
+#[op2(async)]
+#[string]
+pub async fn plugin_command_call(
+    state: Rc<RefCell<OpState>>,
+    #[string] plugin_id: String,
+    #[string] command_id: String,
+    #[string] arguments: String,
+) -> Result<String, GauntletJsError> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.plugin_command_call(PluginId::from_string(plugin_id), command_id, arguments)
+        .await
+        .map_err(Into::into)
+}
+
+#[op2(async)]
+pub async fn plugin_command_result(
+    state: Rc<RefCell<OpState>>,
+    #[string] call_id: String,
+    #[serde] result: Option<String>,
+    #[serde] error: Option<String>,
+) -> Result<(), GauntletJsError> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.plugin_command_result(call_id, result, error)
+        .await
+        .map_err(Into::into)
+}
@@ -9,9 +9,10 @@ pub struct PluginData {
     plugin_uuid: String,
     plugin_cache_dir: String,
     plugin_data_dir: String,
-    inline_view_entrypoint_id: Option<String>,
+    inline_view_entrypoint_ids: Vec<String>,
     entrypoint_names: HashMap<String, String>,
     home_dir: PathBuf,
+    icon_cache_dir: PathBuf,
 }
 
 impl PluginData {
@@ -20,9 +21,10 @@ impl PluginData {
         plugin_uuid: String,
         plugin_cache_dir: String,
         plugin_data_dir: String,
-        inline_view_entrypoint_id: Option<String>,
+        inline_view_entrypoint_ids: Vec<String>,
         entrypoint_names: HashMap<EntrypointId, String>,
         home_dir: PathBuf,
+        icon_cache_dir: PathBuf,
     ) -> Self {
         let entrypoint_names = entrypoint_names
             .into_iter()
@@ -34,9 +36,10 @@ impl PluginData {
             plugin_uuid,
             plugin_cache_dir,
             plugin_data_dir,
-            inline_view_entrypoint_id,
+            inline_view_entrypoint_ids,
             entrypoint_names,
             home_dir,
+            icon_cache_dir,
         }
     }
 
@@ -57,16 +60,19 @@ impl PluginData {
         &self.plugin_data_dir
     }
 
-    pub fn inline_view_entrypoint_id(&self) -> &Option<String> {
-        &self.inline_view_entrypoint_id
+    pub fn inline_view_entrypoint_ids(&self) -> &[String] {
+        &self.inline_view_entrypoint_ids
     }
 
     pub fn entrypoint_names(&self) -> &HashMap<String, String> {
         &self.entrypoint_names
     }
 
-    #[allow(unused)]
     pub fn home_dir(&self) -> PathBuf {
         self.home_dir.clone()
     }
+
+    pub fn icon_cache_dir(&self) -> PathBuf {
+        self.icon_cache_dir.clone()
+    }
 }
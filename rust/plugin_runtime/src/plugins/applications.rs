@@ -43,6 +43,15 @@ pub struct DesktopApplication {
     desktop_file_path: String,
     icon: Option<ToJsBuffer>,
     startup_wm_class: Option<String>,
+    keywords: Vec<String>,
+    actions: Vec<DesktopApplicationAction>,
+}
+
+#[cfg(target_os = "linux")]
+#[derive(Debug, Serialize)]
+pub struct DesktopApplicationAction {
+    id: String,
+    name: String,
 }
 
 #[cfg(target_os = "macos")]
@@ -0,0 +1,557 @@
+use std::collections::HashMap;
+use std::hash::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Stdio;
+use std::sync::Once;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use anyhow::Context;
+use anyhow::anyhow;
+use freedesktop_icons::lookup;
+use image::ImageFormat;
+use image::Rgba;
+use image::RgbaImage;
+use image::imageops::FilterType;
+use once_cell::sync::Lazy;
+use x11rb::connection::Connection;
+use x11rb::protocol::xproto::AtomEnum;
+use x11rb::protocol::xproto::ConnectionExt;
+use x11rb::rust_connection::RustConnection;
+
+const ICON_SIZE: u16 = 48;
+
+// icons of this size are larger than anything reasonable, most likely file is corrupted
+const XPM_MAX_DIMENSION: u32 = 1024;
+
+// cache files which weren't used for this long belong to removed or updated icons
+const ICON_CACHE_MAX_AGE: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+static PRUNE_ICON_CACHE: Once = Once::new();
+
+// detecting theme may require spawning processes, so only do it once
+static DETECTED_ICON_THEME: Lazy<Option<String>> = Lazy::new(|| {
+    let theme = detect_icon_theme();
+
+    tracing::debug!("detected icon theme: {:?}", theme);
+
+    theme
+});
+
+static ICON_SCALE: Lazy<u16> = Lazy::new(detect_icon_scale);
+
+pub fn resolve_icon(
+    icon: &str,
+    icon_theme_override: Option<&str>,
+    icon_cache_dir: &Path,
+) -> anyhow::Result<Option<Vec<u8>>> {
+    PRUNE_ICON_CACHE.call_once(|| prune_icon_cache(icon_cache_dir));
+
+    let scale = *ICON_SCALE;
+
+    let icon_path = PathBuf::from(icon);
+    let icon_path = if icon_path.is_absolute() {
+        Some(icon_path)
+    } else {
+        let theme = icon_theme_override.or(DETECTED_ICON_THEME.as_deref());
+
+        let builder = lookup(icon).with_size(ICON_SIZE).with_scale(scale).with_cache();
+
+        match theme {
+            Some(theme) => builder.with_theme(theme).find(),
+            None => builder.find(),
+        }
+    };
+
+    let Some(icon_path) = icon_path else {
+        return Ok(None);
+    };
+
+    tracing::debug!("icon path: {:?}", icon_path);
+
+    let pixel_size = (ICON_SIZE * scale) as u32;
+
+    let cache_file = cache_file_path(icon_cache_dir, &icon_path, pixel_size)?;
+
+    if let Ok(data) = std::fs::read(&cache_file) {
+        // modification time is used to track when cache file was last used
+        if let Err(err) = touch_cache_file(&cache_file) {
+            tracing::warn!("unable to update icon cache file {:?}: {:?}", cache_file, err);
+        }
+
+        return Ok(Some(data));
+    }
+
+    let data = render_icon(&icon_path, pixel_size)?;
+
+    if let Err(err) = write_cache_file(&cache_file, &data) {
+        tracing::warn!("unable to write icon cache file {:?}: {:?}", cache_file, err);
+    }
+
+    Ok(Some(data))
+}
+
+fn cache_file_path(icon_cache_dir: &Path, icon_path: &Path, pixel_size: u32) -> anyhow::Result<PathBuf> {
+    let modified = std::fs::metadata(icon_path)?
+        .modified()?
+        .duration_since(UNIX_EPOCH)?
+        .as_nanos();
+
+    // only used as a cache key, so it doesn't matter if hash changes between versions
+    let mut hasher = DefaultHasher::new();
+    icon_path.hash(&mut hasher);
+    modified.hash(&mut hasher);
+    pixel_size.hash(&mut hasher);
+    let key = hasher.finish();
+
+    Ok(icon_cache_dir.join("applications").join(format!("{:016x}.png", key)))
+}
+
+fn touch_cache_file(cache_file: &Path) -> std::io::Result<()> {
+    std::fs::File::options()
+        .write(true)
+        .open(cache_file)?
+        .set_modified(SystemTime::now())
+}
+
+fn prune_icon_cache(icon_cache_dir: &Path) {
+    let Ok(entries) = std::fs::read_dir(icon_cache_dir.join("applications")) else {
+        return;
+    };
+
+    let now = SystemTime::now();
+
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        let stale = entry
+            .metadata()
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| now.duration_since(modified).ok())
+            .is_some_and(|age| age > ICON_CACHE_MAX_AGE);
+
+        if stale {
+            if let Err(err) = std::fs::remove_file(entry.path()) {
+                tracing::warn!("unable to remove stale icon cache file {:?}: {:?}", entry.path(), err);
+            }
+        }
+    }
+}
+
+fn write_cache_file(cache_file: &Path, data: &[u8]) -> anyhow::Result<()> {
+    if let Some(parent) = cache_file.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    std::fs::write(cache_file, data)?;
+
+    Ok(())
+}
+
+fn render_icon(icon_path: &Path, pixel_size: u32) -> anyhow::Result<Vec<u8>> {
+    let extension = icon_path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .ok_or(anyhow!("unknown format"))?;
+
+    let image = match extension {
+        "png" => {
+            let data = std::fs::read(icon_path)?;
+
+            image::load_from_memory_with_format(&data, ImageFormat::Png)?.into_rgba8()
+        }
+        "svg" => {
+            let data = std::fs::read(icon_path)?;
+
+            let tree = resvg::usvg::Tree::from_data(&data, &resvg::usvg::Options::default())?;
+
+            // render svg directly at target size instead of scaling raster afterwards
+            let tree_size = tree.size();
+            let scale = pixel_size as f32 / tree_size.width().max(tree_size.height());
+
+            let mut pixmap =
+                resvg::tiny_skia::Pixmap::new(pixel_size, pixel_size).ok_or(anyhow!("unable to create pixmap"))?;
+
+            resvg::render(
+                &tree,
+                resvg::tiny_skia::Transform::from_scale(scale, scale),
+                &mut pixmap.as_mut(),
+            );
+
+            let data = pixmap.encode_png()?;
+
+            image::load_from_memory_with_format(&data, ImageFormat::Png)?.into_rgba8()
+        }
+        "xpm" => {
+            let data = std::fs::read_to_string(icon_path)?;
+
+            decode_xpm(&data)?
+        }
+        _ => Err(anyhow!("unsupported by spec format {:?}", extension))?,
+    };
+
+    let image = image::imageops::resize(&image, pixel_size, pixel_size, FilterType::Lanczos3);
+
+    let mut buffer = std::io::Cursor::new(vec![]);
+
+    image.write_to(&mut buffer, ImageFormat::Png)?;
+
+    Ok(buffer.into_inner())
+}
+
+// https://en.wikipedia.org/wiki/X_PixMap, only XPM3 is supported
+fn decode_xpm(data: &str) -> anyhow::Result<RgbaImage> {
+    // every meaningful line of xpm file is a c string literal
+    let mut lines = data.lines().filter_map(|line| {
+        let line = line.trim();
+        let start = line.find('"')?;
+        let end = line.rfind('"')?;
+
+        if start < end { Some(&line[start + 1..end]) } else { None }
+    });
+
+    let header = lines.next().ok_or(anyhow!("xpm doesn't contain header"))?;
+
+    let header = header
+        .split_whitespace()
+        .map(|value| value.parse::<u32>())
+        .collect::<Result<Vec<_>, _>>()
+        .context("invalid xpm header")?;
+
+    let [width, height, colors, chars_per_pixel, ..] = header[..] else {
+        return Err(anyhow!("invalid xpm header"));
+    };
+
+    if width > XPM_MAX_DIMENSION || height > XPM_MAX_DIMENSION {
+        return Err(anyhow!("xpm is too large: {}x{}", width, height));
+    }
+
+    let chars_per_pixel = chars_per_pixel as usize;
+
+    let mut palette = HashMap::new();
+
+    for _ in 0..colors {
+        let line = lines.next().ok_or(anyhow!("xpm doesn't contain enough colors"))?;
+
+        let key = line.get(..chars_per_pixel).ok_or(anyhow!("invalid xpm color"))?;
+        let rest = line.get(chars_per_pixel..).unwrap_or_default();
+
+        let mut parts = rest.split_whitespace();
+
+        // there may be multiple visuals, color visual is preferred
+        let mut color = None;
+        while let Some(visual) = parts.next() {
+            let value = parts.next().ok_or(anyhow!("invalid xpm color"))?;
+
+            if visual == "c" || color.is_none() {
+                color = Some(value);
+            }
+        }
+
+        let color = parse_xpm_color(color.ok_or(anyhow!("invalid xpm color"))?);
+
+        palette.insert(key.to_string(), color);
+    }
+
+    let mut image = RgbaImage::new(width, height);
+
+    for y in 0..height {
+        let line = lines.next().ok_or(anyhow!("xpm doesn't contain enough pixels"))?;
+
+        for x in 0..width {
+            let start = x as usize * chars_per_pixel;
+
+            let key = line
+                .get(start..start + chars_per_pixel)
+                .ok_or(anyhow!("xpm doesn't contain enough pixels"))?;
+
+            let color = palette.get(key).copied().unwrap_or(Rgba([0, 0, 0, 0]));
+
+            image.put_pixel(x, y, color);
+        }
+    }
+
+    Ok(image)
+}
+
+fn parse_xpm_color(value: &str) -> Rgba<u8> {
+    if let Some(hex) = value.strip_prefix('#') {
+        let component_len = hex.len() / 3;
+
+        if component_len > 0 && hex.len() % 3 == 0 {
+            let component = |index: usize| {
+                let part = &hex[index * component_len..(index + 1) * component_len];
+
+                // use most significant byte, e.g. #RRRRGGGGBBBB
+                let value = u32::from_str_radix(part, 16).unwrap_or(0);
+                let bits = component_len as u32 * 4;
+
+                if bits >= 8 {
+                    (value >> (bits - 8)) as u8
+                } else {
+                    (value * 0x11) as u8
+                }
+            };
+
+            return Rgba([component(0), component(1), component(2), 255]);
+        }
+    }
+
+    match value.to_lowercase().as_str() {
+        "none" => Rgba([0, 0, 0, 0]),
+        "white" => Rgba([255, 255, 255, 255]),
+        "red" => Rgba([255, 0, 0, 255]),
+        "green" => Rgba([0, 255, 0, 255]),
+        "blue" => Rgba([0, 0, 255, 255]),
+        "yellow" => Rgba([255, 255, 0, 255]),
+        "gray" | "grey" => Rgba([190, 190, 190, 255]),
+        _ => Rgba([0, 0, 0, 255]),
+    }
+}
+
+fn detect_icon_scale() -> u16 {
+    ["GDK_SCALE", "QT_SCALE_FACTOR"]
+        .into_iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .filter_map(|value| value.parse::<f32>().ok())
+        .find(|value| *value >= 1.0)
+        .map(|value| value.ceil() as u16)
+        .unwrap_or(1)
+}
+
+fn detect_icon_theme() -> Option<String> {
+    let desktops = std::env::var("XDG_CURRENT_DESKTOP").unwrap_or_default();
+
+    let kde = desktops.split(':').any(|desktop| desktop == "KDE");
+
+    if kde {
+        if let Some(theme) = icon_theme_from_kdeglobals() {
+            return Some(theme);
+        }
+    }
+
+    icon_theme_from_gsettings()
+        .or_else(icon_theme_from_xsettings)
+        .or_else(icon_theme_from_kdeglobals)
+}
+
+fn icon_theme_from_gsettings() -> Option<String> {
+    let output = Command::new("gsettings")
+        .args(["get", "org.gnome.desktop.interface", "icon-theme"])
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let theme = String::from_utf8(output.stdout).ok()?;
+    let theme = theme.trim().trim_matches('\'').to_string();
+
+    if theme.is_empty() { None } else { Some(theme) }
+}
+
+fn icon_theme_from_kdeglobals() -> Option<String> {
+    let config_home = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+    let content = std::fs::read_to_string(config_home.join("kdeglobals")).ok()?;
+
+    let mut in_icons_section = false;
+
+    for line in content.lines() {
+        let line = line.trim();
+
+        if line.starts_with('[') {
+            in_icons_section = line == "[Icons]";
+            continue;
+        }
+
+        if in_icons_section {
+            if let Some(("Theme", value)) = line.split_once('=').map(|(key, value)| (key.trim(), value.trim())) {
+                if !value.is_empty() {
+                    return Some(value.to_string());
+                }
+            }
+        }
+    }
+
+    None
+}
+
+// https://specifications.freedesktop.org/xsettings-spec/0.5/
+fn icon_theme_from_xsettings() -> Option<String> {
+    let (conn, screen_num) = RustConnection::connect(None).ok()?;
+
+    let selection = conn
+        .intern_atom(false, format!("_XSETTINGS_S{}", screen_num).as_bytes())
+        .ok()?
+        .reply()
+        .ok()?
+        .atom;
+
+    let settings_atom = conn.intern_atom(false, b"_XSETTINGS_SETTINGS").ok()?.reply().ok()?.atom;
+
+    let owner = conn.get_selection_owner(selection).ok()?.reply().ok()?.owner;
+
+    if owner == x11rb::NONE {
+        return None;
+    }
+
+    let property = conn
+        .get_property(false, owner, settings_atom, AtomEnum::ANY, 0, u32::MAX / 4)
+        .ok()?
+        .reply()
+        .ok()?;
+
+    let data = property.value;
+
+    let big_endian = *data.first()? == 1;
+
+    let read_u16 = |offset: usize| -> Option<u16> {
+        let bytes = [*data.get(offset)?, *data.get(offset + 1)?];
+        Some(
+            if big_endian {
+                u16::from_be_bytes(bytes)
+            } else {
+                u16::from_le_bytes(bytes)
+            },
+        )
+    };
+
+    let read_u32 = |offset: usize| -> Option<u32> {
+        let bytes = [
+            *data.get(offset)?,
+            *data.get(offset + 1)?,
+            *data.get(offset + 2)?,
+            *data.get(offset + 3)?,
+        ];
+        Some(
+            if big_endian {
+                u32::from_be_bytes(bytes)
+            } else {
+                u32::from_le_bytes(bytes)
+            },
+        )
+    };
+
+    let pad = |len: usize| (len + 3) & !3;
+
+    let settings_count = read_u32(8)?;
+    let mut offset = 12;
+
+    for _ in 0..settings_count {
+        let setting_type = *data.get(offset)?;
+        let name_len = read_u16(offset + 2)? as usize;
+        let name = data.get(offset + 4..offset + 4 + name_len)?;
+
+        offset += 4 + pad(name_len) + 4; // name and last-change-serial
+
+        match setting_type {
+            // integer
+            0 => offset += 4,
+            // string
+            1 => {
+                let value_len = read_u32(offset)? as usize;
+                let value = data.get(offset + 4..offset + 4 + value_len)?;
+
+                if name == b"Net/IconThemeName" {
+                    let theme = String::from_utf8(value.to_vec()).ok()?;
+
+                    return if theme.is_empty() { None } else { Some(theme) };
+                }
+
+                offset += 4 + pad(value_len);
+            }
+            // color
+            2 => offset += 8,
+            _ => return None,
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_xpm_image() {
+        let data = r#"/* XPM */
+static char * icon_xpm[] = {
+"2 2 3 1",
+"  c None",
+". c #FF0000",
+"+ s mask c #0000FF",
+". ",
+" +"};
+"#;
+
+        let image = decode_xpm(data).unwrap();
+
+        assert_eq!(image.dimensions(), (2, 2));
+        assert_eq!(*image.get_pixel(0, 0), Rgba([255, 0, 0, 255]));
+        assert_eq!(*image.get_pixel(1, 0), Rgba([0, 0, 0, 0]));
+        assert_eq!(*image.get_pixel(0, 1), Rgba([0, 0, 0, 0]));
+        assert_eq!(*image.get_pixel(1, 1), Rgba([0, 0, 255, 255]));
+    }
+
+    #[test]
+    fn decode_xpm_multiple_chars_per_pixel() {
+        let data = r#"
+"2 1 2 2",
+"aa c #00FF00",
+"bb c white",
+"aabb"
+"#;
+
+        let image = decode_xpm(data).unwrap();
+
+        assert_eq!(*image.get_pixel(0, 0), Rgba([0, 255, 0, 255]));
+        assert_eq!(*image.get_pixel(1, 0), Rgba([255, 255, 255, 255]));
+    }
+
+    #[test]
+    fn decode_xpm_rejects_too_large_image() {
+        let data = "\"100000 100000 1 1\",\n\". c None\",\n";
+
+        let err = decode_xpm(data).unwrap_err();
+
+        assert!(err.to_string().contains("too large"));
+    }
+
+    #[test]
+    fn decode_xpm_rejects_invalid_data() {
+        assert!(decode_xpm("").is_err());
+        assert!(decode_xpm("\"2 2 1\"").is_err());
+        assert!(decode_xpm("\"a b c d\"").is_err());
+        assert!(decode_xpm("\"1 1 2 1\",\n\". c None\"").is_err());
+        assert!(decode_xpm("\"2 2 1 1\",\n\". c None\",\n\"..\"").is_err());
+        assert!(decode_xpm("\"2 1 1 1\",\n\". c None\",\n\".\"").is_err());
+    }
+
+    #[test]
+    fn parse_xpm_hex_colors() {
+        assert_eq!(parse_xpm_color("#F00"), Rgba([255, 0, 0, 255]));
+        assert_eq!(parse_xpm_color("#12AB34"), Rgba([0x12, 0xAB, 0x34, 255]));
+        assert_eq!(parse_xpm_color("#1234ABCD5678"), Rgba([0x12, 0xAB, 0x56, 255]));
+    }
+
+    #[test]
+    fn parse_xpm_named_colors() {
+        assert_eq!(parse_xpm_color("None"), Rgba([0, 0, 0, 0]));
+        assert_eq!(parse_xpm_color("none"), Rgba([0, 0, 0, 0]));
+        assert_eq!(parse_xpm_color("White"), Rgba([255, 255, 255, 255]));
+        assert_eq!(parse_xpm_color("grey"), Rgba([190, 190, 190, 255]));
+        assert_eq!(parse_xpm_color("unknown"), Rgba([0, 0, 0, 255]));
+        assert_eq!(parse_xpm_color("#12345"), Rgba([0, 0, 0, 255]));
+    }
+}
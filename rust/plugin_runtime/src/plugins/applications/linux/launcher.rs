@@ -0,0 +1,455 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::Context;
+use anyhow::anyhow;
+use freedesktop_entry_parser::parse_entry;
+use gauntlet_common::detached_process::CommandExt;
+use serde::Deserialize;
+use zbus::blocking::Connection;
+use zbus::zvariant::Value;
+
+// terminals tried in order when neither preference nor $TERMINAL is set
+const KNOWN_TERMINALS: [&str; 10] = [
+    "x-terminal-emulator",
+    "xdg-terminal-exec",
+    "kitty",
+    "alacritty",
+    "foot",
+    "wezterm",
+    "gnome-terminal",
+    "konsole",
+    "xfce4-terminal",
+    "xterm",
+];
+
+#[derive(Debug, Default, Deserialize)]
+pub struct LinuxLaunchOptions {
+    terminal: Option<String>,
+    systemd_scope: bool,
+}
+
+pub fn launch_desktop_entry(
+    desktop_file_path: &Path,
+    desktop_file_id: &str,
+    action_id: Option<&str>,
+    options: &LinuxLaunchOptions,
+) -> anyhow::Result<()> {
+    let entry = parse_entry(desktop_file_path)
+        .with_context(|| format!("error parsing .desktop file at path {:?}", desktop_file_path))?;
+
+    let main_section = entry.section("Desktop Entry");
+
+    let dbus_activatable = main_section
+        .attr("DBusActivatable")
+        .map(|val| val == "true")
+        .unwrap_or(false);
+
+    if dbus_activatable {
+        match dbus_activate(desktop_file_id, action_id) {
+            Ok(()) => return Ok(()),
+            Err(err) => {
+                // Exec key, if present, is a fallback for D-Bus activation
+                tracing::warn!(
+                    "unable to activate {} via D-Bus, falling back to Exec: {:?}",
+                    desktop_file_id,
+                    err
+                );
+            }
+        }
+    }
+
+    let exec = match action_id {
+        None => main_section.attr("Exec"),
+        Some(action_id) => entry.section(format!("Desktop Action {}", action_id)).attr("Exec"),
+    };
+
+    let exec = exec.ok_or_else(|| anyhow!("desktop entry {} doesn't have Exec key", desktop_file_id))?;
+
+    let context = ExecContext {
+        icon: main_section.attr("Icon"),
+        name: main_section.attr("Name"),
+        desktop_file_path,
+    };
+
+    let mut args = expand_exec(exec, &context)?;
+
+    let terminal = main_section.attr("Terminal").map(|val| val == "true").unwrap_or(false);
+
+    if terminal {
+        let mut terminal_args = terminal_command(options)?;
+        terminal_args.append(&mut args);
+        args = terminal_args;
+    }
+
+    if options.systemd_scope && which::which("systemd-run").is_ok() {
+        let mut systemd_args = vec![
+            "systemd-run".to_string(),
+            "--user".to_string(),
+            "--scope".to_string(),
+            "--quiet".to_string(),
+            "--collect".to_string(),
+            "--slice=app.slice".to_string(),
+            "--".to_string(),
+        ];
+        systemd_args.append(&mut args);
+        args = systemd_args;
+    }
+
+    let Some((program, args)) = args.split_first() else {
+        return Err(anyhow!("desktop entry {} has empty Exec key", desktop_file_id));
+    };
+
+    let mut command = Command::new(program);
+    command.args(args);
+
+    if let Some(working_dir) = main_section.attr("Path").filter(|path| !path.is_empty()) {
+        command.current_dir(working_dir);
+    }
+
+    tracing::debug!("launching desktop entry {}: {:?}", desktop_file_id, command);
+
+    command
+        .spawn_detached()
+        .with_context(|| format!("unable to launch {}", desktop_file_id))?;
+
+    Ok(())
+}
+
+fn dbus_activate(desktop_file_id: &str, action_id: Option<&str>) -> anyhow::Result<()> {
+    // well known bus name of D-Bus activatable application is its desktop file id
+    let object_path = format!("/{}", desktop_file_id.replace(".", "/").replace("-", "_"));
+
+    let connection = Connection::session().context("unable to connect to session bus")?;
+
+    let platform_data: HashMap<&str, Value> = HashMap::new();
+
+    match action_id {
+        None => {
+            connection
+                .call_method(
+                    Some(desktop_file_id),
+                    object_path.as_str(),
+                    Some("org.freedesktop.Application"),
+                    "Activate",
+                    &(platform_data,),
+                )
+                .context("org.freedesktop.Application.Activate call failed")?;
+        }
+        Some(action_id) => {
+            let parameters: Vec<Value> = vec![];
+
+            connection
+                .call_method(
+                    Some(desktop_file_id),
+                    object_path.as_str(),
+                    Some("org.freedesktop.Application"),
+                    "ActivateAction",
+                    &(action_id, parameters, platform_data),
+                )
+                .context("org.freedesktop.Application.ActivateAction call failed")?;
+        }
+    }
+
+    Ok(())
+}
+
+fn terminal_command(options: &LinuxLaunchOptions) -> anyhow::Result<Vec<String>> {
+    let configured = options
+        .terminal
+        .clone()
+        .filter(|terminal| !terminal.trim().is_empty())
+        .or_else(|| {
+            std::env::var("TERMINAL")
+                .ok()
+                .filter(|terminal| !terminal.trim().is_empty())
+        });
+
+    let mut terminal = match configured {
+        Some(terminal) => split_exec(&terminal)?,
+        None => {
+            let terminal = KNOWN_TERMINALS
+                .into_iter()
+                .find(|terminal| which::which(terminal).is_ok())
+                .ok_or_else(|| anyhow!("unable to find terminal emulator, please specify one in settings"))?;
+
+            vec![terminal.to_string()]
+        }
+    };
+
+    let Some(program) = terminal.first() else {
+        return Err(anyhow!("terminal command is empty"));
+    };
+
+    let program = Path::new(program)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default();
+
+    // most terminals accept -e, but some use separator or nothing at all
+    let separator = match program {
+        "gnome-terminal" | "kgx" | "ptyxis" => Some("--"),
+        "xdg-terminal-exec" => None,
+        _ => Some("-e"),
+    };
+
+    if let Some(separator) = separator {
+        terminal.push(separator.to_string());
+    }
+
+    Ok(terminal)
+}
+
+struct ExecContext<'a> {
+    icon: Option<&'a str>,
+    name: Option<&'a str>,
+    desktop_file_path: &'a Path,
+}
+
+// https://specifications.freedesktop.org/desktop-entry-spec/latest/exec-variables.html
+fn expand_exec(exec: &str, context: &ExecContext) -> anyhow::Result<Vec<String>> {
+    let mut result = vec![];
+
+    for arg in split_exec(&unescape_string(exec))? {
+        match arg.as_str() {
+            // no files or urls are passed when launching from search
+            "%f" | "%F" | "%u" | "%U" => {}
+            "%i" => {
+                if let Some(icon) = context.icon {
+                    result.push("--icon".to_string());
+                    result.push(icon.to_string());
+                }
+            }
+            _ => {
+                let mut expanded = String::new();
+                let mut chars = arg.chars();
+
+                while let Some(char) = chars.next() {
+                    if char != '%' {
+                        expanded.push(char);
+                        continue;
+                    }
+
+                    match chars.next() {
+                        Some('%') => expanded.push('%'),
+                        Some('c') => expanded.push_str(context.name.unwrap_or_default()),
+                        Some('k') => {
+                            expanded.push_str(
+                                context
+                                    .desktop_file_path
+                                    .to_str()
+                                    .expect("non-utf8 paths are not supported"),
+                            )
+                        }
+                        // %f %F %u %U %i are only valid as standalone arguments, the rest are deprecated
+                        Some(_) => {}
+                        None => return Err(anyhow!("Exec key ends with unfinished field code: {}", exec)),
+                    }
+                }
+
+                result.push(expanded);
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+fn split_exec(exec: &str) -> anyhow::Result<Vec<String>> {
+    let mut args = vec![];
+    let mut current: Option<String> = None;
+    let mut chars = exec.chars();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '"' => {
+                let arg = current.get_or_insert_with(String::new);
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => {
+                            match chars.next() {
+                                Some(char @ ('"' | '`' | '$' | '\\')) => arg.push(char),
+                                Some(char) => {
+                                    arg.push('\\');
+                                    arg.push(char);
+                                }
+                                None => return Err(anyhow!("Exec key ends with unfinished escape: {}", exec)),
+                            }
+                        }
+                        Some(char) => arg.push(char),
+                        None => return Err(anyhow!("Exec key contains unterminated quote: {}", exec)),
+                    }
+                }
+            }
+            ' ' | '\t' | '\n' => {
+                args.extend(current.take());
+            }
+            char => current.get_or_insert_with(String::new).push(char),
+        }
+    }
+
+    args.extend(current);
+
+    Ok(args)
+}
+
+// https://specifications.freedesktop.org/desktop-entry-spec/latest/value-types.html
+// general escape rules for string values are applied before Exec quoting rules
+fn unescape_string(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(char) = chars.next() {
+        if char != '\\' {
+            result.push(char);
+            continue;
+        }
+
+        match chars.next() {
+            Some('s') => result.push(' '),
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some('r') => result.push('\r'),
+            Some('\\') => result.push('\\'),
+            Some(char) => {
+                result.push('\\');
+                result.push(char);
+            }
+            None => result.push('\\'),
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context() -> ExecContext<'static> {
+        ExecContext {
+            icon: Some("firefox"),
+            name: Some("Firefox"),
+            desktop_file_path: Path::new("/usr/share/applications/firefox.desktop"),
+        }
+    }
+
+    fn expand(exec: &str) -> Vec<String> {
+        expand_exec(exec, &context()).unwrap()
+    }
+
+    fn terminal(terminal: &str) -> Vec<String> {
+        let options = LinuxLaunchOptions {
+            terminal: Some(terminal.to_string()),
+            systemd_scope: false,
+        };
+
+        terminal_command(&options).unwrap()
+    }
+
+    #[test]
+    fn split_exec_splits_on_whitespace() {
+        assert_eq!(
+            split_exec("app  --flag\tvalue").unwrap(),
+            vec!["app", "--flag", "value"]
+        );
+        assert_eq!(split_exec("").unwrap(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn split_exec_handles_quoted_args() {
+        assert_eq!(
+            split_exec(r#""/opt/my app/app" "hello world" a"b c"d"#).unwrap(),
+            vec!["/opt/my app/app", "hello world", "ab cd"]
+        );
+        assert_eq!(split_exec(r#"app """#).unwrap(), vec!["app", ""]);
+        // single quotes are not special
+        assert_eq!(split_exec("app 'a b'").unwrap(), vec!["app", "'a", "b'"]);
+    }
+
+    #[test]
+    fn split_exec_handles_escapes_in_quoted_args() {
+        assert_eq!(
+            split_exec(r#"app "a\"b" "\$HOME" "\`x\`" "a\\b" "\n""#).unwrap(),
+            vec!["app", "a\"b", "$HOME", "`x`", "a\\b", "\\n"]
+        );
+    }
+
+    #[test]
+    fn split_exec_rejects_unfinished_quotes() {
+        assert!(split_exec(r#"app "a b"#).is_err());
+        assert!(split_exec(r#"app "a\"#).is_err());
+    }
+
+    #[test]
+    fn expand_exec_handles_percent_escape() {
+        assert_eq!(expand(r#"app 100%% "%%f""#), vec!["app", "100%", "%f"]);
+    }
+
+    #[test]
+    fn expand_exec_removes_file_and_url_field_codes() {
+        assert_eq!(expand("app %f"), vec!["app"]);
+        assert_eq!(expand("app %F --flag"), vec!["app", "--flag"]);
+        assert_eq!(expand("app %u"), vec!["app"]);
+        assert_eq!(expand("app %U"), vec!["app"]);
+        assert_eq!(expand("app --file=%f"), vec!["app", "--file="]);
+    }
+
+    #[test]
+    fn expand_exec_expands_icon_name_and_location() {
+        assert_eq!(expand("app %i"), vec!["app", "--icon", "firefox"]);
+        assert_eq!(expand("app --class=%c"), vec!["app", "--class=Firefox"]);
+        assert_eq!(expand("app %k"), vec!["app", "/usr/share/applications/firefox.desktop"]);
+
+        let context = ExecContext {
+            icon: None,
+            name: None,
+            desktop_file_path: Path::new("/app.desktop"),
+        };
+
+        assert_eq!(expand_exec("app %i %c", &context).unwrap(), vec!["app", ""]);
+    }
+
+    #[test]
+    fn expand_exec_removes_deprecated_field_codes() {
+        assert_eq!(expand("app %d%D%n%N%v%m"), vec!["app", ""]);
+    }
+
+    #[test]
+    fn expand_exec_rejects_unfinished_field_code() {
+        assert!(expand_exec("app %", &context()).is_err());
+    }
+
+    #[test]
+    fn expand_exec_applies_string_escapes_before_quoting() {
+        // "\\\\" in desktop file is "\\" after string unescaping and "\" after Exec unquoting
+        assert_eq!(expand(r#"app "a\\\\b" a\sb"#), vec!["app", "a\\b", "a", "b"]);
+    }
+
+    #[test]
+    fn unescape_string_handles_escape_sequences() {
+        assert_eq!(unescape_string(r"a\sb\nc\td\re\\f"), "a b\nc\td\re\\f");
+        assert_eq!(unescape_string(r"a\;b\"), r"a\;b\");
+        assert_eq!(unescape_string("plain"), "plain");
+    }
+
+    #[test]
+    fn terminal_command_uses_terminal_specific_separator() {
+        assert_eq!(terminal("kitty"), vec!["kitty", "-e"]);
+        assert_eq!(
+            terminal("/usr/bin/gnome-terminal"),
+            vec!["/usr/bin/gnome-terminal", "--"]
+        );
+        assert_eq!(terminal("xdg-terminal-exec"), vec!["xdg-terminal-exec"]);
+    }
+
+    #[test]
+    fn terminal_command_keeps_configured_arguments() {
+        assert_eq!(
+            terminal(r#"foot --app-id "my term""#),
+            vec!["foot", "--app-id", "my term", "-e"]
+        );
+    }
+}
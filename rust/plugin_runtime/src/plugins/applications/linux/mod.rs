@@ -7,16 +7,19 @@ use anyhow::anyhow;
 use deno_core::OpState;
 use deno_core::op2;
 use freedesktop_entry_parser::parse_entry;
-use freedesktop_icons::lookup;
-use gauntlet_common::detached_process::CommandExt;
+use serde::Deserialize;
+use serde::Serialize;
 use tokio::task::spawn_blocking;
 
 use crate::deno::GauntletJsError;
 use crate::plugin_data::PluginData;
 use crate::plugins::applications::DesktopApplication;
+use crate::plugins::applications::DesktopApplicationAction;
 use crate::plugins::applications::DesktopPathAction;
-use crate::plugins::applications::resize_icon;
+use crate::plugins::applications::linux::launcher::LinuxLaunchOptions;
 
+mod icons;
+mod launcher;
 mod wayland;
 mod x11;
 
@@ -27,9 +30,14 @@ deno_core::extension!(
         linux_app_from_path,
         linux_application_dirs,
         linux_open_application,
+        linux_open_application_action,
         x11::linux_x11_focus_window,
+        x11::linux_x11_window_action,
+        x11::linux_x11_workspaces,
         x11::application_x11_pending_event,
         wayland::linux_wayland_focus_window,
+        wayland::linux_wayland_window_action,
+        wayland::linux_wayland_workspaces,
         wayland::application_wayland_pending_event,
     ],
     esm_entry_point = "ext:gauntlet/internal-linux/bootstrap.js",
@@ -39,6 +47,22 @@ deno_core::extension!(
     ]
 );
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type")]
+pub enum JsWindowAction {
+    Close,
+    Minimize,
+    ToggleMaximize,
+    ToggleFullscreen,
+    MoveToWorkspace { workspace: u32 },
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsWorkspace {
+    id: u32,
+    name: String,
+}
+
 pub enum LinuxDesktopEnvironment {
     X11(x11::X11DesktopEnvironment),
     Wayland(wayland::WaylandDesktopEnvironment),
@@ -69,16 +93,22 @@ impl LinuxDesktopEnvironment {
 async fn linux_app_from_path(
     state: Rc<RefCell<OpState>>,
     #[string] path: String,
+    #[serde] icon_theme: Option<String>,
 ) -> Result<Option<DesktopPathAction>, GauntletJsError> {
-    let home_dir = {
+    let (home_dir, icon_cache_dir) = {
         let state = state.borrow();
 
-        let home_dir = state.borrow::<PluginData>().home_dir();
+        let plugin_data = state.borrow::<PluginData>();
 
-        home_dir
+        (plugin_data.home_dir(), plugin_data.icon_cache_dir())
     };
 
-    let result = spawn_blocking(|| linux_app_from_path_async(home_dir, PathBuf::from(path)))
+    let icon_context = IconContext {
+        icon_theme: icon_theme.filter(|theme| !theme.trim().is_empty()),
+        icon_cache_dir,
+    };
+
+    let result = spawn_blocking(|| linux_app_from_path_async(home_dir, PathBuf::from(path), icon_context))
         .await
         .map_err(|err| anyhow!(err))?;
 
@@ -102,12 +132,38 @@ fn linux_application_dirs(state: Rc<RefCell<OpState>>) -> Vec<String> {
         .collect()
 }
 
-#[op2(fast)]
-fn linux_open_application(#[string] desktop_file_id: String) -> Result<(), GauntletJsError> {
-    std::process::Command::new("gtk-launch")
-        .args([desktop_file_id])
-        .spawn_detached()
-        .map_err(|err| anyhow!(err))?;
+#[op2(async)]
+async fn linux_open_application(
+    #[string] desktop_file_id: String,
+    #[string] desktop_file_path: String,
+    #[serde] options: LinuxLaunchOptions,
+) -> Result<(), GauntletJsError> {
+    spawn_blocking(move || {
+        launcher::launch_desktop_entry(&PathBuf::from(desktop_file_path), &desktop_file_id, None, &options)
+    })
+    .await
+    .map_err(|err| anyhow!(err))??;
+
+    Ok(())
+}
+
+#[op2(async)]
+async fn linux_open_application_action(
+    #[string] desktop_file_id: String,
+    #[string] desktop_file_path: String,
+    #[string] action_id: String,
+    #[serde] options: LinuxLaunchOptions,
+) -> Result<(), GauntletJsError> {
+    spawn_blocking(move || {
+        launcher::launch_desktop_entry(
+            &PathBuf::from(desktop_file_path),
+            &desktop_file_id,
+            Some(&action_id),
+            &options,
+        )
+    })
+    .await
+    .map_err(|err| anyhow!(err))??;
 
     Ok(())
 }
@@ -139,7 +195,7 @@ fn linux_application_dirs_inner(home_dir: PathBuf) -> Vec<PathBuf> {
     res.into_iter().map(|d| d.join("applications")).collect()
 }
 
-fn linux_app_from_path_async(home_dir: PathBuf, path: PathBuf) -> Option<DesktopPathAction> {
+fn linux_app_from_path_async(home_dir: PathBuf, path: PathBuf, icon_context: IconContext) -> Option<DesktopPathAction> {
     let app_directories = linux_application_dirs_inner(home_dir);
 
     let relative_to_app_dir = app_directories
@@ -182,7 +238,7 @@ fn linux_app_from_path_async(home_dir: PathBuf, path: PathBuf) -> Option<Desktop
             return None;
         }
 
-        if let Some(entry) = create_app_entry(&path) {
+        if let Some(entry) = create_app_entry(&path, &icon_context) {
             tracing::debug!("Adding application at: {:?}", path);
 
             Some(DesktopPathAction::Add {
@@ -195,7 +251,12 @@ fn linux_app_from_path_async(home_dir: PathBuf, path: PathBuf) -> Option<Desktop
     }
 }
 
-fn create_app_entry(desktop_file_path: &Path) -> Option<DesktopApplication> {
+struct IconContext {
+    icon_theme: Option<String>,
+    icon_cache_dir: PathBuf,
+}
+
+fn create_app_entry(desktop_file_path: &Path, icon_context: &IconContext) -> Option<DesktopApplication> {
     let entry = parse_entry(desktop_file_path)
         .inspect_err(|err| tracing::warn!("error parsing .desktop file at path {:?}: {:?}", desktop_file_path, err))
         .ok()?;
@@ -205,66 +266,77 @@ fn create_app_entry(desktop_file_path: &Path) -> Option<DesktopApplication> {
         .expect("non-utf8 paths are not supported")
         .to_string();
 
+    let locales = current_locales();
+    let current_desktops = current_desktops();
+
+    let actions = entry
+        .section("Desktop Entry")
+        .attr("Actions")
+        .map(|actions| split_list(actions))
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|action_id| {
+            let section = entry.section(format!("Desktop Action {}", action_id));
+
+            if !shown_in_current_desktop(section.attr("OnlyShowIn"), section.attr("NotShowIn"), &current_desktops) {
+                return None;
+            }
+
+            let name = localized_attr(
+                &locales,
+                |param| section.attr_with_param("Name", param),
+                || section.attr("Name"),
+            )?;
+
+            Some(DesktopApplicationAction {
+                id: action_id,
+                name: name.to_string(),
+            })
+        })
+        .collect();
+
     let entry = entry.section("Desktop Entry");
 
-    let name = entry.attr("Name")?;
+    let name = localized_attr(
+        &locales,
+        |param| entry.attr_with_param("Name", param),
+        || entry.attr("Name"),
+    )?;
     let icon = entry.attr("Icon").map(|s| s.to_string());
     let no_display = entry.attr("NoDisplay").map(|val| val == "true").unwrap_or(false);
     let hidden = entry.attr("Hidden").map(|val| val == "true").unwrap_or(false);
     let startup_wm_class = entry.attr("StartupWMClass").map(|s| s.to_string());
-    // TODO NotShowIn, OnlyShowIn https://wiki.archlinux.org/title/desktop_entries
 
     if no_display || hidden {
         return None;
     }
 
-    let icon = icon
-        .map(|icon| {
-            let icon_path = PathBuf::from(&icon);
-            if icon_path.is_absolute() {
-                Some(icon_path)
-            } else {
-                lookup(&icon).with_size(48).find()
-            }
-        })
-        .flatten()
-        .inspect(|path| tracing::debug!("icon path: {:?}", path))
-        .map(|path| {
-            match path.extension() {
-                None => Err(anyhow::anyhow!("unknown format")),
-                Some(extension) => {
-                    match extension.to_str() {
-                        Some("png") => {
-                            let data = std::fs::read(path)?;
-
-                            resize_icon(data)
-                        }
-                        Some("svg") => {
-                            let data = std::fs::read(path)?;
-
-                            let tree = resvg::usvg::Tree::from_data(&data, &resvg::usvg::Options::default())?;
-
-                            let pixmap_size = tree.size().to_int_size();
-                            let mut pixmap =
-                                resvg::tiny_skia::Pixmap::new(pixmap_size.width(), pixmap_size.height()).unwrap();
+    if !shown_in_current_desktop(entry.attr("OnlyShowIn"), entry.attr("NotShowIn"), &current_desktops) {
+        return None;
+    }
 
-                            resvg::render(&tree, resvg::tiny_skia::Transform::default(), &mut pixmap.as_mut());
+    let mut keywords = vec![];
 
-                            let data = pixmap.encode_png()?;
+    for key in ["GenericName", "Comment"] {
+        if let Some(value) = localized_attr(&locales, |param| entry.attr_with_param(key, param), || entry.attr(key)) {
+            keywords.push(value.to_string());
+        }
+    }
 
-                            let data = resize_icon(data)?;
+    if let Some(value) = localized_attr(
+        &locales,
+        |param| entry.attr_with_param("Keywords", param),
+        || entry.attr("Keywords"),
+    ) {
+        keywords.extend(split_list(value));
+    }
 
-                            Ok(data)
-                        }
-                        Some("xpm") => Err(anyhow::anyhow!("xpm format")),
-                        _ => Err(anyhow::anyhow!("unsupported by spec format {:?}", extension)),
-                    }
-                }
-            }
-        })
+    let icon = icon
+        .map(|icon| icons::resolve_icon(&icon, icon_context.icon_theme.as_deref(), &icon_context.icon_cache_dir))
         .map(|res| {
             res.inspect_err(|err| tracing::warn!("error processing icon of {:?}: {:?}", desktop_file_path, err))
                 .ok()
+                .flatten()
         })
         .flatten()
         .map(|buffer| buffer.into());
@@ -274,5 +346,169 @@ fn create_app_entry(desktop_file_path: &Path) -> Option<DesktopApplication> {
         desktop_file_path: desktop_file_path_str,
         icon,
         startup_wm_class,
+        keywords,
+        actions,
     })
 }
+
+// semicolon inside of list item is escaped as "\;"
+fn split_list(value: &str) -> Vec<String> {
+    let mut items = vec![];
+    let mut current = String::new();
+    let mut chars = value.chars();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '\\' => {
+                match chars.next() {
+                    Some(';') => current.push(';'),
+                    // other escape sequences are kept as is
+                    Some(char) => {
+                        current.push('\\');
+                        current.push(char);
+                    }
+                    None => current.push('\\'),
+                }
+            }
+            ';' => items.push(std::mem::take(&mut current)),
+            char => current.push(char),
+        }
+    }
+
+    items.push(current);
+
+    items
+        .into_iter()
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+// applies to both main entry and its actions
+fn shown_in_current_desktop(
+    only_show_in: Option<&str>,
+    not_show_in: Option<&str>,
+    current_desktops: &[String],
+) -> bool {
+    if let Some(only_show_in) = only_show_in {
+        if !split_list(only_show_in)
+            .iter()
+            .any(|desktop| current_desktops.contains(desktop))
+        {
+            return false;
+        }
+    }
+
+    if let Some(not_show_in) = not_show_in {
+        if split_list(not_show_in)
+            .iter()
+            .any(|desktop| current_desktops.contains(desktop))
+        {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn current_desktops() -> Vec<String> {
+    std::env::var("XDG_CURRENT_DESKTOP")
+        .map(|val| {
+            val.split(':')
+                .filter(|desktop| !desktop.is_empty())
+                .map(|desktop| desktop.to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// locale keys in order of precedence, as described in
+// https://specifications.freedesktop.org/desktop-entry-spec/latest/localized-keys.html
+fn current_locales() -> Vec<String> {
+    let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|val| !val.is_empty());
+
+    let Some(locale) = locale else {
+        return vec![];
+    };
+
+    // lang_COUNTRY.ENCODING@MODIFIER
+    let (locale, modifier) = match locale.split_once('@') {
+        Some((locale, modifier)) => (locale, Some(modifier)),
+        None => (locale.as_str(), None),
+    };
+
+    let locale = locale.split_once('.').map(|(locale, _)| locale).unwrap_or(locale);
+
+    let (lang, country) = match locale.split_once('_') {
+        Some((lang, country)) => (lang, Some(country)),
+        None => (locale, None),
+    };
+
+    if lang.is_empty() || lang == "C" || lang == "POSIX" {
+        return vec![];
+    }
+
+    let mut result = vec![];
+
+    if let (Some(country), Some(modifier)) = (country, modifier) {
+        result.push(format!("{}_{}@{}", lang, country, modifier));
+    }
+
+    if let Some(country) = country {
+        result.push(format!("{}_{}", lang, country));
+    }
+
+    if let Some(modifier) = modifier {
+        result.push(format!("{}@{}", lang, modifier));
+    }
+
+    result.push(lang.to_string());
+
+    result
+}
+
+fn localized_attr<'a>(
+    locales: &[String],
+    localized: impl Fn(&str) -> Option<&'a str>,
+    default: impl FnOnce() -> Option<&'a str>,
+) -> Option<&'a str> {
+    locales.iter().find_map(|locale| localized(locale)).or_else(default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_list_splits_on_semicolons() {
+        assert_eq!(split_list("GNOME;KDE;"), vec!["GNOME", "KDE"]);
+        assert_eq!(split_list(" web ; browser ;; "), vec!["web", "browser"]);
+        assert_eq!(split_list(""), Vec::<String>::new());
+    }
+
+    #[test]
+    fn split_list_handles_escaped_semicolons() {
+        assert_eq!(split_list(r"a\;b;c"), vec!["a;b", "c"]);
+        assert_eq!(split_list(r"a\;"), vec!["a;"]);
+    }
+
+    #[test]
+    fn split_list_keeps_other_escapes() {
+        assert_eq!(split_list(r"a\\;b"), vec![r"a\\", "b"]);
+        assert_eq!(split_list(r"a\sb;c\"), vec![r"a\sb", r"c\"]);
+    }
+
+    #[test]
+    fn shown_in_current_desktop_evaluates_lists() {
+        let current_desktops = vec!["ubuntu".to_string(), "GNOME".to_string()];
+
+        assert!(shown_in_current_desktop(None, None, &current_desktops));
+        assert!(shown_in_current_desktop(Some("GNOME;"), None, &current_desktops));
+        assert!(!shown_in_current_desktop(Some("KDE;"), None, &current_desktops));
+        assert!(!shown_in_current_desktop(None, Some("KDE;GNOME;"), &current_desktops));
+        assert!(shown_in_current_desktop(None, Some("KDE;"), &current_desktops));
+    }
+}
@@ -4,6 +4,9 @@ use anyhow::anyhow;
 use cosmic_protocols::toplevel_info::v1::client::zcosmic_toplevel_handle_v1;
 use cosmic_protocols::toplevel_info::v1::client::zcosmic_toplevel_info_v1;
 use cosmic_protocols::toplevel_management::v1::client::zcosmic_toplevel_manager_v1;
+use cosmic_protocols::workspace::v1::client::zcosmic_workspace_group_handle_v1;
+use cosmic_protocols::workspace::v1::client::zcosmic_workspace_handle_v1;
+use cosmic_protocols::workspace::v1::client::zcosmic_workspace_manager_v1;
 use smithay_client_toolkit::seat::SeatState;
 use wayland_client::Connection;
 use wayland_client::Dispatch;
@@ -12,17 +15,36 @@ use wayland_client::QueueHandle;
 use wayland_client::backend::ObjectId;
 use wayland_client::event_created_child;
 use wayland_client::globals::GlobalList;
+use wayland_client::protocol::wl_output::WlOutput;
+use wayland_client::protocol::wl_registry::WlRegistry;
 
+use crate::plugins::applications::linux::JsWindowAction;
+use crate::plugins::applications::linux::JsWorkspace;
 use crate::plugins::applications::linux::wayland::JsWaylandApplicationEvent;
 use crate::plugins::applications::linux::wayland::WaylandState;
 use crate::plugins::applications::linux::wayland::WaylandStateInner;
+use crate::plugins::applications::linux::wayland::WaylandWindowState;
 use crate::plugins::applications::linux::wayland::send_event;
 
 pub struct CosmicWaylandState {
     uuid_to_obj_id: HashMap<String, ObjectId>,
     obj_id_to_uuid: HashMap<ObjectId, String>,
+    states: HashMap<ObjectId, WaylandWindowState>,
     toplevels: HashMap<ObjectId, zcosmic_toplevel_handle_v1::ZcosmicToplevelHandleV1>,
+    toplevel_outputs: HashMap<ObjectId, Vec<WlOutput>>,
+    workspace_group_outputs: HashMap<ObjectId, Vec<WlOutput>>,
+    workspaces: Vec<CosmicWorkspace>,
+    next_workspace_id: u32,
     management: zcosmic_toplevel_manager_v1::ZcosmicToplevelManagerV1,
+    _info: zcosmic_toplevel_info_v1::ZcosmicToplevelInfoV1,
+    _workspace_manager: Option<zcosmic_workspace_manager_v1::ZcosmicWorkspaceManagerV1>,
+}
+
+struct CosmicWorkspace {
+    id: u32,
+    name: Option<String>,
+    group: ObjectId,
+    handle: zcosmic_workspace_handle_v1::ZcosmicWorkspaceHandleV1,
 }
 
 impl CosmicWaylandState {
@@ -30,14 +52,54 @@ impl CosmicWaylandState {
         let management =
             globals.bind::<zcosmic_toplevel_manager_v1::ZcosmicToplevelManagerV1, _, _>(&queue_handle, 3..=3, ())?;
 
+        let info = globals.bind::<zcosmic_toplevel_info_v1::ZcosmicToplevelInfoV1, _, _>(&queue_handle, 1..=1, ())?;
+
+        // workspaces are optional, window management still works without them
+        let workspace_manager = globals
+            .bind::<zcosmic_workspace_manager_v1::ZcosmicWorkspaceManagerV1, _, _>(&queue_handle, 1..=1, ())
+            .inspect_err(|err| tracing::warn!("Unable to bind cosmic workspace manager: {:?}", err))
+            .ok();
+
+        // outputs need to be bound for compositor to be able to tell on which output windows and workspaces are
+        for global in globals.contents().clone_list() {
+            if global.interface == WlOutput::interface().name {
+                bind_output(globals.registry(), global.name, global.version, queue_handle);
+            }
+        }
+
         Ok(Self {
             management,
+            _info: info,
+            _workspace_manager: workspace_manager,
             uuid_to_obj_id: HashMap::new(),
             obj_id_to_uuid: HashMap::new(),
             toplevels: HashMap::new(),
+            states: HashMap::new(),
+            toplevel_outputs: HashMap::new(),
+            workspace_group_outputs: HashMap::new(),
+            workspaces: vec![],
+            next_workspace_id: 0,
         })
     }
 
+    fn js_workspaces(&self) -> Vec<JsWorkspace> {
+        self.workspaces
+            .iter()
+            .filter_map(|workspace| {
+                let name = workspace.name.as_ref()?;
+
+                // cosmic names workspaces using their number
+                let name = if name.chars().all(|char| char.is_ascii_digit()) {
+                    format!("Workspace {}", name)
+                } else {
+                    name.clone()
+                };
+
+                Some(JsWorkspace { id: workspace.id, name })
+            })
+            .collect()
+    }
+
     pub fn focus_window(&self, window_uuid: String, seat_state: &SeatState) -> anyhow::Result<()> {
         let obj_id = self
             .uuid_to_obj_id
@@ -56,6 +118,58 @@ impl CosmicWaylandState {
 
         Ok(())
     }
+
+    pub fn window_action(&self, window_uuid: String, action: JsWindowAction) -> anyhow::Result<()> {
+        let obj_id = self
+            .uuid_to_obj_id
+            .get(&window_uuid)
+            .ok_or(anyhow!("Unable to find object id for window uuid: {}", window_uuid))?;
+
+        let toplevel = self
+            .toplevels
+            .get(&obj_id)
+            .ok_or(anyhow!("Unable to find object id for window uuid: {}", window_uuid))?;
+
+        let state = self.states.get(&obj_id).cloned().unwrap_or_default();
+
+        match action {
+            JsWindowAction::Close => self.management.close(&toplevel),
+            JsWindowAction::Minimize => self.management.set_minimized(&toplevel),
+            JsWindowAction::ToggleMaximize => {
+                if state.maximized {
+                    self.management.unset_maximized(&toplevel)
+                } else {
+                    self.management.set_maximized(&toplevel)
+                }
+            }
+            JsWindowAction::ToggleFullscreen => {
+                if state.fullscreen {
+                    self.management.unset_fullscreen(&toplevel)
+                } else {
+                    self.management.set_fullscreen(&toplevel, None)
+                }
+            }
+            JsWindowAction::MoveToWorkspace { workspace } => {
+                let workspace = self
+                    .workspaces
+                    .iter()
+                    .find(|value| value.id == workspace)
+                    .ok_or(anyhow!("Unable to find workspace with id: {}", workspace))?;
+
+                // workspace is moved to output of its group, toplevel output is only a fallback
+                let output = self
+                    .workspace_group_outputs
+                    .get(&workspace.group)
+                    .and_then(|outputs| outputs.first())
+                    .or_else(|| self.toplevel_outputs.get(&obj_id).and_then(|outputs| outputs.first()))
+                    .ok_or(anyhow!("Unable to find output for workspace with id: {}", workspace.id))?;
+
+                self.management.move_to_workspace(&toplevel, &workspace.handle, output)
+            }
+        };
+
+        Ok(())
+    }
 }
 
 impl Dispatch<zcosmic_toplevel_manager_v1::ZcosmicToplevelManagerV1, ()> for WaylandState {
@@ -165,10 +279,40 @@ impl Dispatch<zcosmic_toplevel_handle_v1::ZcosmicToplevelHandleV1, ()> for Wayla
                     _ => {}
                 }
             }
+            zcosmic_toplevel_handle_v1::Event::State { state: raw_state } => {
+                match &mut state.inner {
+                    WaylandStateInner::Cosmic(inner) => {
+                        inner
+                            .states
+                            .insert(proxy.id(), WaylandWindowState::from_raw(&raw_state));
+                    }
+                    _ => {}
+                }
+            }
+            zcosmic_toplevel_handle_v1::Event::OutputEnter { output } => {
+                match &mut state.inner {
+                    WaylandStateInner::Cosmic(inner) => {
+                        inner.toplevel_outputs.entry(proxy.id()).or_default().push(output);
+                    }
+                    _ => {}
+                }
+            }
+            zcosmic_toplevel_handle_v1::Event::OutputLeave { output } => {
+                match &mut state.inner {
+                    WaylandStateInner::Cosmic(inner) => {
+                        if let Some(outputs) = inner.toplevel_outputs.get_mut(&proxy.id()) {
+                            outputs.retain(|value| value != &output);
+                        }
+                    }
+                    _ => {}
+                }
+            }
             zcosmic_toplevel_handle_v1::Event::Closed => {
                 match &mut state.inner {
                     WaylandStateInner::Cosmic(inner) => {
                         inner.toplevels.remove(&proxy.id());
+                        inner.states.remove(&proxy.id());
+                        inner.toplevel_outputs.remove(&proxy.id());
                         match inner.obj_id_to_uuid.remove(&proxy.id()) {
                             Some(window_id) => {
                                 inner.uuid_to_obj_id.remove(&window_id);
@@ -195,3 +339,138 @@ impl Dispatch<zcosmic_toplevel_handle_v1::ZcosmicToplevelHandleV1, ()> for Wayla
         }
     }
 }
+
+pub fn bind_output(registry: &WlRegistry, name: u32, version: u32, queue_handle: &QueueHandle<WaylandState>) {
+    registry.bind::<WlOutput, _, _>(name, version.min(4), queue_handle, ());
+}
+
+impl Dispatch<WlOutput, ()> for WaylandState {
+    fn event(
+        _state: &mut Self,
+        _proxy: &WlOutput,
+        _event: <WlOutput as Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+    }
+}
+
+impl Dispatch<zcosmic_workspace_manager_v1::ZcosmicWorkspaceManagerV1, ()> for WaylandState {
+    fn event(
+        state: &mut Self,
+        _proxy: &zcosmic_workspace_manager_v1::ZcosmicWorkspaceManagerV1,
+        event: <zcosmic_workspace_manager_v1::ZcosmicWorkspaceManagerV1 as Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        match event {
+            zcosmic_workspace_manager_v1::Event::WorkspaceGroup { workspace_group } => {
+                match &mut state.inner {
+                    WaylandStateInner::Cosmic(inner) => {
+                        inner.workspace_group_outputs.insert(workspace_group.id(), vec![]);
+                    }
+                    _ => {}
+                }
+            }
+            // all changes to workspaces are applied atomically on done event
+            zcosmic_workspace_manager_v1::Event::Done => {
+                match &state.inner {
+                    WaylandStateInner::Cosmic(inner) => {
+                        *state.workspaces.lock().expect("lock is poisoned") = inner.js_workspaces();
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    event_created_child!(WaylandState, zcosmic_workspace_manager_v1::ZcosmicWorkspaceManagerV1, [
+        zcosmic_workspace_manager_v1::EVT_WORKSPACE_GROUP_OPCODE => (zcosmic_workspace_group_handle_v1::ZcosmicWorkspaceGroupHandleV1, ()),
+    ]);
+}
+
+impl Dispatch<zcosmic_workspace_group_handle_v1::ZcosmicWorkspaceGroupHandleV1, ()> for WaylandState {
+    fn event(
+        state: &mut Self,
+        proxy: &zcosmic_workspace_group_handle_v1::ZcosmicWorkspaceGroupHandleV1,
+        event: <zcosmic_workspace_group_handle_v1::ZcosmicWorkspaceGroupHandleV1 as Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        let WaylandStateInner::Cosmic(inner) = &mut state.inner else {
+            return;
+        };
+
+        match event {
+            zcosmic_workspace_group_handle_v1::Event::OutputEnter { output } => {
+                inner
+                    .workspace_group_outputs
+                    .entry(proxy.id())
+                    .or_default()
+                    .push(output);
+            }
+            zcosmic_workspace_group_handle_v1::Event::OutputLeave { output } => {
+                if let Some(outputs) = inner.workspace_group_outputs.get_mut(&proxy.id()) {
+                    outputs.retain(|value| value != &output);
+                }
+            }
+            zcosmic_workspace_group_handle_v1::Event::Workspace { workspace } => {
+                let id = inner.next_workspace_id;
+                inner.next_workspace_id += 1;
+
+                inner.workspaces.push(CosmicWorkspace {
+                    id,
+                    name: None,
+                    group: proxy.id(),
+                    handle: workspace,
+                });
+            }
+            zcosmic_workspace_group_handle_v1::Event::Remove => {
+                inner.workspace_group_outputs.remove(&proxy.id());
+            }
+            _ => {}
+        }
+    }
+
+    event_created_child!(WaylandState, zcosmic_workspace_group_handle_v1::ZcosmicWorkspaceGroupHandleV1, [
+        zcosmic_workspace_group_handle_v1::EVT_WORKSPACE_OPCODE => (zcosmic_workspace_handle_v1::ZcosmicWorkspaceHandleV1, ()),
+    ]);
+}
+
+impl Dispatch<zcosmic_workspace_handle_v1::ZcosmicWorkspaceHandleV1, ()> for WaylandState {
+    fn event(
+        state: &mut Self,
+        proxy: &zcosmic_workspace_handle_v1::ZcosmicWorkspaceHandleV1,
+        event: <zcosmic_workspace_handle_v1::ZcosmicWorkspaceHandleV1 as Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        let WaylandStateInner::Cosmic(inner) = &mut state.inner else {
+            return;
+        };
+
+        match event {
+            zcosmic_workspace_handle_v1::Event::Name { name } => {
+                match inner
+                    .workspaces
+                    .iter_mut()
+                    .find(|workspace| workspace.handle.id() == proxy.id())
+                {
+                    Some(workspace) => workspace.name = Some(name),
+                    None => {
+                        tracing::warn!("Received event for cosmic workspace that doesn't exist in state");
+                    }
+                }
+            }
+            zcosmic_workspace_handle_v1::Event::Remove => {
+                inner.workspaces.retain(|workspace| workspace.handle.id() != proxy.id());
+            }
+            _ => {}
+        }
+    }
+}
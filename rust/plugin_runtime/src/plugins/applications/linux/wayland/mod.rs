@@ -1,5 +1,7 @@
 use std::cell::RefCell;
 use std::rc::Rc;
+use std::sync::Arc;
+use std::sync::Mutex;
 use std::thread;
 
 use anyhow::anyhow;
@@ -20,10 +22,12 @@ use tokio::sync::mpsc::Receiver;
 use tokio::sync::mpsc::Sender;
 use wayland_client::Connection;
 use wayland_client::Dispatch;
+use wayland_client::Proxy;
 use wayland_client::QueueHandle;
 use wayland_client::globals::GlobalList;
 use wayland_client::globals::GlobalListContents;
 use wayland_client::globals::registry_queue_init;
+use wayland_client::protocol::wl_output::WlOutput;
 use wayland_client::protocol::wl_registry;
 use wayland_client::protocol::wl_seat::WlSeat;
 
@@ -31,23 +35,28 @@ use crate::deno::GauntletJsError;
 use crate::plugins::applications::ApplicationContext;
 use crate::plugins::applications::DesktopEnvironment;
 use crate::plugins::applications::linux;
+use crate::plugins::applications::linux::JsWindowAction;
+use crate::plugins::applications::linux::JsWorkspace;
 
 mod cosmic;
 mod wlr;
 
 pub struct WaylandDesktopEnvironment {
-    activate_sender: calloop::channel::Sender<String>,
+    request_sender: calloop::channel::Sender<WaylandWindowRequest>,
     event_receiver: Rc<RefCell<Receiver<JsWaylandApplicationEvent>>>,
+    workspaces: Arc<Mutex<Vec<JsWorkspace>>>,
 }
 
 impl WaylandDesktopEnvironment {
     pub fn new() -> anyhow::Result<WaylandDesktopEnvironment> {
         let (event_sender, event_receiver) = tokio::sync::mpsc::channel(100);
-        let (activate_sender, activate_receiver) = calloop::channel::channel();
+        let (request_sender, request_receiver) = calloop::channel::channel();
+        let workspaces = Arc::new(Mutex::new(vec![]));
 
         let environment = WaylandDesktopEnvironment {
-            activate_sender,
+            request_sender,
             event_receiver: Rc::new(RefCell::new(event_receiver)),
+            workspaces: workspaces.clone(),
         };
 
         let handle = Handle::current();
@@ -55,7 +64,7 @@ impl WaylandDesktopEnvironment {
         thread::Builder::new()
             .name("gauntlet-wayland-events".to_string())
             .spawn(|| {
-                if let Err(e) = run_wayland_client(handle, event_sender, activate_receiver) {
+                if let Err(e) = run_wayland_client(handle, event_sender, request_receiver, workspaces) {
                     tracing::error!("Error while running wayland client: {:?}", e);
                 }
             })
@@ -65,10 +74,55 @@ impl WaylandDesktopEnvironment {
     }
 
     pub fn focus_window(&self, window_uuid: String) -> anyhow::Result<()> {
-        self.activate_sender.send(window_uuid)?;
+        self.request_sender.send(WaylandWindowRequest::Focus { window_uuid })?;
 
         Ok(())
     }
+
+    pub fn window_action(&self, window_uuid: String, action: JsWindowAction) -> anyhow::Result<()> {
+        self.request_sender
+            .send(WaylandWindowRequest::Action { window_uuid, action })?;
+
+        Ok(())
+    }
+
+    pub fn workspaces(&self) -> Vec<JsWorkspace> {
+        self.workspaces.lock().expect("lock is poisoned").clone()
+    }
+}
+
+pub enum WaylandWindowRequest {
+    Focus {
+        window_uuid: String,
+    },
+    Action {
+        window_uuid: String,
+        action: JsWindowAction,
+    },
+}
+
+// https://wayland.app/protocols/wlr-foreign-toplevel-management-unstable-v1#zwlr_foreign_toplevel_handle_v1:enum:state
+// cosmic toplevel info protocol uses the same values
+#[derive(Debug, Default, Clone, Copy)]
+pub struct WaylandWindowState {
+    maximized: bool,
+    fullscreen: bool,
+}
+
+impl WaylandWindowState {
+    fn from_raw(raw: &[u8]) -> Self {
+        let mut state = WaylandWindowState::default();
+
+        for value in raw.chunks_exact(4) {
+            match u32::from_ne_bytes([value[0], value[1], value[2], value[3]]) {
+                0 => state.maximized = true,
+                3 => state.fullscreen = true,
+                _ => {}
+            }
+        }
+
+        state
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -84,6 +138,7 @@ pub struct WaylandState {
     seat_state: SeatState,
     tokio_handle: Handle,
     sender: Sender<JsWaylandApplicationEvent>,
+    workspaces: Arc<Mutex<Vec<JsWorkspace>>>,
     inner: WaylandStateInner,
 }
 
@@ -91,6 +146,7 @@ impl WaylandState {
     fn new(
         tokio_handle: Handle,
         sender: Sender<JsWaylandApplicationEvent>,
+        workspaces: Arc<Mutex<Vec<JsWorkspace>>>,
         seat_state: SeatState,
         globals: &GlobalList,
         queue_handle: &QueueHandle<WaylandState>,
@@ -106,6 +162,7 @@ impl WaylandState {
             seat_state,
             tokio_handle,
             sender,
+            workspaces,
             inner,
         })
     }
@@ -129,7 +186,8 @@ fn send_event(tokio_handle: &Handle, sender: &Sender<JsWaylandApplicationEvent>,
 fn run_wayland_client(
     tokio_handle: Handle,
     event_sender: Sender<JsWaylandApplicationEvent>,
-    activate_receiver: Channel<String>,
+    request_receiver: Channel<WaylandWindowRequest>,
+    workspaces: Arc<Mutex<Vec<JsWorkspace>>>,
 ) -> anyhow::Result<()> {
     let conn = Connection::connect_to_env()?;
     let (globals, event_queue) = registry_queue_init::<WaylandState>(&conn)?;
@@ -140,10 +198,10 @@ fn run_wayland_client(
     let seat_state = SeatState::new(&globals, &queue_handle);
     let loop_handle = event_loop.handle();
 
-    if let Err(err) = loop_handle.insert_source(activate_receiver, activation_handler) {
-        tracing::error!("Unable to insert activation source into event loop: {:?}", err);
+    if let Err(err) = loop_handle.insert_source(request_receiver, request_handler) {
+        tracing::error!("Unable to insert request source into event loop: {:?}", err);
 
-        Err(anyhow!("Unable to insert activation source into event loop"))?
+        Err(anyhow!("Unable to insert request source into event loop"))?
     };
 
     if let Err(err) = wayland_source.insert(loop_handle) {
@@ -152,7 +210,14 @@ fn run_wayland_client(
         Err(anyhow!("Unable to insert wayland source into event loop"))?
     };
 
-    let mut state = WaylandState::new(tokio_handle, event_sender, seat_state, &globals, &queue_handle)?;
+    let mut state = WaylandState::new(
+        tokio_handle,
+        event_sender,
+        workspaces,
+        seat_state,
+        &globals,
+        &queue_handle,
+    )?;
 
     loop {
         if let Err(err) = event_loop.dispatch(None, &mut state) {
@@ -185,25 +250,77 @@ pub fn linux_wayland_focus_window(
     Ok(())
 }
 
-fn activation_handler(event: Event<String>, _metadata: &mut (), state: &mut WaylandState) {
-    let window_uuid = match event {
-        Event::Msg(window_uuid) => window_uuid,
-        Event::Closed => panic!("activation source was closed"),
+#[op2]
+pub fn linux_wayland_window_action(
+    state: Rc<RefCell<OpState>>,
+    #[string] window_uuid: String,
+    #[serde] action: JsWindowAction,
+) -> Result<(), GauntletJsError> {
+    {
+        let state = state.borrow();
+
+        let context = state.borrow::<ApplicationContext>();
+
+        match &context.desktop {
+            DesktopEnvironment::Linux(linux::LinuxDesktopEnvironment::Wayland(env)) => {
+                env.window_action(window_uuid, action)?;
+            }
+            _ => Err(anyhow!("Calling linux_wayland_window_action on non-wayland platform"))?,
+        };
     };
 
-    match &state.inner {
-        WaylandStateInner::Wlr(wlr) => {
-            if let Err(err) = wlr.focus_window(window_uuid, &state.seat_state) {
-                tracing::error!("Unable to focus wayland window: {:?}", err);
+    Ok(())
+}
+
+#[op2]
+#[serde]
+pub fn linux_wayland_workspaces(state: Rc<RefCell<OpState>>) -> Result<Vec<JsWorkspace>, GauntletJsError> {
+    let state = state.borrow();
+
+    let context = state.borrow::<ApplicationContext>();
+
+    match &context.desktop {
+        DesktopEnvironment::Linux(linux::LinuxDesktopEnvironment::Wayland(env)) => Ok(env.workspaces()),
+        _ => Err(anyhow!("Calling linux_wayland_workspaces on non-wayland platform"))?,
+    }
+}
+
+fn request_handler(event: Event<WaylandWindowRequest>, _metadata: &mut (), state: &mut WaylandState) {
+    let request = match event {
+        Event::Msg(request) => request,
+        Event::Closed => panic!("request source was closed"),
+    };
+
+    match request {
+        WaylandWindowRequest::Focus { window_uuid } => {
+            let result = match &state.inner {
+                WaylandStateInner::Wlr(wlr) => wlr.focus_window(window_uuid, &state.seat_state),
+                WaylandStateInner::Cosmic(cosmic) => cosmic.focus_window(window_uuid, &state.seat_state),
+                WaylandStateInner::None => {
+                    Err(anyhow!(
+                        "Calling focus window when there is no supported wayland protocols available"
+                    ))
+                }
             };
-        }
-        WaylandStateInner::Cosmic(cosmic) => {
-            if let Err(err) = cosmic.focus_window(window_uuid, &state.seat_state) {
+
+            if let Err(err) = result {
                 tracing::error!("Unable to focus wayland window: {:?}", err);
             };
         }
-        WaylandStateInner::None => {
-            tracing::error!("Calling focus window when there is no supported wayland protocols available");
+        WaylandWindowRequest::Action { window_uuid, action } => {
+            let result = match &state.inner {
+                WaylandStateInner::Wlr(wlr) => wlr.window_action(window_uuid, action),
+                WaylandStateInner::Cosmic(cosmic) => cosmic.window_action(window_uuid, action),
+                WaylandStateInner::None => {
+                    Err(anyhow!(
+                        "Calling window action when there is no supported wayland protocols available"
+                    ))
+                }
+            };
+
+            if let Err(err) = result {
+                tracing::error!("Unable to run action on wayland window: {:?}", err);
+            };
         }
     }
 }
@@ -241,13 +358,28 @@ pub async fn application_wayland_pending_event(
 
 impl Dispatch<wl_registry::WlRegistry, GlobalListContents> for WaylandState {
     fn event(
-        _state: &mut WaylandState,
-        _proxy: &wl_registry::WlRegistry,
-        _event: wl_registry::Event,
+        state: &mut WaylandState,
+        proxy: &wl_registry::WlRegistry,
+        event: wl_registry::Event,
         _data: &GlobalListContents,
         _conn: &Connection,
-        _qhandle: &QueueHandle<WaylandState>,
+        qhandle: &QueueHandle<WaylandState>,
     ) {
+        match event {
+            wl_registry::Event::Global {
+                name,
+                interface,
+                version,
+            } => {
+                match &state.inner {
+                    WaylandStateInner::Cosmic(_) if interface == WlOutput::interface().name => {
+                        cosmic::bind_output(proxy, name, version, qhandle);
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
     }
 }
 
@@ -12,14 +12,17 @@ use wayland_client::globals::GlobalList;
 use wayland_protocols_wlr::foreign_toplevel::v1::client::zwlr_foreign_toplevel_handle_v1;
 use wayland_protocols_wlr::foreign_toplevel::v1::client::zwlr_foreign_toplevel_manager_v1;
 
+use crate::plugins::applications::linux::JsWindowAction;
 use crate::plugins::applications::linux::wayland::JsWaylandApplicationEvent;
 use crate::plugins::applications::linux::wayland::WaylandState;
 use crate::plugins::applications::linux::wayland::WaylandStateInner;
+use crate::plugins::applications::linux::wayland::WaylandWindowState;
 use crate::plugins::applications::linux::wayland::send_event;
 
 pub struct WlrWaylandState {
     uuid_to_obj_id: HashMap<String, ObjectId>,
     obj_id_to_uuid: HashMap<ObjectId, String>,
+    states: HashMap<ObjectId, WaylandWindowState>,
     toplevels: HashMap<ObjectId, zwlr_foreign_toplevel_handle_v1::ZwlrForeignToplevelHandleV1>,
 }
 
@@ -35,6 +38,7 @@ impl WlrWaylandState {
             uuid_to_obj_id: HashMap::new(),
             obj_id_to_uuid: HashMap::new(),
             toplevels: HashMap::new(),
+            states: HashMap::new(),
         })
     }
 
@@ -56,6 +60,46 @@ impl WlrWaylandState {
 
         Ok(())
     }
+
+    pub fn window_action(&self, window_uuid: String, action: JsWindowAction) -> anyhow::Result<()> {
+        let obj_id = self
+            .uuid_to_obj_id
+            .get(&window_uuid)
+            .ok_or(anyhow!("Unable to find object id for window uuid: {}", window_uuid))?;
+
+        let toplevel = self
+            .toplevels
+            .get(&obj_id)
+            .ok_or(anyhow!("Unable to find object id for window uuid: {}", window_uuid))?;
+
+        let state = self.states.get(&obj_id).cloned().unwrap_or_default();
+
+        match action {
+            JsWindowAction::Close => toplevel.close(),
+            JsWindowAction::Minimize => toplevel.set_minimized(),
+            JsWindowAction::ToggleMaximize => {
+                if state.maximized {
+                    toplevel.unset_maximized()
+                } else {
+                    toplevel.set_maximized()
+                }
+            }
+            JsWindowAction::ToggleFullscreen => {
+                if state.fullscreen {
+                    toplevel.unset_fullscreen()
+                } else {
+                    toplevel.set_fullscreen(None)
+                }
+            }
+            JsWindowAction::MoveToWorkspace { .. } => {
+                Err(anyhow!(
+                    "Moving window to workspace is not supported by wlr foreign toplevel protocol"
+                ))?
+            }
+        };
+
+        Ok(())
+    }
 }
 
 impl Dispatch<zwlr_foreign_toplevel_manager_v1::ZwlrForeignToplevelManagerV1, ()> for WaylandState {
@@ -149,10 +193,21 @@ impl Dispatch<zwlr_foreign_toplevel_handle_v1::ZwlrForeignToplevelHandleV1, ()>
                     _ => {}
                 }
             }
+            zwlr_foreign_toplevel_handle_v1::Event::State { state: raw_state } => {
+                match &mut state.inner {
+                    WaylandStateInner::Wlr(inner) => {
+                        inner
+                            .states
+                            .insert(proxy.id(), WaylandWindowState::from_raw(&raw_state));
+                    }
+                    _ => {}
+                }
+            }
             zwlr_foreign_toplevel_handle_v1::Event::Closed => {
                 match &mut state.inner {
                     WaylandStateInner::Wlr(inner) => {
                         inner.toplevels.remove(&proxy.id());
+                        inner.states.remove(&proxy.id());
                         match inner.obj_id_to_uuid.remove(&proxy.id()) {
                             Some(window_id) => {
                                 inner.uuid_to_obj_id.remove(&window_id);
@@ -32,6 +32,8 @@ use crate::deno::GauntletJsError;
 use crate::plugins::applications::ApplicationContext;
 use crate::plugins::applications::DesktopEnvironment;
 use crate::plugins::applications::linux;
+use crate::plugins::applications::linux::JsWindowAction;
+use crate::plugins::applications::linux::JsWorkspace;
 
 pub struct X11DesktopEnvironment {
     receiver: Rc<RefCell<Receiver<JsX11ApplicationEvent>>>,
@@ -216,6 +218,132 @@ fn focus_window(window_id: String) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[op2]
+pub fn linux_x11_window_action(
+    #[string] x11_window_id: String,
+    #[serde] action: JsWindowAction,
+) -> Result<(), GauntletJsError> {
+    window_action(x11_window_id, action)?;
+
+    Ok(())
+}
+
+#[op2]
+#[serde]
+pub fn linux_x11_workspaces() -> Result<Vec<JsWorkspace>, GauntletJsError> {
+    Ok(workspaces()?)
+}
+
+fn window_action(window_id: String, action: JsWindowAction) -> anyhow::Result<()> {
+    let window = Window::from_str(&window_id)?;
+
+    let (conn, screen_num) = RustConnection::connect(None)?;
+    let screen = &conn.setup().roots[screen_num];
+    let atoms = atoms::Atoms::new(&conn)?.reply()?;
+
+    // same as in focus_window
+    let source_indication: u32 = 2; // XCB_EWMH_CLIENT_SOURCE_TYPE_OTHER
+    let timestamp: u32 = x11rb::CURRENT_TIME;
+
+    // https://specifications.freedesktop.org/wm-spec/latest/ar01s05.html#id-1.6.8
+    let net_wm_state_toggle: u32 = 2;
+
+    // https://tronche.com/gui/x/icccm/sec-4.html#s-4.1.4
+    let iconic_state: u32 = 3;
+
+    let (message_type, data) = match action {
+        JsWindowAction::Close => (atoms._NET_CLOSE_WINDOW, [timestamp, source_indication, 0, 0, 0]),
+        JsWindowAction::Minimize => (atoms.WM_CHANGE_STATE, [iconic_state, 0, 0, 0, 0]),
+        JsWindowAction::ToggleMaximize => {
+            (
+                atoms._NET_WM_STATE,
+                [
+                    net_wm_state_toggle,
+                    atoms._NET_WM_STATE_MAXIMIZED_VERT,
+                    atoms._NET_WM_STATE_MAXIMIZED_HORZ,
+                    source_indication,
+                    0,
+                ],
+            )
+        }
+        JsWindowAction::ToggleFullscreen => {
+            (
+                atoms._NET_WM_STATE,
+                [
+                    net_wm_state_toggle,
+                    atoms._NET_WM_STATE_FULLSCREEN,
+                    0,
+                    source_indication,
+                    0,
+                ],
+            )
+        }
+        JsWindowAction::MoveToWorkspace { workspace } => {
+            (atoms._NET_WM_DESKTOP, [workspace, source_indication, 0, 0, 0])
+        }
+    };
+
+    let event = ClientMessageEvent::new(32, window, message_type, data);
+
+    conn.send_event(
+        false,
+        screen.root,
+        EventMask::SUBSTRUCTURE_NOTIFY | EventMask::SUBSTRUCTURE_REDIRECT,
+        event,
+    )?;
+
+    conn.flush()?;
+
+    Ok(())
+}
+
+fn workspaces() -> anyhow::Result<Vec<JsWorkspace>> {
+    let (conn, screen_num) = RustConnection::connect(None)?;
+    let screen = &conn.setup().roots[screen_num];
+    let atoms = atoms::Atoms::new(&conn)?.reply()?;
+
+    let number_of_desktops = conn
+        .get_property(
+            false,
+            screen.root,
+            atoms._NET_NUMBER_OF_DESKTOPS,
+            AtomEnum::CARDINAL,
+            0,
+            1,
+        )?
+        .reply()?
+        .value32()
+        .and_then(|mut iter| iter.next())
+        .unwrap_or(0);
+
+    // null separated list of utf8 strings
+    let desktop_names_property = conn
+        .get_property(false, screen.root, atoms._NET_DESKTOP_NAMES, atoms.UTF8_STRING, 0, 2048)?
+        .reply()?;
+
+    let desktop_names: Vec<String> = desktop_names_property
+        .value8()
+        .map(|bytes| bytes.collect::<Vec<u8>>())
+        .unwrap_or_default()
+        .split(|byte| *byte == 0)
+        .map(|name| String::from_utf8_lossy(name).to_string())
+        .collect();
+
+    let workspaces = (0..number_of_desktops)
+        .map(|id| {
+            let name = desktop_names
+                .get(id as usize)
+                .filter(|name| !name.is_empty())
+                .cloned()
+                .unwrap_or_else(|| format!("Workspace {}", id + 1));
+
+            JsWorkspace { id, name }
+        })
+        .collect();
+
+    Ok(workspaces)
+}
+
 fn send_event(tokio_handle: &Handle, sender: &Sender<JsX11ApplicationEvent>, app_event: JsX11ApplicationEvent) {
     let sender = sender.clone();
     tokio_handle.spawn(async move {
@@ -656,6 +784,7 @@ mod atoms {
             WM_PROTOCOLS,
             WM_TAKE_FOCUS,
             WM_DELETE_WINDOW,
+            WM_CHANGE_STATE,
             _NET_WM_NAME,
             _NET_WM_PID,
             _NET_WM_WINDOW_TYPE,
@@ -671,6 +800,14 @@ mod atoms {
             _NET_WM_WINDOW_TYPE_UTILITY,
             _NET_WM_STATE_MODAL,
             _NET_ACTIVE_WINDOW,
+            _NET_CLOSE_WINDOW,
+            _NET_WM_STATE,
+            _NET_WM_STATE_MAXIMIZED_VERT,
+            _NET_WM_STATE_MAXIMIZED_HORZ,
+            _NET_WM_STATE_FULLSCREEN,
+            _NET_WM_DESKTOP,
+            _NET_NUMBER_OF_DESKTOPS,
+            _NET_DESKTOP_NAMES,
 
             // non-standard
             _KDE_NET_WM_DESKTOP_FILE,
@@ -0,0 +1,366 @@
+use std::path::Path;
+use std::sync::Mutex;
+
+use serde::Serialize;
+use tantivy::Index;
+use tantivy::IndexReader;
+use tantivy::IndexWriter;
+use tantivy::ReloadPolicy;
+use tantivy::TantivyDocument;
+use tantivy::Term;
+use tantivy::collector::TopDocs;
+use tantivy::directory::MmapDirectory;
+use tantivy::query::AllQuery;
+use tantivy::query::BooleanQuery;
+use tantivy::query::Occur;
+use tantivy::query::Query;
+use tantivy::query::RegexQuery;
+use tantivy::query::TermQuery;
+use tantivy::schema::Field;
+use tantivy::schema::INDEXED;
+use tantivy::schema::IndexRecordOption;
+use tantivy::schema::STORED;
+use tantivy::schema::STRING;
+use tantivy::schema::Schema;
+use tantivy::schema::TEXT;
+use tantivy::schema::Value;
+use tantivy::tokenizer::TokenStream;
+
+// only this many candidates are ranked, everything else is considered irrelevant
+const CANDIDATE_LIMIT: usize = 500;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FileSearchItem {
+    pub path: String,
+    pub name: String,
+    pub directory: bool,
+}
+
+pub struct FileIndex {
+    index: Index,
+    index_reader: IndexReader,
+    // tantivy allows only one writer per index
+    index_writer: Mutex<IndexWriter>,
+
+    path: Field,
+    name: Field,
+    path_text: Field,
+    ancestors: Field,
+    directory: Field,
+    generation: Field,
+}
+
+impl FileIndex {
+    pub fn open(index_dir: &Path) -> anyhow::Result<Self> {
+        let schema = {
+            let mut schema_builder = Schema::builder();
+
+            schema_builder.add_text_field("path", STRING | STORED);
+            schema_builder.add_text_field("name", TEXT | STORED);
+            schema_builder.add_text_field("path_text", TEXT);
+            schema_builder.add_text_field("ancestors", STRING);
+            schema_builder.add_bool_field("directory", STORED);
+            schema_builder.add_u64_field("generation", INDEXED);
+
+            schema_builder.build()
+        };
+
+        std::fs::create_dir_all(index_dir)?;
+
+        let index = match Index::open_or_create(MmapDirectory::open(index_dir)?, schema.clone()) {
+            Ok(index) => index,
+            Err(err) => {
+                // index is only a cache, so if it was created with different schema just start over
+                tracing::warn!("unable to open file index, recreating: {:?}", err);
+
+                std::fs::remove_dir_all(index_dir)?;
+                std::fs::create_dir_all(index_dir)?;
+
+                Index::create_in_dir(index_dir, schema.clone())?
+            }
+        };
+
+        let index_reader = index.reader_builder().reload_policy(ReloadPolicy::Manual).try_into()?;
+        let index_writer = index.writer(50_000_000)?;
+
+        let get_field = |name: &str| schema.get_field(name).expect("field should exist");
+
+        Ok(Self {
+            path: get_field("path"),
+            name: get_field("name"),
+            path_text: get_field("path_text"),
+            ancestors: get_field("ancestors"),
+            directory: get_field("directory"),
+            generation: get_field("generation"),
+            index,
+            index_reader,
+            index_writer: Mutex::new(index_writer),
+        })
+    }
+
+    pub fn add(&self, path: &Path, directory: bool, generation: u64) -> anyhow::Result<()> {
+        let Some(path_str) = path.to_str() else {
+            return Ok(()); // non-utf8 file paths are not supported
+        };
+
+        let name = path.file_name().and_then(|name| name.to_str()).unwrap_or(path_str);
+
+        let mut document = TantivyDocument::new();
+        document.add_text(self.path, path_str);
+        document.add_text(self.name, name);
+        document.add_text(self.path_text, path_str);
+        document.add_bool(self.directory, directory);
+        document.add_u64(self.generation, generation);
+
+        for ancestor in path.ancestors().skip(1) {
+            if let Some(ancestor) = ancestor.to_str() {
+                document.add_text(self.ancestors, ancestor);
+            }
+        }
+
+        let index_writer = self.index_writer.lock().expect("lock is poisoned");
+
+        index_writer.delete_term(Term::from_field_text(self.path, path_str));
+        index_writer.add_document(document)?;
+
+        Ok(())
+    }
+
+    pub fn remove(&self, path: &Path) {
+        let Some(path) = path.to_str() else {
+            return;
+        };
+
+        let index_writer = self.index_writer.lock().expect("lock is poisoned");
+
+        // if removed path was a directory, everything inside is gone too
+        index_writer.delete_term(Term::from_field_text(self.path, path));
+        index_writer.delete_term(Term::from_field_text(self.ancestors, path));
+    }
+
+    pub fn remove_other_generations(&self, generation: u64) -> anyhow::Result<()> {
+        let query = BooleanQuery::new(vec![
+            (Occur::Must, Box::new(AllQuery) as Box<dyn Query>),
+            (
+                Occur::MustNot,
+                Box::new(TermQuery::new(
+                    Term::from_field_u64(self.generation, generation),
+                    IndexRecordOption::Basic,
+                )),
+            ),
+        ]);
+
+        let index_writer = self.index_writer.lock().expect("lock is poisoned");
+
+        index_writer.delete_query(Box::new(query))?;
+
+        Ok(())
+    }
+
+    pub fn commit(&self) -> anyhow::Result<()> {
+        let mut index_writer = self.index_writer.lock().expect("lock is poisoned");
+
+        index_writer.commit()?;
+        self.index_reader.reload()?;
+
+        Ok(())
+    }
+
+    pub fn indexed_files(&self) -> u64 {
+        self.index_reader.searcher().num_docs()
+    }
+
+    pub fn search(&self, query: &str, limit: usize) -> anyhow::Result<Vec<FileSearchItem>> {
+        let terms = self.tokenize(query);
+
+        if terms.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let contains_terms = |field: Field| -> Box<dyn Query> {
+            let queries = terms
+                .iter()
+                .map(|term| -> Box<dyn Query> {
+                    Box::new(
+                        // basically a "contains" query
+                        RegexQuery::from_pattern(&format!(".*{}.*", regex::escape(term)), field)
+                            .expect("there should not exist a situation where that regex is invalid"),
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            Box::new(BooleanQuery::intersection(queries))
+        };
+
+        let tantivy_query = BooleanQuery::union(vec![contains_terms(self.name), contains_terms(self.path_text)]);
+
+        let searcher = self.index_reader.searcher();
+
+        let mut items = searcher
+            .search(&tantivy_query, &TopDocs::with_limit(CANDIDATE_LIMIT))?
+            .into_iter()
+            .map(|(_, doc_address)| {
+                let document = searcher.doc::<TantivyDocument>(doc_address)?;
+
+                let get_str = |field: Field| {
+                    document
+                        .get_first(field)
+                        .and_then(|value| value.as_str())
+                        .unwrap_or_default()
+                        .to_owned()
+                };
+
+                let directory = document
+                    .get_first(self.directory)
+                    .and_then(|value| value.as_bool())
+                    .unwrap_or_default();
+
+                anyhow::Ok(FileSearchItem {
+                    path: get_str(self.path),
+                    name: get_str(self.name),
+                    directory,
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let query = query.trim().to_lowercase();
+
+        // regex queries have constant score, so rank by how well file name matches instead
+        items.sort_by_cached_key(|item| {
+            let name = item.name.to_lowercase();
+
+            let rank = if name == query {
+                0
+            } else if name.starts_with(&query) {
+                1
+            } else if name.contains(&query) {
+                2
+            } else if terms.iter().all(|term| name.contains(term)) {
+                3
+            } else {
+                4
+            };
+
+            (rank, item.path.len())
+        });
+
+        items.truncate(limit);
+
+        Ok(items)
+    }
+
+    fn tokenize(&self, query: &str) -> Vec<String> {
+        let mut text_analyzer = self
+            .index
+            .tokenizers()
+            .get("default")
+            .expect("default tokenizer should always exist");
+
+        let mut terms: Vec<String> = Vec::new();
+        let mut token_stream = text_analyzer.token_stream(query);
+        token_stream.process(&mut |token| {
+            terms.push(token.text.to_string());
+        });
+
+        terms
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::files::tests::temp_dir;
+
+    fn names(index: &FileIndex, query: &str) -> Vec<String> {
+        index
+            .search(query, 10)
+            .unwrap()
+            .into_iter()
+            .map(|item| item.name)
+            .collect()
+    }
+
+    #[test]
+    fn search_ranks_exact_then_prefix_then_contained_names() {
+        let dir = temp_dir("index-rank");
+        let index = FileIndex::open(&dir.join("index")).unwrap();
+
+        index.add(Path::new("/home/user/old_notes"), false, 1).unwrap();
+        index.add(Path::new("/home/user/notes_2024"), false, 1).unwrap();
+        index.add(Path::new("/home/user/notes"), true, 1).unwrap();
+        index.add(Path::new("/home/user/notes/todo"), false, 1).unwrap();
+        index.commit().unwrap();
+
+        let result = names(&index, "notes");
+
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(result, vec!["notes", "notes_2024", "old_notes", "todo"]);
+    }
+
+    #[test]
+    fn removed_directory_takes_its_content_with_it() {
+        let dir = temp_dir("index-remove");
+        let index = FileIndex::open(&dir.join("index")).unwrap();
+
+        index.add(Path::new("/data/project"), true, 1).unwrap();
+        index.add(Path::new("/data/project/readme.md"), false, 1).unwrap();
+        index.add(Path::new("/data/project/src/main.rs"), false, 1).unwrap();
+        index.add(Path::new("/data/project-old/readme.md"), false, 1).unwrap();
+        index.commit().unwrap();
+
+        index.remove(Path::new("/data/project"));
+        index.commit().unwrap();
+
+        let readme = index.search("readme", 10).unwrap();
+        let main = names(&index, "main");
+
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(readme.len(), 1);
+        assert_eq!(readme[0].path, "/data/project-old/readme.md");
+        assert!(main.is_empty());
+    }
+
+    #[test]
+    fn files_not_found_in_latest_generation_are_removed() {
+        let dir = temp_dir("index-generation");
+        let index = FileIndex::open(&dir.join("index")).unwrap();
+
+        index.add(Path::new("/data/kept.txt"), false, 1).unwrap();
+        index.add(Path::new("/data/gone.txt"), false, 1).unwrap();
+        index.commit().unwrap();
+
+        index.add(Path::new("/data/kept.txt"), false, 2).unwrap();
+        index.remove_other_generations(2).unwrap();
+        index.commit().unwrap();
+
+        let result = names(&index, "txt");
+        let indexed_files = index.indexed_files();
+
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(result, vec!["kept.txt"]);
+        assert_eq!(indexed_files, 1);
+    }
+
+    #[test]
+    fn index_survives_reopening() {
+        let dir = temp_dir("index-reopen");
+
+        {
+            let index = FileIndex::open(&dir.join("index")).unwrap();
+
+            index.add(Path::new("/data/persisted.txt"), false, 1).unwrap();
+            index.commit().unwrap();
+        }
+
+        let index = FileIndex::open(&dir.join("index")).unwrap();
+
+        let result = names(&index, "persisted");
+
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(result, vec!["persisted.txt"]);
+    }
+}
@@ -0,0 +1,715 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::mpsc::Sender;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use ignore::WalkBuilder;
+use ignore::gitignore::Gitignore;
+use ignore::gitignore::GitignoreBuilder;
+use notify::RecommendedWatcher;
+use notify::RecursiveMode;
+use notify::Watcher;
+
+use crate::plugins::files::index::FileIndex;
+
+// file system events are applied in batches, so that e.g. checking out git branch results in one commit
+const EVENT_BATCH_TIMEOUT: Duration = Duration::from_millis(500);
+
+const COMMIT_EVERY: usize = 10_000;
+
+// inotify watches are limited per user and shared with every other application,
+// so only this many directories are watched, changes in the rest are picked up by periodic rescans
+const MAX_WATCHED_DIRECTORIES: usize = 8192;
+
+const RESCAN_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileIndexConfig {
+    pub roots: Vec<PathBuf>,
+    pub ignore: Vec<String>,
+}
+
+pub struct FileIndexer {
+    config: FileIndexConfig,
+    indexing: Arc<AtomicBool>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl FileIndexer {
+    pub fn start(index: Arc<FileIndex>, config: FileIndexConfig) -> FileIndexer {
+        let indexing = Arc::new(AtomicBool::new(true));
+        let cancelled = Arc::new(AtomicBool::new(false));
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        // watcher is started before the walk, so that changes made during the walk are not missed
+        let mut watcher = DirectoryWatcher::new(sender);
+
+        for root in &config.roots {
+            watcher.watch_root(root);
+        }
+
+        {
+            let config = config.clone();
+            let indexing = indexing.clone();
+            let cancelled = cancelled.clone();
+
+            // watcher is owned by the thread, and is dropped together with it when indexer is stopped
+            std::thread::spawn(move || {
+                let mut rules = IgnoreRules::new(&config);
+
+                if let Err(err) = full_index(&index, &config, &rules, &mut watcher, &cancelled) {
+                    tracing::error!("unable to index files: {:?}", err);
+                }
+
+                indexing.store(false, Ordering::SeqCst);
+
+                let mut last_full_index = Instant::now();
+
+                let mut pending = HashSet::new();
+
+                loop {
+                    if cancelled.load(Ordering::SeqCst) {
+                        break;
+                    }
+
+                    match receiver.recv_timeout(EVENT_BATCH_TIMEOUT) {
+                        Ok(Ok(event)) => pending.extend(event.paths),
+                        Ok(Err(err)) => tracing::warn!("file watcher error: {:?}", err),
+                        Err(RecvTimeoutError::Timeout) => {
+                            if watcher.incomplete() && last_full_index.elapsed() >= RESCAN_INTERVAL {
+                                pending.clear();
+
+                                rules = IgnoreRules::new(&config);
+
+                                if let Err(err) = full_index(&index, &config, &rules, &mut watcher, &cancelled) {
+                                    tracing::error!("unable to reindex files: {:?}", err);
+                                }
+
+                                last_full_index = Instant::now();
+
+                                continue;
+                            }
+
+                            if pending.is_empty() {
+                                continue;
+                            }
+
+                            let paths = std::mem::take(&mut pending);
+
+                            if let Err(err) = apply_changes(&index, &mut rules, &mut watcher, paths) {
+                                tracing::error!("unable to update file index: {:?}", err);
+                            }
+                        }
+                        Err(RecvTimeoutError::Disconnected) => break,
+                    }
+                }
+            });
+        }
+
+        FileIndexer {
+            config,
+            indexing,
+            cancelled,
+        }
+    }
+
+    pub fn config(&self) -> &FileIndexConfig {
+        &self.config
+    }
+
+    pub fn indexing(&self) -> bool {
+        self.indexing.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for FileIndexer {
+    fn drop(&mut self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+}
+
+fn full_index(
+    index: &FileIndex,
+    config: &FileIndexConfig,
+    rules: &IgnoreRules,
+    watcher: &mut DirectoryWatcher,
+    cancelled: &AtomicBool,
+) -> anyhow::Result<()> {
+    // index is kept between runs, so instead of clearing it upfront,
+    // everything not found during this walk is removed at the end
+    let generation = SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos() as u64;
+
+    let mut count = 0;
+
+    for root in &config.roots {
+        for (path, directory) in walk(root, rules.user_rules.clone()) {
+            if cancelled.load(Ordering::SeqCst) {
+                return Ok(());
+            }
+
+            if directory {
+                watcher.watch_directory(&path);
+            }
+
+            index.add(&path, directory, generation)?;
+
+            count += 1;
+
+            if count % COMMIT_EVERY == 0 {
+                index.commit()?;
+            }
+        }
+    }
+
+    index.remove_other_generations(generation)?;
+    index.commit()?;
+
+    tracing::debug!("indexed {} files", count);
+
+    Ok(())
+}
+
+fn apply_changes(
+    index: &FileIndex,
+    rules: &mut IgnoreRules,
+    watcher: &mut DirectoryWatcher,
+    mut paths: HashSet<PathBuf>,
+) -> anyhow::Result<()> {
+    let gitignore_dirs: Vec<_> = paths
+        .iter()
+        .filter(|path| path.file_name().is_some_and(|name| name == ".gitignore"))
+        .filter_map(|path| path.parent())
+        .map(|dir| dir.to_path_buf())
+        .collect();
+
+    for dir in gitignore_dirs {
+        rules.invalidate(&dir);
+
+        // changed rules can hide or reveal anything in that directory, so it is indexed again
+        paths.insert(dir);
+    }
+
+    let generation = SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos() as u64;
+
+    // events don't reliably say what happened, e.g. rename is reported differently on each platform,
+    // so just check current state of the path
+    for path in paths {
+        let metadata = std::fs::symlink_metadata(&path).ok();
+
+        let directory = metadata.as_ref().is_some_and(|metadata| metadata.is_dir());
+
+        // path could have been deleted, moved away or become ignored
+        index.remove(&path);
+
+        if metadata.is_none() {
+            watcher.forget(&path);
+            continue;
+        }
+
+        if rules.is_ignored(&path, directory) {
+            continue;
+        }
+
+        // roots themselves are not part of the index
+        if !rules.roots.contains(&path) {
+            index.add(&path, directory, generation)?;
+        }
+
+        // directory could have been moved in with all its content
+        if directory {
+            watcher.watch_directory(&path);
+
+            for (path, directory) in walk(&path, rules.user_rules.clone()) {
+                if directory {
+                    watcher.watch_directory(&path);
+                }
+
+                index.add(&path, directory, generation)?;
+            }
+        }
+    }
+
+    index.commit()
+}
+
+// inotify watches every directory separately even in recursive mode,
+// so on linux directories are watched one by one to not spend watches on ignored subtrees,
+// other platforms watch whole tree natively and events for ignored paths are dropped
+struct DirectoryWatcher {
+    watcher: Option<RecommendedWatcher>,
+    watched_directories: HashSet<PathBuf>,
+    // some directories are not watched, because watch limit was reached
+    incomplete: bool,
+}
+
+impl DirectoryWatcher {
+    fn new(sender: Sender<notify::Result<notify::Event>>) -> Self {
+        let watcher = notify::recommended_watcher(sender)
+            .inspect_err(|err| tracing::warn!("unable to create file watcher: {:?}", err))
+            .ok();
+
+        Self {
+            watcher,
+            watched_directories: HashSet::new(),
+            incomplete: false,
+        }
+    }
+
+    fn incomplete(&self) -> bool {
+        self.incomplete
+    }
+
+    fn watch_root(&mut self, root: &Path) {
+        if cfg!(target_os = "linux") {
+            self.watch_directory(root);
+        } else {
+            self.watch(root, RecursiveMode::Recursive);
+        }
+    }
+
+    fn watch_directory(&mut self, dir: &Path) {
+        if !cfg!(target_os = "linux") {
+            return;
+        }
+
+        if self.watched_directories.contains(dir) {
+            return;
+        }
+
+        if self.watched_directories.len() >= MAX_WATCHED_DIRECTORIES {
+            if !self.incomplete {
+                tracing::warn!(
+                    "more than {} directories to watch, file index will be periodically rescanned instead",
+                    MAX_WATCHED_DIRECTORIES
+                );
+            }
+
+            self.incomplete = true;
+            return;
+        }
+
+        if self.watch(dir, RecursiveMode::NonRecursive) {
+            self.watched_directories.insert(dir.to_path_buf());
+        }
+    }
+
+    // watches of removed directories are dropped by the os
+    fn forget(&mut self, path: &Path) {
+        self.watched_directories.retain(|dir| !dir.starts_with(path));
+    }
+
+    fn watch(&mut self, path: &Path, mode: RecursiveMode) -> bool {
+        let Some(watcher) = &mut self.watcher else {
+            return false;
+        };
+
+        match watcher.watch(path, mode) {
+            Ok(()) => true,
+            Err(err) => {
+                if let notify::ErrorKind::MaxFilesWatch = err.kind {
+                    self.incomplete = true;
+                }
+
+                tracing::warn!("unable to watch {:?} for file changes: {:?}", path, err);
+
+                false
+            }
+        }
+    }
+}
+
+fn walk(root: &Path, user_rules: Arc<Vec<Gitignore>>) -> impl Iterator<Item = (PathBuf, bool)> {
+    WalkBuilder::new(root)
+        .hidden(true)
+        .git_ignore(true)
+        .git_global(false)
+        .git_exclude(false)
+        .require_git(false)
+        .ignore(false)
+        .parents(true)
+        .follow_links(false)
+        .filter_entry(move |entry| {
+            let directory = entry.file_type().is_some_and(|file_type| file_type.is_dir());
+
+            !user_rules.iter().any(|rules| is_match(rules, entry.path(), directory))
+        })
+        .build()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.depth() > 0)
+        .map(|entry| {
+            let directory = entry.file_type().is_some_and(|file_type| file_type.is_dir());
+
+            (entry.into_path(), directory)
+        })
+}
+
+fn is_match(rules: &Gitignore, path: &Path, directory: bool) -> bool {
+    if !path.starts_with(rules.path()) {
+        return false;
+    }
+
+    rules.matched_path_or_any_parents(path, directory).is_ignore()
+}
+
+// same rules as used by walker, but for single paths reported by file watcher
+struct IgnoreRules {
+    roots: Vec<PathBuf>,
+    user_rules: Arc<Vec<Gitignore>>,
+    gitignores: HashMap<PathBuf, Option<Gitignore>>,
+}
+
+impl IgnoreRules {
+    fn new(config: &FileIndexConfig) -> Self {
+        let user_rules = config
+            .roots
+            .iter()
+            .filter_map(|root| {
+                let mut builder = GitignoreBuilder::new(root);
+
+                for pattern in &config.ignore {
+                    if let Err(err) = builder.add_line(None, pattern) {
+                        tracing::warn!("invalid file search ignore pattern {:?}: {:?}", pattern, err);
+                    }
+                }
+
+                builder
+                    .build()
+                    .inspect_err(|err| tracing::warn!("unable to build file search ignore rules: {:?}", err))
+                    .ok()
+            })
+            .collect();
+
+        Self {
+            roots: config.roots.clone(),
+            user_rules: Arc::new(user_rules),
+            gitignores: HashMap::new(),
+        }
+    }
+
+    fn invalidate(&mut self, dir: &Path) {
+        self.gitignores.remove(dir);
+    }
+
+    fn is_ignored(&mut self, path: &Path, directory: bool) -> bool {
+        let Some(root) = self.roots.iter().find(|root| path.starts_with(root)).cloned() else {
+            return true;
+        };
+
+        let Ok(relative) = path.strip_prefix(&root) else {
+            return true;
+        };
+
+        let hidden = relative
+            .components()
+            .any(|component| component.as_os_str().to_string_lossy().starts_with('.'));
+
+        if hidden {
+            return true;
+        }
+
+        if self.user_rules.iter().any(|rules| is_match(rules, path, directory)) {
+            return true;
+        }
+
+        let mut dir = path.parent();
+
+        while let Some(current) = dir {
+            let gitignore = self.gitignores.entry(current.to_path_buf()).or_insert_with(|| {
+                let file = current.join(".gitignore");
+
+                if file.exists() {
+                    let (gitignore, err) = Gitignore::new(file);
+                    if let Some(err) = err {
+                        tracing::warn!("unable to fully parse .gitignore in {:?}: {:?}", current, err);
+                    }
+                    Some(gitignore)
+                } else {
+                    None
+                }
+            });
+
+            if let Some(gitignore) = gitignore {
+                if gitignore.matched_path_or_any_parents(path, directory).is_ignore() {
+                    return true;
+                }
+            }
+
+            if current == root {
+                break;
+            }
+
+            dir = current.parent();
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::files::tests::temp_dir;
+
+    struct TestIndex {
+        dir: PathBuf,
+        root: PathBuf,
+        config: FileIndexConfig,
+        index: FileIndex,
+        watcher: DirectoryWatcher,
+    }
+
+    impl TestIndex {
+        fn new(name: &str, ignore: &[&str]) -> Self {
+            let dir = temp_dir(name);
+            let root = dir.join("root");
+
+            std::fs::create_dir_all(&root).unwrap();
+
+            let config = FileIndexConfig {
+                roots: vec![root.clone()],
+                ignore: ignore.iter().map(|pattern| pattern.to_string()).collect(),
+            };
+
+            let index = FileIndex::open(&dir.join("index")).unwrap();
+
+            // changes are applied manually in tests
+            let watcher = DirectoryWatcher {
+                watcher: None,
+                watched_directories: HashSet::new(),
+                incomplete: false,
+            };
+
+            Self {
+                dir,
+                root,
+                config,
+                index,
+                watcher,
+            }
+        }
+
+        fn write(&self, path: &str, content: &str) -> PathBuf {
+            let path = self.root.join(path);
+
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, content).unwrap();
+
+            path
+        }
+
+        fn full_index(&mut self) {
+            let rules = IgnoreRules::new(&self.config);
+
+            full_index(
+                &self.index,
+                &self.config,
+                &rules,
+                &mut self.watcher,
+                &AtomicBool::new(false),
+            )
+            .unwrap();
+        }
+
+        fn apply_changes(&mut self, paths: &[&Path]) {
+            let mut rules = IgnoreRules::new(&self.config);
+
+            let paths = paths.iter().map(|path| path.to_path_buf()).collect();
+
+            apply_changes(&self.index, &mut rules, &mut self.watcher, paths).unwrap();
+        }
+
+        fn paths(&self, query: &str) -> Vec<String> {
+            let mut paths: Vec<_> = self
+                .index
+                .search(query, 100)
+                .unwrap()
+                .into_iter()
+                .map(|item| {
+                    Path::new(&item.path)
+                        .strip_prefix(&self.root)
+                        .unwrap()
+                        .to_string_lossy()
+                        .to_string()
+                })
+                .collect();
+
+            paths.sort();
+
+            paths
+        }
+    }
+
+    impl Drop for TestIndex {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.dir);
+        }
+    }
+
+    #[test]
+    fn ignore_rules_match_walker() {
+        let test = TestIndex::new("indexer-rules", &["*.tmp", "node_modules"]);
+
+        test.write(".gitignore", "target\n");
+        test.write("src/.gitignore", "*.gen\n");
+
+        let mut rules = IgnoreRules::new(&test.config);
+
+        let root = &test.root;
+
+        assert!(!rules.is_ignored(&root.join("src/main.rs"), false));
+        assert!(rules.is_ignored(&root.join(".git"), true));
+        assert!(rules.is_ignored(&root.join("src/.hidden/file"), false));
+        assert!(rules.is_ignored(&root.join("cache.tmp"), false));
+        assert!(rules.is_ignored(&root.join("web/node_modules/react/index.js"), false));
+        assert!(rules.is_ignored(&root.join("target/debug/app"), false));
+        assert!(rules.is_ignored(&root.join("src/parser.gen"), false));
+        assert!(!rules.is_ignored(&root.join("parser.gen"), false));
+        assert!(rules.is_ignored(&test.dir.join("outside.txt"), false));
+    }
+
+    #[test]
+    fn full_index_respects_ignore_rules() {
+        let mut test = TestIndex::new("indexer-full", &["*.tmp"]);
+
+        test.write(".gitignore", "target\n");
+        test.write("src/main.rs", "");
+        test.write("src/cache.tmp", "");
+        test.write("target/main.o", "");
+        test.write(".config/main.toml", "");
+
+        test.full_index();
+
+        assert_eq!(test.paths("main"), vec!["src/main.rs"]);
+        assert_eq!(test.paths("cache"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn renamed_file_is_reindexed_under_new_path() {
+        let mut test = TestIndex::new("indexer-rename", &[]);
+
+        let old_path = test.write("docs/draft.md", "");
+
+        test.full_index();
+
+        let new_path = test.root.join("docs/final.md");
+        std::fs::rename(&old_path, &new_path).unwrap();
+
+        test.apply_changes(&[&old_path, &new_path]);
+
+        assert_eq!(test.paths("draft"), Vec::<String>::new());
+        assert_eq!(test.paths("final"), vec!["docs/final.md"]);
+    }
+
+    #[test]
+    fn deleted_directory_is_removed_with_its_content() {
+        let mut test = TestIndex::new("indexer-delete", &[]);
+
+        test.write("photos/2023/beach.jpg", "");
+        test.write("photos/2024/mountains.jpg", "");
+        test.write("documents/photos.txt", "");
+
+        test.full_index();
+
+        let photos = test.root.join("photos");
+        std::fs::remove_dir_all(&photos).unwrap();
+
+        test.apply_changes(&[&photos]);
+
+        assert_eq!(test.paths("jpg"), Vec::<String>::new());
+        assert_eq!(test.paths("photos"), vec!["documents/photos.txt"]);
+    }
+
+    #[test]
+    fn moved_in_directory_is_indexed_with_its_content() {
+        let mut test = TestIndex::new("indexer-move-in", &[]);
+
+        test.full_index();
+
+        let outside = test.dir.join("downloads");
+        std::fs::create_dir_all(outside.join("archive")).unwrap();
+        std::fs::write(outside.join("archive/invoice.pdf"), "").unwrap();
+
+        let moved = test.root.join("downloads");
+        std::fs::rename(&outside, &moved).unwrap();
+
+        test.apply_changes(&[&moved]);
+
+        assert_eq!(
+            test.paths("downloads"),
+            vec!["downloads", "downloads/archive", "downloads/archive/invoice.pdf"]
+        );
+    }
+
+    #[test]
+    fn paths_which_became_ignored_are_removed() {
+        let mut test = TestIndex::new("indexer-newly-ignored", &[]);
+
+        test.write("app/main.rs", "");
+        test.write("app/out/main.js", "");
+        test.write("app/out/main.js.map", "");
+
+        test.full_index();
+
+        assert_eq!(
+            test.paths("main"),
+            vec!["app/main.rs", "app/out/main.js", "app/out/main.js.map"]
+        );
+
+        let gitignore = test.write("app/.gitignore", "out\n");
+
+        test.apply_changes(&[&gitignore]);
+
+        assert_eq!(test.paths("main"), vec!["app/main.rs"]);
+    }
+
+    #[test]
+    fn ignored_paths_reported_by_watcher_are_not_indexed() {
+        let mut test = TestIndex::new("indexer-ignored-event", &["*.swp"]);
+
+        test.full_index();
+
+        let swap = test.write("notes.txt.swp", "");
+        let notes = test.write("notes.txt", "");
+
+        test.apply_changes(&[&swap, &notes]);
+
+        assert_eq!(test.paths("notes"), vec!["notes.txt"]);
+    }
+
+    #[test]
+    fn watched_directories_are_capped() {
+        let mut test = TestIndex::new("indexer-watch-limit", &[]);
+
+        for index in 0..MAX_WATCHED_DIRECTORIES {
+            test.watcher
+                .watched_directories
+                .insert(test.root.join(index.to_string()));
+        }
+
+        assert!(!test.watcher.incomplete());
+
+        test.watcher.watch_directory(&test.root.join("one-too-many"));
+
+        assert_eq!(test.watcher.incomplete(), cfg!(target_os = "linux"));
+        assert!(
+            !test
+                .watcher
+                .watched_directories
+                .contains(&test.root.join("one-too-many"))
+        );
+
+        test.watcher.forget(&test.root.join("1"));
+
+        assert_eq!(test.watcher.watched_directories.len(), MAX_WATCHED_DIRECTORIES - 1);
+    }
+}
@@ -0,0 +1,281 @@
+use std::cell::RefCell;
+use std::path::Path;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use deno_core::OpState;
+use deno_core::op2;
+use deno_runtime::deno_permissions::PermissionsContainer;
+use serde::Serialize;
+
+use crate::deno::GauntletJsError;
+use crate::plugin_data::PluginData;
+use crate::plugins::files::index::FileIndex;
+use crate::plugins::files::index::FileSearchItem;
+use crate::plugins::files::indexer::FileIndexConfig;
+use crate::plugins::files::indexer::FileIndexer;
+
+mod index;
+mod indexer;
+
+const PERMISSION_API_NAME: &str = "Gauntlet.fileSearch";
+
+pub struct FileSearchContext {
+    index_dir: PathBuf,
+    // index is opened lazily, so that nothing is created on disk until file search is used
+    index: Option<Arc<FileIndex>>,
+    indexer: Option<FileIndexer>,
+}
+
+impl FileSearchContext {
+    pub fn new(index_dir: PathBuf) -> Self {
+        Self {
+            index_dir,
+            index: None,
+            indexer: None,
+        }
+    }
+
+    fn configure(&mut self, config: FileIndexConfig) -> anyhow::Result<()> {
+        if self.indexer.as_ref().is_some_and(|indexer| indexer.config() == &config) {
+            return Ok(());
+        }
+
+        // stop previous indexer before starting a new one
+        self.indexer = None;
+
+        let index = match &self.index {
+            Some(index) => index.clone(),
+            None => {
+                let index = Arc::new(FileIndex::open(&self.index_dir)?);
+                self.index = Some(index.clone());
+                index
+            }
+        };
+
+        self.indexer = Some(FileIndexer::start(index, config));
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct FileSearchStatus {
+    indexing: bool,
+    #[serde(rename = "indexedFiles")]
+    indexed_files: u64,
+}
+
+fn check_read(state: &Rc<RefCell<OpState>>, path: &str) -> anyhow::Result<PathBuf> {
+    let mut state = state.borrow_mut();
+
+    state
+        .borrow_mut::<PermissionsContainer>()
+        .check_read(path, PERMISSION_API_NAME)
+        .map_err(|err| anyhow!(err))
+}
+
+#[op2]
+pub fn file_search_configure(
+    state: Rc<RefCell<OpState>>,
+    #[serde] roots: Vec<String>,
+    #[serde] ignore: Vec<String>,
+) -> Result<(), GauntletJsError> {
+    let roots = if roots.is_empty() {
+        let home_dir = { state.borrow().borrow::<PluginData>().home_dir() };
+
+        vec![home_dir]
+    } else {
+        roots.into_iter().map(PathBuf::from).collect()
+    };
+
+    // only locations allowed by filesystem.read permission in plugin manifest can be indexed
+    let roots = roots
+        .into_iter()
+        .map(|root| {
+            let root = root.to_str().ok_or(anyhow!("non-utf8 file paths are not supported"))?;
+
+            check_read(&state, root)
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let config = FileIndexConfig { roots, ignore };
+
+    let mut state = state.borrow_mut();
+
+    state.borrow_mut::<FileSearchContext>().configure(config)?;
+
+    Ok(())
+}
+
+#[op2]
+#[serde]
+pub fn file_search(
+    state: Rc<RefCell<OpState>>,
+    #[string] query: String,
+    #[smi] limit: u32,
+) -> Result<Vec<FileSearchItem>, GauntletJsError> {
+    let index = {
+        let state = state.borrow();
+
+        state.borrow::<FileSearchContext>().index.clone()
+    };
+
+    let Some(index) = index else {
+        return Ok(vec![]);
+    };
+
+    Ok(index.search(&query, limit as usize)?)
+}
+
+#[op2]
+#[serde]
+pub fn file_search_status(state: Rc<RefCell<OpState>>) -> FileSearchStatus {
+    let state = state.borrow();
+
+    let context = state.borrow::<FileSearchContext>();
+
+    FileSearchStatus {
+        indexing: context.indexer.as_ref().is_some_and(|indexer| indexer.indexing()),
+        indexed_files: context
+            .index
+            .as_ref()
+            .map(|index| index.indexed_files())
+            .unwrap_or_default(),
+    }
+}
+
+#[op2]
+pub fn file_search_open(state: Rc<RefCell<OpState>>, #[string] path: String) -> Result<(), GauntletJsError> {
+    let path = check_read(&state, &path)?;
+
+    open::that_detached(path).map_err(|err| anyhow!(err))?;
+
+    Ok(())
+}
+
+#[op2]
+pub fn file_search_reveal(state: Rc<RefCell<OpState>>, #[string] path: String) -> Result<(), GauntletJsError> {
+    let path = check_read(&state, &path)?;
+
+    reveal(&path)?;
+
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn reveal(path: &Path) -> anyhow::Result<()> {
+    std::process::Command::new("open").arg("-R").arg(path).spawn()?;
+
+    Ok(())
+}
+
+#[cfg(target_os = "windows")]
+fn reveal(path: &Path) -> anyhow::Result<()> {
+    let mut select = std::ffi::OsString::from("/select,");
+    select.push(path);
+
+    std::process::Command::new("explorer").arg(select).spawn()?;
+
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn reveal(path: &Path) -> anyhow::Result<()> {
+    // there is no standard way to select a file in file manager, so just open containing directory
+    let dir = path.parent().unwrap_or(path);
+
+    open::that_detached(dir)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use std::time::Instant;
+
+    use super::*;
+
+    // fresh directory for each test, removed by the test itself
+    pub(super) fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("gauntlet-file-search-test-{}-{}", std::process::id(), name));
+
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        dir
+    }
+
+    fn wait_for_indexing(context: &FileSearchContext) {
+        let start = Instant::now();
+
+        while context.indexer.as_ref().is_some_and(|indexer| indexer.indexing()) {
+            assert!(
+                start.elapsed() < Duration::from_secs(30),
+                "indexing didn't finish in time"
+            );
+
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    fn search(context: &FileSearchContext, query: &str) -> Vec<String> {
+        context
+            .index
+            .as_ref()
+            .unwrap()
+            .search(query, 10)
+            .unwrap()
+            .into_iter()
+            .map(|item| item.name)
+            .collect()
+    }
+
+    #[test]
+    fn configured_roots_are_indexed_and_searchable() {
+        let dir = temp_dir("configure");
+        let root = dir.join("root");
+
+        std::fs::create_dir_all(root.join("docs")).unwrap();
+        std::fs::create_dir_all(root.join("build")).unwrap();
+        std::fs::write(root.join("docs/report.pdf"), "").unwrap();
+        std::fs::write(root.join("build/report.o"), "").unwrap();
+        std::fs::write(root.join("notes.txt"), "").unwrap();
+
+        let mut context = FileSearchContext::new(dir.join("index"));
+
+        context
+            .configure(FileIndexConfig {
+                roots: vec![root.clone()],
+                ignore: vec!["build".to_string()],
+            })
+            .unwrap();
+
+        wait_for_indexing(&context);
+
+        let report = search(&context, "report");
+        let docs = search(&context, "docs");
+
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(report, vec!["report.pdf"]);
+        assert_eq!(docs, vec!["docs", "report.pdf"]);
+    }
+
+    #[test]
+    fn search_returns_nothing_before_configure() {
+        let dir = temp_dir("unconfigured");
+
+        let context = FileSearchContext::new(dir.join("index"));
+
+        let index_created = dir.join("index").exists();
+
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert!(context.index.is_none());
+        assert!(!index_created);
+    }
+}
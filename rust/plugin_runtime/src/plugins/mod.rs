@@ -1,3 +1,4 @@
 pub mod applications;
+pub mod files;
 pub mod numbat;
 pub mod settings;
@@ -1,5 +1,9 @@
 use std::cell::RefCell;
+use std::path::Path;
+use std::path::PathBuf;
 use std::rc::Rc;
+use std::time::Duration;
+use std::time::SystemTime;
 
 use anyhow::anyhow;
 use deno_core::OpState;
@@ -9,53 +13,302 @@ use numbat::InterpreterResult;
 use numbat::markup::Formatter;
 use numbat::markup::PlainTextFormatter;
 use numbat::module_importer::BuiltinModuleImporter;
+use numbat::module_importer::ChainedImporter;
+use numbat::module_importer::FileSystemImporter;
 use numbat::pretty_print::PrettyPrint;
 use numbat::resolver::CodeSource;
+use serde::Deserialize;
 use serde::Serialize;
 
 use crate::deno::GauntletJsError;
 
+const HISTORY_LIMIT: usize = 100;
+const DEFINITIONS_LIMIT: usize = 100;
+
+const EXCHANGE_RATES_URL: &str = "https://www.ecb.europa.eu/stats/eurofxref/eurofxref-daily.xml";
+const EXCHANGE_RATES_MAX_AGE: Duration = Duration::from_secs(60 * 60 * 12);
+
 #[derive(Clone)]
-pub struct NumbatContext(Rc<RefCell<Context>>);
+pub struct NumbatContext(Rc<RefCell<NumbatContextInner>>);
+
+struct NumbatContextInner {
+    context: Context,
+    // inline view runs on every key press, so inputs are evaluated on a copy
+    // to not define variables or change `ans` until result is saved
+    preview_context: Option<Context>,
+    state: NumbatState,
+    state_file: PathBuf,
+}
+
+// persisted between sessions
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct NumbatState {
+    history: Vec<NumbatHistoryEntry>,
+    // inputs which define variables, functions or units, replayed on startup
+    definitions: Vec<String>,
+    // replayed on startup so that `ans` refers to last saved result
+    last_input: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct NumbatHistoryEntry {
+    input: String,
+    left: String,
+    right: String,
+}
+
+#[derive(Debug, Serialize)]
+struct NumbatResult {
+    left: String,
+    right: String,
+}
 
 impl NumbatContext {
-    pub fn new() -> NumbatContext {
-        let mut context = Context::new(BuiltinModuleImporter::default());
+    pub fn new(modules_dir: PathBuf, plugin_data_dir: PathBuf, plugin_cache_dir: PathBuf) -> NumbatContext {
+        let mut fs_importer = FileSystemImporter::default();
+        fs_importer.add_path(&modules_dir);
+
+        let importer = ChainedImporter::new(Box::new(fs_importer), Box::new(BuiltinModuleImporter::default()));
+
+        let mut context = Context::new(importer);
 
         context.load_currency_module_on_demand(true);
 
-        if cfg!(feature = "release") {
-            Context::prefetch_exchange_rates();
-        }
+        load_exchange_rates(plugin_cache_dir.join("exchange_rates.xml"));
 
         let _ = context.interpret("use prelude", CodeSource::Internal);
 
-        NumbatContext(Rc::new(RefCell::new(context)))
+        load_user_modules(&mut context, &modules_dir);
+
+        let state_file = plugin_data_dir.join("calculator.json");
+
+        let state = read_state(&state_file)
+            .inspect_err(|err| tracing::warn!("unable to read calculator state: {:?}", err))
+            .unwrap_or_default();
+
+        let last_input = state.last_input.iter().filter(|input| definition_name(input).is_none());
+
+        for input in state.definitions.iter().chain(last_input) {
+            if let Err(err) = context.interpret(input, CodeSource::Text) {
+                tracing::warn!("unable to restore calculator input {:?}: {:?}", input, err);
+            }
+        }
+
+        NumbatContext(Rc::new(RefCell::new(NumbatContextInner {
+            context,
+            preview_context: None,
+            state,
+            state_file,
+        })))
     }
 }
 
-#[derive(Debug, Serialize)]
-struct NumbatResult {
-    left: String,
-    right: String,
+fn load_user_modules(context: &mut Context, modules_dir: &Path) {
+    let Ok(entries) = std::fs::read_dir(modules_dir) else {
+        return;
+    };
+
+    let mut paths = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "nbt"))
+        .collect::<Vec<_>>();
+
+    // make loading order predictable so that modules can depend on each other
+    paths.sort();
+
+    for path in paths {
+        let code = match std::fs::read_to_string(&path) {
+            Ok(code) => code,
+            Err(err) => {
+                tracing::warn!("unable to read numbat module {:?}: {:?}", path, err);
+                continue;
+            }
+        };
+
+        if let Err(err) = context.interpret(&code, CodeSource::File(path.clone())) {
+            tracing::warn!("unable to load numbat module {:?}: {:?}", path, err);
+        }
+    }
 }
 
-#[op2]
-#[serde]
-pub fn run_numbat(state: Rc<RefCell<OpState>>, #[string] input: String) -> Result<NumbatResult, GauntletJsError> {
-    let context = {
-        let state = state.borrow();
+fn load_exchange_rates(cache_file: PathBuf) {
+    let cached = std::fs::read_to_string(&cache_file).ok();
 
-        let context = state.borrow::<NumbatContext>().clone();
+    if let Some(xml) = &cached {
+        Context::set_exchange_rates(xml);
+    }
 
-        context
+    if !cfg!(feature = "release") {
+        return;
+    }
+
+    let fresh = std::fs::metadata(&cache_file)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age < EXCHANGE_RATES_MAX_AGE);
+
+    if cached.is_some() && fresh {
+        return;
+    }
+
+    std::thread::spawn(move || {
+        match fetch_exchange_rates(&cache_file) {
+            Ok(xml) => Context::set_exchange_rates(&xml),
+            Err(err) => tracing::warn!("unable to fetch exchange rates: {:?}", err),
+        }
+    });
+}
+
+fn fetch_exchange_rates(cache_file: &Path) -> anyhow::Result<String> {
+    let xml = ureq::get(EXCHANGE_RATES_URL)
+        .timeout(Duration::from_secs(10))
+        .call()?
+        .into_string()?;
+
+    if let Some(parent) = cache_file.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    std::fs::write(cache_file, &xml)?;
+
+    Ok(xml)
+}
+
+fn read_state(state_file: &Path) -> anyhow::Result<NumbatState> {
+    if !state_file.exists() {
+        return Ok(NumbatState::default());
+    }
+
+    let content = std::fs::read_to_string(state_file)?;
+
+    Ok(serde_json::from_str(&content)?)
+}
+
+fn write_state(state_file: &Path, state: &NumbatState) -> anyhow::Result<()> {
+    if let Some(parent) = state_file.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    std::fs::write(state_file, serde_json::to_string(state)?)?;
+
+    Ok(())
+}
+
+// name of variable, function, unit, dimension, struct or module which input defines
+fn definition_name(input: &str) -> Option<String> {
+    let input = input.trim_start();
+
+    let rest = ["let ", "fn ", "unit ", "dimension ", "struct ", "use "]
+        .iter()
+        .find_map(|keyword| input.strip_prefix(keyword))?
+        .trim_start();
+
+    let name = rest
+        .split(|char: char| !(char.is_alphanumeric() || char == '_' || char == ':'))
+        .next()
+        .unwrap_or_default();
+
+    Some(name.to_string())
+}
+
+// identifiers used by input, except the name which it defines
+fn referenced_names(input: &str) -> Vec<&str> {
+    let defined_name = definition_name(input);
+    let mut skipped = false;
+
+    input
+        .split(|char: char| !(char.is_alphanumeric() || char == '_'))
+        .filter(|token| !token.is_empty())
+        .filter(|token| {
+            if !skipped && defined_name.as_deref() == Some(*token) {
+                skipped = true;
+                false
+            } else {
+                true
+            }
+        })
+        .collect()
+}
+
+fn refers_to(input: &str, name: &str) -> bool {
+    referenced_names(input).contains(&name)
+}
+
+// definitions are replayed in order, so each of them can only refer to ones before it
+fn add_definition(definitions: &mut Vec<String>, input: String, name: &str) {
+    let Some(index) = definitions
+        .iter()
+        .rposition(|definition| definition_name(definition).as_deref() == Some(name))
+    else {
+        definitions.push(input);
+        return;
     };
 
-    let mut context = context.0.borrow_mut();
+    let refers_to_following = definitions[index..]
+        .iter()
+        .filter_map(|definition| definition_name(definition))
+        .any(|following_name| refers_to(&input, &following_name));
 
-    let (statements, result) = context
-        .interpret(&input, CodeSource::Text)
-        .map_err(|err| anyhow!(err))?;
+    if !refers_to_following {
+        // replaced in place, so definitions which come after it and refer to it keep working
+        definitions[index] = input;
+        return;
+    }
+
+    // previous definition is kept if anything still depends on it
+    let still_used = refers_to(&input, name)
+        || definitions[index + 1..]
+            .iter()
+            .any(|definition| refers_to(definition, name));
+
+    if !still_used {
+        definitions.remove(index);
+    }
+
+    definitions.push(input);
+}
+
+// oldest definitions are removed, together with ones which can't be replayed without them,
+// so values of remaining definitions never change after restart
+fn trim_definitions(definitions: &mut Vec<String>, limit: usize) {
+    let overflow = definitions.len().saturating_sub(limit);
+
+    let start = (overflow..definitions.len())
+        .find(|&start| is_replayable(&definitions[..start], &definitions[start..]))
+        .unwrap_or(definitions.len());
+
+    definitions.drain(..start);
+}
+
+fn is_replayable(removed: &[String], definitions: &[String]) -> bool {
+    let removed_names: Vec<String> = removed
+        .iter()
+        .filter_map(|definition| definition_name(definition))
+        .collect();
+
+    definitions.iter().enumerate().all(|(index, definition)| {
+        referenced_names(definition).into_iter().all(|name| {
+            let removed = removed_names.iter().any(|removed_name| removed_name == name);
+
+            let defined_before = definitions[..index]
+                .iter()
+                .any(|previous| definition_name(previous).as_deref() == Some(name));
+
+            !removed || defined_before
+        })
+    })
+}
+
+fn refers_to_last_result(input: &str) -> bool {
+    input
+        .split(|char: char| !(char.is_alphanumeric() || char == '_'))
+        .any(|token| token == "ans" || token == "_")
+}
+
+fn interpret(context: &mut Context, input: &str) -> anyhow::Result<NumbatResult> {
+    let (statements, result) = context.interpret(input, CodeSource::Text).map_err(|err| anyhow!(err))?;
 
     let formatter = PlainTextFormatter;
 
@@ -76,3 +329,224 @@ pub fn run_numbat(state: Rc<RefCell<OpState>>, #[string] input: String) -> Resul
         right: value,
     })
 }
+
+#[op2]
+#[serde]
+pub fn run_numbat(state: Rc<RefCell<OpState>>, #[string] input: String) -> Result<NumbatResult, GauntletJsError> {
+    let context = {
+        let state = state.borrow();
+
+        let context = state.borrow::<NumbatContext>().clone();
+
+        context
+    };
+
+    let mut context = context.0.borrow_mut();
+    let context = &mut *context;
+
+    // previous previews may have changed `ans`, so use fresh copy if input refers to it
+    if refers_to_last_result(&input) {
+        let mut preview_context = context.context.clone();
+
+        return Ok(interpret(&mut preview_context, &input)?);
+    }
+
+    let preview_context = context.preview_context.get_or_insert_with(|| context.context.clone());
+
+    let result = interpret(preview_context, &input);
+
+    // definition would be visible in previews of following inputs
+    if definition_name(&input).is_some() {
+        context.preview_context = None;
+    }
+
+    Ok(result?)
+}
+
+#[op2]
+#[serde]
+pub fn save_numbat(state: Rc<RefCell<OpState>>, #[string] input: String) -> Result<NumbatResult, GauntletJsError> {
+    let context = {
+        let state = state.borrow();
+
+        let context = state.borrow::<NumbatContext>().clone();
+
+        context
+    };
+
+    let mut context = context.0.borrow_mut();
+
+    let result = interpret(&mut context.context, &input)?;
+
+    context.state.history.insert(
+        0,
+        NumbatHistoryEntry {
+            input: input.clone(),
+            left: result.left.clone(),
+            right: result.right.clone(),
+        },
+    );
+    context.state.history.truncate(HISTORY_LIMIT);
+
+    match definition_name(&input) {
+        Some(name) => {
+            add_definition(&mut context.state.definitions, input, &name);
+            trim_definitions(&mut context.state.definitions, DEFINITIONS_LIMIT);
+        }
+        None => {
+            context.state.last_input = Some(input);
+        }
+    }
+
+    context.preview_context = None;
+
+    write_state(&context.state_file, &context.state)?;
+
+    Ok(result)
+}
+
+#[op2]
+#[serde]
+pub fn numbat_history(state: Rc<RefCell<OpState>>) -> Vec<NumbatHistoryEntry> {
+    let state = state.borrow();
+
+    let context = state.borrow::<NumbatContext>();
+
+    let history = context.0.borrow().state.history.clone();
+
+    history
+}
+
+#[op2(fast)]
+pub fn clear_numbat_history(state: Rc<RefCell<OpState>>) -> Result<(), GauntletJsError> {
+    let context = {
+        let state = state.borrow();
+
+        let context = state.borrow::<NumbatContext>().clone();
+
+        context
+    };
+
+    let mut context = context.0.borrow_mut();
+
+    context.state.history.clear();
+
+    write_state(&context.state_file, &context.state)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn add(definitions: &mut Vec<String>, input: &str) {
+        let name = definition_name(input).unwrap();
+
+        add_definition(definitions, input.to_string(), &name);
+    }
+
+    fn definitions(inputs: &[&str]) -> Vec<String> {
+        inputs.iter().map(|input| input.to_string()).collect()
+    }
+
+    #[test]
+    fn referenced_names_skip_defined_name() {
+        assert_eq!(referenced_names("let b = a * 2"), vec!["let", "a", "2"]);
+        assert_eq!(referenced_names("let a = a + 1"), vec!["let", "a", "1"]);
+        assert_eq!(referenced_names("fn f(x) = x * rate"), vec!["fn", "x", "x", "rate"]);
+    }
+
+    #[test]
+    fn new_definition_is_appended() {
+        let mut result = definitions(&["let a = 1"]);
+
+        add(&mut result, "let b = a * 2");
+
+        assert_eq!(result, definitions(&["let a = 1", "let b = a * 2"]));
+    }
+
+    #[test]
+    fn redefinition_is_replaced_in_place() {
+        let mut result = definitions(&["let a = 1", "let b = a * 2", "let c = 3"]);
+
+        add(&mut result, "let a = 5");
+
+        assert_eq!(result, definitions(&["let a = 5", "let b = a * 2", "let c = 3"]));
+    }
+
+    #[test]
+    fn redefinition_referring_to_following_definition_is_moved_to_end() {
+        let mut result = definitions(&["let a = 1", "let b = 2"]);
+
+        add(&mut result, "let a = b + 1");
+
+        assert_eq!(result, definitions(&["let b = 2", "let a = b + 1"]));
+    }
+
+    #[test]
+    fn redefinition_keeps_previous_definition_which_is_still_used() {
+        let mut result = definitions(&["let a = 1", "let b = a * 2"]);
+
+        add(&mut result, "let a = a + 1");
+
+        assert_eq!(result, definitions(&["let a = 1", "let b = a * 2", "let a = a + 1"]));
+
+        add(&mut result, "let a = 10");
+
+        assert_eq!(result, definitions(&["let a = 1", "let b = a * 2", "let a = 10"]));
+    }
+
+    #[test]
+    fn trim_removes_oldest_definitions() {
+        let mut result = definitions(&["let a = 1", "let b = 2", "let c = 3"]);
+
+        trim_definitions(&mut result, 2);
+
+        assert_eq!(result, definitions(&["let b = 2", "let c = 3"]));
+    }
+
+    #[test]
+    fn trim_removes_definitions_which_depend_on_removed_ones() {
+        let mut result = definitions(&["let a = 1", "let b = 2", "let c = a * 3", "let d = 4"]);
+
+        trim_definitions(&mut result, 3);
+
+        assert_eq!(result, definitions(&["let d = 4"]));
+    }
+
+    #[test]
+    fn trim_keeps_definitions_which_depend_on_redefined_ones() {
+        let mut result = definitions(&["let a = 1", "let b = 2", "let a = 5", "let c = a"]);
+
+        trim_definitions(&mut result, 3);
+
+        assert_eq!(result, definitions(&["let b = 2", "let a = 5", "let c = a"]));
+    }
+
+    #[test]
+    fn trim_does_nothing_under_limit() {
+        let mut result = definitions(&["let a = 1", "let b = a"]);
+
+        trim_definitions(&mut result, 2);
+
+        assert_eq!(result, definitions(&["let a = 1", "let b = a"]));
+    }
+
+    #[test]
+    fn definitions_are_replayable_after_redefinition_and_trim() {
+        let mut result = vec![];
+
+        for input in ["let a = 1", "let b = a * 2", "let a = b + 1", "let c = 3", "let d = 4"] {
+            add(&mut result, input);
+            trim_definitions(&mut result, 3);
+        }
+
+        let mut context = Context::new(BuiltinModuleImporter::default());
+        let _ = context.interpret("use prelude", CodeSource::Internal);
+
+        for input in &result {
+            context.interpret(input, CodeSource::Text).unwrap();
+        }
+    }
+}
@@ -0,0 +1,401 @@
+use std::ffi::CStr;
+use std::os::unix::process::CommandExt;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+
+use anyhow::Context;
+use anyhow::anyhow;
+use gauntlet_common_plugin_runtime::model::JsInit;
+use gauntlet_common_plugin_runtime::model::JsPluginSandbox;
+use landlock::ABI;
+use landlock::Access;
+use landlock::AccessFs;
+use landlock::Ruleset;
+use landlock::RulesetAttr;
+use landlock::RulesetCreatedAttr;
+use landlock::RulesetStatus;
+use landlock::path_beneath_rules;
+use seccompiler::BpfProgram;
+use seccompiler::SeccompAction;
+use seccompiler::SeccompFilter;
+use seccompiler::TargetArch;
+
+use crate::permissions::augment_path;
+
+const LANDLOCK_ABI: ABI = ABI::V3;
+
+// needed by deno and v8 itself, and by commands which plugin is allowed to execute
+const SYSTEM_READ_PATHS: &[&str] = &[
+    "/usr",
+    "/lib",
+    "/lib32",
+    "/lib64",
+    "/bin",
+    "/sbin",
+    "/etc",
+    "/opt",
+    "/nix/store",
+    "/run/current-system",
+    "/run/systemd/resolve",
+    "/run/NetworkManager",
+    "/run/resolvconf",
+    "/proc/self",
+    "/proc/cpuinfo",
+    "/proc/meminfo",
+    "/proc/stat",
+    "/proc/loadavg",
+    "/proc/uptime",
+    "/sys/devices/system/cpu",
+    "/sys/fs/cgroup",
+    "/dev/urandom",
+    "/dev/random",
+];
+
+const SYSTEM_WRITE_PATHS: &[&str] = &["/dev/null"];
+
+// syscalls which are not needed by plugins, but could be used to escape the sandbox or to attack the system
+const DENIED_SYSCALLS: &[libc::c_long] = &[
+    libc::SYS_ptrace,
+    libc::SYS_process_vm_readv,
+    libc::SYS_process_vm_writev,
+    libc::SYS_kexec_load,
+    libc::SYS_kexec_file_load,
+    libc::SYS_init_module,
+    libc::SYS_finit_module,
+    libc::SYS_delete_module,
+    libc::SYS_mount,
+    libc::SYS_umount2,
+    libc::SYS_pivot_root,
+    libc::SYS_chroot,
+    libc::SYS_swapon,
+    libc::SYS_swapoff,
+    libc::SYS_reboot,
+    libc::SYS_bpf,
+    libc::SYS_perf_event_open,
+    libc::SYS_keyctl,
+    libc::SYS_add_key,
+    libc::SYS_request_key,
+    libc::SYS_unshare,
+    libc::SYS_setns,
+    libc::SYS_userfaultfd,
+    libc::SYS_open_by_handle_at,
+    libc::SYS_name_to_handle_at,
+    libc::SYS_acct,
+    libc::SYS_quotactl,
+    libc::SYS_syslog,
+    libc::SYS_settimeofday,
+    libc::SYS_clock_settime,
+];
+
+// if network namespace cannot be created, runtime process fails to start,
+// unless user explicitly allowed plugins without network permission to run with network access
+pub fn isolate_network(command: &mut Command, allow_fallback: bool) {
+    // prepared before fork, only async-signal-safe functions can be called in pre_exec
+    let uid = unsafe { libc::getuid() };
+    let gid = unsafe { libc::getgid() };
+    let uid_map = format!("{uid} {uid} 1");
+    let gid_map = format!("{gid} {gid} 1");
+
+    unsafe {
+        command.pre_exec(move || {
+            // unprivileged user namespaces can be disabled by the distribution,
+            // with fallback allowed runtime process is started anyway and reports it after start
+            if libc::unshare(libc::CLONE_NEWUSER | libc::CLONE_NEWNET) != 0 {
+                return if allow_fallback {
+                    Ok(())
+                } else {
+                    Err(std::io::Error::last_os_error())
+                };
+            }
+
+            // map current user to itself so that files created by plugin are owned by it
+            write_proc_file(c"/proc/self/setgroups", b"deny")?;
+            write_proc_file(c"/proc/self/uid_map", uid_map.as_bytes())?;
+            write_proc_file(c"/proc/self/gid_map", gid_map.as_bytes())?;
+
+            Ok(())
+        });
+    }
+}
+
+fn write_proc_file(path: &CStr, content: &[u8]) -> std::io::Result<()> {
+    let fd = unsafe { libc::open(path.as_ptr(), libc::O_WRONLY | libc::O_CLOEXEC) };
+    if fd < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+
+    let written = unsafe { libc::write(fd, content.as_ptr().cast(), content.len()) };
+    let result = if written < 0 {
+        Err(std::io::Error::last_os_error())
+    } else {
+        Ok(())
+    };
+
+    unsafe {
+        libc::close(fd);
+    }
+
+    result
+}
+
+// has to be called before any thread which runs plugin code is started,
+// landlock restrictions are only inherited by threads and processes started after this call
+pub fn apply_sandbox(init: &JsInit, sandbox: &JsPluginSandbox) -> anyhow::Result<()> {
+    if sandbox.isolate_network && !network_isolated() {
+        tracing::warn!(
+            "Unable to isolate network of plugin {:?}, unprivileged user namespaces are probably disabled. Plugin has network access because `linux.plugin_sandbox_network_fallback` is enabled",
+            init.plugin_id
+        );
+    }
+
+    std::fs::create_dir_all(&init.plugin_cache_dir).context("Unable to create plugin cache directory")?;
+
+    std::fs::create_dir_all(&init.plugin_data_dir).context("Unable to create plugin data directory")?;
+
+    std::fs::create_dir_all(&init.local_storage_dir).context("Unable to create plugin local storage directory")?;
+
+    restrict_filesystem(init)?;
+
+    restrict_syscalls()?;
+
+    Ok(())
+}
+
+fn network_isolated() -> bool {
+    let own_namespace = std::fs::read_link("/proc/self/ns/net");
+    let parent_namespace = std::fs::read_link(format!("/proc/{}/ns/net", std::os::unix::process::parent_id()));
+
+    match (own_namespace, parent_namespace) {
+        (Ok(own_namespace), Ok(parent_namespace)) => own_namespace != parent_namespace,
+        _ => false,
+    }
+}
+
+fn restrict_filesystem(init: &JsInit) -> anyhow::Result<()> {
+    let home_dir = Path::new(&init.home_dir);
+    let plugin_data_dir = Path::new(&init.plugin_data_dir);
+    let plugin_cache_dir = Path::new(&init.plugin_cache_dir);
+
+    let manifest_paths = |paths: &[String]| -> anyhow::Result<Vec<PathBuf>> {
+        let paths = paths
+            .iter()
+            .map(|path| augment_path(path, home_dir, plugin_data_dir, plugin_cache_dir))
+            .collect::<anyhow::Result<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect();
+
+        Ok(paths)
+    };
+
+    let mut read_paths: Vec<PathBuf> = SYSTEM_READ_PATHS.iter().map(PathBuf::from).collect();
+    read_paths.extend(manifest_paths(&init.permissions.filesystem.read)?);
+    read_paths.extend(manifest_paths(&init.permissions.exec.executable)?);
+    read_paths.extend(
+        init.permissions
+            .exec
+            .command
+            .iter()
+            .flat_map(|command| which::which_global(command)),
+    );
+
+    let mut write_paths: Vec<PathBuf> = SYSTEM_WRITE_PATHS.iter().map(PathBuf::from).collect();
+    write_paths.extend(manifest_paths(&init.permissions.filesystem.write)?);
+    write_paths.extend(
+        [&init.plugin_data_dir, &init.plugin_cache_dir, &init.local_storage_dir]
+            .into_iter()
+            .map(PathBuf::from),
+    );
+    write_paths.extend(init.stdout_file.iter().map(PathBuf::from));
+    write_paths.extend(init.stderr_file.iter().map(PathBuf::from));
+
+    let status = restrict_paths(read_paths, write_paths)?;
+
+    match status {
+        RulesetStatus::FullyEnforced => {}
+        RulesetStatus::PartiallyEnforced => {
+            tracing::warn!("Landlock filesystem restrictions are only partially supported by the kernel")
+        }
+        RulesetStatus::NotEnforced => {
+            tracing::warn!("Landlock is not supported by the kernel, filesystem access is not restricted")
+        }
+    }
+
+    Ok(())
+}
+
+fn restrict_paths(mut read_paths: Vec<PathBuf>, mut write_paths: Vec<PathBuf>) -> anyhow::Result<RulesetStatus> {
+    // non-existent paths cannot be added to ruleset
+    read_paths.retain(|path| path.exists());
+    write_paths.retain(|path| path.exists());
+
+    let status = Ruleset::default()
+        .handle_access(AccessFs::from_all(LANDLOCK_ABI))?
+        .create()?
+        .add_rules(path_beneath_rules(read_paths, AccessFs::from_read(LANDLOCK_ABI)))?
+        .add_rules(path_beneath_rules(write_paths, AccessFs::from_all(LANDLOCK_ABI)))?
+        .restrict_self()
+        .context("Unable to apply landlock ruleset")?;
+
+    Ok(status.ruleset)
+}
+
+fn restrict_syscalls() -> anyhow::Result<()> {
+    let rules = DENIED_SYSCALLS
+        .iter()
+        // empty list of conditions means that syscall is matched regardless of arguments
+        .map(|syscall| (*syscall as i64, vec![]))
+        .collect();
+
+    let arch: TargetArch = std::env::consts::ARCH
+        .try_into()
+        .map_err(|err| anyhow!("Unsupported architecture for seccomp filter: {:?}", err))?;
+
+    let filter = SeccompFilter::new(
+        rules,
+        SeccompAction::Allow,
+        SeccompAction::Errno(libc::EPERM as u32),
+        arch,
+    )
+    .map_err(|err| anyhow!("Unable to create seccomp filter: {:?}", err))?;
+
+    let program: BpfProgram = filter
+        .try_into()
+        .map_err(|err| anyhow!("Unable to compile seccomp filter: {:?}", err))?;
+
+    // unlike landlock, seccomp filter can be applied to threads which are already running
+    seccompiler::apply_filter_all_threads(&program)
+        .map_err(|err| anyhow!("Unable to apply seccomp filter: {:?}", err))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpStream;
+    use std::process::Output;
+
+    use super::*;
+
+    // restrictions are applied to the whole process, so each case is run in a separate child process
+    const CHILD_CASE_ENV: &str = "GAUNTLET_SANDBOX_TEST_CASE";
+    const CHILD_DIR_ENV: &str = "GAUNTLET_SANDBOX_TEST_DIR";
+    const CHILD_SKIPPED: &str = "sandbox test skipped";
+
+    fn child_command(case: &str) -> Command {
+        let mut command = Command::new(std::env::current_exe().unwrap());
+
+        command
+            .args([
+                "--exact",
+                "sandbox::tests::sandboxed_child",
+                "--nocapture",
+                "--test-threads=1",
+            ])
+            .env(CHILD_CASE_ENV, case);
+
+        command
+    }
+
+    fn assert_child_succeeded(output: Output) {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        assert!(output.status.success(), "stdout: {}\nstderr: {}", stdout, stderr);
+
+        if stdout.contains(CHILD_SKIPPED) {
+            eprintln!("{}", CHILD_SKIPPED);
+        }
+    }
+
+    #[test]
+    fn sandboxed_child() {
+        let Ok(case) = std::env::var(CHILD_CASE_ENV) else {
+            return;
+        };
+
+        match case.as_str() {
+            "filesystem" => {
+                let dir = PathBuf::from(std::env::var(CHILD_DIR_ENV).unwrap());
+
+                let allowed_dir = dir.join("allowed");
+                let denied_file = dir.join("denied.txt");
+
+                let read_paths = SYSTEM_READ_PATHS.iter().map(PathBuf::from).collect();
+                let status = restrict_paths(read_paths, vec![allowed_dir.clone()]).unwrap();
+
+                if let RulesetStatus::NotEnforced = status {
+                    println!("{}", CHILD_SKIPPED);
+                    return;
+                }
+
+                std::fs::write(allowed_dir.join("file.txt"), "allowed").unwrap();
+
+                let err = std::fs::read_to_string(&denied_file).unwrap_err();
+                assert_eq!(err.raw_os_error(), Some(libc::EACCES));
+
+                let err = std::fs::write(dir.join("created.txt"), "denied").unwrap_err();
+                assert_eq!(err.raw_os_error(), Some(libc::EACCES));
+            }
+            "syscall" => {
+                restrict_syscalls().unwrap();
+
+                let result = unsafe { libc::ptrace(libc::PTRACE_TRACEME, 0, 0, 0) };
+                assert_eq!(result, -1);
+                assert_eq!(std::io::Error::last_os_error().raw_os_error(), Some(libc::EPERM));
+
+                let result = unsafe { libc::unshare(libc::CLONE_NEWUSER) };
+                assert_eq!(result, -1);
+                assert_eq!(std::io::Error::last_os_error().raw_os_error(), Some(libc::EPERM));
+            }
+            "network" => {
+                assert!(network_isolated());
+
+                // loopback interface of new network namespace is down and there are no other interfaces
+                let err = TcpStream::connect("1.1.1.1:80").unwrap_err();
+                assert_eq!(err.raw_os_error(), Some(libc::ENETUNREACH));
+
+                let err = TcpStream::connect("127.0.0.1:80").unwrap_err();
+                assert_eq!(err.raw_os_error(), Some(libc::ENETUNREACH));
+            }
+            case => panic!("unknown sandbox test case: {}", case),
+        }
+    }
+
+    #[test]
+    fn filesystem_access_outside_of_allowed_paths_is_denied() {
+        let dir = std::env::temp_dir().join(format!("gauntlet-sandbox-test-{}", std::process::id()));
+
+        std::fs::create_dir_all(dir.join("allowed")).unwrap();
+        std::fs::write(dir.join("denied.txt"), "denied").unwrap();
+
+        let output = child_command("filesystem").env(CHILD_DIR_ENV, &dir).output().unwrap();
+
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_child_succeeded(output);
+    }
+
+    #[test]
+    fn denied_syscalls_fail_with_eperm() {
+        let output = child_command("syscall").output().unwrap();
+
+        assert_child_succeeded(output);
+    }
+
+    #[test]
+    fn network_is_isolated_or_process_is_not_started() {
+        let mut command = child_command("network");
+
+        isolate_network(&mut command, false);
+
+        match command.output() {
+            Ok(output) => assert_child_succeeded(output),
+            Err(err) => {
+                // user namespaces are not available, process must not be started without isolation
+                assert!(err.raw_os_error().is_some(), "unexpected error: {:?}", err);
+            }
+        }
+    }
+}
@@ -17,6 +17,7 @@ use tokio::runtime::Handle;
 use crate::component_model::ComponentModel;
 use crate::deno::GauntletJsError;
 use crate::model_deserialization::deserialize_root_widget;
+use crate::model_deserialization::deserialize_widget_patch;
 use crate::plugin_data::PluginData;
 
 #[op2]
@@ -69,13 +70,13 @@ pub fn show_preferences_required_view(
 }
 
 #[op2]
-#[string]
-pub fn op_inline_view_entrypoint_id(state: Rc<RefCell<OpState>>) -> Option<String> {
+#[serde]
+pub fn op_inline_view_entrypoint_ids(state: Rc<RefCell<OpState>>) -> Vec<String> {
     state
         .borrow()
         .borrow::<PluginData>()
-        .inline_view_entrypoint_id()
-        .clone()
+        .inline_view_entrypoint_ids()
+        .to_vec()
 }
 
 #[op2]
@@ -92,6 +93,7 @@ pub fn op_react_replace_view<'a>(
     top_level_view: bool,
     #[string] entrypoint_id: String,
     #[string] entrypoint_name: String,
+    #[serde] inline_view_generation: Option<u64>,
     container: v8::Local<v8::Value>,
 ) -> Result<(), GauntletJsError> {
     tracing::trace!(target = "renderer_rs", "Calling op_react_replace_view...");
@@ -119,6 +121,7 @@ pub fn op_react_replace_view<'a>(
                     entrypoint_name,
                     render_location,
                     top_level_view,
+                    inline_view_generation,
                     container,
                 )
                 .await
@@ -131,6 +134,47 @@ pub fn op_react_replace_view<'a>(
     Ok(())
 }
 
+#[op2]
+pub fn op_react_patch_view<'a>(
+    scope: &mut v8::HandleScope,
+    state: Rc<RefCell<OpState>>,
+    #[serde] render_location: JsUiRenderLocation,
+    top_level_view: bool,
+    #[string] entrypoint_id: String,
+    #[string] entrypoint_name: String,
+    patch: v8::Local<v8::Value>,
+) -> Result<(), GauntletJsError> {
+    tracing::trace!(target = "renderer_rs", "Calling op_react_patch_view...");
+
+    let patch = deserialize_widget_patch(scope, patch)
+        .map_err(|err| anyhow!("Unable to deserialize component patch: {:#}", err))?;
+
+    let entrypoint_id = EntrypointId::from_string(entrypoint_id);
+
+    let (api, outer_handle) = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        let outer_handle = state.borrow::<Handle>().clone();
+
+        (api, outer_handle)
+    };
+
+    block_on(async move {
+        outer_handle
+            .spawn(async move {
+                api.ui_render_patch(entrypoint_id, entrypoint_name, render_location, top_level_view, patch)
+                    .await
+                    .map_err(|err| anyhow!(err))
+            })
+            .await
+            .map_err(|err| anyhow!(err))
+    })??;
+
+    Ok(())
+}
+
 #[op2]
 #[serde]
 pub fn op_component_model(state: Rc<RefCell<OpState>>) -> HashMap<String, Component> {
@@ -215,6 +259,58 @@ pub async fn update_loading_bar(
         .map_err(Into::into)
 }
 
+#[op2(async)]
+pub async fn task_start(
+    state: Rc<RefCell<OpState>>,
+    #[string] task_id: String,
+    #[string] title: String,
+) -> Result<(), GauntletJsError> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.ui_task_start(task_id, title).await.map_err(Into::into)
+}
+
+#[op2(async)]
+pub async fn task_update(
+    state: Rc<RefCell<OpState>>,
+    #[string] task_id: String,
+    #[serde] progress: Option<f64>,
+    #[serde] status: Option<String>,
+) -> Result<(), GauntletJsError> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.ui_task_update(task_id, progress, status).await.map_err(Into::into)
+}
+
+#[op2(async)]
+pub async fn task_finish(
+    state: Rc<RefCell<OpState>>,
+    #[string] task_id: String,
+    #[serde] error: Option<String>,
+) -> Result<(), GauntletJsError> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.ui_task_finish(task_id, error).await.map_err(Into::into)
+}
+
 #[allow(unused)]
 fn debug_object_to_json(scope: &mut v8::HandleScope, val: v8::Local<v8::Value>) -> String {
     let local = scope.get_current_context();
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use gauntlet_common::model::EntrypointId;
 use gauntlet_common::model::KeyboardEventOrigin;
 use gauntlet_common::model::PhysicalKey;
@@ -8,6 +10,8 @@ use gauntlet_common::model::UiWidgetId;
 pub enum IntermediateUiEvent {
     OpenView {
         entrypoint_id: EntrypointId,
+        arguments: HashMap<String, String>,
+        search_text: Option<String>,
     },
     CloseView,
     PopView {
@@ -15,6 +19,7 @@ pub enum IntermediateUiEvent {
     },
     RunCommand {
         entrypoint_id: String,
+        arguments: HashMap<String, String>,
     },
     RunGeneratedEntrypoint {
         entrypoint_id: String,
@@ -36,8 +41,19 @@ pub enum IntermediateUiEvent {
     },
     OpenInlineView {
         text: String,
+        generation: u64,
+        entrypoint_id: Option<EntrypointId>,
     },
     RefreshSearchIndex,
+    CancelTask {
+        task_id: String,
+    },
+    CallExportedCommand {
+        call_id: String,
+        command_id: String,
+        arguments: String,
+    },
+    ReplaceView,
 }
 
 pub enum ActionShortcutKey {
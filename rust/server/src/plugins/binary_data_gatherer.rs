@@ -8,6 +8,8 @@ use gauntlet_common::model::ImageLike;
 use gauntlet_common::model::RootWidget;
 use gauntlet_common::model::SvgWidget;
 use gauntlet_common::model::UiWidgetId;
+use gauntlet_common::model::UiWidgetPatchChild;
+use gauntlet_common::model::UiWidgetPatchOp;
 use gauntlet_common::model::WidgetVisitor;
 use gauntlet_common_plugin_runtime::api::BackendForPluginRuntimeApi;
 
@@ -43,8 +45,39 @@ impl<'a> BinaryDataGatherer<'a> {
 
         gatherer.root_widget(root_widget).await;
 
-        gatherer
-            .data
+        gatherer.into_data()
+    }
+
+    // data for widgets which are not part of the patch was already sent to the client
+    pub async fn run_gatherer_for_patch(
+        api: &'a BackendForPluginRuntimeApiImpl,
+        patch: &[UiWidgetPatchOp],
+    ) -> anyhow::Result<HashMap<UiWidgetId, Vec<u8>>> {
+        let mut gatherer = Self {
+            api,
+            data: HashMap::new(),
+        };
+
+        for op in patch {
+            match op {
+                UiWidgetPatchOp::Replace { widget } | UiWidgetPatchOp::UpdateProps { widget } => {
+                    gatherer.widget(widget).await
+                }
+                UiWidgetPatchOp::UpdateChildren { children, .. } => {
+                    for child in children {
+                        if let UiWidgetPatchChild::New(widget) = child {
+                            gatherer.widget(widget).await
+                        }
+                    }
+                }
+            }
+        }
+
+        gatherer.into_data()
+    }
+
+    fn into_data(self) -> anyhow::Result<HashMap<UiWidgetId, Vec<u8>>> {
+        self.data
             .into_iter()
             .map(|(widget_id, image)| image.map(|image| (widget_id, image)))
             .collect::<anyhow::Result<_>>()
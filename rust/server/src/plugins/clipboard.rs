@@ -82,6 +82,34 @@ impl Clipboard {
         Ok(data)
     }
 
+    #[cfg(target_os = "linux")]
+    pub fn read_selection_text(&self) -> anyhow::Result<Option<String>> {
+        use arboard::GetExtLinux;
+        use arboard::LinuxClipboardKind;
+
+        let mut clipboard = self.clipboard.write().expect("lock is poisoned");
+
+        let data = match clipboard.get().clipboard(LinuxClipboardKind::Primary).text() {
+            Ok(data) => Some(data),
+            Err(err) => {
+                match err {
+                    arboard::Error::ContentNotAvailable => None,
+                    err @ _ => {
+                        return Err(unknown_err_clipboard(err));
+                    }
+                }
+            }
+        };
+
+        Ok(data)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn read_selection_text(&self) -> anyhow::Result<Option<String>> {
+        // primary selection only exists on linux
+        Ok(None)
+    }
+
     pub fn write(&self, data: JsClipboardData) -> anyhow::Result<()> {
         let mut clipboard = self.clipboard.write().expect("lock is poisoned");
 
@@ -46,6 +46,8 @@ pub struct DbReadPlugin {
     pub preferences: HashMap<String, DbPluginPreference>,
     #[rusqlite(json)]
     pub preferences_user_data: HashMap<String, DbPluginPreferenceUserData>,
+    #[rusqlite(json)]
+    pub exported_commands: Vec<DbPluginExportedCommand>,
 }
 
 #[derive(RusqliteFromRow)]
@@ -67,6 +69,8 @@ pub struct DbReadPluginEntrypoint {
     pub actions: Vec<DbPluginAction>,
     #[rusqlite(json)]
     pub actions_user_data: Vec<DbPluginActionUserData>,
+    #[rusqlite(json)]
+    pub arguments: Vec<DbPluginArgument>,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -85,6 +89,7 @@ pub struct DbWritePlugin {
     pub permissions: DbPluginPermissions,
     pub plugin_type: String,
     pub preferences: HashMap<String, DbPluginPreference>,
+    pub exported_commands: Vec<DbPluginExportedCommand>,
 }
 
 pub struct DbWritePluginEntrypoint {
@@ -95,6 +100,7 @@ pub struct DbWritePluginEntrypoint {
     pub entrypoint_type: String,
     pub preferences: HashMap<String, DbPluginPreference>,
     pub actions: Vec<DbPluginAction>,
+    pub arguments: Vec<DbPluginArgument>,
 }
 
 pub struct DbWritePluginAssetData {
@@ -134,6 +140,12 @@ pub struct DbPluginPermissions {
     pub clipboard: Vec<DbPluginClipboardPermissions>,
     #[serde(default)]
     pub main_search_bar: Vec<DbPluginMainSearchBarPermissions>,
+    #[serde(default)]
+    pub launch_context: Vec<DbPluginLaunchContextPermissions>,
+    #[serde(default)]
+    pub plugin_command: Vec<DbPluginCommandPermission>,
+    #[serde(default)]
+    pub deep_link: Vec<DbPluginDeepLinkPermissions>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Default)]
@@ -160,6 +172,8 @@ pub enum DbPluginClipboardPermissions {
     Write,
     #[serde(rename = "clear")]
     Clear,
+    #[serde(rename = "paste")]
+    Paste,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -168,6 +182,53 @@ pub enum DbPluginMainSearchBarPermissions {
     Read,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub enum DbPluginLaunchContextPermissions {
+    #[serde(rename = "selected_text")]
+    SelectedText,
+    #[serde(rename = "focused_window")]
+    FocusedWindow,
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub enum DbPluginDeepLinkPermissions {
+    #[serde(rename = "run")]
+    Run,
+    #[serde(rename = "open")]
+    Open,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DbPluginCommandPermission {
+    pub plugin: String,
+    pub command: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DbPluginExportedCommand {
+    pub id: String,
+    pub description: String,
+    pub arguments: Vec<DbPluginExportedCommandArgument>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DbPluginExportedCommandArgument {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub argument_type: DbPluginExportedCommandArgumentType,
+    pub required: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub enum DbPluginExportedCommandArgumentType {
+    #[serde(rename = "string")]
+    String,
+    #[serde(rename = "number")]
+    Number,
+    #[serde(rename = "boolean")]
+    Boolean,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(tag = "type")]
 pub enum DbPluginPreferenceUserData {
@@ -195,6 +256,25 @@ pub struct DbPluginAction {
     pub kind: Option<DbPluginActionShortcutKind>,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DbPluginArgument {
+    pub id: String,
+    pub kind: DbPluginArgumentKind,
+    pub placeholder: String,
+    pub required: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "type")]
+pub enum DbPluginArgumentKind {
+    #[serde(rename = "text")]
+    Text,
+    #[serde(rename = "password")]
+    Password,
+    #[serde(rename = "dropdown")]
+    Dropdown { options: Vec<DbPreferenceEnumValue> },
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct DbPluginActionUserData {
     pub id: String,
@@ -240,16 +320,35 @@ pub struct DbSettingsEntrypointSearchAliasData {
     pub alias: String,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DbSettingsEntrypointSearchPinData {
+    pub plugin_id: String,
+    pub entrypoint_id: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DbSettingsEntrypointSearchHiddenData {
+    pub plugin_id: String,
+    pub entrypoint_id: String,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct DbSettings {
     // none means auto-detect
     pub theme: Option<DbTheme>,
     // none is static mode
     pub window_position_mode: Option<DbWindowPositionMode>,
+    // none is default size
+    pub window_size: Option<DbWindowSize>,
+    // none is disabled
+    pub compact_mode: Option<bool>,
     // none is unset, if whole settings object is unset, it is likely a first start and default shortcut will be used
     pub global_shortcut: Option<DbSettingsGlobalShortcutData>,
     pub global_entrypoint_shortcuts: Option<Vec<DbSettingsGlobalEntrypointShortcutData>>,
     pub entrypoint_search_aliases: Option<Vec<DbSettingsEntrypointSearchAliasData>>,
+    // order of items is the order in which pinned entrypoints are shown
+    pub entrypoint_search_pins: Option<Vec<DbSettingsEntrypointSearchPinData>>,
+    pub entrypoint_search_hidden: Option<Vec<DbSettingsEntrypointSearchHiddenData>>,
 }
 
 impl Default for DbSettings {
@@ -275,12 +374,16 @@ impl Default for DbSettings {
         DbSettings {
             theme: None,
             window_position_mode: None,
+            window_size: None,
+            compact_mode: None,
             global_shortcut: Some(DbSettingsGlobalShortcutData {
                 shortcut: default_global_shortcut,
                 error: None,
             }),
             global_entrypoint_shortcuts: None,
             entrypoint_search_aliases: None,
+            entrypoint_search_pins: None,
+            entrypoint_search_hidden: None,
         }
     }
 }
@@ -299,6 +402,18 @@ pub enum DbTheme {
 pub enum DbWindowPositionMode {
     #[serde(rename = "active_monitor")]
     ActiveMonitor,
+    #[serde(rename = "monitor_under_cursor")]
+    MonitorUnderCursor,
+    #[serde(rename = "fixed_monitor")]
+    FixedMonitor { name: String },
+    #[serde(rename = "top_third_centered")]
+    TopThirdCentered,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DbWindowSize {
+    pub width: f32,
+    pub height: f32,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -364,6 +479,29 @@ pub struct DbPreferenceEnumValue {
     pub value: String,
 }
 
+#[derive(RusqliteFromRow)]
+pub struct DbReadQuicklink {
+    pub id: String,
+    pub name: String,
+    pub icon_path: Option<String>,
+    pub template: String,
+    pub kind: String,
+}
+
+pub struct DbWriteQuicklink {
+    pub id: String,
+    pub name: String,
+    pub icon_path: Option<String>,
+    pub template: String,
+    pub kind: DbQuicklinkKind,
+}
+
+#[derive(Debug, Clone)]
+pub enum DbQuicklinkKind {
+    Url,
+    Command,
+}
+
 #[derive(RusqliteFromRow)]
 pub struct DbPluginEntrypointFrecencyStats {
     #[allow(unused)]
@@ -558,7 +696,7 @@ impl DataDbRepository {
         Ok(result)
     }
 
-    pub fn get_inline_view_entrypoint_id_for_plugin(&self, plugin_id: &str) -> anyhow::Result<Option<String>> {
+    pub fn get_inline_view_entrypoint_ids_for_plugin(&self, plugin_id: &str) -> anyhow::Result<Vec<String>> {
         let connection = self.connection.lock().map_err(|_| anyhow!("lock is poisoned"))?;
 
         // language=SQLite
@@ -570,15 +708,17 @@ impl DataDbRepository {
         }
 
         let result = connection
-            .query_row(
-                query,
+            .prepare(query)?
+            .query_and_then(
                 named_params! {
                     ":plugin_id": plugin_id
                 },
                 DbReadInlineViewEntrypoint::from_row,
-            )
-            .optional()?
-            .map(|row| row.id);
+            )?
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .map(|row| row.id)
+            .collect();
 
         Ok(result)
     }
@@ -847,7 +987,9 @@ impl DataDbRepository {
         Ok(result)
     }
 
-    pub fn inline_view_shortcuts(&self) -> anyhow::Result<HashMap<String, HashMap<String, PhysicalShortcut>>> {
+    pub fn inline_view_shortcuts(
+        &self,
+    ) -> anyhow::Result<HashMap<(String, String), HashMap<String, PhysicalShortcut>>> {
         let connection = self.connection.lock().map_err(|_| anyhow!("lock is poisoned"))?;
 
         #[derive(RusqliteFromRow)]
@@ -867,7 +1009,7 @@ impl DataDbRepository {
             .map(|row| {
                 let shortcuts = self.action_shortcuts_with_executor(&row.plugin_id, &row.id, &connection)?;
 
-                Ok::<_, anyhow::Error>((row.plugin_id, shortcuts))
+                Ok::<_, anyhow::Error>(((row.plugin_id, row.id), shortcuts))
             })
             .collect::<Result<HashMap<_, _>, _>>()?;
 
@@ -969,6 +1111,35 @@ impl DataDbRepository {
         Ok(())
     }
 
+    pub fn inline_view_frecency(&self) -> anyhow::Result<HashMap<(String, String), f64>> {
+        let connection = self.connection.lock().map_err(|_| anyhow!("lock is poisoned"))?;
+
+        #[derive(RusqliteFromRow)]
+        struct DbInlineViewFrecency {
+            pub plugin_id: String,
+            pub entrypoint_id: String,
+            pub frecency: f64,
+        }
+
+        // language=SQLite
+        let query = r#"
+            SELECT e.plugin_id, f.entrypoint_id, f.frecency
+            FROM plugin_entrypoint e
+                JOIN plugin_entrypoint_frecency_stats f ON e.plugin_id = f.plugin_id AND e.id = f.entrypoint_id
+            WHERE e.type = 'inline-view'
+        "#;
+
+        let result = connection
+            .prepare(query)?
+            .query_and_then([], DbInlineViewFrecency::from_row)?
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .map(|row| ((row.plugin_id, row.entrypoint_id), row.frecency))
+            .collect::<HashMap<_, _>>();
+
+        Ok(result)
+    }
+
     pub fn get_frecency_for_plugin(&self, plugin_id: &str) -> anyhow::Result<HashMap<String, f64>> {
         let connection = self.connection.lock().map_err(|_| anyhow!("lock is poisoned"))?;
 
@@ -1158,6 +1329,78 @@ impl DataDbRepository {
         Ok(())
     }
 
+    pub fn list_quicklinks(&self) -> anyhow::Result<Vec<DbReadQuicklink>> {
+        // language=SQLite
+        let query = "SELECT * FROM quicklink ORDER BY name";
+
+        let connection = self.connection.lock().map_err(|_| anyhow!("lock is poisoned"))?;
+
+        let quicklinks = connection
+            .prepare(query)?
+            .query_map([], DbReadQuicklink::from_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(quicklinks)
+    }
+
+    pub fn get_quicklink_by_id_option(&self, quicklink_id: &str) -> anyhow::Result<Option<DbReadQuicklink>> {
+        // language=SQLite
+        let query = "SELECT * FROM quicklink WHERE id = :id";
+
+        let connection = self.connection.lock().map_err(|_| anyhow!("lock is poisoned"))?;
+
+        let result = connection
+            .query_row(
+                query,
+                named_params! {
+                    ":id": quicklink_id
+                },
+                DbReadQuicklink::from_row,
+            )
+            .optional()?;
+
+        Ok(result)
+    }
+
+    pub fn save_quicklink(&self, quicklink: DbWriteQuicklink) -> anyhow::Result<()> {
+        let connection = self.connection.lock().map_err(|_| anyhow!("lock is poisoned"))?;
+
+        // language=SQLite
+        let query = r#"
+            INSERT OR REPLACE INTO quicklink (id, name, icon_path, template, kind)
+                VALUES(:id, :name, :icon_path, :template, :kind)
+        "#;
+
+        connection.execute(
+            query,
+            named_params! {
+                ":id": quicklink.id,
+                ":name": quicklink.name,
+                ":icon_path": quicklink.icon_path,
+                ":template": quicklink.template,
+                ":kind": db_quicklink_kind_to_str(quicklink.kind),
+            },
+        )?;
+
+        Ok(())
+    }
+
+    pub fn remove_quicklink(&self, quicklink_id: &str) -> anyhow::Result<()> {
+        let connection = self.connection.lock().map_err(|_| anyhow!("lock is poisoned"))?;
+
+        // language=SQLite
+        let query = "DELETE FROM quicklink WHERE id = :id";
+
+        connection.execute(
+            query,
+            named_params! {
+                ":id": quicklink_id
+            },
+        )?;
+
+        Ok(())
+    }
+
     pub fn save_plugin(&self, new_plugin: DbWritePlugin) -> anyhow::Result<()> {
         let mut connection = self.connection.lock().map_err(|_| anyhow!("lock is poisoned"))?;
         let mut tx = connection.transaction()?;
@@ -1169,8 +1412,8 @@ impl DataDbRepository {
 
         // language=SQLite
         let query = r#"
-            INSERT INTO plugin (id, name, enabled, code, permissions, preferences, preferences_user_data, description, type, uuid)
-                VALUES(:id, :name, :enabled, :code, :permissions, :preferences, :preferences_user_data, :description, :type, :uuid)
+            INSERT INTO plugin (id, name, enabled, code, permissions, preferences, preferences_user_data, description, type, uuid, exported_commands)
+                VALUES(:id, :name, :enabled, :code, :permissions, :preferences, :preferences_user_data, :description, :type, :uuid, :exported_commands)
                     ON CONFLICT (id)
                         DO UPDATE SET
                             name = :name,
@@ -1181,7 +1424,8 @@ impl DataDbRepository {
                             preferences_user_data = :preferences_user_data ,
                             description = :description ,
                             type = :type,
-                            uuid = :uuid
+                            uuid = :uuid,
+                            exported_commands = :exported_commands
         "#;
 
         tx.execute(
@@ -1196,7 +1440,8 @@ impl DataDbRepository {
                 ":preferences_user_data": serde_json::to_value(&preferences_user_data)?,
                 ":description": new_plugin.description,
                 ":type": new_plugin.plugin_type,
-                ":uuid": uuid
+                ":uuid": uuid,
+                ":exported_commands": serde_json::to_value(&new_plugin.exported_commands)?
             },
         )?;
 
@@ -1223,7 +1468,7 @@ impl DataDbRepository {
 
             // language=SQLite
             let query = r#"
-                INSERT OR REPLACE INTO plugin_entrypoint (id, plugin_id, name, enabled, type, preferences, preferences_user_data, description, actions, actions_user_data, icon_path, uuid)
+                INSERT OR REPLACE INTO plugin_entrypoint (id, plugin_id, name, enabled, type, preferences, preferences_user_data, description, actions, actions_user_data, icon_path, uuid, arguments)
                     VALUES(
                         :id,
                         :plugin_id,
@@ -1236,7 +1481,8 @@ impl DataDbRepository {
                         :actions,
                         :actions_user_data,
                         :icon_path,
-                        :uuid
+                        :uuid,
+                        :arguments
                     )
             "#;
 
@@ -1255,6 +1501,7 @@ impl DataDbRepository {
                     ":actions_user_data": serde_json::to_value(actions_user_data)?,
                     ":icon_path": new_entrypoint.icon_path,
                     ":uuid": uuid,
+                    ":arguments": serde_json::to_value(new_entrypoint.arguments)?,
                 },
             )?;
         }
@@ -1348,6 +1595,21 @@ pub fn db_plugin_type_from_str(value: &str) -> DbPluginType {
     }
 }
 
+pub fn db_quicklink_kind_to_str(value: DbQuicklinkKind) -> &'static str {
+    match value {
+        DbQuicklinkKind::Url => "url",
+        DbQuicklinkKind::Command => "command",
+    }
+}
+
+pub fn db_quicklink_kind_from_str(value: &str) -> DbQuicklinkKind {
+    match value {
+        "url" => DbQuicklinkKind::Url,
+        "command" => DbQuicklinkKind::Command,
+        _ => panic!("illegal quicklink kind: {}", value),
+    }
+}
+
 pub trait RusqliteFromRow {
     fn from_row(row: &Row<'_>) -> rusqlite::Result<Self>
     where
@@ -230,6 +230,9 @@ pub fn setup_migrator() -> Migrations<'static> {
             Ok(())
         })),
         M::up_with_hook("-- 16", legacy_migration("remove old global shortcut", include_str!("migrations/13_remove_old_global_shortcut.sql"))),
-        M::up(include_str!("migrations/14_migrate_to_rusqlite_migration.sql"))
+        M::up(include_str!("migrations/14_migrate_to_rusqlite_migration.sql")),
+        M::up(include_str!("migrations/15_quicklinks.sql")),
+        M::up(include_str!("migrations/16_plugin_entrypoint_arguments.sql")),
+        M::up(include_str!("migrations/17_plugin_exported_commands.sql")),
     ])
 }
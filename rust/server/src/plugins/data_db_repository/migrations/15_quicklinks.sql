@@ -0,0 +1,10 @@
+CREATE TABLE quicklink
+(
+    id        TEXT NOT NULL,
+    name      TEXT NOT NULL,
+    icon_path TEXT,
+    template  TEXT NOT NULL,
+    kind      TEXT NOT NULL,
+
+    PRIMARY KEY (id)
+);
@@ -0,0 +1 @@
+ALTER TABLE plugin ADD COLUMN exported_commands JSON NOT NULL DEFAULT ('[]');
@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+
+use anyhow::Context;
+use anyhow::anyhow;
+use gauntlet_common::model::EntrypointId;
+use gauntlet_common::model::PluginId;
+use percent_encoding::percent_decode_str;
+use url::Url;
+
+const DEEP_LINK_SCHEME: &str = "gauntlet";
+
+// query parameter of `open` deep link which is used as search text instead of entrypoint argument
+const SEARCH_TEXT_PARAM: &str = "query";
+
+#[derive(Debug)]
+pub enum DeepLinkRequest {
+    RunAction {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        action_id: String,
+        arguments: HashMap<String, String>,
+    },
+    OpenView {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        arguments: HashMap<String, String>,
+        search_text: Option<String>,
+    },
+}
+
+// supported formats:
+// gauntlet://run/<plugin-id>/<entrypoint-id>/<action-id>?<argument-id>=<value>
+// gauntlet://open/<plugin-id>/<entrypoint-id>?query=<search-text>&<argument-id>=<value>
+// plugin id is an url itself so it is expected to be percent-encoded
+pub fn parse_deep_link(url: &str) -> anyhow::Result<DeepLinkRequest> {
+    let url = Url::parse(url).context("Unable to parse deep link")?;
+
+    if url.scheme() != DEEP_LINK_SCHEME {
+        return Err(anyhow!("Unsupported deep link scheme: {}", url.scheme()));
+    }
+
+    let segments = url
+        .path_segments()
+        .map(|segments| {
+            segments
+                .filter(|segment| !segment.is_empty())
+                .map(|segment| {
+                    percent_decode_str(segment)
+                        .decode_utf8()
+                        .map(|segment| segment.to_string())
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()
+        .context("Deep link path is not valid utf-8")?
+        .unwrap_or_default();
+
+    let mut arguments: HashMap<String, String> = url.query_pairs().into_owned().collect();
+
+    match (url.host_str(), segments.as_slice()) {
+        (Some("run"), [plugin_id, entrypoint_id, action_id]) => {
+            Ok(DeepLinkRequest::RunAction {
+                plugin_id: PluginId::from_string(plugin_id),
+                entrypoint_id: EntrypointId::from_string(entrypoint_id),
+                action_id: action_id.to_string(),
+                arguments,
+            })
+        }
+        (Some("open"), [plugin_id, entrypoint_id]) => {
+            let search_text = arguments.remove(SEARCH_TEXT_PARAM);
+
+            Ok(DeepLinkRequest::OpenView {
+                plugin_id: PluginId::from_string(plugin_id),
+                entrypoint_id: EntrypointId::from_string(entrypoint_id),
+                arguments,
+                search_text,
+            })
+        }
+        (Some("run"), _) => {
+            Err(anyhow!(
+                "Expected deep link in `gauntlet://run/<plugin-id>/<entrypoint-id>/<action-id>` format"
+            ))
+        }
+        (Some("open"), _) => {
+            Err(anyhow!(
+                "Expected deep link in `gauntlet://open/<plugin-id>/<entrypoint-id>` format"
+            ))
+        }
+        (Some(kind), _) => Err(anyhow!("Unknown deep link kind: {}", kind)),
+        (None, _) => Err(anyhow!("Deep link kind is missing")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_action_with_percent_encoded_plugin_id() {
+        let request = parse_deep_link(
+            "gauntlet://run/https%3A%2F%2Fgithub.com%2Fproject-gauntlet%2Fplugin.git/main/copy?text=hello%20world",
+        )
+        .unwrap();
+
+        let DeepLinkRequest::RunAction {
+            plugin_id,
+            entrypoint_id,
+            action_id,
+            arguments,
+        } = request
+        else {
+            panic!("unexpected request: {:?}", request)
+        };
+
+        assert_eq!(
+            plugin_id,
+            PluginId::from_string("https://github.com/project-gauntlet/plugin.git")
+        );
+        assert_eq!(entrypoint_id, EntrypointId::from_string("main"));
+        assert_eq!(action_id, "copy");
+        assert_eq!(
+            arguments,
+            HashMap::from([("text".to_string(), "hello world".to_string())])
+        );
+    }
+
+    #[test]
+    fn open_view_with_query() {
+        let request =
+            parse_deep_link("gauntlet://open/file%3A%2F%2F%2Fhome%2Fuser%2Fplugin/search?query=abc&scope=all").unwrap();
+
+        let DeepLinkRequest::OpenView {
+            plugin_id,
+            entrypoint_id,
+            arguments,
+            search_text,
+        } = request
+        else {
+            panic!("unexpected request: {:?}", request)
+        };
+
+        assert_eq!(plugin_id, PluginId::from_string("file:///home/user/plugin"));
+        assert_eq!(entrypoint_id, EntrypointId::from_string("search"));
+        assert_eq!(search_text, Some("abc".to_string()));
+        assert_eq!(arguments, HashMap::from([("scope".to_string(), "all".to_string())]));
+    }
+
+    #[test]
+    fn open_view_without_query() {
+        let request = parse_deep_link("gauntlet://open/plugin/search").unwrap();
+
+        let DeepLinkRequest::OpenView {
+            arguments, search_text, ..
+        } = request
+        else {
+            panic!("unexpected request: {:?}", request)
+        };
+
+        assert_eq!(search_text, None);
+        assert!(arguments.is_empty());
+    }
+
+    #[test]
+    fn run_action_keeps_query_as_argument() {
+        let request = parse_deep_link("gauntlet://run/plugin/main/copy?query=abc").unwrap();
+
+        let DeepLinkRequest::RunAction { arguments, .. } = request else {
+            panic!("unexpected request: {:?}", request)
+        };
+
+        assert_eq!(arguments, HashMap::from([("query".to_string(), "abc".to_string())]));
+    }
+
+    #[test]
+    fn wrong_segment_count() {
+        assert!(parse_deep_link("gauntlet://run/plugin/main").is_err());
+        assert!(parse_deep_link("gauntlet://run/plugin/main/copy/extra").is_err());
+        assert!(parse_deep_link("gauntlet://open/plugin").is_err());
+        assert!(parse_deep_link("gauntlet://open/plugin/search/extra").is_err());
+        assert!(parse_deep_link("gauntlet://open").is_err());
+    }
+
+    #[test]
+    fn unencoded_plugin_id_is_rejected() {
+        assert!(parse_deep_link("gauntlet://open/https://github.com/project-gauntlet/plugin.git/search").is_err());
+    }
+
+    #[test]
+    fn unknown_kind_or_scheme() {
+        assert!(parse_deep_link("gauntlet://delete/plugin/main").is_err());
+        assert!(parse_deep_link("https://open/plugin/search").is_err());
+        assert!(parse_deep_link("not a url").is_err());
+    }
+}
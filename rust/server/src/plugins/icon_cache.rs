@@ -1,3 +1,4 @@
+use anyhow::Context;
 use gauntlet_common::dirs::Dirs;
 
 #[derive(Clone)]
@@ -10,13 +11,36 @@ impl IconCache {
         Self { dirs }
     }
 
-    // legacy
-    pub fn clear_all_icon_cache_dir(&self) -> anyhow::Result<()> {
+    pub fn icon_cache_dir(&self) -> anyhow::Result<String> {
         let cache_dir = self.dirs.icon_cache_dir();
         std::fs::create_dir_all(&cache_dir)?;
 
-        if cache_dir.exists() {
-            std::fs::remove_dir_all(&cache_dir)?;
+        let cache_dir = cache_dir
+            .to_str()
+            .context("non-uft8 paths are not supported")?
+            .to_string();
+
+        Ok(cache_dir)
+    }
+
+    // legacy, icons used to be written directly into cache dir and were cleared on every start,
+    // rendered application icons are kept in subdirectory and are reused between starts
+    pub fn clear_legacy_icon_cache_dir(&self) -> anyhow::Result<()> {
+        let cache_dir = self.dirs.icon_cache_dir();
+        std::fs::create_dir_all(&cache_dir)?;
+
+        for entry in std::fs::read_dir(&cache_dir)? {
+            let path = entry?.path();
+
+            if path.is_dir() {
+                if path.file_name().is_some_and(|name| name == "applications") {
+                    continue;
+                }
+
+                std::fs::remove_dir_all(&path)?;
+            } else {
+                std::fs::remove_file(&path)?;
+            }
         }
 
         Ok(())
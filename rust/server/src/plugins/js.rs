@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fs::File;
 use std::sync::Arc;
 use std::vec;
@@ -12,10 +13,14 @@ use gauntlet_common::model::PhysicalKey;
 use gauntlet_common::model::PluginId;
 use gauntlet_common::model::RootWidget;
 use gauntlet_common::model::SearchResultAccessory;
+use gauntlet_common::model::SearchResultEntrypointArgument;
+use gauntlet_common::model::SearchResultEntrypointArgumentKind;
+use gauntlet_common::model::SearchResultEntrypointArgumentOption;
 use gauntlet_common::model::SearchResultEntrypointType;
 use gauntlet_common::model::UiPropertyValue;
 use gauntlet_common::model::UiRenderLocation;
 use gauntlet_common::model::UiWidgetId;
+use gauntlet_common::model::UiWidgetPatchOp;
 use gauntlet_common::rpc::frontend_api::FrontendApi;
 use gauntlet_common::rpc::frontend_api::FrontendApiProxy;
 use gauntlet_common_plugin_runtime::JsMessageSide;
@@ -23,6 +28,7 @@ use gauntlet_common_plugin_runtime::api::BackendForPluginRuntimeApi;
 use gauntlet_common_plugin_runtime::api::handle_proxy_message_backend_for_plugin_runtime_api;
 use gauntlet_common_plugin_runtime::model::JsClipboardData;
 use gauntlet_common_plugin_runtime::model::JsEvent;
+use gauntlet_common_plugin_runtime::model::JsFocusedWindow;
 use gauntlet_common_plugin_runtime::model::JsGeneratedSearchItem;
 use gauntlet_common_plugin_runtime::model::JsGeneratedSearchItemAccessory;
 use gauntlet_common_plugin_runtime::model::JsGeneratedSearchItemActionType;
@@ -35,6 +41,7 @@ use gauntlet_common_plugin_runtime::model::JsPluginPermissionsExec;
 use gauntlet_common_plugin_runtime::model::JsPluginPermissionsFileSystem;
 use gauntlet_common_plugin_runtime::model::JsPluginPermissionsMainSearchBar;
 use gauntlet_common_plugin_runtime::model::JsPluginRuntimeMessage;
+use gauntlet_common_plugin_runtime::model::JsPluginSandbox;
 use gauntlet_common_plugin_runtime::model::JsPreferenceUserData;
 use gauntlet_common_plugin_runtime::model::JsUiPropertyValue;
 use gauntlet_common_plugin_runtime::model::JsUiRenderLocation;
@@ -49,19 +56,27 @@ use interprocess::local_socket::traits::tokio::Stream;
 use serde::Deserialize;
 use serde::Serialize;
 use tokio::sync::Mutex;
+use tokio::task::AbortHandle;
 
 use crate::model::IntermediateUiEvent;
 use crate::plugins::Settings;
 use crate::plugins::binary_data_gatherer::BinaryDataGatherer;
 use crate::plugins::clipboard::Clipboard;
 use crate::plugins::data_db_repository::DataDbRepository;
+use crate::plugins::data_db_repository::DbPluginArgument;
+use crate::plugins::data_db_repository::DbPluginArgumentKind;
 use crate::plugins::data_db_repository::DbPluginEntrypointType;
 use crate::plugins::data_db_repository::DbPluginPreference;
 use crate::plugins::data_db_repository::DbPluginPreferenceUserData;
+use crate::plugins::data_db_repository::DbPreferenceEnumValue;
 use crate::plugins::data_db_repository::DbReadPlugin;
 use crate::plugins::data_db_repository::DbReadPluginEntrypoint;
 use crate::plugins::data_db_repository::db_entrypoint_from_str;
 use crate::plugins::icon_cache::IconCache;
+use crate::plugins::launch_context::LaunchContext;
+use crate::plugins::paste::paste_text;
+use crate::plugins::plugin_commands::PluginCommandCalls;
+use crate::plugins::plugin_commands::validate_plugin_command_arguments;
 use crate::plugins::run_status::RunStatusGuard;
 use crate::search::SearchIndex;
 use crate::search::SearchIndexItem;
@@ -74,7 +89,7 @@ pub struct PluginRuntimeData {
     pub name: String,
     pub entrypoint_names: HashMap<EntrypointId, String>,
     pub code: JsPluginCode,
-    pub inline_view_entrypoint_id: Option<String>,
+    pub inline_view_entrypoint_ids: Vec<String>,
     pub permissions: PluginPermissions,
     pub command_receiver: tokio::sync::broadcast::Receiver<PluginCommand>,
     pub db_repository: DataDbRepository,
@@ -84,6 +99,8 @@ pub struct PluginRuntimeData {
     pub frontend_api: FrontendApiProxy,
     pub dirs: Dirs,
     pub clipboard: Clipboard,
+    pub launch_context: LaunchContext,
+    pub plugin_command_calls: PluginCommandCalls,
 }
 
 pub struct PluginPermissions {
@@ -94,11 +111,15 @@ pub struct PluginPermissions {
     pub system: Vec<String>,
     pub clipboard: Vec<PluginPermissionsClipboard>,
     pub main_search_bar: Vec<JsPluginPermissionsMainSearchBar>,
+    pub launch_context: Vec<PluginPermissionsLaunchContext>,
+    pub plugin_command: Vec<PluginPermissionsPluginCommand>,
 }
 
 #[derive(Clone, Debug)]
 pub struct PluginRuntimePermissions {
     pub clipboard: Vec<PluginPermissionsClipboard>,
+    pub launch_context: Vec<PluginPermissionsLaunchContext>,
+    pub plugin_command: Vec<PluginPermissionsPluginCommand>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -106,6 +127,19 @@ pub enum PluginPermissionsClipboard {
     Read,
     Write,
     Clear,
+    Paste,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PluginPermissionsLaunchContext {
+    SelectedText,
+    FocusedWindow,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PluginPermissionsPluginCommand {
+    pub plugin_id: PluginId,
+    pub command_id: String,
 }
 
 #[derive(Clone, Debug)]
@@ -118,6 +152,14 @@ pub enum PluginCommand {
 pub enum OnePluginCommandData {
     OpenView {
         entrypoint_id: EntrypointId,
+        arguments: HashMap<String, String>,
+        search_text: Option<String>,
+    },
+    OpenInlineView {
+        text: String,
+        generation: u64,
+        // only this inline view is rendered, when search is scoped to it
+        entrypoint_id: Option<EntrypointId>,
     },
     CloseView,
     PopView {
@@ -125,6 +167,7 @@ pub enum OnePluginCommandData {
     },
     RunCommand {
         entrypoint_id: String,
+        arguments: HashMap<String, String>,
     },
     RunGeneratedEntrypoint {
         entrypoint_id: String,
@@ -145,29 +188,44 @@ pub enum OnePluginCommandData {
         modifier_meta: bool,
     },
     RefreshSearchIndex,
+    CancelTask {
+        task_id: String,
+    },
+    CallExportedCommand {
+        call_id: String,
+        command_id: String,
+        arguments: String,
+    },
+    ReplaceView,
 }
 
 #[derive(Clone, Debug)]
 pub enum AllPluginCommandData {
-    OpenInlineView { text: String },
+    OpenInlineView { text: String, generation: u64 },
 }
 
 pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: RunStatusGuard) -> anyhow::Result<()> {
     let runtime_permissions = PluginRuntimePermissions {
         clipboard: data.permissions.clipboard,
+        launch_context: data.permissions.launch_context,
+        plugin_command: data.permissions.plugin_command,
     };
 
-    let api = BackendForPluginRuntimeApiImpl::new(
+    let config = data.settings.config();
+
+    let api = Arc::new(BackendForPluginRuntimeApiImpl::new(
         data.db_repository,
         data.search_index,
         data.clipboard,
+        data.launch_context,
+        data.plugin_command_calls,
         data.frontend_api,
         data.settings,
         data.uuid.clone(),
         data.id.clone(),
         data.name,
         runtime_permissions,
-    );
+    ));
 
     let mut command_receiver = data.command_receiver;
     let plugin_uuid = data.uuid.clone();
@@ -206,6 +264,8 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
     let uds_socket_file = data.dirs.plugin_uds_socket(&plugin_uuid);
     let plugin_cache_dir = data.dirs.plugin_cache(&plugin_uuid)?;
     let plugin_data_dir = data.dirs.plugin_data(&plugin_uuid)?;
+    let icon_cache_dir = data.icon_cache.icon_cache_dir()?;
+    let calculator_modules_dir = data.dirs.calculator_modules_dir();
 
     #[cfg(target_os = "windows")]
     let name_str = format!("project-gauntlet-{}", plugin_uuid);
@@ -269,6 +329,26 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
         .context("non-uft8 paths are not supported")?
         .to_string();
 
+    let calculator_modules_dir = calculator_modules_dir
+        .to_str()
+        .context("non-uft8 paths are not supported")?
+        .to_string();
+
+    // bundled plugin launches applications which would inherit restrictions of the runtime process
+    let sandbox = if cfg!(all(target_os = "linux", not(feature = "scenario_runner")))
+        && config.linux_plugin_sandbox
+        && !plugin_id_str.starts_with("bundled://")
+    {
+        Some(JsPluginSandbox {
+            isolate_network: data.permissions.network.is_empty(),
+        })
+    } else {
+        None
+    };
+
+    #[cfg(all(target_os = "linux", not(feature = "scenario_runner")))]
+    let isolate_network = sandbox.as_ref().is_some_and(|sandbox| sandbox.isolate_network);
+
     let permissions = JsPluginPermissions {
         environment: data.permissions.environment,
         network: data.permissions.network,
@@ -283,26 +363,44 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
         plugin_uuid: plugin_uuid.clone(),
         code: data.code,
         permissions,
-        inline_view_entrypoint_id: data.inline_view_entrypoint_id,
+        inline_view_entrypoint_ids: data.inline_view_entrypoint_ids,
         entrypoint_names: data.entrypoint_names,
         dev_plugin,
         home_dir,
         local_storage_dir,
         plugin_cache_dir,
         plugin_data_dir,
+        icon_cache_dir,
+        calculator_modules_dir,
         stdout_file,
         stderr_file,
+        sandbox,
     };
 
     #[cfg(not(feature = "scenario_runner"))]
     let mut runtime_process = {
         let current_exe = std::env::current_exe().context("unable to get current_exe")?;
 
-        std::process::Command::new(current_exe)
+        let mut command = std::process::Command::new(current_exe);
+
+        command
             .env(crate::PLUGIN_CONNECT_ENV, name_str)
-            .env(crate::PLUGIN_UUID_ENV, plugin_uuid.clone())
-            .spawn()
-            .context("start plugin runtime process")?
+            .env(crate::PLUGIN_UUID_ENV, plugin_uuid.clone());
+
+        // network namespace has to be created before runtime process starts any threads
+        #[cfg(target_os = "linux")]
+        let spawn_context = if isolate_network {
+            gauntlet_plugin_runtime::isolate_network(&mut command, config.linux_plugin_sandbox_network_fallback);
+
+            "start plugin runtime process in isolated network namespace, unprivileged user namespaces are probably disabled. Set `linux.plugin_sandbox_network_fallback = true` in config to start plugins without network permission anyway"
+        } else {
+            "start plugin runtime process"
+        };
+
+        #[cfg(not(target_os = "linux"))]
+        let spawn_context = "start plugin runtime process";
+
+        command.spawn().context(spawn_context)?
     };
 
     // use only for debugging and scenario_runner, only works if only one plugin is enabled
@@ -316,7 +414,7 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
 
     let (mut recver, mut sender) = conn.split();
 
-    send_message(JsMessageSide::Backend, &mut sender, init).await?;
+    send_message(JsMessageSide::Backend, &mut sender, None, init).await?;
 
     let sender = Arc::new(Mutex::new(sender));
 
@@ -328,12 +426,14 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
             tracing::info!("Requesting plugin runtime to stop...");
 
             let mut sender = sender.lock().await;
-            if let Err(err) = send_message(JsMessageSide::Backend, &mut sender, JsMessage::Stop).await {
+            if let Err(err) = send_message(JsMessageSide::Backend, &mut sender, None, JsMessage::Stop).await {
                 tracing::error!("Error when sending stop request to plugin runtime: {:?}", err);
             }
         }
     });
 
+    let mut in_flight_requests = HashMap::new();
+
     tokio::select! {
         _ = {
             let sender = sender.clone();
@@ -353,7 +453,7 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
              tokio::task::unconstrained(async {
                  let sender = sender.clone();
                  loop {
-                     match request_loop(&mut recver, &sender, &api).await {
+                     match request_loop(&mut recver, &sender, &api, &mut in_flight_requests).await {
                          Ok(stop) => {
                              if stop {
                                  tracing::debug!("Stopping request loop as requested by plugin runtime");
@@ -372,8 +472,15 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
         }
     }
 
+    // responses to these requests will never be read, so no point in finishing them
+    for (_, request) in in_flight_requests {
+        request.abort();
+    }
+
     drop((recver, sender));
 
+    api.fail_running_tasks().await;
+
     #[cfg(not(feature = "scenario_runner"))]
     {
         let code = runtime_process
@@ -409,15 +516,40 @@ async fn event_loop(
                 None
             } else {
                 match data {
-                    OnePluginCommandData::OpenView { entrypoint_id } => {
-                        Some(IntermediateUiEvent::OpenView { entrypoint_id })
+                    OnePluginCommandData::OpenView {
+                        entrypoint_id,
+                        arguments,
+                        search_text,
+                    } => {
+                        Some(IntermediateUiEvent::OpenView {
+                            entrypoint_id,
+                            arguments,
+                            search_text,
+                        })
+                    }
+                    OnePluginCommandData::OpenInlineView {
+                        text,
+                        generation,
+                        entrypoint_id,
+                    } => {
+                        Some(IntermediateUiEvent::OpenInlineView {
+                            text,
+                            generation,
+                            entrypoint_id,
+                        })
                     }
                     OnePluginCommandData::CloseView => Some(IntermediateUiEvent::CloseView),
                     OnePluginCommandData::PopView { entrypoint_id } => {
                         Some(IntermediateUiEvent::PopView { entrypoint_id })
                     }
-                    OnePluginCommandData::RunCommand { entrypoint_id } => {
-                        Some(IntermediateUiEvent::RunCommand { entrypoint_id })
+                    OnePluginCommandData::RunCommand {
+                        entrypoint_id,
+                        arguments,
+                    } => {
+                        Some(IntermediateUiEvent::RunCommand {
+                            entrypoint_id,
+                            arguments,
+                        })
                     }
                     OnePluginCommandData::RunGeneratedEntrypoint {
                         entrypoint_id,
@@ -459,12 +591,31 @@ async fn event_loop(
                         })
                     }
                     OnePluginCommandData::RefreshSearchIndex => Some(IntermediateUiEvent::RefreshSearchIndex),
+                    OnePluginCommandData::CancelTask { task_id } => Some(IntermediateUiEvent::CancelTask { task_id }),
+                    OnePluginCommandData::ReplaceView => Some(IntermediateUiEvent::ReplaceView),
+                    OnePluginCommandData::CallExportedCommand {
+                        call_id,
+                        command_id,
+                        arguments,
+                    } => {
+                        Some(IntermediateUiEvent::CallExportedCommand {
+                            call_id,
+                            command_id,
+                            arguments,
+                        })
+                    }
                 }
             }
         }
         PluginCommand::All { data } => {
             match data {
-                AllPluginCommandData::OpenInlineView { text } => Some(IntermediateUiEvent::OpenInlineView { text }),
+                AllPluginCommandData::OpenInlineView { text, generation } => {
+                    Some(IntermediateUiEvent::OpenInlineView {
+                        text,
+                        generation,
+                        entrypoint_id: None,
+                    })
+                }
             }
         }
     };
@@ -475,6 +626,7 @@ async fn event_loop(
         send_message(
             JsMessageSide::Backend,
             &mut send,
+            None,
             JsMessage::Event(from_intermediate_to_js_event(event)),
         )
         .await?;
@@ -485,37 +637,58 @@ async fn event_loop(
 
 async fn request_loop(
     recv: &mut RecvHalf,
-    send: &Mutex<SendHalf>,
-    api: &BackendForPluginRuntimeApiImpl,
+    send: &Arc<Mutex<SendHalf>>,
+    api: &Arc<BackendForPluginRuntimeApiImpl>,
+    in_flight_requests: &mut HashMap<u32, AbortHandle>,
 ) -> anyhow::Result<bool> {
     match recv_message::<JsPluginRuntimeMessage>(JsMessageSide::Backend, recv).await {
         Err(e) => Err(anyhow!("Unable to handle message: {:?}", e)),
-        Ok(message) => {
+        Ok((request_id, message)) => {
             tracing::trace!("Handling js runtime message: {:?}", message);
 
             match message {
                 JsPluginRuntimeMessage::Stopped => Ok(true),
                 JsPluginRuntimeMessage::Request(message) => {
-                    match handle_proxy_message_backend_for_plugin_runtime_api(message, api).await {
-                        Ok(response) => {
-                            let mut send = send.lock().await;
+                    let Some(request_id) = request_id else {
+                        return Err(anyhow!("Received request without request id: {:?}", message));
+                    };
 
-                            tracing::trace!("Sending request response: {:?}", response);
+                    in_flight_requests.retain(|_, request| !request.is_finished());
 
-                            send_message(JsMessageSide::Backend, &mut send, JsMessage::Response(Ok(response))).await?;
+                    // requests are handled concurrently, e.g. so that slow asset loading doesn't delay rendering,
+                    // response is matched to the request on the plugin runtime side using request id
+                    let request = tokio::spawn({
+                        let send = send.clone();
+                        let api = api.clone();
 
-                            Ok(false)
-                        }
-                        Err(err) => {
-                            let mut send = send.lock().await;
+                        async move {
+                            let response =
+                                match handle_proxy_message_backend_for_plugin_runtime_api(message, &api).await {
+                                    Ok(response) => {
+                                        tracing::trace!("Sending request response: {:?}", response);
 
-                            let err = format!("{:?}", err);
+                                        JsMessage::Response(Ok(response))
+                                    }
+                                    Err(err) => JsMessage::Response(Err(format!("{:?}", err))),
+                                };
 
-                            send_message(JsMessageSide::Backend, &mut send, JsMessage::Response(Err(err))).await?;
+                            let mut send = send.lock().await;
 
-                            Ok(false)
+                            if let Err(err) =
+                                send_message(JsMessageSide::Backend, &mut send, Some(request_id), response).await
+                            {
+                                tracing::error!(
+                                    "Unable to send response for request with id {}: {:?}",
+                                    request_id,
+                                    err
+                                );
+                            }
                         }
-                    }
+                    });
+
+                    in_flight_requests.insert(request_id, request.abort_handle());
+
+                    Ok(false)
                 }
             }
         }
@@ -524,9 +697,15 @@ async fn request_loop(
 
 fn from_intermediate_to_js_event(event: IntermediateUiEvent) -> JsEvent {
     match event {
-        IntermediateUiEvent::OpenView { entrypoint_id } => {
+        IntermediateUiEvent::OpenView {
+            entrypoint_id,
+            arguments,
+            search_text,
+        } => {
             JsEvent::OpenView {
                 entrypoint_id: entrypoint_id.to_string(),
+                arguments,
+                search_text,
             }
         }
         IntermediateUiEvent::CloseView => JsEvent::CloseView,
@@ -535,7 +714,15 @@ fn from_intermediate_to_js_event(event: IntermediateUiEvent) -> JsEvent {
                 entrypoint_id: entrypoint_id.to_string(),
             }
         }
-        IntermediateUiEvent::RunCommand { entrypoint_id } => JsEvent::RunCommand { entrypoint_id },
+        IntermediateUiEvent::RunCommand {
+            entrypoint_id,
+            arguments,
+        } => {
+            JsEvent::RunCommand {
+                entrypoint_id,
+                arguments,
+            }
+        }
         IntermediateUiEvent::RunGeneratedEntrypoint {
             entrypoint_id,
             action_index,
@@ -594,8 +781,31 @@ fn from_intermediate_to_js_event(event: IntermediateUiEvent) -> JsEvent {
                 modifier_meta,
             }
         }
-        IntermediateUiEvent::OpenInlineView { text } => JsEvent::OpenInlineView { text },
+        IntermediateUiEvent::OpenInlineView {
+            text,
+            generation,
+            entrypoint_id,
+        } => {
+            JsEvent::OpenInlineView {
+                text,
+                generation,
+                entrypoint_id: entrypoint_id.map(|entrypoint_id| entrypoint_id.to_string()),
+            }
+        }
         IntermediateUiEvent::RefreshSearchIndex => JsEvent::RefreshSearchIndex,
+        IntermediateUiEvent::CancelTask { task_id } => JsEvent::CancelTask { task_id },
+        IntermediateUiEvent::ReplaceView => JsEvent::ReplaceView,
+        IntermediateUiEvent::CallExportedCommand {
+            call_id,
+            command_id,
+            arguments,
+        } => {
+            JsEvent::CallExportedCommand {
+                call_id,
+                command_id,
+                arguments,
+            }
+        }
     }
 }
 
@@ -604,6 +814,8 @@ pub struct BackendForPluginRuntimeApiImpl {
     repository: DataDbRepository,
     search_index: SearchIndex,
     clipboard: Clipboard,
+    launch_context: LaunchContext,
+    plugin_command_calls: PluginCommandCalls,
     frontend_api: FrontendApiProxy,
     settings: Settings,
     #[allow(unused)]
@@ -611,6 +823,8 @@ pub struct BackendForPluginRuntimeApiImpl {
     plugin_id: PluginId,
     plugin_name: String,
     permissions: PluginRuntimePermissions,
+    // tasks started by this runtime which are not finished yet
+    running_tasks: Mutex<HashSet<String>>,
 }
 
 impl BackendForPluginRuntimeApiImpl {
@@ -618,6 +832,8 @@ impl BackendForPluginRuntimeApiImpl {
         repository: DataDbRepository,
         search_index: SearchIndex,
         clipboard: Clipboard,
+        launch_context: LaunchContext,
+        plugin_command_calls: PluginCommandCalls,
         frontend_api: FrontendApiProxy,
         settings: Settings,
         plugin_uuid: String,
@@ -629,12 +845,32 @@ impl BackendForPluginRuntimeApiImpl {
             repository,
             search_index,
             clipboard,
+            launch_context,
+            plugin_command_calls,
             frontend_api,
             settings,
             plugin_uuid,
             plugin_id,
             plugin_name,
             permissions,
+            running_tasks: Mutex::new(HashSet::new()),
+        }
+    }
+
+    // plugin runtime is the only one who can finish tasks,
+    // so once it stops, crashes or is reloaded, tasks started by it will never finish
+    async fn fail_running_tasks(&self) {
+        let running_tasks = std::mem::take(&mut *self.running_tasks.lock().await);
+
+        for task_id in running_tasks {
+            let result = self
+                .frontend_api
+                .finish_task(self.plugin_id.clone(), task_id, Some("Plugin was stopped".to_string()))
+                .await;
+
+            if let Err(err) = result {
+                tracing::warn!("Unable to mark task as failed: {:?}", err);
+            }
         }
     }
 }
@@ -741,6 +977,8 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
                     entrypoint_frecency,
                     entrypoint_actions,
                     entrypoint_accessories,
+                    entrypoint_arguments: vec![],
+                    entrypoint_keywords: item.entrypoint_keywords,
                     entrypoint_generator,
                 })
             })
@@ -781,6 +1019,12 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
 
                 let entrypoint_id = EntrypointId::from_string(entrypoint_id);
 
+                let entrypoint_arguments = entrypoint
+                    .arguments
+                    .into_iter()
+                    .map(|argument| search_result_argument_from_db(argument))
+                    .collect();
+
                 match &entrypoint_type {
                     DbPluginEntrypointType::Command => {
                         Ok(Some(SearchIndexItem {
@@ -792,6 +1036,8 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
                             entrypoint_frecency,
                             entrypoint_actions: vec![],
                             entrypoint_accessories: vec![],
+                            entrypoint_arguments,
+                            entrypoint_keywords: vec![],
                         }))
                     }
                     DbPluginEntrypointType::View => {
@@ -804,6 +1050,8 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
                             entrypoint_frecency,
                             entrypoint_actions: vec![],
                             entrypoint_accessories: vec![],
+                            entrypoint_arguments,
+                            entrypoint_keywords: vec![],
                         }))
                     }
                     DbPluginEntrypointType::EntrypointGenerator | DbPluginEntrypointType::InlineView => Ok(None),
@@ -959,6 +1207,130 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
         self.clipboard.clear().map_err(Into::into)
     }
 
+    async fn clipboard_paste_text(&self, data: String) -> RequestResult<()> {
+        let allow = self.permissions.clipboard.contains(&PluginPermissionsClipboard::Paste);
+
+        if !allow {
+            return Err(anyhow!("Plugin doesn't have 'paste' permission for clipboard").into());
+        }
+
+        tracing::debug!(
+            "Pasting text into previously focused window, plugin id: {:?}",
+            self.plugin_id
+        );
+
+        paste_text(&self.clipboard, &self.frontend_api, data)
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn launch_context_selected_text(&self) -> RequestResult<Option<String>> {
+        let allow = self
+            .permissions
+            .launch_context
+            .contains(&PluginPermissionsLaunchContext::SelectedText);
+
+        if !allow {
+            return Err(anyhow!("Plugin doesn't have 'selected_text' permission for launch context").into());
+        }
+
+        tracing::debug!("Reading selected text, plugin id: {:?}", self.plugin_id);
+
+        Ok(self.launch_context.selected_text())
+    }
+
+    async fn launch_context_focused_window(&self) -> RequestResult<Option<JsFocusedWindow>> {
+        let allow = self
+            .permissions
+            .launch_context
+            .contains(&PluginPermissionsLaunchContext::FocusedWindow);
+
+        if !allow {
+            return Err(anyhow!("Plugin doesn't have 'focused_window' permission for launch context").into());
+        }
+
+        tracing::debug!("Reading focused window, plugin id: {:?}", self.plugin_id);
+
+        let focused_window = self.launch_context.focused_window().map(|window| {
+            JsFocusedWindow {
+                app_id: window.app_id,
+                title: window.title,
+            }
+        });
+
+        Ok(focused_window)
+    }
+
+    async fn plugin_command_call(
+        &self,
+        plugin_id: PluginId,
+        command_id: String,
+        arguments: String,
+    ) -> RequestResult<String> {
+        let allow = self
+            .permissions
+            .plugin_command
+            .iter()
+            .any(|permission| permission.plugin_id == plugin_id && permission.command_id == command_id);
+
+        if !allow {
+            return Err(anyhow!(
+                "Plugin doesn't have permission to call command '{}' of plugin '{}'",
+                command_id,
+                plugin_id
+            )
+            .into());
+        }
+
+        let plugin = self
+            .repository
+            .get_plugin_by_id_option(&plugin_id.to_string())
+            .context("error when getting plugin by id")?
+            .ok_or_else(|| anyhow!("Plugin '{}' is not installed", plugin_id))?;
+
+        if !plugin.enabled {
+            return Err(anyhow!("Plugin '{}' is not enabled", plugin_id).into());
+        }
+
+        let command = plugin
+            .exported_commands
+            .iter()
+            .find(|command| command.id == command_id)
+            .ok_or_else(|| anyhow!("Plugin '{}' doesn't export command '{}'", plugin_id, command_id))?;
+
+        validate_plugin_command_arguments(&command.id, &command.arguments, &arguments)?;
+
+        tracing::debug!(
+            "Calling command '{}' of plugin {:?}, caller plugin id: {:?}",
+            command_id,
+            plugin_id,
+            self.plugin_id
+        );
+
+        let result = self
+            .plugin_command_calls
+            .call(self.plugin_id.clone(), plugin_id, command_id, arguments)
+            .await?;
+
+        Ok(result)
+    }
+
+    async fn plugin_command_result(
+        &self,
+        call_id: String,
+        result: Option<String>,
+        error: Option<String>,
+    ) -> RequestResult<()> {
+        let result = match error {
+            Some(error) => Err(error),
+            None => Ok(result.unwrap_or("null".to_string())),
+        };
+
+        self.plugin_command_calls.complete(&self.plugin_id, &call_id, result)?;
+
+        Ok(())
+    }
+
     async fn ui_update_loading_bar(&self, entrypoint_id: EntrypointId, show: bool) -> RequestResult<()> {
         self.frontend_api
             .update_loading_bar(self.plugin_id.clone(), entrypoint_id, show)
@@ -967,6 +1339,39 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
         Ok(())
     }
 
+    async fn ui_task_start(&self, task_id: String, title: String) -> RequestResult<()> {
+        self.running_tasks.lock().await.insert(task_id.clone());
+
+        self.frontend_api
+            .start_task(self.plugin_id.clone(), self.plugin_name.clone(), task_id, title)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn ui_task_update(
+        &self,
+        task_id: String,
+        progress: Option<f64>,
+        status: Option<String>,
+    ) -> RequestResult<()> {
+        self.frontend_api
+            .update_task(self.plugin_id.clone(), task_id, progress, status)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn ui_task_finish(&self, task_id: String, error: Option<String>) -> RequestResult<()> {
+        self.running_tasks.lock().await.remove(&task_id);
+
+        self.frontend_api
+            .finish_task(self.plugin_id.clone(), task_id, error)
+            .await?;
+
+        Ok(())
+    }
+
     async fn ui_show_hud(&self, display: String) -> RequestResult<()> {
         if cfg!(target_os = "linux") {
             if self.settings.config().linux_native_hud {
@@ -1022,6 +1427,7 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
         entrypoint_name: String,
         render_location: JsUiRenderLocation,
         top_level_view: bool,
+        inline_view_generation: Option<u64>,
         container: RootWidget,
     ) -> RequestResult<()> {
         let data = BinaryDataGatherer::run_gatherer(&self, &container).await?;
@@ -1039,6 +1445,7 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
                 entrypoint_name,
                 render_location,
                 top_level_view,
+                inline_view_generation,
                 container,
                 data,
             )
@@ -1047,6 +1454,37 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
         Ok(())
     }
 
+    async fn ui_render_patch(
+        &self,
+        entrypoint_id: EntrypointId,
+        entrypoint_name: String,
+        render_location: JsUiRenderLocation,
+        top_level_view: bool,
+        patch: Vec<UiWidgetPatchOp>,
+    ) -> RequestResult<()> {
+        let data = BinaryDataGatherer::run_gatherer_for_patch(&self, &patch).await?;
+
+        let render_location = match render_location {
+            JsUiRenderLocation::InlineView => UiRenderLocation::InlineView,
+            JsUiRenderLocation::View => UiRenderLocation::View,
+        };
+
+        self.frontend_api
+            .patch_view(
+                self.plugin_id.clone(),
+                self.plugin_name.clone(),
+                entrypoint_id,
+                entrypoint_name,
+                render_location,
+                top_level_view,
+                patch,
+                data,
+            )
+            .await?;
+
+        Ok(())
+    }
+
     async fn ui_show_plugin_error_view(
         &self,
         entrypoint_id: EntrypointId,
@@ -1083,6 +1521,28 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
     }
 }
 
+fn search_result_argument_from_db(argument: DbPluginArgument) -> SearchResultEntrypointArgument {
+    let kind = match argument.kind {
+        DbPluginArgumentKind::Text => SearchResultEntrypointArgumentKind::Text,
+        DbPluginArgumentKind::Password => SearchResultEntrypointArgumentKind::Password,
+        DbPluginArgumentKind::Dropdown { options } => {
+            let options = options
+                .into_iter()
+                .map(|DbPreferenceEnumValue { label, value }| SearchResultEntrypointArgumentOption { label, value })
+                .collect();
+
+            SearchResultEntrypointArgumentKind::Dropdown { options }
+        }
+    };
+
+    SearchResultEntrypointArgument {
+        id: argument.id,
+        kind,
+        placeholder: argument.placeholder,
+        required: argument.required,
+    }
+}
+
 fn preferences_to_js(
     preferences: HashMap<String, DbPluginPreference>,
     mut preferences_user_data: HashMap<String, DbPluginPreferenceUserData>,
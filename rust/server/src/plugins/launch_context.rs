@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use gauntlet_common::model::FocusedWindow;
+use gauntlet_common::model::PluginId;
+
+use crate::plugins::clipboard::Clipboard;
+use crate::plugins::js::PluginPermissionsLaunchContext;
+
+#[derive(Clone)]
+pub struct LaunchContext {
+    inner: Arc<RwLock<LaunchContextData>>,
+}
+
+#[derive(Debug, Default)]
+struct LaunchContextData {
+    selected_text: Option<String>,
+    focused_window: Option<FocusedWindow>,
+    permissions: HashMap<PluginId, Vec<PluginPermissionsLaunchContext>>,
+}
+
+impl LaunchContext {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(LaunchContextData::default())),
+        }
+    }
+
+    pub fn set_plugin_permissions(&self, plugin_id: PluginId, permissions: Vec<PluginPermissionsLaunchContext>) {
+        let mut inner = self.inner.write().expect("lock is poisoned");
+
+        if permissions.is_empty() {
+            inner.permissions.remove(&plugin_id);
+        } else {
+            inner.permissions.insert(plugin_id, permissions);
+        }
+    }
+
+    pub fn remove_plugin(&self, plugin_id: &PluginId) {
+        let mut inner = self.inner.write().expect("lock is poisoned");
+
+        inner.permissions.remove(plugin_id);
+    }
+
+    pub fn needs_capture(&self) -> bool {
+        let inner = self.inner.read().expect("lock is poisoned");
+
+        !inner.permissions.is_empty()
+    }
+
+    // captured once when window is shown, so that plugins see what was there before gauntlet took focus.
+    // only what at least one running plugin is allowed to read is captured
+    pub fn capture(&self, clipboard: &Clipboard, focused_window: Option<FocusedWindow>) {
+        let (selected_text_permitted, focused_window_permitted) = {
+            let inner = self.inner.read().expect("lock is poisoned");
+
+            let permitted = |permission: PluginPermissionsLaunchContext| {
+                inner
+                    .permissions
+                    .values()
+                    .any(|permissions| permissions.contains(&permission))
+            };
+
+            (
+                permitted(PluginPermissionsLaunchContext::SelectedText),
+                permitted(PluginPermissionsLaunchContext::FocusedWindow),
+            )
+        };
+
+        let selected_text = if selected_text_permitted {
+            clipboard
+                .read_selection_text()
+                .inspect_err(|err| tracing::warn!("unable to read selected text: {:?}", err))
+                .ok()
+                .flatten()
+                .filter(|text| !text.is_empty())
+        } else {
+            None
+        };
+
+        let focused_window = focused_window.filter(|_| focused_window_permitted);
+
+        let mut inner = self.inner.write().expect("lock is poisoned");
+
+        inner.selected_text = selected_text;
+        inner.focused_window = focused_window;
+    }
+
+    pub fn selected_text(&self) -> Option<String> {
+        let inner = self.inner.read().expect("lock is poisoned");
+
+        inner.selected_text.clone()
+    }
+
+    pub fn focused_window(&self) -> Option<FocusedWindow> {
+        let inner = self.inner.read().expect("lock is poisoned");
+
+        inner.focused_window.clone()
+    }
+}
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::ffi::OsStr;
 use std::fs::DirEntry;
 use std::io::ErrorKind;
@@ -22,8 +23,16 @@ use crate::plugins::data_db_repository::DataDbRepository;
 use crate::plugins::data_db_repository::DbCode;
 use crate::plugins::data_db_repository::DbPluginAction;
 use crate::plugins::data_db_repository::DbPluginActionShortcutKind;
+use crate::plugins::data_db_repository::DbPluginArgument;
+use crate::plugins::data_db_repository::DbPluginArgumentKind;
 use crate::plugins::data_db_repository::DbPluginClipboardPermissions;
+use crate::plugins::data_db_repository::DbPluginCommandPermission;
+use crate::plugins::data_db_repository::DbPluginDeepLinkPermissions;
 use crate::plugins::data_db_repository::DbPluginEntrypointType;
+use crate::plugins::data_db_repository::DbPluginExportedCommand;
+use crate::plugins::data_db_repository::DbPluginExportedCommandArgument;
+use crate::plugins::data_db_repository::DbPluginExportedCommandArgumentType;
+use crate::plugins::data_db_repository::DbPluginLaunchContextPermissions;
 use crate::plugins::data_db_repository::DbPluginMainSearchBarPermissions;
 use crate::plugins::data_db_repository::DbPluginPermissions;
 use crate::plugins::data_db_repository::DbPluginPermissionsExec;
@@ -85,6 +94,7 @@ impl PluginLoader {
                         permissions: plugin_data.permissions,
                         plugin_type: db_plugin_type_to_str(DbPluginType::Normal).to_owned(),
                         preferences: plugin_data.preferences,
+                        exported_commands: plugin_data.exported_commands,
                     })?;
 
                     anyhow::Ok(())
@@ -125,6 +135,7 @@ impl PluginLoader {
             permissions: plugin_data.permissions,
             plugin_type: db_plugin_type_to_str(DbPluginType::Normal).to_owned(),
             preferences: plugin_data.preferences,
+            exported_commands: plugin_data.exported_commands,
         })?;
 
         Ok(plugin_id)
@@ -150,6 +161,7 @@ impl PluginLoader {
             permissions: plugin_data.permissions,
             plugin_type: db_plugin_type_to_str(DbPluginType::Bundled).to_owned(),
             preferences: plugin_data.preferences,
+            exported_commands: plugin_data.exported_commands,
         })?;
 
         Ok(plugin_id)
@@ -404,6 +416,58 @@ impl PluginLoader {
                             }
                         })
                         .collect(),
+                    arguments: entrypoint
+                        .arguments
+                        .into_iter()
+                        .map(|argument| {
+                            match argument {
+                                PluginManifestArgument::Text {
+                                    id,
+                                    placeholder,
+                                    required,
+                                } => {
+                                    DbPluginArgument {
+                                        id,
+                                        kind: DbPluginArgumentKind::Text,
+                                        placeholder,
+                                        required,
+                                    }
+                                }
+                                PluginManifestArgument::Password {
+                                    id,
+                                    placeholder,
+                                    required,
+                                } => {
+                                    DbPluginArgument {
+                                        id,
+                                        kind: DbPluginArgumentKind::Password,
+                                        placeholder,
+                                        required,
+                                    }
+                                }
+                                PluginManifestArgument::Dropdown {
+                                    id,
+                                    placeholder,
+                                    required,
+                                    data,
+                                } => {
+                                    let options = data
+                                        .into_iter()
+                                        .map(|PluginManifestPreferenceEnumValue { label, value }| {
+                                            DbPreferenceEnumValue { label, value }
+                                        })
+                                        .collect();
+
+                                    DbPluginArgument {
+                                        id,
+                                        kind: DbPluginArgumentKind::Dropdown { options },
+                                        placeholder,
+                                        required,
+                                    }
+                                }
+                            }
+                        })
+                        .collect(),
                 }
             })
             .collect();
@@ -538,6 +602,7 @@ impl PluginLoader {
                     PluginManifestClipboardPermissions::Read => DbPluginClipboardPermissions::Read,
                     PluginManifestClipboardPermissions::Write => DbPluginClipboardPermissions::Write,
                     PluginManifestClipboardPermissions::Clear => DbPluginClipboardPermissions::Clear,
+                    PluginManifestClipboardPermissions::Paste => DbPluginClipboardPermissions::Paste,
                 }
             })
             .collect();
@@ -553,6 +618,82 @@ impl PluginLoader {
             })
             .collect();
 
+        let launch_context = plugin_manifest
+            .permissions
+            .launch_context
+            .into_iter()
+            .map(|permission| {
+                match permission {
+                    PluginManifestLaunchContextPermissions::SelectedText => {
+                        DbPluginLaunchContextPermissions::SelectedText
+                    }
+                    PluginManifestLaunchContextPermissions::FocusedWindow => {
+                        DbPluginLaunchContextPermissions::FocusedWindow
+                    }
+                }
+            })
+            .collect();
+
+        let plugin_command = plugin_manifest
+            .permissions
+            .plugin_command
+            .into_iter()
+            .map(|permission| {
+                DbPluginCommandPermission {
+                    plugin: permission.plugin,
+                    command: permission.command,
+                }
+            })
+            .collect();
+
+        let deep_link = plugin_manifest
+            .permissions
+            .deep_link
+            .into_iter()
+            .map(|permission| {
+                match permission {
+                    PluginManifestDeepLinkPermissions::Run => DbPluginDeepLinkPermissions::Run,
+                    PluginManifestDeepLinkPermissions::Open => DbPluginDeepLinkPermissions::Open,
+                }
+            })
+            .collect();
+
+        let exported_commands = plugin_manifest
+            .exported_command
+            .into_iter()
+            .map(|command| {
+                let arguments = command
+                    .arguments
+                    .into_iter()
+                    .map(|argument| {
+                        let argument_type = match argument.argument_type {
+                            PluginManifestExportedCommandArgumentType::String => {
+                                DbPluginExportedCommandArgumentType::String
+                            }
+                            PluginManifestExportedCommandArgumentType::Number => {
+                                DbPluginExportedCommandArgumentType::Number
+                            }
+                            PluginManifestExportedCommandArgumentType::Boolean => {
+                                DbPluginExportedCommandArgumentType::Boolean
+                            }
+                        };
+
+                        DbPluginExportedCommandArgument {
+                            id: argument.id,
+                            argument_type,
+                            required: argument.required,
+                        }
+                    })
+                    .collect();
+
+                DbPluginExportedCommand {
+                    id: command.id,
+                    description: command.description,
+                    arguments,
+                }
+            })
+            .collect();
+
         let permissions = DbPluginPermissions {
             environment: plugin_manifest.permissions.environment,
             network: plugin_manifest.permissions.network,
@@ -567,6 +708,9 @@ impl PluginLoader {
             system: plugin_manifest.permissions.system,
             clipboard,
             main_search_bar,
+            launch_context,
+            plugin_command,
+            deep_link,
         };
 
         Ok(PluginDownloadData {
@@ -578,6 +722,7 @@ impl PluginLoader {
             asset_data,
             permissions,
             preferences: plugin_preferences,
+            exported_commands,
         })
     }
 
@@ -669,6 +814,84 @@ impl PluginLoader {
             }
         }
 
+        for entrypoint in &plugin_manifest.entrypoint {
+            if entrypoint.arguments.is_empty() {
+                continue;
+            }
+
+            if !matches!(
+                entrypoint.entrypoint_type,
+                PluginManifestEntrypointTypes::Command | PluginManifestEntrypointTypes::View
+            ) {
+                return Err(anyhow!(
+                    "Entrypoint '{}' specifies arguments, but arguments are only supported for 'command' and 'view' entrypoints",
+                    entrypoint.id
+                ));
+            }
+
+            let mut argument_ids = HashSet::new();
+
+            for argument in &entrypoint.arguments {
+                if !argument_ids.insert(argument.id()) {
+                    return Err(anyhow!(
+                        "Entrypoint '{}' specifies argument with id '{}' more than once",
+                        entrypoint.id,
+                        argument.id()
+                    ));
+                }
+
+                if let PluginManifestArgument::Dropdown { id, data, .. } = argument {
+                    if data.is_empty() {
+                        return Err(anyhow!(
+                            "Dropdown argument '{}' of entrypoint '{}' doesn't specify any values",
+                            id,
+                            entrypoint.id
+                        ));
+                    }
+                }
+            }
+        }
+
+        let mut exported_command_ids = HashSet::new();
+
+        for command in &plugin_manifest.exported_command {
+            if !exported_command_ids.insert(&command.id) {
+                return Err(anyhow!("Command '{}' is exported more than once", command.id));
+            }
+
+            let is_command_entrypoint = plugin_manifest.entrypoint.iter().any(|entrypoint| {
+                entrypoint.id == command.id
+                    && matches!(entrypoint.entrypoint_type, PluginManifestEntrypointTypes::Command)
+            });
+
+            if !is_command_entrypoint {
+                return Err(anyhow!(
+                    "Exported command '{}' doesn't match any entrypoint of type 'command'",
+                    command.id
+                ));
+            }
+
+            let mut argument_ids = HashSet::new();
+
+            for argument in &command.arguments {
+                if !argument_ids.insert(&argument.id) {
+                    return Err(anyhow!(
+                        "Exported command '{}' specifies argument with id '{}' more than once",
+                        command.id,
+                        argument.id
+                    ));
+                }
+            }
+        }
+
+        for permission in &permissions.plugin_command {
+            if permission.plugin.is_empty() || permission.command.is_empty() {
+                return Err(anyhow!(
+                    "Plugin command permission requires both plugin and command to be specified"
+                ));
+            }
+        }
+
         Ok(())
     }
 
@@ -892,4 +1115,5 @@ struct PluginDownloadData {
     pub asset_data: Vec<DbWritePluginAssetData>,
     pub permissions: DbPluginPermissions,
     pub preferences: HashMap<String, DbPluginPreference>,
+    pub exported_commands: Vec<DbPluginExportedCommand>,
 }
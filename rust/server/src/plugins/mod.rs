@@ -1,9 +1,13 @@
 use std::collections::HashMap;
+use std::collections::HashSet;
 
 use anyhow::anyhow;
 use gauntlet_common::dirs::Dirs;
+use gauntlet_common::model::DeepLink;
+use gauntlet_common::model::DeepLinkAction;
 use gauntlet_common::model::DownloadStatus;
 use gauntlet_common::model::EntrypointId;
+use gauntlet_common::model::FocusedWindow;
 use gauntlet_common::model::KeyboardEventOrigin;
 use gauntlet_common::model::LocalSaveData;
 use gauntlet_common::model::PhysicalKey;
@@ -12,8 +16,13 @@ use gauntlet_common::model::PluginId;
 use gauntlet_common::model::PluginPreference;
 use gauntlet_common::model::PluginPreferenceUserData;
 use gauntlet_common::model::PreferenceEnumValue;
+use gauntlet_common::model::Quicklink;
+use gauntlet_common::model::SearchPinMove;
 use gauntlet_common::model::SearchResult;
+use gauntlet_common::model::SearchResultEntrypointArgument;
 use gauntlet_common::model::SearchResultEntrypointType;
+use gauntlet_common::model::SearchScope;
+use gauntlet_common::model::SearchScopeType;
 use gauntlet_common::model::SettingsEntrypoint;
 use gauntlet_common::model::SettingsEntrypointType;
 use gauntlet_common::model::SettingsGeneratedEntrypoint;
@@ -23,6 +32,7 @@ use gauntlet_common::model::UiPropertyValue;
 use gauntlet_common::model::UiSetupData;
 use gauntlet_common::model::UiWidgetId;
 use gauntlet_common::model::WindowPositionMode;
+use gauntlet_common::model::WindowSize;
 use gauntlet_common::rpc::frontend_api::FrontendApi;
 use gauntlet_common::rpc::frontend_api::FrontendApiProxy;
 use gauntlet_common::rpc::frontend_api::FrontendApiRequestData;
@@ -36,24 +46,38 @@ use global_hotkey::GlobalHotKeyManager;
 use include_dir::Dir;
 use include_dir::include_dir;
 use itertools::Itertools;
+use uuid::Uuid;
 
 use crate::plugins::clipboard::Clipboard;
 use crate::plugins::data_db_repository::DataDbRepository;
 use crate::plugins::data_db_repository::DbPluginClipboardPermissions;
+use crate::plugins::data_db_repository::DbPluginDeepLinkPermissions;
 use crate::plugins::data_db_repository::DbPluginEntrypointType;
+use crate::plugins::data_db_repository::DbPluginLaunchContextPermissions;
 use crate::plugins::data_db_repository::DbPluginMainSearchBarPermissions;
 use crate::plugins::data_db_repository::DbPluginPreference;
 use crate::plugins::data_db_repository::DbPluginPreferenceUserData;
 use crate::plugins::data_db_repository::db_entrypoint_from_str;
+use crate::plugins::deep_link::DeepLinkRequest;
+use crate::plugins::deep_link::parse_deep_link;
 use crate::plugins::icon_cache::IconCache;
 use crate::plugins::js::AllPluginCommandData;
 use crate::plugins::js::OnePluginCommandData;
 use crate::plugins::js::PluginCommand;
 use crate::plugins::js::PluginPermissions;
 use crate::plugins::js::PluginPermissionsClipboard;
+use crate::plugins::js::PluginPermissionsLaunchContext;
+use crate::plugins::js::PluginPermissionsPluginCommand;
 use crate::plugins::js::PluginRuntimeData;
 use crate::plugins::js::start_plugin_runtime;
+use crate::plugins::launch_context::LaunchContext;
 use crate::plugins::loader::PluginLoader;
+use crate::plugins::plugin_commands::PluginCommandCalls;
+use crate::plugins::quicklinks::QUICKLINKS_PLUGIN_ID;
+use crate::plugins::quicklinks::QUICKLINKS_PLUGIN_NAME;
+use crate::plugins::quicklinks::quicklink_from_db;
+use crate::plugins::quicklinks::quicklink_search_items;
+use crate::plugins::quicklinks::quicklink_to_db;
 use crate::plugins::run_status::RunStatusHolder;
 pub(crate) use crate::plugins::settings::Settings;
 use crate::plugins::settings::global_shortcut::GlobalShortcutAction;
@@ -67,12 +91,17 @@ use crate::search::SearchIndex;
 mod binary_data_gatherer;
 mod clipboard;
 mod data_db_repository;
+mod deep_link;
 mod download_status;
 pub(super) mod frecency;
 mod icon_cache;
 pub mod js;
+mod launch_context;
 mod loader;
+mod paste;
+mod plugin_commands;
 pub mod plugin_manifest;
+mod quicklinks;
 mod run_status;
 pub mod settings;
 pub mod theme;
@@ -92,6 +121,8 @@ pub struct ApplicationManager {
     frontend_api: FrontendApiProxy,
     dirs: Dirs,
     clipboard: Clipboard,
+    launch_context: LaunchContext,
+    plugin_command_calls: PluginCommandCalls,
     settings: Settings,
 }
 
@@ -107,6 +138,7 @@ impl ApplicationManager {
         let icon_cache = IconCache::new(dirs.clone());
         let run_status_holder = RunStatusHolder::new();
         let clipboard = Clipboard::new()?;
+        let launch_context = LaunchContext::new();
         let settings = Settings::new(
             dirs.clone(),
             db_repository.clone(),
@@ -116,8 +148,9 @@ impl ApplicationManager {
         let search_index = SearchIndex::create_index(frontend_api.clone(), settings.clone())?;
 
         let (command_broadcaster, _) = tokio::sync::broadcast::channel::<PluginCommand>(100);
+        let plugin_command_calls = PluginCommandCalls::new(command_broadcaster.clone());
 
-        icon_cache.clear_all_icon_cache_dir()?;
+        icon_cache.clear_legacy_icon_cache_dir()?;
 
         let application_manager = Self {
             search_index,
@@ -128,6 +161,8 @@ impl ApplicationManager {
             icon_cache,
             frontend_api,
             clipboard,
+            launch_context,
+            plugin_command_calls,
             settings,
             dirs,
         };
@@ -145,6 +180,9 @@ impl ApplicationManager {
         #[cfg(not(feature = "scenario_runner"))]
         application_manager.reload_all_plugins()?;
 
+        #[cfg(not(feature = "scenario_runner"))]
+        application_manager.reload_quicklinks(false)?;
+
         Ok(application_manager)
     }
 
@@ -156,7 +194,10 @@ impl ApplicationManager {
         let window_position_file = self.dirs.window_position();
         let theme = self.settings.effective_theme()?;
         let window_position_mode = self.settings.window_position_mode_setting()?;
+        let window_size = self.settings.window_size_setting()?;
         let close_on_unfocus = self.settings.config().close_on_unfocus;
+        let compact_mode = self.settings.compact_mode_setting()?;
+        let compact_mode_in_config = self.settings.config().compact_mode.is_some();
         let layer_shell = self.settings.config().layer_shell;
         let wayland_use_legacy_x11_api = self.settings.config().wayland_use_legacy_x11_api;
 
@@ -165,11 +206,22 @@ impl ApplicationManager {
             theme,
             close_on_unfocus,
             window_position_mode,
+            window_size,
+            compact_mode,
+            compact_mode_in_config,
             layer_shell,
             wayland_use_legacy_x11_api,
         })
     }
 
+    pub fn launch_context_needs_capture(&self) -> bool {
+        self.launch_context.needs_capture()
+    }
+
+    pub fn capture_launch_context(&self, focused_window: Option<FocusedWindow>) {
+        self.launch_context.capture(&self.clipboard, focused_window)
+    }
+
     pub fn download_plugin(&self, plugin_id: PluginId) {
         self.plugin_downloader.download_plugin(plugin_id)
     }
@@ -178,21 +230,96 @@ impl ApplicationManager {
         self.plugin_downloader.download_status()
     }
 
-    pub fn search(&self, text: &str, render_inline_view: bool) -> anyhow::Result<Vec<SearchResult>> {
+    pub fn search(&self, text: &str, inline_view_generation: Option<u64>) -> anyhow::Result<Vec<SearchResult>> {
         let result = self.search_index.search(&text);
 
-        if render_inline_view {
-            self.handle_inline_view(&text);
+        if let Some(generation) = inline_view_generation {
+            self.handle_inline_view(&text, generation);
         }
 
         result
     }
 
+    // validates deep link and checks whether plugin allowed it to be handled without confirmation,
+    // actual handling is done by the frontend because it may need to ask the user first
+    pub fn resolve_deep_link(&self, url: &str) -> anyhow::Result<DeepLink> {
+        let request = parse_deep_link(url)?;
+
+        let (plugin_id, entrypoint_id) = match &request {
+            DeepLinkRequest::RunAction {
+                plugin_id,
+                entrypoint_id,
+                ..
+            } => (plugin_id.clone(), entrypoint_id.clone()),
+            DeepLinkRequest::OpenView {
+                plugin_id,
+                entrypoint_id,
+                ..
+            } => (plugin_id.clone(), entrypoint_id.clone()),
+        };
+
+        let Some(plugin) = self.db_repository.get_plugin_by_id_option(&plugin_id.to_string())? else {
+            return Err(anyhow!("Unable to find plugin with id: {}", plugin_id));
+        };
+
+        if !plugin.enabled {
+            return Err(anyhow!("Plugin is disabled: {}", plugin_id));
+        }
+
+        let data = self.search_index.plugin_entrypoint_data();
+
+        let Some(entrypoint_data) = data
+            .get(&plugin_id)
+            .and_then(|data| data.entrypoints.get(&entrypoint_id))
+        else {
+            return Err(anyhow!("Unable to find entrypoint with id: {}", entrypoint_id));
+        };
+
+        let entrypoint_name = entrypoint_data.entrypoint_name.clone();
+
+        let (action, permission) = match request {
+            DeepLinkRequest::RunAction {
+                action_id, arguments, ..
+            } => {
+                validate_entrypoint_arguments(&entrypoint_data.arguments, &arguments)?;
+
+                (
+                    DeepLinkAction::RunAction { action_id, arguments },
+                    DbPluginDeepLinkPermissions::Run,
+                )
+            }
+            DeepLinkRequest::OpenView {
+                arguments, search_text, ..
+            } => {
+                if !matches!(entrypoint_data.entrypoint_type, SearchResultEntrypointType::View) {
+                    return Err(anyhow!("Only view entrypoints can be opened: {}", entrypoint_id));
+                }
+
+                validate_entrypoint_arguments(&entrypoint_data.arguments, &arguments)?;
+
+                (
+                    DeepLinkAction::OpenView { arguments, search_text },
+                    DbPluginDeepLinkPermissions::Open,
+                )
+            }
+        };
+
+        Ok(DeepLink {
+            plugin_id,
+            plugin_name: plugin.name,
+            entrypoint_id,
+            entrypoint_name,
+            action,
+            trusted: plugin.permissions.deep_link.contains(&permission),
+        })
+    }
+
     pub async fn run_action(
         &self,
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
         action_id: String,
+        arguments: HashMap<String, String>,
     ) -> anyhow::Result<()> {
         let data = self.search_index.plugin_entrypoint_data();
 
@@ -214,16 +341,21 @@ impl ApplicationManager {
             entrypoint_generator: _,
             entrypoint_type,
             actions,
+            arguments: entrypoint_arguments,
         } = entrypoint_data;
 
+        validate_entrypoint_arguments(entrypoint_arguments, &arguments)?;
+
         match action_id.as_str() {
             ":primary" => {
                 match entrypoint_type {
                     SearchResultEntrypointType::Command => {
-                        self.run_command(plugin_id, entrypoint_id);
+                        self.run_command(plugin_id, entrypoint_id, arguments);
                     }
                     SearchResultEntrypointType::View => {
-                        self.frontend_api.open_plugin_view(plugin_id, entrypoint_id).await?;
+                        self.frontend_api
+                            .open_plugin_view(plugin_id, entrypoint_id, arguments)
+                            .await?;
                     }
                     SearchResultEntrypointType::Generated => {
                         let Some(action_data) = actions.get(0) else {
@@ -592,6 +724,59 @@ impl ApplicationManager {
         self.settings.entrypoint_search_aliases()
     }
 
+    pub fn set_entrypoint_search_pinned(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        pinned: bool,
+    ) -> anyhow::Result<()> {
+        self.settings
+            .set_entrypoint_search_pinned(plugin_id, entrypoint_id, pinned)?;
+
+        self.search_index
+            .set_entrypoint_search_pins(self.settings.entrypoint_search_pins()?);
+
+        Ok(())
+    }
+
+    pub fn move_entrypoint_search_pin(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        direction: SearchPinMove,
+    ) -> anyhow::Result<()> {
+        self.settings
+            .move_entrypoint_search_pin(plugin_id, entrypoint_id, direction)?;
+
+        self.search_index
+            .set_entrypoint_search_pins(self.settings.entrypoint_search_pins()?);
+
+        Ok(())
+    }
+
+    pub fn get_entrypoint_search_pins(&self) -> anyhow::Result<Vec<(PluginId, EntrypointId)>> {
+        self.settings.entrypoint_search_pins()
+    }
+
+    pub fn set_entrypoint_search_hidden(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        hidden: bool,
+    ) -> anyhow::Result<()> {
+        self.settings
+            .set_entrypoint_search_hidden(plugin_id.clone(), entrypoint_id.clone(), hidden)?;
+
+        self.search_index
+            .set_entrypoint_search_hidden(plugin_id, entrypoint_id, hidden);
+
+        Ok(())
+    }
+
+    pub fn get_entrypoint_search_hidden(&self) -> anyhow::Result<HashSet<(PluginId, EntrypointId)>> {
+        self.settings.entrypoint_search_hidden()
+    }
+
     pub async fn set_theme(&self, theme: SettingsTheme) -> anyhow::Result<()> {
         self.settings.set_theme_setting(theme).await
     }
@@ -613,6 +798,22 @@ impl ApplicationManager {
         self.settings.window_position_mode_setting()
     }
 
+    pub async fn set_window_size(&self, size: WindowSize) -> anyhow::Result<()> {
+        self.settings.set_window_size_setting(size).await
+    }
+
+    pub fn get_window_size(&self) -> anyhow::Result<WindowSize> {
+        self.settings.window_size_setting()
+    }
+
+    pub async fn set_compact_mode(&self, enabled: bool) -> anyhow::Result<()> {
+        self.settings.set_compact_mode_setting(enabled).await
+    }
+
+    pub fn get_compact_mode(&self) -> anyhow::Result<bool> {
+        self.settings.compact_mode_setting()
+    }
+
     pub fn set_preference_value(
         &self,
         plugin_id: PluginId,
@@ -685,17 +886,87 @@ impl ApplicationManager {
         Ok(())
     }
 
-    pub fn handle_inline_view(&self, text: &str) {
+    pub fn handle_inline_view(&self, text: &str, generation: u64) {
         self.send_command(PluginCommand::All {
-            data: AllPluginCommandData::OpenInlineView { text: text.to_owned() },
+            data: AllPluginCommandData::OpenInlineView {
+                text: text.to_owned(),
+                generation,
+            },
         })
     }
 
-    pub fn run_command(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) {
+    pub fn search_scope(&self, alias: &str) -> anyhow::Result<Option<SearchScope>> {
+        if let Some(scope) = self.search_index.find_scope(alias) {
+            return Ok(Some(scope));
+        }
+
+        // inline views are not part of search index, so look up their aliases separately
+        let aliases = self.settings.entrypoint_search_aliases()?;
+
+        for ((plugin_id, entrypoint_id), entrypoint_alias) in aliases {
+            if !entrypoint_alias.eq_ignore_ascii_case(alias) {
+                continue;
+            }
+
+            let Some(plugin) = self.db_repository.get_plugin_by_id_option(&plugin_id.to_string())? else {
+                continue;
+            };
+
+            let Some(entrypoint) = self
+                .db_repository
+                .get_entrypoint_by_id_option(&plugin_id.to_string(), &entrypoint_id.to_string())?
+            else {
+                continue;
+            };
+
+            let inline_view = matches!(
+                db_entrypoint_from_str(&entrypoint.entrypoint_type),
+                DbPluginEntrypointType::InlineView
+            );
+
+            if plugin.enabled && entrypoint.enabled && inline_view {
+                return Ok(Some(SearchScope {
+                    plugin_id,
+                    entrypoint_id,
+                    entrypoint_name: entrypoint.name,
+                    scope_type: SearchScopeType::InlineView,
+                }));
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub fn render_scoped_inline_view(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        text: &str,
+        generation: u64,
+    ) {
+        self.send_command(PluginCommand::One {
+            id: plugin_id,
+            data: OnePluginCommandData::OpenInlineView {
+                text: text.to_owned(),
+                generation,
+                entrypoint_id: Some(entrypoint_id),
+            },
+        })
+    }
+
+    pub fn run_command(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, arguments: HashMap<String, String>) {
+        if plugin_id.to_string() == QUICKLINKS_PLUGIN_ID {
+            if let Err(err) = self.run_quicklink(entrypoint_id, arguments) {
+                tracing::error!("error occurred when running quicklink: {:?}", err);
+            }
+            return;
+        }
+
         self.send_command(PluginCommand::One {
             id: plugin_id.clone(),
             data: OnePluginCommandData::RunCommand {
                 entrypoint_id: entrypoint_id.to_string(),
+                arguments,
             },
         });
 
@@ -723,11 +994,15 @@ impl ApplicationManager {
         &self,
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
+        arguments: HashMap<String, String>,
+        search_text: Option<String>,
     ) -> anyhow::Result<HashMap<String, PhysicalShortcut>> {
         self.send_command(PluginCommand::One {
             id: plugin_id.clone(),
             data: OnePluginCommandData::OpenView {
                 entrypoint_id: entrypoint_id.clone(),
+                arguments,
+                search_text,
             },
         });
 
@@ -794,6 +1069,21 @@ impl ApplicationManager {
         })
     }
 
+    pub fn cancel_task(&self, plugin_id: PluginId, task_id: String) {
+        self.send_command(PluginCommand::One {
+            id: plugin_id,
+            data: OnePluginCommandData::CancelTask { task_id },
+        })
+    }
+
+    // client was unable to apply the patch, so the whole view needs to be sent again
+    pub fn request_view_replace(&self, plugin_id: PluginId) {
+        self.send_command(PluginCommand::One {
+            id: plugin_id,
+            data: OnePluginCommandData::ReplaceView,
+        })
+    }
+
     pub fn request_search_index_refresh(&self, plugin_id: PluginId) {
         self.send_command(PluginCommand::One {
             id: plugin_id,
@@ -808,6 +1098,78 @@ impl ApplicationManager {
         }
     }
 
+    pub fn list_quicklinks(&self) -> anyhow::Result<Vec<Quicklink>> {
+        let quicklinks = self
+            .db_repository
+            .list_quicklinks()?
+            .into_iter()
+            .map(|quicklink| quicklink_from_db(quicklink))
+            .collect();
+
+        Ok(quicklinks)
+    }
+
+    pub fn save_quicklink(&self, mut quicklink: Quicklink) -> anyhow::Result<String> {
+        if quicklink.name.trim().is_empty() {
+            return Err(anyhow!("Quicklink name cannot be empty"));
+        }
+
+        if quicklink.template.trim().is_empty() {
+            return Err(anyhow!("Quicklink template cannot be empty"));
+        }
+
+        if quicklink.id.is_empty() {
+            quicklink.id = Uuid::new_v4().to_string();
+        }
+
+        let id = quicklink.id.clone();
+
+        self.db_repository.save_quicklink(quicklink_to_db(quicklink))?;
+
+        self.reload_quicklinks(true)?;
+
+        Ok(id)
+    }
+
+    pub fn remove_quicklink(&self, quicklink_id: String) -> anyhow::Result<()> {
+        if self.db_repository.get_quicklink_by_id_option(&quicklink_id)?.is_none() {
+            return Err(anyhow!("Unable to find quicklink with id: {}", quicklink_id));
+        }
+
+        self.db_repository.remove_quicklink(&quicklink_id)?;
+
+        self.reload_quicklinks(true)?;
+
+        Ok(())
+    }
+
+    fn reload_quicklinks(&self, refresh_search_list: bool) -> anyhow::Result<()> {
+        let search_items = quicklink_search_items(self.list_quicklinks()?);
+
+        self.search_index.save_for_plugin(
+            PluginId::from_string(QUICKLINKS_PLUGIN_ID),
+            QUICKLINKS_PLUGIN_NAME.to_string(),
+            search_items,
+            refresh_search_list,
+        )
+    }
+
+    fn run_quicklink(&self, entrypoint_id: EntrypointId, arguments: HashMap<String, String>) -> anyhow::Result<()> {
+        let Some(quicklink) = self
+            .db_repository
+            .get_quicklink_by_id_option(&entrypoint_id.to_string())?
+        else {
+            return Err(anyhow!("Unable to find quicklink with id: {}", entrypoint_id));
+        };
+
+        // quicklinks are not plugin entrypoints so frecency is not tracked for them
+        if let Some(href) = quicklinks::run_quicklink(quicklink_from_db(quicklink), arguments, &self.clipboard)? {
+            self.handle_open(href);
+        }
+
+        Ok(())
+    }
+
     pub async fn open_window(&self) {
         self.frontend_api
             .toggle_window()
@@ -862,9 +1224,9 @@ impl ApplicationManager {
             .map(|entrypoint| (EntrypointId::from_string(entrypoint.id), entrypoint.name))
             .collect::<HashMap<EntrypointId, String>>();
 
-        let inline_view_entrypoint_id = self
+        let inline_view_entrypoint_ids = self
             .db_repository
-            .get_inline_view_entrypoint_id_for_plugin(&plugin_id_str)?;
+            .get_inline_view_entrypoint_ids_for_plugin(&plugin_id_str)?;
 
         let receiver = self.command_broadcaster.subscribe();
 
@@ -877,6 +1239,7 @@ impl ApplicationManager {
                     DbPluginClipboardPermissions::Read => PluginPermissionsClipboard::Read,
                     DbPluginClipboardPermissions::Write => PluginPermissionsClipboard::Write,
                     DbPluginClipboardPermissions::Clear => PluginPermissionsClipboard::Clear,
+                    DbPluginClipboardPermissions::Paste => PluginPermissionsClipboard::Paste,
                 }
             })
             .collect();
@@ -892,13 +1255,40 @@ impl ApplicationManager {
             })
             .collect();
 
+        let launch_context_permissions = plugin
+            .permissions
+            .launch_context
+            .into_iter()
+            .map(|permission| {
+                match permission {
+                    DbPluginLaunchContextPermissions::SelectedText => PluginPermissionsLaunchContext::SelectedText,
+                    DbPluginLaunchContextPermissions::FocusedWindow => PluginPermissionsLaunchContext::FocusedWindow,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        self.launch_context
+            .set_plugin_permissions(plugin_id.clone(), launch_context_permissions.clone());
+
+        let plugin_command_permissions = plugin
+            .permissions
+            .plugin_command
+            .into_iter()
+            .map(|permission| {
+                PluginPermissionsPluginCommand {
+                    plugin_id: PluginId::from_string(permission.plugin),
+                    command_id: permission.command,
+                }
+            })
+            .collect();
+
         let data = PluginRuntimeData {
             id: plugin_id,
             uuid: plugin.uuid,
             name: plugin.name,
             entrypoint_names,
             code: JsPluginCode { js: plugin.code.js },
-            inline_view_entrypoint_id,
+            inline_view_entrypoint_ids,
             permissions: PluginPermissions {
                 environment: plugin.permissions.environment,
                 network: plugin.permissions.network,
@@ -913,6 +1303,8 @@ impl ApplicationManager {
                 system: plugin.permissions.system,
                 clipboard: clipboard_permissions,
                 main_search_bar: main_search_bar_permissions,
+                launch_context: launch_context_permissions,
+                plugin_command: plugin_command_permissions,
             },
             command_receiver: receiver,
             db_repository: self.db_repository.clone(),
@@ -922,14 +1314,16 @@ impl ApplicationManager {
             settings: self.settings.clone(),
             dirs: self.dirs.clone(),
             clipboard: self.clipboard.clone(),
+            launch_context: self.launch_context.clone(),
+            plugin_command_calls: self.plugin_command_calls.clone(),
         };
 
         let run_status_guard = self.run_status_holder.start_block(data.id.clone());
 
         tokio::spawn(async {
-            start_plugin_runtime(data, run_status_guard)
-                .await
-                .expect("failed to start plugin runtime")
+            if let Err(err) = start_plugin_runtime(data, run_status_guard).await {
+                tracing::error!("failed to start plugin runtime: {:?}", err);
+            }
         });
 
         Ok(())
@@ -938,6 +1332,8 @@ impl ApplicationManager {
     fn stop_plugin(&self, plugin_id: PluginId) {
         tracing::info!(target = "plugin", "Stopping plugin with id: {:?}", plugin_id);
 
+        self.launch_context.remove_plugin(&plugin_id);
+
         self.run_status_holder.stop_plugin(&plugin_id)
     }
 
@@ -962,18 +1358,76 @@ impl ApplicationManager {
         self.request_search_index_refresh(plugin_id);
     }
 
-    pub fn inline_view_shortcuts(&self) -> anyhow::Result<HashMap<PluginId, HashMap<String, PhysicalShortcut>>> {
+    pub fn inline_view_shortcuts(
+        &self,
+    ) -> anyhow::Result<HashMap<(PluginId, EntrypointId), HashMap<String, PhysicalShortcut>>> {
         let result: HashMap<_, _> = self
             .db_repository
             .inline_view_shortcuts()?
             .into_iter()
-            .map(|(plugin_id, shortcuts)| (PluginId::from_string(plugin_id), shortcuts))
+            .map(|((plugin_id, entrypoint_id), shortcuts)| {
+                (
+                    (
+                        PluginId::from_string(plugin_id),
+                        EntrypointId::from_string(entrypoint_id),
+                    ),
+                    shortcuts,
+                )
+            })
+            .collect();
+
+        Ok(result)
+    }
+
+    pub fn mark_inline_view_usage(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) {
+        self.mark_entrypoint_frecency(plugin_id, entrypoint_id)
+    }
+
+    pub fn inline_view_usage(&self) -> anyhow::Result<HashMap<(PluginId, EntrypointId), f64>> {
+        let result: HashMap<_, _> = self
+            .db_repository
+            .inline_view_frecency()?
+            .into_iter()
+            .map(|((plugin_id, entrypoint_id), frecency)| {
+                (
+                    (
+                        PluginId::from_string(plugin_id),
+                        EntrypointId::from_string(entrypoint_id),
+                    ),
+                    frecency,
+                )
+            })
             .collect();
 
         Ok(result)
     }
 }
 
+fn validate_entrypoint_arguments(
+    entrypoint_arguments: &[SearchResultEntrypointArgument],
+    arguments: &HashMap<String, String>,
+) -> anyhow::Result<()> {
+    for argument_id in arguments.keys() {
+        if !entrypoint_arguments.iter().any(|argument| &argument.id == argument_id) {
+            return Err(anyhow!("Entrypoint doesn't have argument with id: {}", argument_id));
+        }
+    }
+
+    let missing_argument = entrypoint_arguments.iter().find(|argument| {
+        argument.required
+            && arguments
+                .get(&argument.id)
+                .map(|value| value.is_empty())
+                .unwrap_or(true)
+    });
+
+    if let Some(argument) = missing_argument {
+        return Err(anyhow!("Required argument is missing: {}", argument.id));
+    }
+
+    Ok(())
+}
+
 fn plugin_preference_from_db(id: &str, value: DbPluginPreference) -> PluginPreference {
     match value {
         DbPluginPreference::Number {
@@ -0,0 +1,304 @@
+use std::time::Duration;
+
+use anyhow::Context;
+use gauntlet_common::rpc::frontend_api::FrontendApi;
+use gauntlet_common::rpc::frontend_api::FrontendApiProxy;
+use gauntlet_common_plugin_runtime::model::JsClipboardData;
+
+use crate::plugins::clipboard::Clipboard;
+
+#[cfg(target_os = "linux")]
+mod wayland;
+#[cfg(target_os = "linux")]
+mod x11;
+
+// time for previously focused window to get focus back after gauntlet window is closed
+const FOCUS_RESTORE_DELAY: Duration = Duration::from_millis(150);
+
+// pasting is asynchronous, target application requests clipboard contents some time after key press
+const CLIPBOARD_RESTORE_DELAY: Duration = Duration::from_millis(500);
+
+pub async fn paste_text(clipboard: &Clipboard, frontend_api: &FrontendApiProxy, text: String) -> anyhow::Result<()> {
+    let hide_window = async { frontend_api.hide_window().await.map_err(anyhow::Error::from) };
+
+    paste_text_with(clipboard, hide_window, simulate_paste_shortcut, text).await
+}
+
+// clipboard operations needed for paste, separate from Clipboard to be able to test paste without display server
+trait PasteClipboard {
+    fn read(&self) -> anyhow::Result<JsClipboardData>;
+
+    fn write(&self, data: JsClipboardData) -> anyhow::Result<()>;
+
+    fn write_text(&self, data: String) -> anyhow::Result<()>;
+
+    fn clear(&self) -> anyhow::Result<()>;
+}
+
+impl PasteClipboard for Clipboard {
+    fn read(&self) -> anyhow::Result<JsClipboardData> {
+        Clipboard::read(self)
+    }
+
+    fn write(&self, data: JsClipboardData) -> anyhow::Result<()> {
+        Clipboard::write(self, data)
+    }
+
+    fn write_text(&self, data: String) -> anyhow::Result<()> {
+        Clipboard::write_text(self, data)
+    }
+
+    fn clear(&self) -> anyhow::Result<()> {
+        Clipboard::clear(self)
+    }
+}
+
+async fn paste_text_with(
+    clipboard: &impl PasteClipboard,
+    hide_window: impl Future<Output = anyhow::Result<()>>,
+    simulate_paste_shortcut: fn() -> anyhow::Result<()>,
+    text: String,
+) -> anyhow::Result<()> {
+    // clipboard can only be read as text or image, so any other formats
+    // (files, rich text, application specific data) are lost after paste
+    let original = clipboard.read()?;
+
+    clipboard.write_text(text)?;
+
+    let pasted = paste(hide_window, simulate_paste_shortcut).await;
+
+    // original clipboard content is restored even if paste failed, so that it is not lost
+    if original.text_data.is_none() && original.png_data.is_none() {
+        clipboard.clear()?;
+    } else {
+        clipboard.write(original)?;
+    }
+
+    pasted
+}
+
+async fn paste(
+    hide_window: impl Future<Output = anyhow::Result<()>>,
+    simulate_paste_shortcut: fn() -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    hide_window.await?;
+
+    tokio::time::sleep(FOCUS_RESTORE_DELAY).await;
+
+    tokio::task::spawn_blocking(simulate_paste_shortcut)
+        .await?
+        .context("Unable to paste into previously focused window")?;
+
+    tokio::time::sleep(CLIPBOARD_RESTORE_DELAY).await;
+
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn simulate_paste_shortcut() -> anyhow::Result<()> {
+    if std::env::var("WAYLAND_DISPLAY").is_ok() {
+        wayland::simulate_paste_shortcut()
+    } else {
+        x11::simulate_paste_shortcut()
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn simulate_paste_shortcut() -> anyhow::Result<()> {
+    use anyhow::anyhow;
+    use core_graphics::event::CGEvent;
+    use core_graphics::event::CGEventFlags;
+    use core_graphics::event::CGEventTapLocation;
+    use core_graphics::event_source::CGEventSource;
+    use core_graphics::event_source::CGEventSourceStateID;
+
+    // kVK_ANSI_V
+    const KEY_V: u16 = 9;
+
+    // requires accessibility permission, without it events are silently dropped
+    let source = CGEventSource::new(CGEventSourceStateID::HIDSystemState)
+        .map_err(|_| anyhow!("unable to create keyboard event source"))?;
+
+    for key_down in [true, false] {
+        let event = CGEvent::new_keyboard_event(source.clone(), KEY_V, key_down)
+            .map_err(|_| anyhow!("unable to create keyboard event"))?;
+
+        event.set_flags(CGEventFlags::CGEventFlagCommand);
+        event.post(CGEventTapLocation::HID);
+    }
+
+    Ok(())
+}
+
+#[cfg(target_os = "windows")]
+fn simulate_paste_shortcut() -> anyhow::Result<()> {
+    use anyhow::anyhow;
+    use windows::Win32::UI::Input::KeyboardAndMouse::INPUT;
+    use windows::Win32::UI::Input::KeyboardAndMouse::INPUT_0;
+    use windows::Win32::UI::Input::KeyboardAndMouse::INPUT_KEYBOARD;
+    use windows::Win32::UI::Input::KeyboardAndMouse::KEYBD_EVENT_FLAGS;
+    use windows::Win32::UI::Input::KeyboardAndMouse::KEYBDINPUT;
+    use windows::Win32::UI::Input::KeyboardAndMouse::KEYEVENTF_KEYUP;
+    use windows::Win32::UI::Input::KeyboardAndMouse::SendInput;
+    use windows::Win32::UI::Input::KeyboardAndMouse::VIRTUAL_KEY;
+    use windows::Win32::UI::Input::KeyboardAndMouse::VK_CONTROL;
+    use windows::Win32::UI::Input::KeyboardAndMouse::VK_V;
+
+    let input = |key: VIRTUAL_KEY, flags: KEYBD_EVENT_FLAGS| {
+        INPUT {
+            r#type: INPUT_KEYBOARD,
+            Anonymous: INPUT_0 {
+                ki: KEYBDINPUT {
+                    wVk: key,
+                    wScan: 0,
+                    dwFlags: flags,
+                    time: 0,
+                    dwExtraInfo: 0,
+                },
+            },
+        }
+    };
+
+    let inputs = [
+        input(VK_CONTROL, KEYBD_EVENT_FLAGS(0)),
+        input(VK_V, KEYBD_EVENT_FLAGS(0)),
+        input(VK_V, KEYEVENTF_KEYUP),
+        input(VK_CONTROL, KEYEVENTF_KEYUP),
+    ];
+
+    let sent = unsafe { SendInput(&inputs, size_of::<INPUT>() as i32) };
+
+    if sent as usize != inputs.len() {
+        return Err(anyhow!("input was blocked by another thread"));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use anyhow::anyhow;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct TestClipboard {
+        text_data: Mutex<Option<String>>,
+        png_data: Mutex<Option<Vec<u8>>>,
+        // text which was in clipboard at the moment paste shortcut was pressed
+        pasted: Mutex<Vec<Option<String>>>,
+    }
+
+    impl PasteClipboard for TestClipboard {
+        fn read(&self) -> anyhow::Result<JsClipboardData> {
+            Ok(JsClipboardData {
+                text_data: self.text_data.lock().unwrap().clone(),
+                png_data: self.png_data.lock().unwrap().clone(),
+            })
+        }
+
+        fn write(&self, data: JsClipboardData) -> anyhow::Result<()> {
+            *self.text_data.lock().unwrap() = data.text_data;
+            *self.png_data.lock().unwrap() = data.png_data;
+            Ok(())
+        }
+
+        fn write_text(&self, data: String) -> anyhow::Result<()> {
+            *self.text_data.lock().unwrap() = Some(data);
+            *self.png_data.lock().unwrap() = None;
+            Ok(())
+        }
+
+        fn clear(&self) -> anyhow::Result<()> {
+            *self.text_data.lock().unwrap() = None;
+            *self.png_data.lock().unwrap() = None;
+            Ok(())
+        }
+    }
+
+    fn successful_key_press() -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn failed_key_press() -> anyhow::Result<()> {
+        Err(anyhow!("no input method available"))
+    }
+
+    async fn window_hidden() -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn original_text_is_restored_after_paste() {
+        let clipboard = TestClipboard::default();
+        clipboard.write_text("original".to_string()).unwrap();
+
+        let hide_window = async {
+            // text to paste is already in clipboard when window is hidden
+            clipboard
+                .pasted
+                .lock()
+                .unwrap()
+                .push(clipboard.text_data.lock().unwrap().clone());
+            Ok(())
+        };
+
+        paste_text_with(&clipboard, hide_window, successful_key_press, "pasted".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(*clipboard.pasted.lock().unwrap(), vec![Some("pasted".to_string())]);
+        assert_eq!(*clipboard.text_data.lock().unwrap(), Some("original".to_string()));
+    }
+
+    #[tokio::test]
+    async fn original_image_is_restored_after_paste() {
+        let clipboard = TestClipboard::default();
+        *clipboard.png_data.lock().unwrap() = Some(vec![1, 2, 3]);
+
+        paste_text_with(&clipboard, window_hidden(), successful_key_press, "pasted".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(*clipboard.text_data.lock().unwrap(), None);
+        assert_eq!(*clipboard.png_data.lock().unwrap(), Some(vec![1, 2, 3]));
+    }
+
+    #[tokio::test]
+    async fn empty_clipboard_is_cleared_after_paste() {
+        let clipboard = TestClipboard::default();
+
+        paste_text_with(&clipboard, window_hidden(), successful_key_press, "pasted".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(*clipboard.text_data.lock().unwrap(), None);
+        assert_eq!(*clipboard.png_data.lock().unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn original_text_is_restored_when_key_press_fails() {
+        let clipboard = TestClipboard::default();
+        clipboard.write_text("original".to_string()).unwrap();
+
+        let result = paste_text_with(&clipboard, window_hidden(), failed_key_press, "pasted".to_string()).await;
+
+        assert!(result.is_err());
+        assert_eq!(*clipboard.text_data.lock().unwrap(), Some("original".to_string()));
+    }
+
+    #[tokio::test]
+    async fn original_text_is_restored_when_window_cannot_be_hidden() {
+        let clipboard = TestClipboard::default();
+        clipboard.write_text("original".to_string()).unwrap();
+
+        let hide_window = async { Err(anyhow!("frontend is not available")) };
+
+        let result = paste_text_with(&clipboard, hide_window, successful_key_press, "pasted".to_string()).await;
+
+        assert!(result.is_err());
+        assert_eq!(*clipboard.text_data.lock().unwrap(), Some("original".to_string()));
+    }
+}
@@ -0,0 +1,98 @@
+use std::io::Write;
+use std::os::fd::AsFd;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use anyhow::Context;
+use wayland_client::Connection;
+use wayland_client::Dispatch;
+use wayland_client::QueueHandle;
+use wayland_client::delegate_noop;
+use wayland_client::globals::GlobalListContents;
+use wayland_client::globals::registry_queue_init;
+use wayland_client::protocol::wl_keyboard;
+use wayland_client::protocol::wl_registry;
+use wayland_client::protocol::wl_seat::WlSeat;
+use wayland_protocols_misc::zwp_virtual_keyboard_v1::client::zwp_virtual_keyboard_manager_v1::ZwpVirtualKeyboardManagerV1;
+use wayland_protocols_misc::zwp_virtual_keyboard_v1::client::zwp_virtual_keyboard_v1::ZwpVirtualKeyboardV1;
+
+// virtual keyboard brings its own keymap, so it only needs to contain keys used for pasting.
+// keycodes in keymap are offset by 8 from ones sent in key events
+const KEYMAP: &str = r#"xkb_keymap {
+    xkb_keycodes "gauntlet" {
+        minimum = 8;
+        maximum = 9;
+        <K1> = 9;
+    };
+    xkb_types "gauntlet" { include "complete" };
+    xkb_compatibility "gauntlet" { include "complete" };
+    xkb_symbols "gauntlet" {
+        key <K1> { [ v ] };
+    };
+};
+"#;
+
+const KEY_V: u32 = 1;
+
+// xkb "complete" compatibility maps Control to third modifier bit
+const MODIFIER_CONTROL: u32 = 1 << 2;
+
+struct VirtualKeyboardState;
+
+pub fn simulate_paste_shortcut() -> anyhow::Result<()> {
+    let conn = Connection::connect_to_env()?;
+    let (globals, mut event_queue) = registry_queue_init::<VirtualKeyboardState>(&conn)?;
+    let queue_handle = event_queue.handle();
+
+    let seat = globals.bind::<WlSeat, _, _>(&queue_handle, 1..=1, ())?;
+    let manager = globals
+        .bind::<ZwpVirtualKeyboardManagerV1, _, _>(&queue_handle, 1..=1, ())
+        .context("compositor doesn't support virtual keyboard protocol")?;
+
+    let keyboard = manager.create_virtual_keyboard(&seat, &queue_handle, ());
+
+    // compositor expects null terminated string
+    let mut keymap = KEYMAP.as_bytes().to_vec();
+    keymap.push(0);
+
+    let mut keymap_file = tempfile::tempfile()?;
+    keymap_file.write_all(&keymap)?;
+    keymap_file.flush()?;
+
+    keyboard.keymap(
+        wl_keyboard::KeymapFormat::XkbV1.into(),
+        keymap_file.as_fd(),
+        keymap.len() as u32,
+    );
+
+    let time = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u32;
+
+    keyboard.modifiers(MODIFIER_CONTROL, 0, 0, 0);
+    keyboard.key(time, KEY_V, wl_keyboard::KeyState::Pressed.into());
+    keyboard.key(time.wrapping_add(1), KEY_V, wl_keyboard::KeyState::Released.into());
+    keyboard.modifiers(0, 0, 0, 0);
+
+    event_queue.roundtrip(&mut VirtualKeyboardState)?;
+
+    keyboard.destroy();
+
+    event_queue.roundtrip(&mut VirtualKeyboardState)?;
+
+    Ok(())
+}
+
+delegate_noop!(VirtualKeyboardState: ignore WlSeat);
+delegate_noop!(VirtualKeyboardState: ZwpVirtualKeyboardManagerV1);
+delegate_noop!(VirtualKeyboardState: ZwpVirtualKeyboardV1);
+
+impl Dispatch<wl_registry::WlRegistry, GlobalListContents> for VirtualKeyboardState {
+    fn event(
+        _state: &mut VirtualKeyboardState,
+        _proxy: &wl_registry::WlRegistry,
+        _event: wl_registry::Event,
+        _data: &GlobalListContents,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<VirtualKeyboardState>,
+    ) {
+    }
+}
@@ -0,0 +1,59 @@
+use anyhow::anyhow;
+use x11rb::CURRENT_TIME;
+use x11rb::connection::Connection;
+use x11rb::protocol::xproto::ConnectionExt as _;
+use x11rb::protocol::xproto::KEY_PRESS_EVENT;
+use x11rb::protocol::xproto::KEY_RELEASE_EVENT;
+use x11rb::protocol::xproto::Keycode;
+use x11rb::protocol::xproto::Keysym;
+use x11rb::protocol::xtest::ConnectionExt as _;
+
+const XK_CONTROL_L: Keysym = 0xffe3;
+const XK_V: Keysym = 0x0076;
+
+pub fn simulate_paste_shortcut() -> anyhow::Result<()> {
+    let (conn, screen_num) = x11rb::connect(None)?;
+
+    let root = conn.setup().roots[screen_num].root;
+
+    let control = find_keycode(&conn, XK_CONTROL_L)?;
+    let v = find_keycode(&conn, XK_V)?;
+
+    let events = [
+        (KEY_PRESS_EVENT, control),
+        (KEY_PRESS_EVENT, v),
+        (KEY_RELEASE_EVENT, v),
+        (KEY_RELEASE_EVENT, control),
+    ];
+
+    for (event_type, keycode) in events {
+        conn.xtest_fake_input(event_type, keycode, CURRENT_TIME, root, 0, 0, 0)?
+            .check()?;
+    }
+
+    conn.flush()?;
+
+    Ok(())
+}
+
+fn find_keycode(conn: &impl Connection, keysym: Keysym) -> anyhow::Result<Keycode> {
+    let min_keycode = conn.setup().min_keycode;
+    let max_keycode = conn.setup().max_keycode;
+
+    let mapping = conn
+        .get_keyboard_mapping(min_keycode, max_keycode - min_keycode + 1)?
+        .reply()?;
+
+    let keysyms_per_keycode = mapping.keysyms_per_keycode as usize;
+
+    if keysyms_per_keycode == 0 {
+        return Err(anyhow!("keyboard mapping is empty"));
+    }
+
+    mapping
+        .keysyms
+        .chunks(keysyms_per_keycode)
+        .position(|keysyms| keysyms.contains(&keysym))
+        .map(|index| min_keycode + index as Keycode)
+        .ok_or(anyhow!("no keycode found for keysym {:#x}", keysym))
+}
@@ -0,0 +1,327 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use gauntlet_common::model::PluginId;
+use tokio::sync::oneshot;
+use uuid::Uuid;
+
+use crate::plugins::data_db_repository::DbPluginExportedCommandArgument;
+use crate::plugins::data_db_repository::DbPluginExportedCommandArgumentType;
+use crate::plugins::js::OnePluginCommandData;
+use crate::plugins::js::PluginCommand;
+
+const PLUGIN_COMMAND_TIMEOUT: Duration = Duration::from_secs(30);
+
+// keeps track of calls of exported commands between plugins,
+// results are sent back by callee plugin runtime using call id
+#[derive(Clone)]
+pub struct PluginCommandCalls {
+    command_broadcaster: tokio::sync::broadcast::Sender<PluginCommand>,
+    inner: Arc<Mutex<PluginCommandCallsData>>,
+}
+
+#[derive(Default)]
+struct PluginCommandCallsData {
+    in_flight: HashMap<String, InFlightCall>,
+}
+
+struct InFlightCall {
+    caller: PluginId,
+    callee: PluginId,
+    result_sender: oneshot::Sender<Result<String, String>>,
+}
+
+impl PluginCommandCalls {
+    pub fn new(command_broadcaster: tokio::sync::broadcast::Sender<PluginCommand>) -> Self {
+        Self {
+            command_broadcaster,
+            inner: Arc::new(Mutex::new(PluginCommandCallsData::default())),
+        }
+    }
+
+    pub async fn call(
+        &self,
+        caller: PluginId,
+        callee: PluginId,
+        command_id: String,
+        arguments: String,
+    ) -> anyhow::Result<String> {
+        let call_id = Uuid::new_v4().to_string();
+        let (result_sender, result_receiver) = oneshot::channel();
+
+        {
+            let mut inner = self.inner.lock().expect("lock is poisoned");
+
+            if inner.is_waiting_for(&callee, &caller) {
+                return Err(anyhow!(
+                    "Calling command '{}' of plugin '{}' would create a cycle",
+                    command_id,
+                    callee
+                ));
+            }
+
+            inner.in_flight.insert(
+                call_id.clone(),
+                InFlightCall {
+                    caller,
+                    callee: callee.clone(),
+                    result_sender,
+                },
+            );
+        }
+
+        let _ = self.command_broadcaster.send(PluginCommand::One {
+            id: callee,
+            data: OnePluginCommandData::CallExportedCommand {
+                call_id: call_id.clone(),
+                command_id: command_id.clone(),
+                arguments,
+            },
+        });
+
+        let result = tokio::time::timeout(PLUGIN_COMMAND_TIMEOUT, result_receiver).await;
+
+        // removed here in case callee didn't respond in time
+        self.inner.lock().expect("lock is poisoned").in_flight.remove(&call_id);
+
+        match result {
+            Err(_) => {
+                Err(anyhow!(
+                    "Command '{}' didn't return a result within {} seconds",
+                    command_id,
+                    PLUGIN_COMMAND_TIMEOUT.as_secs()
+                ))
+            }
+            Ok(Err(_)) => {
+                Err(anyhow!(
+                    "Command '{}' was dropped before returning a result",
+                    command_id
+                ))
+            }
+            Ok(Ok(Err(error))) => Err(anyhow!("Command '{}' returned an error: {}", command_id, error)),
+            Ok(Ok(Ok(result))) => Ok(result),
+        }
+    }
+
+    pub fn complete(&self, callee: &PluginId, call_id: &str, result: Result<String, String>) -> anyhow::Result<()> {
+        let mut inner = self.inner.lock().expect("lock is poisoned");
+
+        let is_callee = inner.in_flight.get(call_id).is_some_and(|call| &call.callee == callee);
+
+        if !is_callee {
+            return Err(anyhow!("Unknown plugin command call: {}", call_id));
+        }
+
+        if let Some(call) = inner.in_flight.remove(call_id) {
+            let _ = call.result_sender.send(result);
+        }
+
+        Ok(())
+    }
+}
+
+impl PluginCommandCallsData {
+    // true if plugin is directly or transitively waiting for result of the other plugin.
+    // plugin can have multiple unrelated calls in flight so this may reject calls
+    // which would not actually deadlock, but it is not possible to tell them apart
+    fn is_waiting_for(&self, plugin_id: &PluginId, other_plugin_id: &PluginId) -> bool {
+        let mut visited = HashSet::new();
+        let mut to_visit = vec![plugin_id];
+
+        while let Some(current) = to_visit.pop() {
+            if current == other_plugin_id {
+                return true;
+            }
+
+            if !visited.insert(current) {
+                continue;
+            }
+
+            for call in self.in_flight.values() {
+                if &call.caller == current {
+                    to_visit.push(&call.callee);
+                }
+            }
+        }
+
+        false
+    }
+}
+
+pub fn validate_plugin_command_arguments(
+    command_id: &str,
+    expected_arguments: &[DbPluginExportedCommandArgument],
+    arguments: &str,
+) -> anyhow::Result<()> {
+    let arguments: serde_json::Value = serde_json::from_str(arguments)?;
+
+    let serde_json::Value::Object(arguments) = arguments else {
+        return Err(anyhow!("Arguments of command '{}' have to be an object", command_id));
+    };
+
+    for id in arguments.keys() {
+        if !expected_arguments.iter().any(|argument| &argument.id == id) {
+            return Err(anyhow!("Command '{}' doesn't accept argument '{}'", command_id, id));
+        }
+    }
+
+    for argument in expected_arguments {
+        let valid = match (arguments.get(&argument.id), &argument.argument_type) {
+            (None | Some(serde_json::Value::Null), _) => !argument.required,
+            (Some(serde_json::Value::String(_)), DbPluginExportedCommandArgumentType::String) => true,
+            (Some(serde_json::Value::Number(_)), DbPluginExportedCommandArgumentType::Number) => true,
+            (Some(serde_json::Value::Bool(_)), DbPluginExportedCommandArgumentType::Boolean) => true,
+            (Some(_), _) => false,
+        };
+
+        if !valid {
+            return Err(anyhow!(
+                "Argument '{}' of command '{}' is required to be a {}",
+                argument.id,
+                command_id,
+                match argument.argument_type {
+                    DbPluginExportedCommandArgumentType::String => "string",
+                    DbPluginExportedCommandArgumentType::Number => "number",
+                    DbPluginExportedCommandArgumentType::Boolean => "boolean",
+                }
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn argument(
+        id: &str,
+        argument_type: DbPluginExportedCommandArgumentType,
+        required: bool,
+    ) -> DbPluginExportedCommandArgument {
+        DbPluginExportedCommandArgument {
+            id: id.to_string(),
+            argument_type,
+            required,
+        }
+    }
+
+    fn expected_arguments() -> Vec<DbPluginExportedCommandArgument> {
+        vec![
+            argument("query", DbPluginExportedCommandArgumentType::String, true),
+            argument("limit", DbPluginExportedCommandArgumentType::Number, false),
+            argument("exact", DbPluginExportedCommandArgumentType::Boolean, false),
+        ]
+    }
+
+    fn call_data(calls: &[(&str, &str)]) -> PluginCommandCallsData {
+        let in_flight = calls
+            .iter()
+            .enumerate()
+            .map(|(index, (caller, callee))| {
+                let (result_sender, _) = oneshot::channel();
+
+                let call = InFlightCall {
+                    caller: PluginId::from_string(*caller),
+                    callee: PluginId::from_string(*callee),
+                    result_sender,
+                };
+
+                (index.to_string(), call)
+            })
+            .collect();
+
+        PluginCommandCallsData { in_flight }
+    }
+
+    #[test]
+    fn valid_arguments() {
+        let arguments = expected_arguments();
+
+        assert!(validate_plugin_command_arguments("search", &arguments, r#"{"query": "text"}"#).is_ok());
+        assert!(
+            validate_plugin_command_arguments("search", &arguments, r#"{"query": "text", "limit": 10, "exact": true}"#)
+                .is_ok()
+        );
+        assert!(validate_plugin_command_arguments("search", &arguments, r#"{"query": "text", "limit": null}"#).is_ok());
+        assert!(validate_plugin_command_arguments("search", &[], "{}").is_ok());
+    }
+
+    #[test]
+    fn missing_required_argument() {
+        let arguments = expected_arguments();
+
+        assert!(validate_plugin_command_arguments("search", &arguments, "{}").is_err());
+        assert!(validate_plugin_command_arguments("search", &arguments, r#"{"query": null}"#).is_err());
+    }
+
+    #[test]
+    fn wrong_argument_type() {
+        let arguments = expected_arguments();
+
+        assert!(validate_plugin_command_arguments("search", &arguments, r#"{"query": 1}"#).is_err());
+        assert!(
+            validate_plugin_command_arguments("search", &arguments, r#"{"query": "text", "limit": "10"}"#).is_err()
+        );
+        assert!(validate_plugin_command_arguments("search", &arguments, r#"{"query": "text", "exact": 1}"#).is_err());
+    }
+
+    #[test]
+    fn unknown_argument() {
+        let arguments = expected_arguments();
+
+        assert!(validate_plugin_command_arguments("search", &arguments, r#"{"query": "text", "other": 1}"#).is_err());
+    }
+
+    #[test]
+    fn arguments_not_an_object() {
+        let arguments = expected_arguments();
+
+        assert!(validate_plugin_command_arguments("search", &arguments, "[]").is_err());
+        assert!(validate_plugin_command_arguments("search", &arguments, "\"text\"").is_err());
+        assert!(validate_plugin_command_arguments("search", &arguments, "not json").is_err());
+    }
+
+    #[test]
+    fn plugin_is_waiting_for_itself() {
+        let data = call_data(&[]);
+
+        assert!(data.is_waiting_for(&PluginId::from_string("a"), &PluginId::from_string("a")));
+    }
+
+    #[test]
+    fn plugin_is_waiting_for_callee() {
+        let data = call_data(&[("a", "b")]);
+
+        assert!(data.is_waiting_for(&PluginId::from_string("a"), &PluginId::from_string("b")));
+        assert!(!data.is_waiting_for(&PluginId::from_string("b"), &PluginId::from_string("a")));
+    }
+
+    #[test]
+    fn plugin_is_waiting_for_callee_transitively() {
+        let data = call_data(&[("a", "b"), ("b", "c"), ("c", "d")]);
+
+        assert!(data.is_waiting_for(&PluginId::from_string("a"), &PluginId::from_string("d")));
+        assert!(!data.is_waiting_for(&PluginId::from_string("d"), &PluginId::from_string("a")));
+    }
+
+    #[test]
+    fn unrelated_calls_are_not_waiting() {
+        let data = call_data(&[("a", "b"), ("c", "d")]);
+
+        assert!(!data.is_waiting_for(&PluginId::from_string("a"), &PluginId::from_string("d")));
+        assert!(!data.is_waiting_for(&PluginId::from_string("c"), &PluginId::from_string("b")));
+    }
+
+    #[test]
+    fn cycle_in_calls_terminates() {
+        let data = call_data(&[("a", "b"), ("b", "a")]);
+
+        assert!(!data.is_waiting_for(&PluginId::from_string("a"), &PluginId::from_string("c")));
+    }
+}
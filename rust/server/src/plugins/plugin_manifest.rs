@@ -23,6 +23,9 @@ pub struct PluginManifest {
     #[serde(default)]
     #[schemars(description = "Preferences that can be configured by the user in the settings view")]
     pub preferences: Vec<PluginManifestPreference>,
+    #[serde(default)]
+    #[schemars(description = "Commands that can be called by other plugins")]
+    pub exported_command: Vec<PluginManifestExportedCommand>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema)]
@@ -48,6 +51,98 @@ pub struct PluginManifestEntrypoint {
     #[serde(default)]
     #[schemars(description = "List of definitions of plugin actions")]
     pub actions: Vec<PluginManifestAction>,
+    #[serde(default)]
+    #[schemars(
+        description = "List of arguments shown next to main search bar when entrypoint is selected. Only supported for 'command' and 'view' entrypoints"
+    )]
+    pub arguments: Vec<PluginManifestArgument>,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(tag = "type")]
+#[schemars(description = "Value provided by the user when running the entrypoint")]
+pub enum PluginManifestArgument {
+    #[serde(rename = "text")]
+    #[schemars(description = "A text argument")]
+    Text {
+        #[schemars(description = "Unique identifier of the argument, can only contain letters and numbers")]
+        id: String,
+        #[schemars(description = "Text shown in empty argument field")]
+        placeholder: String,
+        #[serde(default)]
+        #[schemars(description = "Whether entrypoint can be run without providing value for this argument")]
+        required: bool,
+    },
+    #[serde(rename = "password")]
+    #[schemars(description = "A text argument, value of which is hidden while typing")]
+    Password {
+        #[schemars(description = "Unique identifier of the argument, can only contain letters and numbers")]
+        id: String,
+        #[schemars(description = "Text shown in empty argument field")]
+        placeholder: String,
+        #[serde(default)]
+        #[schemars(description = "Whether entrypoint can be run without providing value for this argument")]
+        required: bool,
+    },
+    #[serde(rename = "dropdown")]
+    #[schemars(description = "An argument with a list of predefined values")]
+    Dropdown {
+        #[schemars(description = "Unique identifier of the argument, can only contain letters and numbers")]
+        id: String,
+        #[schemars(description = "Text shown when no value is selected")]
+        placeholder: String,
+        #[serde(default)]
+        #[schemars(description = "Whether entrypoint can be run without providing value for this argument")]
+        required: bool,
+        #[schemars(description = "List of values that can be selected")]
+        data: Vec<PluginManifestPreferenceEnumValue>,
+    },
+}
+
+impl PluginManifestArgument {
+    pub fn id(&self) -> &str {
+        match self {
+            PluginManifestArgument::Text { id, .. } => id,
+            PluginManifestArgument::Password { id, .. } => id,
+            PluginManifestArgument::Dropdown { id, .. } => id,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+#[schemars(description = "Command that can be called by other plugins")]
+pub struct PluginManifestExportedCommand {
+    #[schemars(description = "Id of 'command' entrypoint that is run when the command is called")]
+    pub id: String,
+    #[schemars(description = "Description of what the command does and what it returns")]
+    pub description: String,
+    #[serde(default)]
+    #[schemars(description = "List of arguments that caller needs to provide")]
+    pub arguments: Vec<PluginManifestExportedCommandArgument>,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+#[schemars(description = "Argument of the command that can be called by other plugins")]
+pub struct PluginManifestExportedCommandArgument {
+    #[schemars(description = "Unique identifier of the argument, can only contain letters and numbers")]
+    pub id: String,
+    #[serde(rename = "type")]
+    #[schemars(description = "Type of the argument value")]
+    pub argument_type: PluginManifestExportedCommandArgumentType,
+    #[serde(default)]
+    #[schemars(description = "Whether caller has to provide value for this argument")]
+    pub required: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+#[schemars(description = "Type of the argument value")]
+pub enum PluginManifestExportedCommandArgumentType {
+    #[serde(rename = "string")]
+    String,
+    #[serde(rename = "number")]
+    Number,
+    #[serde(rename = "boolean")]
+    Boolean,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema)]
@@ -560,6 +655,15 @@ pub struct PluginManifestPermissions {
     #[serde(default)]
     #[schemars(description = "Permissions for the main search bar")]
     pub main_search_bar: Vec<PluginManifestMainSearchBarPermissions>,
+    #[serde(default)]
+    #[schemars(description = "Permissions for the context captured when Gauntlet window was opened")]
+    pub launch_context: Vec<PluginManifestLaunchContextPermissions>,
+    #[serde(default)]
+    #[schemars(description = "Commands of other plugins that the plugin can call")]
+    pub plugin_command: Vec<PluginManifestPluginCommandPermission>,
+    #[serde(default)]
+    #[schemars(description = "Deep links that can be handled without asking the user for confirmation")]
+    pub deep_link: Vec<PluginManifestDeepLinkPermissions>,
 }
 
 #[derive(Debug, Deserialize, Default, Serialize, JsonSchema)]
@@ -596,6 +700,9 @@ pub enum PluginManifestClipboardPermissions {
     #[serde(rename = "clear")]
     #[schemars(description = "Allows the plugin to clear the clipboard contents")]
     Clear,
+    #[serde(rename = "paste")]
+    #[schemars(description = "Allows the plugin to paste text into previously focused window")]
+    Paste,
 }
 
 #[derive(Debug, Deserialize, Eq, PartialEq, Serialize, JsonSchema)]
@@ -604,3 +711,34 @@ pub enum PluginManifestMainSearchBarPermissions {
     #[schemars(description = "Allows the plugin to read the main search bar")]
     Read,
 }
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub enum PluginManifestLaunchContextPermissions {
+    #[serde(rename = "selected_text")]
+    #[schemars(description = "Allows the plugin to read text that was selected when Gauntlet window was opened")]
+    SelectedText,
+    #[serde(rename = "focused_window")]
+    #[schemars(
+        description = "Allows the plugin to read application id and title of the window that was focused when Gauntlet window was opened"
+    )]
+    FocusedWindow,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub enum PluginManifestDeepLinkPermissions {
+    #[serde(rename = "run")]
+    #[schemars(description = "Allows `gauntlet://run/...` deep links to run actions of the plugin entrypoints")]
+    Run,
+    #[serde(rename = "open")]
+    #[schemars(description = "Allows `gauntlet://open/...` deep links to open views of the plugin")]
+    Open,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+#[schemars(description = "Command of other plugin that the plugin can call")]
+pub struct PluginManifestPluginCommandPermission {
+    #[schemars(description = "Id of the plugin that exports the command, e.g. 'https://github.com/user/repo'")]
+    pub plugin: String,
+    #[schemars(description = "Id of the exported command")]
+    pub command: String,
+}
@@ -0,0 +1,305 @@
+use std::collections::HashMap;
+use std::process::Command;
+
+use anyhow::Context;
+use anyhow::anyhow;
+use gauntlet_common::detached_process::CommandExt;
+use gauntlet_common::model::EntrypointId;
+use gauntlet_common::model::Quicklink;
+use gauntlet_common::model::QuicklinkKind;
+use gauntlet_common::model::SearchResultEntrypointArgument;
+use gauntlet_common::model::SearchResultEntrypointArgumentKind;
+use gauntlet_common::model::SearchResultEntrypointType;
+
+use crate::plugins::clipboard::Clipboard;
+use crate::plugins::data_db_repository::DbQuicklinkKind;
+use crate::plugins::data_db_repository::DbReadQuicklink;
+use crate::plugins::data_db_repository::DbWriteQuicklink;
+use crate::plugins::data_db_repository::db_quicklink_kind_from_str;
+use crate::search::SearchIndexItem;
+
+pub const QUICKLINKS_PLUGIN_ID: &str = "builtin://quicklinks";
+pub const QUICKLINKS_PLUGIN_NAME: &str = "Quicklinks";
+
+const QUERY_PLACEHOLDER: &str = "{query}";
+const CLIPBOARD_PLACEHOLDER: &str = "{clipboard}";
+const SELECTION_PLACEHOLDER: &str = "{selection}";
+
+pub const QUERY_ARGUMENT_ID: &str = "query";
+
+pub fn quicklink_from_db(quicklink: DbReadQuicklink) -> Quicklink {
+    let kind = match db_quicklink_kind_from_str(&quicklink.kind) {
+        DbQuicklinkKind::Url => QuicklinkKind::Url,
+        DbQuicklinkKind::Command => QuicklinkKind::Command,
+    };
+
+    Quicklink {
+        id: quicklink.id,
+        name: quicklink.name,
+        icon_path: quicklink.icon_path,
+        template: quicklink.template,
+        kind,
+    }
+}
+
+pub fn quicklink_to_db(quicklink: Quicklink) -> DbWriteQuicklink {
+    let kind = match quicklink.kind {
+        QuicklinkKind::Url => DbQuicklinkKind::Url,
+        QuicklinkKind::Command => DbQuicklinkKind::Command,
+    };
+
+    DbWriteQuicklink {
+        id: quicklink.id,
+        name: quicklink.name,
+        icon_path: quicklink.icon_path,
+        template: quicklink.template,
+        kind,
+    }
+}
+
+pub fn quicklink_search_items(quicklinks: Vec<Quicklink>) -> Vec<SearchIndexItem> {
+    quicklinks
+        .into_iter()
+        .map(|quicklink| {
+            let entrypoint_icon = quicklink.icon_path.as_ref().and_then(|path| {
+                match std::fs::read(path) {
+                    Ok(data) => Some(bytes::Bytes::from(data)),
+                    Err(err) => {
+                        tracing::warn!(
+                            "unable to read icon of quicklink '{}' at {}: {:?}",
+                            quicklink.name,
+                            path,
+                            err
+                        );
+                        None
+                    }
+                }
+            });
+
+            let entrypoint_arguments = if quicklink.template.contains(QUERY_PLACEHOLDER) {
+                vec![SearchResultEntrypointArgument {
+                    id: QUERY_ARGUMENT_ID.to_string(),
+                    kind: SearchResultEntrypointArgumentKind::Text,
+                    placeholder: "Query".to_string(),
+                    required: true,
+                }]
+            } else {
+                vec![]
+            };
+
+            SearchIndexItem {
+                entrypoint_type: SearchResultEntrypointType::Command,
+                entrypoint_id: EntrypointId::from_string(quicklink.id),
+                entrypoint_name: quicklink.name,
+                entrypoint_generator: None,
+                entrypoint_icon,
+                entrypoint_frecency: 0.0,
+                entrypoint_actions: vec![],
+                entrypoint_accessories: vec![],
+                entrypoint_arguments,
+                entrypoint_keywords: vec![],
+            }
+        })
+        .collect()
+}
+
+pub fn run_quicklink(
+    quicklink: Quicklink,
+    arguments: HashMap<String, String>,
+    clipboard: &Clipboard,
+) -> anyhow::Result<Option<String>> {
+    let template = &quicklink.template;
+
+    let query = match arguments.get(QUERY_ARGUMENT_ID) {
+        Some(query) => query.clone(),
+        None if template.contains(QUERY_PLACEHOLDER) => {
+            return Err(anyhow!("Quicklink '{}' requires query argument", quicklink.name));
+        }
+        None => String::new(),
+    };
+
+    let clipboard_text = if template.contains(CLIPBOARD_PLACEHOLDER) {
+        clipboard.read_text()?.unwrap_or_default()
+    } else {
+        String::new()
+    };
+
+    let selection_text = if template.contains(SELECTION_PLACEHOLDER) {
+        clipboard.read_selection_text()?.unwrap_or_default()
+    } else {
+        String::new()
+    };
+
+    let placeholders = [
+        (QUERY_PLACEHOLDER, query.as_str()),
+        (CLIPBOARD_PLACEHOLDER, clipboard_text.as_str()),
+        (SELECTION_PLACEHOLDER, selection_text.as_str()),
+    ];
+
+    match quicklink.kind {
+        QuicklinkKind::Url => Ok(Some(expand_template(template, &placeholders, url_encode))),
+        QuicklinkKind::Command => {
+            template_command(template, &placeholders)?
+                .spawn_detached()
+                .with_context(|| format!("unable to run quicklink '{}'", quicklink.name))?;
+
+            Ok(None)
+        }
+    }
+}
+
+// single pass, so placeholders contained in substituted values are not expanded
+fn expand_template(template: &str, placeholders: &[(&str, &str)], escape: fn(&str) -> String) -> String {
+    let mut result = String::new();
+    let mut rest = template;
+
+    loop {
+        let next = placeholders
+            .iter()
+            .filter_map(|(placeholder, value)| rest.find(placeholder).map(|index| (index, *placeholder, *value)))
+            .min_by_key(|(index, _, _)| *index);
+
+        let Some((index, placeholder, value)) = next else {
+            result.push_str(rest);
+            return result;
+        };
+
+        result.push_str(&rest[..index]);
+        result.push_str(&escape(value));
+        rest = &rest[index + placeholder.len()..];
+    }
+}
+
+fn url_encode(value: &str) -> String {
+    url::form_urlencoded::byte_serialize(value.as_bytes()).collect()
+}
+
+#[cfg(unix)]
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r#"'\''"#))
+}
+
+#[cfg(unix)]
+fn template_command(template: &str, placeholders: &[(&str, &str)]) -> anyhow::Result<Command> {
+    let mut result = Command::new("sh");
+    result
+        .arg("-c")
+        .arg(expand_template(template, placeholders, shell_quote));
+    Ok(result)
+}
+
+// cmd.exe has no reliable way to quote arbitrary values, so template is
+// split into arguments and values are passed to the program as is
+#[cfg(windows)]
+fn template_command(template: &str, placeholders: &[(&str, &str)]) -> anyhow::Result<Command> {
+    let args = template_args(template, placeholders)?;
+
+    let Some((program, args)) = args.split_first() else {
+        return Err(anyhow!("Quicklink command is empty"));
+    };
+
+    let mut result = Command::new(program);
+    result.args(args);
+    Ok(result)
+}
+
+#[cfg(any(windows, test))]
+fn template_args(template: &str, placeholders: &[(&str, &str)]) -> anyhow::Result<Vec<String>> {
+    let args = split_template(template)?
+        .iter()
+        .map(|arg| expand_template(arg, placeholders, |value| value.to_string()))
+        .collect();
+
+    Ok(args)
+}
+
+#[cfg(any(windows, test))]
+fn split_template(template: &str) -> anyhow::Result<Vec<String>> {
+    let mut args = vec![];
+    let mut current: Option<String> = None;
+    let mut quoted = false;
+
+    for char in template.chars() {
+        match char {
+            '"' => {
+                current.get_or_insert_with(String::new);
+                quoted = !quoted;
+            }
+            char if char.is_whitespace() && !quoted => {
+                args.extend(current.take());
+            }
+            char => current.get_or_insert_with(String::new).push(char),
+        }
+    }
+
+    if quoted {
+        return Err(anyhow!("Quicklink command contains unterminated quote: {}", template));
+    }
+
+    args.extend(current);
+
+    Ok(args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn placeholders_in_values_are_not_expanded() {
+        let placeholders = [
+            (CLIPBOARD_PLACEHOLDER, "{selection}"),
+            (SELECTION_PLACEHOLDER, "selected"),
+        ];
+
+        let expanded = expand_template("{clipboard} {selection}", &placeholders, |value| value.to_string());
+
+        assert_eq!(expanded, "{selection} selected");
+    }
+
+    #[test]
+    fn url_values_are_encoded() {
+        let placeholders = [(QUERY_PLACEHOLDER, "a b&c=d")];
+
+        let expanded = expand_template("https://example.com/?q={query}", &placeholders, url_encode);
+
+        assert_eq!(expanded, "https://example.com/?q=a+b%26c%3Dd");
+    }
+
+    #[test]
+    fn template_args_pass_values_as_is() {
+        let placeholders = [(QUERY_PLACEHOLDER, "%PATH%&calc")];
+
+        let args = template_args(r#""C:\Program Files\app.exe" --search {query}"#, &placeholders).unwrap();
+
+        assert_eq!(args, vec![r"C:\Program Files\app.exe", "--search", "%PATH%&calc"]);
+    }
+
+    #[test]
+    fn template_args_keep_values_in_single_argument() {
+        let placeholders = [(QUERY_PLACEHOLDER, "a \"b\" c")];
+
+        let args = template_args(r#"app "prefix {query} suffix""#, &placeholders).unwrap();
+
+        assert_eq!(args, vec!["app", "prefix a \"b\" c suffix"]);
+    }
+
+    #[test]
+    fn template_args_reject_unterminated_quote() {
+        assert!(template_args(r#"app "{query}"#, &[]).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn shell_values_are_not_interpreted() {
+        let value = "%PATH%&calc '$(calc)' `calc`; \\";
+        let placeholders = [(QUERY_PLACEHOLDER, value)];
+
+        let output = template_command("printf %s {query}", &placeholders)
+            .unwrap()
+            .output()
+            .unwrap();
+
+        assert_eq!(String::from_utf8(output.stdout).unwrap(), value);
+    }
+}
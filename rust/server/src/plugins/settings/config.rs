@@ -11,6 +11,7 @@ pub struct ApplicationConfig {
 #[derive(Deserialize, Debug, Default)]
 pub struct ApplicationWindowConfig {
     pub close_on_unfocus: Option<bool>,
+    pub compact_mode: Option<bool>,
 }
 
 #[derive(Deserialize, Debug, Default)]
@@ -22,6 +23,8 @@ pub struct WaylandConfig {
 #[derive(Deserialize, Debug, Default)]
 pub struct LinuxConfig {
     pub native_hud: Option<bool>,
+    pub plugin_sandbox: Option<bool>,
+    pub plugin_sandbox_network_fallback: Option<bool>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -44,7 +47,11 @@ pub enum WaylandGlobalShortcutConfig {
 
 pub struct EffectiveConfig {
     pub close_on_unfocus: bool,
+    // none means value is not defined in config and settings value is used
+    pub compact_mode: Option<bool>,
     pub layer_shell: bool,
     pub wayland_use_legacy_x11_api: bool,
     pub linux_native_hud: bool,
+    pub linux_plugin_sandbox: bool,
+    pub linux_plugin_sandbox_network_fallback: bool,
 }
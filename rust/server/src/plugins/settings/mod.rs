@@ -3,7 +3,9 @@ pub mod config_reader;
 pub mod global_shortcut;
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
+use std::sync::Mutex;
 
 use anyhow::anyhow;
 use dark_light::Mode;
@@ -11,17 +13,22 @@ use gauntlet_common::dirs::Dirs;
 use gauntlet_common::model::EntrypointId;
 use gauntlet_common::model::PhysicalShortcut;
 use gauntlet_common::model::PluginId;
+use gauntlet_common::model::SearchPinMove;
 use gauntlet_common::model::SettingsTheme;
 use gauntlet_common::model::UiTheme;
 use gauntlet_common::model::WindowPositionMode;
+use gauntlet_common::model::WindowSize;
 use gauntlet_common::rpc::frontend_api::FrontendApi;
 use gauntlet_common::rpc::frontend_api::FrontendApiProxy;
 use global_hotkey::GlobalHotKeyManager;
 
 use crate::plugins::data_db_repository::DataDbRepository;
 use crate::plugins::data_db_repository::DbSettingsEntrypointSearchAliasData;
+use crate::plugins::data_db_repository::DbSettingsEntrypointSearchHiddenData;
+use crate::plugins::data_db_repository::DbSettingsEntrypointSearchPinData;
 use crate::plugins::data_db_repository::DbTheme;
 use crate::plugins::data_db_repository::DbWindowPositionMode;
+use crate::plugins::data_db_repository::DbWindowSize;
 use crate::plugins::settings::config::ApplicationConfig;
 use crate::plugins::settings::config::EffectiveConfig;
 use crate::plugins::settings::config::WaylandGlobalShortcutConfig;
@@ -33,6 +40,11 @@ use crate::plugins::settings::global_shortcut::GlobalShortcutSettings;
 use crate::plugins::theme::BundledThemes;
 use crate::plugins::theme::read_theme_file;
 
+const MIN_WINDOW_WIDTH: f32 = 400.0;
+const MIN_WINDOW_HEIGHT: f32 = 200.0;
+const MAX_WINDOW_WIDTH: f32 = 8192.0;
+const MAX_WINDOW_HEIGHT: f32 = 8192.0;
+
 #[derive(Clone)]
 pub struct Settings {
     dirs: Dirs,
@@ -41,6 +53,8 @@ pub struct Settings {
     config: Arc<EffectiveConfig>,
     global_hotkey_settings: GlobalShortcutSettings,
     themes: Arc<BundledThemes>,
+    // used to resolve search scope on every prompt change, so kept in memory
+    entrypoint_search_aliases_cache: Arc<Mutex<Option<HashMap<(PluginId, EntrypointId), String>>>>,
 }
 
 impl Settings {
@@ -61,6 +75,7 @@ impl Settings {
             global_hotkey_settings: GlobalShortcutSettings::new(repository)?,
             config: Arc::new(effective_config(config, layer_shell_supported)),
             themes: Arc::new(BundledThemes::new()?),
+            entrypoint_search_aliases_cache: Arc::new(Mutex::new(None)),
         })
     }
 
@@ -112,6 +127,12 @@ impl Settings {
     }
 
     pub fn entrypoint_search_aliases(&self) -> anyhow::Result<HashMap<(PluginId, EntrypointId), String>> {
+        let mut cache = self.entrypoint_search_aliases_cache.lock().expect("lock is poisoned");
+
+        if let Some(data) = cache.as_ref() {
+            return Ok(data.clone());
+        }
+
         let settings = self.repository.get_settings()?;
 
         let data: HashMap<_, _> = settings
@@ -129,6 +150,8 @@ impl Settings {
             })
             .collect();
 
+        *cache = Some(data.clone());
+
         Ok(data)
     }
 
@@ -175,6 +198,166 @@ impl Settings {
             Ok(settings)
         })?;
 
+        *self.entrypoint_search_aliases_cache.lock().expect("lock is poisoned") = None;
+
+        Ok(())
+    }
+
+    pub fn entrypoint_search_pins(&self) -> anyhow::Result<Vec<(PluginId, EntrypointId)>> {
+        let settings = self.repository.get_settings()?;
+
+        let data = settings
+            .entrypoint_search_pins
+            .unwrap_or_default()
+            .into_iter()
+            .map(|data| {
+                (
+                    PluginId::from_string(data.plugin_id),
+                    EntrypointId::from_string(data.entrypoint_id),
+                )
+            })
+            .collect();
+
+        Ok(data)
+    }
+
+    pub fn set_entrypoint_search_pinned(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        pinned: bool,
+    ) -> anyhow::Result<()> {
+        self.mutate_entrypoint_search_pins(|pins| {
+            let position = pins.iter().position(|(pin_plugin_id, pin_entrypoint_id)| {
+                pin_plugin_id == &plugin_id && pin_entrypoint_id == &entrypoint_id
+            });
+
+            match (position, pinned) {
+                (None, true) => pins.push((plugin_id, entrypoint_id)),
+                (Some(position), false) => {
+                    pins.remove(position);
+                }
+                _ => {}
+            }
+        })
+    }
+
+    pub fn move_entrypoint_search_pin(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        direction: SearchPinMove,
+    ) -> anyhow::Result<()> {
+        self.mutate_entrypoint_search_pins(|pins| {
+            let Some(position) = pins.iter().position(|(pin_plugin_id, pin_entrypoint_id)| {
+                pin_plugin_id == &plugin_id && pin_entrypoint_id == &entrypoint_id
+            }) else {
+                return;
+            };
+
+            let new_position = match direction {
+                SearchPinMove::Up => position.checked_sub(1),
+                SearchPinMove::Down => Some(position + 1).filter(|new_position| *new_position < pins.len()),
+            };
+
+            if let Some(new_position) = new_position {
+                pins.swap(position, new_position);
+            }
+        })
+    }
+
+    fn mutate_entrypoint_search_pins(&self, f: impl FnOnce(&mut Vec<(PluginId, EntrypointId)>)) -> anyhow::Result<()> {
+        self.repository.mutate_settings(|mut settings| {
+            let mut pins: Vec<_> = settings
+                .entrypoint_search_pins
+                .unwrap_or_default()
+                .into_iter()
+                .map(|data| {
+                    (
+                        PluginId::from_string(data.plugin_id),
+                        EntrypointId::from_string(data.entrypoint_id),
+                    )
+                })
+                .collect();
+
+            f(&mut pins);
+
+            let pins = pins
+                .into_iter()
+                .map(|(plugin_id, entrypoint_id)| {
+                    DbSettingsEntrypointSearchPinData {
+                        plugin_id: plugin_id.to_string(),
+                        entrypoint_id: entrypoint_id.to_string(),
+                    }
+                })
+                .collect();
+
+            settings.entrypoint_search_pins = Some(pins);
+
+            Ok(settings)
+        })?;
+
+        Ok(())
+    }
+
+    pub fn entrypoint_search_hidden(&self) -> anyhow::Result<HashSet<(PluginId, EntrypointId)>> {
+        let settings = self.repository.get_settings()?;
+
+        let data = settings
+            .entrypoint_search_hidden
+            .unwrap_or_default()
+            .into_iter()
+            .map(|data| {
+                (
+                    PluginId::from_string(data.plugin_id),
+                    EntrypointId::from_string(data.entrypoint_id),
+                )
+            })
+            .collect();
+
+        Ok(data)
+    }
+
+    pub fn set_entrypoint_search_hidden(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        hidden: bool,
+    ) -> anyhow::Result<()> {
+        self.repository.mutate_settings(|mut settings| {
+            let mut hidden_data: HashSet<_> = settings
+                .entrypoint_search_hidden
+                .unwrap_or_default()
+                .into_iter()
+                .map(|data| {
+                    (
+                        PluginId::from_string(data.plugin_id),
+                        EntrypointId::from_string(data.entrypoint_id),
+                    )
+                })
+                .collect();
+
+            if hidden {
+                hidden_data.insert((plugin_id, entrypoint_id));
+            } else {
+                hidden_data.remove(&(plugin_id, entrypoint_id));
+            }
+
+            let hidden_data = hidden_data
+                .into_iter()
+                .map(|(plugin_id, entrypoint_id)| {
+                    DbSettingsEntrypointSearchHiddenData {
+                        plugin_id: plugin_id.to_string(),
+                        entrypoint_id: entrypoint_id.to_string(),
+                    }
+                })
+                .collect();
+
+            settings.entrypoint_search_hidden = Some(hidden_data);
+
+            Ok(settings)
+        })?;
+
         Ok(())
     }
 
@@ -260,6 +443,11 @@ impl Settings {
         let window_position_mode = match &settings.window_position_mode {
             None => WindowPositionMode::Static,
             Some(DbWindowPositionMode::ActiveMonitor) => WindowPositionMode::ActiveMonitor,
+            Some(DbWindowPositionMode::MonitorUnderCursor) => WindowPositionMode::MonitorUnderCursor,
+            Some(DbWindowPositionMode::FixedMonitor { name }) => {
+                WindowPositionMode::FixedMonitor { name: name.clone() }
+            }
+            Some(DbWindowPositionMode::TopThirdCentered) => WindowPositionMode::TopThirdCentered,
         };
 
         Ok(window_position_mode)
@@ -267,9 +455,14 @@ impl Settings {
 
     pub async fn set_window_position_mode_setting(&self, mode: WindowPositionMode) -> anyhow::Result<()> {
         self.repository.mutate_settings(|mut settings| {
-            let window_position_mode = match mode {
+            let window_position_mode = match &mode {
                 WindowPositionMode::Static => None,
                 WindowPositionMode::ActiveMonitor => Some(DbWindowPositionMode::ActiveMonitor),
+                WindowPositionMode::MonitorUnderCursor => Some(DbWindowPositionMode::MonitorUnderCursor),
+                WindowPositionMode::FixedMonitor { name } => {
+                    Some(DbWindowPositionMode::FixedMonitor { name: name.clone() })
+                }
+                WindowPositionMode::TopThirdCentered => Some(DbWindowPositionMode::TopThirdCentered),
             };
 
             settings.window_position_mode = window_position_mode;
@@ -282,6 +475,79 @@ impl Settings {
         Ok(())
     }
 
+    pub fn window_size_setting(&self) -> anyhow::Result<WindowSize> {
+        let settings = self.repository.get_settings()?;
+
+        let window_size = match &settings.window_size {
+            None => WindowSize::default(),
+            Some(DbWindowSize { width, height }) => {
+                let window_size = WindowSize {
+                    width: *width,
+                    height: *height,
+                };
+
+                match validate_window_size(&window_size) {
+                    Ok(()) => window_size,
+                    Err(err) => {
+                        tracing::warn!("Invalid window size in settings, using default: {:?}", err);
+                        WindowSize::default()
+                    }
+                }
+            }
+        };
+
+        Ok(window_size)
+    }
+
+    pub async fn set_window_size_setting(&self, size: WindowSize) -> anyhow::Result<()> {
+        validate_window_size(&size)?;
+
+        self.repository.mutate_settings(|mut settings| {
+            settings.window_size = if size == WindowSize::default() {
+                None
+            } else {
+                Some(DbWindowSize {
+                    width: size.width,
+                    height: size.height,
+                })
+            };
+
+            Ok(settings)
+        })?;
+
+        self.frontend_api.set_window_size(size).await?;
+
+        Ok(())
+    }
+
+    pub fn compact_mode_setting(&self) -> anyhow::Result<bool> {
+        if let Some(compact_mode) = self.config.compact_mode {
+            return Ok(compact_mode);
+        }
+
+        let settings = self.repository.get_settings()?;
+
+        Ok(settings.compact_mode.unwrap_or(false))
+    }
+
+    pub async fn set_compact_mode_setting(&self, enabled: bool) -> anyhow::Result<()> {
+        if let Some(_) = self.config.compact_mode {
+            return Err(anyhow!(
+                "Unable to change compact mode because value is defined in config"
+            ));
+        }
+
+        self.repository.mutate_settings(|mut settings| {
+            settings.compact_mode = if enabled { Some(true) } else { None };
+
+            Ok(settings)
+        })?;
+
+        self.frontend_api.set_compact_mode(enabled).await?;
+
+        Ok(())
+    }
+
     fn autodetect_theme(&self) -> UiTheme {
         match dark_light::detect() {
             Mode::Dark => self.themes.macos_dark_theme.clone(),
@@ -301,7 +567,10 @@ fn effective_config(config: ApplicationConfig, layer_shell_supported: bool) -> E
     let linux_config = config.linux.unwrap_or_default();
 
     let close_on_unfocus = window_config.close_on_unfocus.unwrap_or(true);
+    let compact_mode = window_config.compact_mode;
     let linux_native_hud = linux_config.native_hud.unwrap_or(true);
+    let linux_plugin_sandbox = linux_config.plugin_sandbox.unwrap_or(true);
+    let linux_plugin_sandbox_network_fallback = linux_config.plugin_sandbox_network_fallback.unwrap_or(false);
 
     let main_window_surface = wayland_config
         .main_window_surface
@@ -324,8 +593,68 @@ fn effective_config(config: ApplicationConfig, layer_shell_supported: bool) -> E
 
     EffectiveConfig {
         close_on_unfocus,
+        compact_mode,
         layer_shell,
         wayland_use_legacy_x11_api,
         linux_native_hud,
+        linux_plugin_sandbox,
+        linux_plugin_sandbox_network_fallback,
+    }
+}
+
+fn validate_window_size(size: &WindowSize) -> anyhow::Result<()> {
+    if !size.width.is_finite() || !size.height.is_finite() {
+        return Err(anyhow!("Window size should be a finite number"));
+    }
+
+    if size.width < MIN_WINDOW_WIDTH || size.height < MIN_WINDOW_HEIGHT {
+        return Err(anyhow!(
+            "Window size should be at least {}x{}",
+            MIN_WINDOW_WIDTH,
+            MIN_WINDOW_HEIGHT
+        ));
+    }
+
+    if size.width > MAX_WINDOW_WIDTH || size.height > MAX_WINDOW_HEIGHT {
+        return Err(anyhow!(
+            "Window size should be at most {}x{}",
+            MAX_WINDOW_WIDTH,
+            MAX_WINDOW_HEIGHT
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn size(width: f32, height: f32) -> WindowSize {
+        WindowSize { width, height }
+    }
+
+    #[test]
+    fn window_size_within_bounds_is_valid() {
+        assert!(validate_window_size(&WindowSize::default()).is_ok());
+        assert!(validate_window_size(&size(MIN_WINDOW_WIDTH, MIN_WINDOW_HEIGHT)).is_ok());
+        assert!(validate_window_size(&size(MAX_WINDOW_WIDTH, MAX_WINDOW_HEIGHT)).is_ok());
+    }
+
+    #[test]
+    fn window_size_out_of_bounds_is_rejected() {
+        assert!(validate_window_size(&size(MIN_WINDOW_WIDTH - 1.0, 500.0)).is_err());
+        assert!(validate_window_size(&size(750.0, MIN_WINDOW_HEIGHT - 1.0)).is_err());
+        assert!(validate_window_size(&size(MAX_WINDOW_WIDTH + 1.0, 500.0)).is_err());
+        assert!(validate_window_size(&size(750.0, MAX_WINDOW_HEIGHT + 1.0)).is_err());
+    }
+
+    #[test]
+    fn non_finite_window_size_is_rejected() {
+        assert!(validate_window_size(&size(f32::NAN, 500.0)).is_err());
+        assert!(validate_window_size(&size(750.0, f32::NAN)).is_err());
+        assert!(validate_window_size(&size(f32::INFINITY, 500.0)).is_err());
+        assert!(validate_window_size(&size(750.0, f32::INFINITY)).is_err());
+        assert!(validate_window_size(&size(f32::NEG_INFINITY, 500.0)).is_err());
     }
 }
@@ -1,6 +1,9 @@
+use std::collections::HashMap;
+
 use gauntlet_common::model::EntrypointId;
 use gauntlet_common::model::LocalSaveData;
 use gauntlet_common::model::PluginId;
+use gauntlet_common::model::Quicklink;
 use gauntlet_common::rpc::backend_api::BackendForCliApi;
 use gauntlet_common::rpc::backend_api::BackendForToolsApi;
 use gauntlet_common::rpc::backend_server::start_backend_server;
@@ -52,8 +55,35 @@ impl BackendForCliApi for BackendServerImpl {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
         action_id: String,
+        arguments: HashMap<String, String>,
     ) -> RequestResult<()> {
-        self.proxy.run_action(plugin_id, entrypoint_id, action_id).await?;
+        self.proxy
+            .run_action(plugin_id, entrypoint_id, action_id, arguments)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn open_deep_link(&self, url: String) -> RequestResult<()> {
+        self.proxy.open_deep_link(url).await?;
+
+        Ok(())
+    }
+
+    async fn list_quicklinks(&self) -> RequestResult<Vec<Quicklink>> {
+        let result = self.proxy.list_quicklinks().await?;
+
+        Ok(result)
+    }
+
+    async fn save_quicklink(&self, quicklink: Quicklink) -> RequestResult<String> {
+        let result = self.proxy.save_quicklink(quicklink).await?;
+
+        Ok(result)
+    }
+
+    async fn remove_quicklink(&self, quicklink_id: String) -> RequestResult<()> {
+        self.proxy.remove_quicklink(quicklink_id).await?;
 
         Ok(())
     }
@@ -1,3 +1,4 @@
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::Mutex;
@@ -10,7 +11,10 @@ use gauntlet_common::model::SearchResult;
 use gauntlet_common::model::SearchResultAccessory;
 use gauntlet_common::model::SearchResultEntrypointAction;
 use gauntlet_common::model::SearchResultEntrypointActionType;
+use gauntlet_common::model::SearchResultEntrypointArgument;
 use gauntlet_common::model::SearchResultEntrypointType;
+use gauntlet_common::model::SearchScope;
+use gauntlet_common::model::SearchScopeType;
 use gauntlet_common::rpc::frontend_api::FrontendApi;
 use gauntlet_common::rpc::frontend_api::FrontendApiProxy;
 use tantivy::Index;
@@ -44,6 +48,7 @@ pub struct SearchIndex {
     plugin_name: Field,
     plugin_id: Field,
     entrypoint_alias: Field,
+    entrypoint_keywords: Field,
 }
 
 struct PluginData {
@@ -59,7 +64,11 @@ struct EntrypointData {
     frecency: f64,
     actions: Vec<EntrypointActionData>,
     accessories: Vec<SearchResultAccessory>,
+    arguments: Vec<SearchResultEntrypointArgument>,
+    keywords: Vec<String>,
     search_alias: Option<String>,
+    search_pin: Option<usize>,
+    search_hidden: bool,
 }
 
 struct EntrypointActionData {
@@ -85,6 +94,7 @@ pub struct EntrypointDataView {
     pub entrypoint_generator: Option<(EntrypointId, String)>,
     pub entrypoint_type: SearchResultEntrypointType,
     pub actions: Vec<EntrypointActionDataView>,
+    pub arguments: Vec<SearchResultEntrypointArgument>,
 }
 
 pub struct EntrypointActionDataView {
@@ -104,6 +114,8 @@ pub struct SearchIndexItem {
     pub entrypoint_frecency: f64,
     pub entrypoint_actions: Vec<SearchIndexItemAction>,
     pub entrypoint_accessories: Vec<SearchResultAccessory>,
+    pub entrypoint_arguments: Vec<SearchResultEntrypointArgument>,
+    pub entrypoint_keywords: Vec<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -130,6 +142,7 @@ impl SearchIndex {
             schema_builder.add_text_field("plugin_name", TEXT | STORED);
             schema_builder.add_text_field("plugin_id", STRING | STORED);
             schema_builder.add_text_field("entrypoint_alias", TEXT | STORED);
+            schema_builder.add_text_field("entrypoint_keywords", TEXT);
 
             schema_builder.build()
         };
@@ -145,6 +158,9 @@ impl SearchIndex {
         let entrypoint_alias = schema
             .get_field("entrypoint_alias")
             .expect("plugin_id field should exist");
+        let entrypoint_keywords = schema
+            .get_field("entrypoint_keywords")
+            .expect("entrypoint_keywords field should exist");
 
         let index = Index::create_in_ram(schema.clone());
 
@@ -162,6 +178,7 @@ impl SearchIndex {
             plugin_name,
             plugin_id,
             entrypoint_alias,
+            entrypoint_keywords,
         })
     }
 
@@ -235,6 +252,10 @@ impl SearchIndex {
             document.add_field_value(self.entrypoint_alias, alias.clone())
         }
 
+        for keyword in &entrypoint_data.keywords {
+            document.add_field_value(self.entrypoint_keywords, keyword.clone())
+        }
+
         index_writer.add_document(document)?;
 
         index_writer.commit()?;
@@ -243,6 +264,58 @@ impl SearchIndex {
         Ok(())
     }
 
+    // view entrypoints can be used as search scope by typing their alias followed by space
+    pub fn find_scope(&self, alias: &str) -> Option<SearchScope> {
+        let plugins = self.entrypoint_data.lock().expect("lock is poisoned");
+
+        plugins.iter().find_map(|(plugin_id, plugin_data)| {
+            plugin_data
+                .entrypoints
+                .iter()
+                .find(|(_, entrypoint_data)| {
+                    matches!(entrypoint_data.entrypoint_type, SearchResultEntrypointType::View)
+                        && entrypoint_data
+                            .search_alias
+                            .as_deref()
+                            .is_some_and(|search_alias| search_alias.eq_ignore_ascii_case(alias))
+                })
+                .map(|(entrypoint_id, entrypoint_data)| {
+                    SearchScope {
+                        plugin_id: plugin_id.clone(),
+                        entrypoint_id: entrypoint_id.clone(),
+                        entrypoint_name: entrypoint_data.entrypoint_name.clone(),
+                        scope_type: SearchScopeType::View,
+                    }
+                })
+        })
+    }
+
+    pub fn set_entrypoint_search_pins(&self, pins: Vec<(PluginId, EntrypointId)>) {
+        let mut plugins = self.entrypoint_data.lock().expect("lock is poisoned");
+
+        for (plugin_id, plugin_data) in plugins.iter_mut() {
+            for (entrypoint_id, entrypoint_data) in plugin_data.entrypoints.iter_mut() {
+                entrypoint_data.search_pin = pins.iter().position(|(pin_plugin_id, pin_entrypoint_id)| {
+                    pin_plugin_id == plugin_id && pin_entrypoint_id == entrypoint_id
+                });
+            }
+        }
+    }
+
+    pub fn set_entrypoint_search_hidden(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, hidden: bool) {
+        let mut plugins = self.entrypoint_data.lock().expect("lock is poisoned");
+
+        let Some(plugin_data) = plugins.get_mut(&plugin_id) else {
+            return;
+        };
+
+        let Some(entrypoint_data) = plugin_data.entrypoints.get_mut(&entrypoint_id) else {
+            return;
+        };
+
+        entrypoint_data.search_hidden = hidden;
+    }
+
     pub fn save_for_plugin(
         &self,
         plugin_id: PluginId,
@@ -253,6 +326,8 @@ impl SearchIndex {
         tracing::debug!("Reloading search index for plugin {:?}", plugin_id);
 
         let aliases = self.settings.entrypoint_search_aliases()?;
+        let pins = self.settings.entrypoint_search_pins()?;
+        let hidden = self.settings.entrypoint_search_hidden()?;
 
         // writer panics if another writer exists
         let _guard = self.index_writer_mutex.lock().expect("lock is poisoned");
@@ -287,7 +362,13 @@ impl SearchIndex {
                     frecency: item.entrypoint_frecency,
                     actions,
                     accessories: item.entrypoint_accessories,
+                    arguments: item.entrypoint_arguments,
+                    keywords: item.entrypoint_keywords,
                     search_alias: aliases.get(&(plugin_id.clone(), item.entrypoint_id.clone())).cloned(),
+                    search_pin: pins.iter().position(|(pin_plugin_id, pin_entrypoint_id)| {
+                        pin_plugin_id == &plugin_id && pin_entrypoint_id == &item.entrypoint_id
+                    }),
+                    search_hidden: hidden.contains(&(plugin_id.clone(), item.entrypoint_id.clone())),
                 };
 
                 (item.entrypoint_id.clone(), data)
@@ -324,6 +405,10 @@ impl SearchIndex {
                 document.add_field_value(self.entrypoint_alias, alias.clone())
             }
 
+            for keyword in &entrypoint_data.keywords {
+                document.add_field_value(self.entrypoint_keywords, keyword.clone())
+            }
+
             index_writer.add_document(document)?;
         }
 
@@ -379,6 +464,7 @@ impl SearchIndex {
                                 entrypoint_generator: data.entrypoint_generator.clone(),
                                 entrypoint_type: data.entrypoint_type.clone(),
                                 actions,
+                                arguments: data.arguments.clone(),
                             },
                         )
                     })
@@ -405,13 +491,16 @@ impl SearchIndex {
             self.entrypoint_name,
             self.plugin_name,
             self.entrypoint_alias,
+            self.entrypoint_keywords,
         );
 
+        let query_is_empty = query.is_empty();
+        let query_terms = query_parser.tokenize(query);
         let query = query_parser.create_query(query);
 
         let mut index = 0;
 
-        let fetch = std::iter::from_fn(|| -> Option<anyhow::Result<Vec<(SearchResult, f64)>>> {
+        let fetch = std::iter::from_fn(|| -> Option<anyhow::Result<Vec<(SearchResult, f64, Option<usize>)>>> {
             let result = self.fetch(
                 &entrypoint_data,
                 &query,
@@ -437,9 +526,26 @@ impl SearchIndex {
 
         let mut result = result.into_iter().flatten().collect::<Vec<_>>();
 
-        result.sort_by(|(_, score_a), (_, score_b)| score_b.total_cmp(score_a));
+        // hidden entrypoints are only shown when search query matches their alias
+        result.retain(|(item, _, _)| {
+            !item.entrypoint_hidden || query_parser.matches_alias(&query_terms, item.entrypoint_alias.as_deref())
+        });
+
+        result.sort_by(|(_, score_a, pin_a), (_, score_b, pin_b)| {
+            // pinned entrypoints are shown at the top in user-defined order, but only when nothing is searched
+            if query_is_empty {
+                match (pin_a, pin_b) {
+                    (Some(pin_a), Some(pin_b)) => return pin_a.cmp(pin_b),
+                    (Some(_), None) => return Ordering::Less,
+                    (None, Some(_)) => return Ordering::Greater,
+                    (None, None) => {}
+                }
+            }
+
+            score_b.total_cmp(score_a)
+        });
 
-        let result = result.into_iter().map(|(item, _)| item).collect::<Vec<_>>();
+        let result = result.into_iter().map(|(item, _, _)| item).collect::<Vec<_>>();
 
         drop(entrypoint_data);
 
@@ -452,7 +558,7 @@ impl SearchIndex {
         query: &dyn Query,
         collector: TopDocs,
         searcher: &Searcher,
-    ) -> anyhow::Result<Vec<(SearchResult, f64)>> {
+    ) -> anyhow::Result<Vec<(SearchResult, f64, Option<usize>)>> {
         let get_str_field = |retrieved_doc: &TantivyDocument, field: Field| -> Option<String> {
             retrieved_doc
                 .get_first(field)
@@ -520,9 +626,12 @@ impl SearchIndex {
                     entrypoint_actions,
                     entrypoint_accessories,
                     entrypoint_alias,
+                    entrypoint_pinned: entrypoint_data.search_pin.is_some(),
+                    entrypoint_hidden: entrypoint_data.search_hidden,
+                    entrypoint_arguments: entrypoint_data.arguments.clone(),
                 };
 
-                Ok((result_item, entrypoint_data.frecency))
+                Ok((result_item, entrypoint_data.frecency, entrypoint_data.search_pin))
             })
             .collect::<anyhow::Result<Vec<_>>>()?;
 
@@ -535,6 +644,7 @@ struct QueryParser {
     entrypoint_name: Field,
     plugin_name: Field,
     entrypoint_alias: Field,
+    entrypoint_keywords: Field,
 }
 
 impl QueryParser {
@@ -543,12 +653,14 @@ impl QueryParser {
         entrypoint_name: Field,
         plugin_name: Field,
         entrypoint_alias: Field,
+        entrypoint_keywords: Field,
     ) -> Self {
         Self {
             tokenizer_manager,
             entrypoint_name,
             plugin_name,
             entrypoint_alias,
+            entrypoint_keywords,
         }
     }
 
@@ -578,14 +690,32 @@ impl QueryParser {
         let entrypoint_name_terms = terms_fn(self.entrypoint_name);
         let plugin_name_terms = terms_fn(self.plugin_name);
         let entrypoint_alias_terms = terms_fn(self.entrypoint_alias);
+        let entrypoint_keywords_terms = terms_fn(self.entrypoint_keywords);
 
         Box::new(BooleanQuery::union(vec![
             Box::new(entrypoint_name_terms),
             Box::new(plugin_name_terms),
             Box::new(entrypoint_alias_terms),
+            Box::new(entrypoint_keywords_terms),
         ]))
     }
 
+    fn matches_alias(&self, query_terms: &[String], alias: Option<&str>) -> bool {
+        let Some(alias) = alias else {
+            return false;
+        };
+
+        if query_terms.is_empty() {
+            return false;
+        }
+
+        let alias_terms = self.tokenize(alias);
+
+        query_terms
+            .iter()
+            .all(|query_term| alias_terms.iter().any(|alias_term| alias_term.contains(query_term)))
+    }
+
     fn tokenize(&self, query: &str) -> Vec<String> {
         let mut text_analyzer = self
             .tokenizer_manager
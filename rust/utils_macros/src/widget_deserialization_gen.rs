@@ -18,6 +18,7 @@ pub fn widget_deserialization_gen() -> TokenStream {
     let components = create_component_model();
 
     let mut output_components = vec![];
+    let mut widget_match_arms = vec![];
 
     for component in &components {
         match component {
@@ -231,6 +232,13 @@ pub fn widget_deserialization_gen() -> TokenStream {
                 let widget_struct_name = format!("{}Widget", name);
                 let widget_struct_name = syn::Ident::new(&widget_struct_name, proc_macro2::Span::call_site());
 
+                let react_id = format!("gauntlet:{}", internal_name);
+                let name_ident = syn::Ident::new(&name, proc_macro2::Span::call_site());
+
+                widget_match_arms.push(quote! {
+                    #react_id => UiWidget::#name_ident(#deserialize_method(scope, container)?),
+                });
+
                 output_components.push(quote! {
                     fn #deserialize_method(
                         scope: &mut v8::HandleScope,
@@ -852,6 +860,28 @@ pub fn widget_deserialization_gen() -> TokenStream {
         }
     }
 
+    output_components.push(quote! {
+        pub fn deserialize_widget(
+            scope: &mut v8::HandleScope,
+            container: v8::Local<v8::Value>,
+        ) -> Result<UiWidget> {
+            let container: v8::Local<v8::Object> = container
+                .try_into()
+                .map_err(|_| error_internal!("invalid widget container, expected 'object', got: '{}'", container.type_repr()))?;
+
+            let widget_type = deserialize_widget_type(scope, container)?;
+
+            let widget = match widget_type.as_str() {
+                #(#widget_match_arms)*
+                _ => {
+                    return Err(error_internal!("unknown widget type: '{}'", widget_type));
+                }
+            };
+
+            Ok(widget)
+        }
+    });
+
     let result = quote! {
         #(#output_components)*
     };
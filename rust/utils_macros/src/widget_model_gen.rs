@@ -22,6 +22,7 @@ pub fn widget_model_gen() -> TokenStream {
     let components = create_component_model();
 
     let mut output_components = vec![];
+    let mut widget_names = vec![];
 
     for component in &components {
         match component {
@@ -88,7 +89,7 @@ pub fn widget_model_gen() -> TokenStream {
                     let enum_name = syn::Ident::new(enum_name, proc_macro2::Span::call_site());
 
                     output_components.push(quote! {
-                        #[derive(Debug, Encode, Decode)]
+                        #[derive(Debug, Clone, Encode, Decode)]
                         pub enum #enum_name {
                             #(#component_refs),*
                         }
@@ -194,7 +195,7 @@ pub fn widget_model_gen() -> TokenStream {
                         let widget_content_name = syn::Ident::new(&widget_content_name, proc_macro2::Span::call_site());
 
                         output_components.push(quote! {
-                            #[derive(Debug, Encode, Decode)]
+                            #[derive(Debug, Clone, Encode, Decode)]
                             pub struct #widget_content_name {
                                 #(#props)*
                                 #(#per_type_members)*
@@ -235,7 +236,7 @@ pub fn widget_model_gen() -> TokenStream {
                 let widget_name = syn::Ident::new(&widget_name, proc_macro2::Span::call_site());
 
                 output_components.push(quote! {
-                    #[derive(Debug, Encode, Decode)]
+                    #[derive(Debug, Clone, Encode, Decode)]
                     pub struct #widget_name {
                         pub __id__: UiWidgetId,
                         #(#prop_fields)*
@@ -243,6 +244,146 @@ pub fn widget_model_gen() -> TokenStream {
                     }
                 });
 
+                widget_names.push(name.to_string());
+
+                let name_ident = syn::Ident::new(&name.to_string(), proc_macro2::Span::call_site());
+
+                // only ordered members can be inserted, removed or replaced by patch,
+                // everything else is sent together with properties of the parent widget
+                if !ordered_members.is_empty() {
+                    let ordered_members_name = &format!("{}WidgetOrderedMembers", name);
+                    let ordered_members_name = syn::Ident::new(&ordered_members_name, proc_macro2::Span::call_site());
+
+                    let member_names: Vec<_> = ordered_members
+                        .iter()
+                        .map(|(_member_name, component_ref)| component_ref)
+                        .unique_by(|component_ref| component_ref.component_name.clone())
+                        .map(|component_ref| {
+                            syn::Ident::new(
+                                &component_ref.component_name.to_string(),
+                                proc_macro2::Span::call_site(),
+                            )
+                        })
+                        .collect();
+
+                    output_components.push(quote! {
+                        impl #ordered_members_name {
+                            pub fn widget_id(&self) -> UiWidgetId {
+                                match self {
+                                    #(Self::#member_names(widget) => widget.__id__,)*
+                                }
+                            }
+
+                            fn accepts(widget: &UiWidget) -> bool {
+                                matches!(widget, #(UiWidget::#member_names(_))|*)
+                            }
+
+                            fn from_widget(widget: UiWidget) -> Result<Self, UiWidget> {
+                                match widget {
+                                    #(UiWidget::#member_names(widget) => Ok(Self::#member_names(widget)),)*
+                                    widget @ _ => Err(widget),
+                                }
+                            }
+
+                            fn apply_patch_op(&mut self, op: UiWidgetPatchOp) -> Result<(), UiWidgetPatchOp> {
+                                match self {
+                                    #(Self::#member_names(widget) => widget.apply_patch_op(op),)*
+                                }
+                            }
+                        }
+
+                        impl #widget_name {
+                            pub fn apply_patch_op(&mut self, op: UiWidgetPatchOp) -> Result<(), UiWidgetPatchOp> {
+                                let op = match op {
+                                    UiWidgetPatchOp::UpdateProps { widget: UiWidget::#name_ident(mut widget) } if widget.__id__ == self.__id__ => {
+                                        widget.content.ordered_members = std::mem::take(&mut self.content.ordered_members);
+
+                                        *self = widget;
+
+                                        return Ok(());
+                                    }
+                                    UiWidgetPatchOp::UpdateChildren { widget_id, children } if widget_id == self.__id__ => {
+                                        let valid = {
+                                            let existing_ids: std::collections::HashSet<_> = self.content.ordered_members
+                                                .iter()
+                                                .map(|member| member.widget_id())
+                                                .collect();
+
+                                            children.iter().all(|child| match child {
+                                                UiWidgetPatchChild::Existing(widget_id) => existing_ids.contains(widget_id),
+                                                UiWidgetPatchChild::New(widget) => #ordered_members_name::accepts(widget),
+                                            })
+                                        };
+
+                                        if !valid {
+                                            return Err(UiWidgetPatchOp::UpdateChildren { widget_id, children });
+                                        }
+
+                                        let mut existing: std::collections::HashMap<_, _> = std::mem::take(&mut self.content.ordered_members)
+                                            .into_iter()
+                                            .map(|member| (member.widget_id(), member))
+                                            .collect();
+
+                                        self.content.ordered_members = children
+                                            .into_iter()
+                                            .filter_map(|child| match child {
+                                                UiWidgetPatchChild::Existing(widget_id) => existing.remove(&widget_id),
+                                                UiWidgetPatchChild::New(widget) => #ordered_members_name::from_widget(widget).ok(),
+                                            })
+                                            .collect();
+
+                                        return Ok(());
+                                    }
+                                    UiWidgetPatchOp::Replace { widget } => {
+                                        let widget_id = widget.widget_id();
+
+                                        match self.content.ordered_members.iter_mut().find(|member| member.widget_id() == widget_id) {
+                                            Some(member) => {
+                                                match #ordered_members_name::from_widget(widget) {
+                                                    Ok(widget) => {
+                                                        *member = widget;
+
+                                                        return Ok(());
+                                                    }
+                                                    Err(widget) => return Err(UiWidgetPatchOp::Replace { widget }),
+                                                }
+                                            }
+                                            None => UiWidgetPatchOp::Replace { widget },
+                                        }
+                                    }
+                                    op @ _ => op,
+                                };
+
+                                let mut op = op;
+
+                                for member in &mut self.content.ordered_members {
+                                    match member.apply_patch_op(op) {
+                                        Ok(()) => return Ok(()),
+                                        Err(returned_op) => op = returned_op,
+                                    }
+                                }
+
+                                Err(op)
+                            }
+                        }
+                    })
+                } else {
+                    output_components.push(quote! {
+                        impl #widget_name {
+                            pub fn apply_patch_op(&mut self, op: UiWidgetPatchOp) -> Result<(), UiWidgetPatchOp> {
+                                match op {
+                                    UiWidgetPatchOp::UpdateProps { widget: UiWidget::#name_ident(widget) } if widget.__id__ == self.__id__ => {
+                                        *self = widget;
+
+                                        Ok(())
+                                    }
+                                    op @ _ => Err(op),
+                                }
+                            }
+                        }
+                    })
+                }
+
                 fn generate_union(
                     name: &ComponentName,
                     items: &Vec<PropertyType>,
@@ -265,7 +406,7 @@ pub fn widget_model_gen() -> TokenStream {
                     let enum_name = syn::Ident::new(&enum_name, proc_macro2::Span::call_site());
 
                     quote! {
-                        #[derive(Debug, Encode, Decode)]
+                        #[derive(Debug, Clone, Encode, Decode)]
                         pub enum #enum_name {
                             #(#enum_items),*
                         }
@@ -339,7 +480,7 @@ pub fn widget_model_gen() -> TokenStream {
                                 let type_name = syn::Ident::new(&type_name, proc_macro2::Span::call_site());
 
                                 quote! {
-                                    #[derive(Debug, Encode, Decode)]
+                                    #[derive(Debug, Clone, Encode, Decode)]
                                     pub struct #type_name {
                                         #(#items),*
                                     }
@@ -369,7 +510,7 @@ pub fn widget_model_gen() -> TokenStream {
                                     .collect();
 
                                 quote! {
-                                    #[derive(Debug, Encode, Decode)]
+                                    #[derive(Debug, Clone, Encode, Decode)]
                                     pub enum #type_name {
                                         #(#items),*
                                     }
@@ -397,23 +538,105 @@ pub fn widget_model_gen() -> TokenStream {
                     .collect();
 
                 output_components.push(quote! {
-                    #[derive(Debug, Encode, Decode)]
+                    #[derive(Debug, Clone, Encode, Decode)]
                     pub enum RootWidgetMembers {
                         #(#children),*
                     }
                 });
 
                 output_components.push(quote! {
-                    #[derive(Debug, Encode, Decode)]
+                    #[derive(Debug, Clone, Encode, Decode)]
                     pub struct RootWidget {
                         pub content: Option<RootWidgetMembers>
                     }
+                });
+
+                let member_names: Vec<_> = children
+                    .iter()
+                    .map(|component_ref| {
+                        syn::Ident::new(
+                            &component_ref.component_name.to_string(),
+                            proc_macro2::Span::call_site(),
+                        )
+                    })
+                    .collect();
+
+                output_components.push(quote! {
+                    impl RootWidgetMembers {
+                        pub fn widget_id(&self) -> UiWidgetId {
+                            match self {
+                                #(Self::#member_names(widget) => widget.__id__,)*
+                            }
+                        }
+
+                        fn from_widget(widget: UiWidget) -> Result<Self, UiWidget> {
+                            match widget {
+                                #(UiWidget::#member_names(widget) => Ok(Self::#member_names(widget)),)*
+                                widget @ _ => Err(widget),
+                            }
+                        }
+
+                        fn apply_patch_op(&mut self, op: UiWidgetPatchOp) -> Result<(), UiWidgetPatchOp> {
+                            match self {
+                                #(Self::#member_names(widget) => widget.apply_patch_op(op),)*
+                            }
+                        }
+                    }
+
+                    impl RootWidget {
+                        pub fn apply_patch_op(&mut self, op: UiWidgetPatchOp) -> Result<(), UiWidgetPatchOp> {
+                            let Some(content) = &mut self.content else {
+                                return Err(op);
+                            };
+
+                            let op = match op {
+                                UiWidgetPatchOp::Replace { widget } if widget.widget_id() == content.widget_id() => {
+                                    match RootWidgetMembers::from_widget(widget) {
+                                        Ok(widget) => {
+                                            *content = widget;
+
+                                            return Ok(());
+                                        }
+                                        Err(widget) => return Err(UiWidgetPatchOp::Replace { widget }),
+                                    }
+                                }
+                                op @ _ => op,
+                            };
+
+                            content.apply_patch_op(op)
+                        }
+                    }
                 })
             }
             Component::TextPart { .. } => {}
         }
     }
 
+    let widget_names: Vec<_> = widget_names
+        .iter()
+        .map(|name| syn::Ident::new(name, proc_macro2::Span::call_site()))
+        .collect();
+
+    let widget_types: Vec<_> = widget_names
+        .iter()
+        .map(|name| syn::Ident::new(&format!("{}Widget", name), proc_macro2::Span::call_site()))
+        .collect();
+
+    output_components.push(quote! {
+        #[derive(Debug, Clone, Encode, Decode)]
+        pub enum UiWidget {
+            #(#widget_names(#widget_types)),*
+        }
+
+        impl UiWidget {
+            pub fn widget_id(&self) -> UiWidgetId {
+                match self {
+                    #(UiWidget::#widget_names(widget) => widget.__id__,)*
+                }
+            }
+        }
+    });
+
     let result = quote! {
         #(#output_components)*
     };